@@ -0,0 +1,231 @@
+use crate::Uint;
+use core::cmp::Ordering;
+
+/// Unsigned integer with `W` 32-bit words, so four times as many bits as a `Uint<W>`. This is wide enough to hold every intermediate coefficient that arises when computing a correctly rounded result: at most `2 * PRECISION + 4` decimal digits, which is fewer than `16 * W` bits.
+#[derive(Clone, Copy)]
+pub(super) struct Big<const W: usize> {
+    words: [u32; W],
+}
+
+impl<const W: usize> Big<W> {
+    pub const ZERO: Self = Self { words: [0; W] };
+
+    pub const ONE: Self = Self::from_u32(1);
+
+    #[inline]
+    pub const fn from_u32(n: u32) -> Self {
+        let mut out = Self::ZERO;
+        out.words[0] = n;
+        out
+    }
+
+    pub const fn from_uint(n: Uint<W>) -> Self {
+        let bytes = n.to_le_bytes();
+        let mut out = Self::ZERO;
+        let mut i = 0;
+        while i < W {
+            out.words[i / 4] |= (bytes[i] as u32) << ((i % 4) * 8);
+            i += 1;
+        }
+        out
+    }
+
+    /// `self` must fit in a `Uint<W>`
+    pub const fn to_uint(self) -> Uint<W> {
+        let mut bytes = [0; W];
+        let mut i = 0;
+        while i < W {
+            bytes[i] = (self.words[i / 4] >> ((i % 4) * 8)) as u8;
+            i += 1;
+        }
+        Uint::from_le_bytes(bytes)
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < W {
+            if self.words[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    #[inline]
+    pub const fn is_odd(&self) -> bool {
+        self.words[0] & 1 == 1
+    }
+
+    pub const fn cmp(&self, rhs: &Self) -> Ordering {
+        let mut i = W;
+        while i > 0 {
+            i -= 1;
+            if self.words[i] != rhs.words[i] {
+                return if self.words[i] > rhs.words[i] {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                };
+            }
+        }
+        Ordering::Equal
+    }
+
+    pub const fn add(mut self, rhs: &Self) -> Self {
+        let mut carry = 0;
+        let mut i = 0;
+        while i < W {
+            let sum = self.words[i] as u64 + rhs.words[i] as u64 + carry;
+            self.words[i] = sum as u32;
+            carry = sum >> u32::BITS;
+            i += 1;
+        }
+        debug_assert!(carry == 0);
+        self
+    }
+
+    /// `rhs` must not be larger than `self`
+    pub const fn sub(mut self, rhs: &Self) -> Self {
+        let mut borrow = false;
+        let mut i = 0;
+        while i < W {
+            let (difference, overflow1) = self.words[i].overflowing_sub(rhs.words[i]);
+            let (difference, overflow2) = difference.overflowing_sub(borrow as u32);
+            self.words[i] = difference;
+            borrow = overflow1 || overflow2;
+            i += 1;
+        }
+        debug_assert!(!borrow);
+        self
+    }
+
+    pub const fn mul_small(mut self, rhs: u32) -> Self {
+        let mut carry = 0;
+        let mut i = 0;
+        while i < W {
+            let product = self.words[i] as u64 * rhs as u64 + carry;
+            self.words[i] = product as u32;
+            carry = product >> u32::BITS;
+            i += 1;
+        }
+        debug_assert!(carry == 0);
+        self
+    }
+
+    pub const fn div_rem_small(mut self, rhs: u32) -> (Self, u32) {
+        let mut remainder = 0;
+        let mut i = W;
+        while i > 0 {
+            i -= 1;
+            let dividend = (remainder << u32::BITS) | self.words[i] as u64;
+            self.words[i] = (dividend / rhs as u64) as u32;
+            remainder = dividend % rhs as u64;
+        }
+        (self, remainder as u32)
+    }
+
+    /// the product must fit in a `Big<W>`
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        let mut out = Self::ZERO;
+        let mut i = 0;
+        while i < W {
+            let mut carry = 0;
+            let mut j = 0;
+            while i + j < W {
+                let t = out.words[i + j] as u64 + self.words[i] as u64 * rhs.words[j] as u64 + carry;
+                out.words[i + j] = t as u32;
+                carry = t >> u32::BITS;
+                j += 1;
+            }
+            i += 1;
+        }
+        out
+    }
+
+    #[inline]
+    const fn bit_width(&self) -> u32 {
+        let mut i = W;
+        while i > 0 {
+            i -= 1;
+            if self.words[i] != 0 {
+                return i as u32 * u32::BITS + u32::BITS - self.words[i].leading_zeros();
+            }
+        }
+        0
+    }
+
+    #[inline]
+    const fn shl1(mut self, low_bit: bool) -> Self {
+        let mut carry = low_bit as u32;
+        let mut i = 0;
+        while i < W {
+            let word = self.words[i];
+            self.words[i] = (word << 1) | carry;
+            carry = word >> (u32::BITS - 1);
+            i += 1;
+        }
+        self
+    }
+
+    /// long division. `rhs` must be non-zero
+    pub const fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let mut quotient = Self::ZERO;
+        let mut remainder = Self::ZERO;
+        let mut i = self.bit_width();
+        while i > 0 {
+            i -= 1;
+            let bit = self.words[(i / u32::BITS) as usize] & (1 << (i % u32::BITS)) != 0;
+            remainder = remainder.shl1(bit);
+            if !matches!(remainder.cmp(rhs), Ordering::Less) {
+                remainder = remainder.sub(rhs);
+                quotient.words[(i / u32::BITS) as usize] |= 1 << (i % u32::BITS);
+            }
+        }
+        (quotient, remainder)
+    }
+
+    /// `10^n`, which must fit in a `Big<W>`
+    pub const fn pow10(mut n: u32) -> Self {
+        let mut out = Self::ONE;
+        while n >= 9 {
+            out = out.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        out.mul_small(10u32.pow(n))
+    }
+
+    /// `self * 10^n`, which must fit in a `Big<W>`
+    pub const fn mul_pow10(mut self, mut n: u32) -> Self {
+        while n >= 9 {
+            self = self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        self.mul_small(10u32.pow(n))
+    }
+
+    /// the number of decimal digits of `self`, which is zero if `self` is zero
+    pub const fn digits(&self) -> u32 {
+        // `bit_width * log10(2)` is a lower bound for the number of digits, which is then corrected
+        let mut digits = ((self.bit_width() as u64 * 1233) >> 12) as u32;
+        let mut power = Self::pow10(digits);
+        while !matches!(self.cmp(&power), Ordering::Less) {
+            digits += 1;
+            power = power.mul_small(10);
+        }
+        digits
+    }
+
+    /// writes the decimal digits of `self` as ASCII into the end of `buf`, returning the number written. writes nothing if `self` is zero
+    pub const fn write_digits(mut self, buf: &mut [u8]) -> usize {
+        let mut end = buf.len();
+        while !self.is_zero() {
+            let (quotient, digit) = self.div_rem_small(10);
+            end -= 1;
+            buf[end] = b'0' + digit as u8;
+            self = quotient;
+        }
+        buf.len() - end
+    }
+}
@@ -0,0 +1,143 @@
+use super::{Big, Decimal, Decoded};
+use crate::Uint;
+use core::cmp::{Ordering, PartialEq, PartialOrd};
+
+/// compares the magnitudes of two non-zero finite values
+const fn cmp_magnitude<const W: usize>(a: Uint<W>, a_exp: i32, b: Uint<W>, b_exp: i32) -> Ordering {
+    let (a, b) = (Big::from_uint(a), Big::from_uint(b));
+    let a_adjusted = a_exp as i64 + a.digits() as i64;
+    let b_adjusted = b_exp as i64 + b.digits() as i64;
+    if a_adjusted != b_adjusted {
+        return if a_adjusted > b_adjusted {
+            Ordering::Greater
+        } else {
+            Ordering::Less
+        };
+    }
+    // the adjusted exponents are equal, so the exponents differ by less than `PRECISION`
+    if a_exp > b_exp {
+        a.mul_pow10((a_exp - b_exp) as u32).cmp(&b)
+    } else {
+        a.cmp(&b.mul_pow10((b_exp - a_exp) as u32))
+    }
+}
+
+/// Comparison methods.
+impl<const W: usize> Decimal<W> {
+    /// Compares `self` and `other` numerically, so that values with different exponents but the same value (e.g. `1.0` and `1.00`) are equal, and zeros of either sign are equal. Returns `None` if either value is NaN.
+    #[must_use]
+    pub const fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let (a_negative, b_negative) = (self.is_sign_negative(), other.is_sign_negative());
+        let magnitude = match (self.decode(), other.decode()) {
+            (Decoded::Nan { .. }, _) | (_, Decoded::Nan { .. }) => return None,
+            (Decoded::Infinite { .. }, Decoded::Infinite { .. }) => Ordering::Equal,
+            (Decoded::Infinite { .. }, _) => Ordering::Greater,
+            (_, Decoded::Infinite { .. }) => Ordering::Less,
+            (
+                Decoded::Finite {
+                    coefficient: a,
+                    exponent: a_exp,
+                    ..
+                },
+                Decoded::Finite {
+                    coefficient: b,
+                    exponent: b_exp,
+                    ..
+                },
+            ) => match (a.is_zero(), b.is_zero()) {
+                (true, true) => return Some(Ordering::Equal),
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => cmp_magnitude(a, a_exp, b, b_exp),
+            },
+        };
+        // at most one of the values is zero here, so the signs are significant
+        let ordering = if a_negative != b_negative {
+            if a_negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        } else if a_negative {
+            magnitude.reverse()
+        } else {
+            magnitude
+        };
+        Some(ordering)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn eq(&self, other: &Self) -> bool {
+        matches!(self.partial_cmp(other), Some(Ordering::Equal))
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn lt(&self, other: &Self) -> bool {
+        matches!(self.partial_cmp(other), Some(Ordering::Less))
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn le(&self, other: &Self) -> bool {
+        matches!(self.partial_cmp(other), Some(Ordering::Less | Ordering::Equal))
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn gt(&self, other: &Self) -> bool {
+        matches!(self.partial_cmp(other), Some(Ordering::Greater))
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn ge(&self, other: &Self) -> bool {
+        matches!(self.partial_cmp(other), Some(Ordering::Greater | Ordering::Equal))
+    }
+}
+
+impl<const W: usize> PartialEq for Decimal<W> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        Self::eq(self, other)
+    }
+}
+
+impl<const W: usize> PartialOrd for Decimal<W> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Self::partial_cmp(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::D64;
+    use core::cmp::Ordering;
+    use core::str::FromStr;
+
+    #[test]
+    fn numeric_comparison() {
+        let d = |s: &str| D64::from_str(s).unwrap();
+        assert_eq!(d("1.0"), d("1.00"));
+        assert_eq!(d("-0"), d("0E+10"));
+        assert_eq!(d("1E+3"), d("1000"));
+        assert!(d("1.1") > d("1.099999999999999"));
+        assert!(d("-1E+3") < d("-999.9"));
+        assert!(d("-0.0001") < D64::ZERO);
+        assert!(D64::MAX < D64::INFINITY && D64::NEG_INFINITY < D64::MIN);
+        assert_eq!(D64::NAN.partial_cmp(&D64::ONE), None);
+        assert!(D64::NAN != D64::NAN);
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_antisymmetric(a: D64, b: D64) -> bool {
+            a.partial_cmp(&b) == b.partial_cmp(&a).map(Ordering::reverse)
+        }
+
+        fn quickcheck_neg_reverses(a: D64, b: D64) -> bool {
+            a.partial_cmp(&b) == (-b).partial_cmp(&-a)
+        }
+    }
+}
@@ -0,0 +1,156 @@
+use super::{Big, Decimal, Decoded};
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// enough for the sign, the digits of the largest coefficient of any supported width, a decimal point, up to six leading zeros and an exponent
+const BUF_LEN: usize = 160;
+
+/// a string of ASCII characters built on the stack
+struct Buf {
+    bytes: [u8; BUF_LEN],
+    len: usize,
+}
+
+impl Buf {
+    #[inline]
+    const fn new() -> Self {
+        Self {
+            bytes: [0; BUF_LEN],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    #[inline]
+    fn push_slice(&mut self, bytes: &[u8]) {
+        self.bytes[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    #[inline]
+    fn as_str(&self) -> &str {
+        // only ASCII characters are ever pushed
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+}
+
+impl<const W: usize> Decimal<W> {
+    /// writes `self` in the scientific string format of the General Decimal Arithmetic specification, which is also the format accepted by the `FromStr` implementation
+    fn to_sci_string(self) -> Buf {
+        let mut out = Buf::new();
+        if self.is_sign_negative() {
+            out.push(b'-');
+        }
+        let mut digits = [0; BUF_LEN];
+        match self.decode() {
+            Decoded::Infinite { .. } => out.push_slice(b"Infinity"),
+            Decoded::Nan {
+                signaling, payload, ..
+            } => {
+                if signaling {
+                    out.push(b's');
+                }
+                out.push_slice(b"NaN");
+                let n = Big::from_uint(payload).write_digits(&mut digits);
+                out.push_slice(&digits[BUF_LEN - n..]);
+            }
+            Decoded::Finite {
+                coefficient,
+                exponent,
+                ..
+            } => {
+                let mut n = Big::from_uint(coefficient).write_digits(&mut digits);
+                if n == 0 {
+                    digits[BUF_LEN - 1] = b'0';
+                    n = 1;
+                }
+                let digits = &digits[BUF_LEN - n..];
+                let adjusted = exponent as i64 + n as i64 - 1;
+                if exponent <= 0 && adjusted >= -6 {
+                    let point = n as i64 + exponent as i64;
+                    if exponent == 0 {
+                        out.push_slice(digits);
+                    } else if point > 0 {
+                        out.push_slice(&digits[..point as usize]);
+                        out.push(b'.');
+                        out.push_slice(&digits[point as usize..]);
+                    } else {
+                        out.push_slice(b"0.");
+                        for _ in 0..-point {
+                            out.push(b'0');
+                        }
+                        out.push_slice(digits);
+                    }
+                } else {
+                    out.push(digits[0]);
+                    if n > 1 {
+                        out.push(b'.');
+                        out.push_slice(&digits[1..]);
+                    }
+                    out.push(b'E');
+                    out.push(if adjusted < 0 { b'-' } else { b'+' });
+                    let mut exponent_digits = [0; 20];
+                    let mut i = exponent_digits.len();
+                    let mut adjusted = adjusted.unsigned_abs();
+                    loop {
+                        i -= 1;
+                        exponent_digits[i] = b'0' + (adjusted % 10) as u8;
+                        adjusted /= 10;
+                        if adjusted == 0 {
+                            break;
+                        }
+                    }
+                    out.push_slice(&exponent_digits[i..]);
+                }
+            }
+        }
+        out
+    }
+}
+
+impl<const W: usize> Display for Decimal<W> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.to_sci_string().as_str())
+    }
+}
+
+impl<const W: usize> Debug for Decimal<W> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{D128, D64};
+    use alloc::string::ToString;
+
+    #[test]
+    fn sci_string() {
+        let d = |coefficient: u64, exponent: i32| D64::from_parts(false, coefficient.as_(), exponent).unwrap();
+        assert_eq!(d(0, 0).to_string(), "0");
+        assert_eq!(d(0, -2).to_string(), "0.00");
+        assert_eq!(d(0, 2).to_string(), "0E+2");
+        assert_eq!(d(123, 0).to_string(), "123");
+        assert_eq!(d(123, 1).to_string(), "1.23E+3");
+        assert_eq!(d(123, -1).to_string(), "12.3");
+        assert_eq!(d(123, -5).to_string(), "0.00123");
+        assert_eq!(d(123, -8).to_string(), "0.00000123");
+        assert_eq!(d(123, -9).to_string(), "1.23E-7");
+        assert_eq!(d(5, -12).to_string(), "5E-12");
+        assert_eq!(D64::NEG_ZERO.to_string(), "-0");
+        assert_eq!(D64::MAX.to_string(), "9.999999999999999E+384");
+        assert_eq!(D64::MIN_POSITIVE_SUBNORMAL.to_string(), "1E-398");
+        assert_eq!(D128::MIN.to_string(), "-9.999999999999999999999999999999999E+6144");
+        assert_eq!(D64::NEG_INFINITY.to_string(), "-Infinity");
+        assert_eq!(D64::NAN.to_string(), "NaN");
+        assert_eq!("-sNaN42".parse::<D64>().unwrap().to_string(), "-sNaN42");
+        assert_eq!(format!("{:>6}|{:<6}|{:?}", D64::ONE, D64::NEG_ONE, D64::INFINITY), "     1|-1    |Infinity");
+    }
+}
@@ -0,0 +1,448 @@
+use crate::doc;
+use crate::{Exponent, Uint};
+use core::num::FpCategory;
+
+mod big;
+mod cmp;
+mod fmt;
+mod ops;
+mod parse;
+mod round;
+
+use big::Big;
+
+/// Decimal floating point type, stored in the binary integer decimal (BID) encoding of the `decimal{8W}` interchange format defined in IEEE 754-2019.
+///
+/// A `Decimal<W>` is stored in `W` bytes: `W` must be a multiple of `4`, and at most `48`. The value of a finite `Decimal` is `(-1)^sign * coefficient * 10^exponent`, where the coefficient is an integer with at most [`PRECISION`](Self::PRECISION) decimal digits. Since different pairs of coefficient and exponent can represent the same number (e.g. `1.0` and `1.00`), arithmetic results are given the exponent preferred by IEEE 754 whenever this is possible without rounding, so that trailing zeros are preserved as in decimal arithmetic performed by hand.
+///
+/// All arithmetic operations are correctly rounded according to a [`RoundingMode`](crate::RoundingMode), and the [`FromStr`](core::str::FromStr) and [`Display`](core::fmt::Display) implementations round-trip exactly, preserving the exponent.
+///
+/// # Examples
+///
+/// ```
+/// use bnum::types::D64;
+///
+/// let price: D64 = "19.99".parse().unwrap();
+/// let quantity: D64 = "3".parse().unwrap();
+/// let total = price * quantity;
+/// assert_eq!(total.to_string(), "59.97");
+///
+/// let third = D64::ONE / quantity;
+/// assert_eq!(third.to_string(), "0.3333333333333333");
+/// assert_eq!(third.quantize("0.01".parse().unwrap()).to_string(), "0.33");
+/// ```
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct Decimal<const W: usize> {
+    bits: Uint<W>,
+}
+
+/// the value of a `Decimal` with its encoding fields separated
+#[derive(Clone, Copy)]
+pub(crate) enum Decoded<const W: usize> {
+    Finite {
+        negative: bool,
+        coefficient: Uint<W>,
+        exponent: i32,
+    },
+    Infinite {
+        negative: bool,
+    },
+    Nan {
+        signaling: bool,
+        payload: Uint<W>,
+    },
+}
+
+impl<const W: usize> Decimal<W> {
+    const VALID: () = assert!(
+        W.is_multiple_of(4) && W >= 4 && W <= 48,
+        "the width of a `Decimal` must be a multiple of 4 bytes, and at most 48 bytes"
+    );
+
+    const BITS: Exponent = W as Exponent * 8;
+
+    /// number of bits of the trailing significand field
+    const TRAILING_BITS: Exponent = Self::BITS * 15 / 16 - 10;
+
+    /// number of bits of the biased exponent
+    const EXPONENT_BITS: Exponent = Self::BITS / 16 + 6;
+
+    const SIGN_MASK: Uint<W> = Uint::ONE.shl(Self::BITS - 1);
+
+    /// The number of significant decimal digits.
+    pub const PRECISION: u32 = 9 * W as u32 / 4 - 2;
+
+    /// The maximum exponent of a normal number in scientific notation: every finite value is less than `10^(EMAX + 1)`.
+    pub const EMAX: i32 = 3 << (Self::BITS / 16 + 3);
+
+    /// The minimum exponent of a normal number in scientific notation: every non-zero value whose magnitude is less than `10^EMIN` is subnormal.
+    pub const EMIN: i32 = 1 - Self::EMAX;
+
+    const EXP_BIAS: i32 = Self::EMAX + Self::PRECISION as i32 - 2;
+
+    /// the smallest exponent of the coefficient
+    const MIN_EXP: i32 = -Self::EXP_BIAS;
+
+    /// the largest exponent of the coefficient
+    const MAX_EXP: i32 = Self::EMAX - Self::PRECISION as i32 + 1;
+
+    /// `10^PRECISION - 1`
+    const MAX_COEFFICIENT: Uint<W> = Big::<W>::pow10(Self::PRECISION).sub(&Big::ONE).to_uint();
+
+    /// `10^(PRECISION - 1) - 1`
+    const MAX_PAYLOAD: Uint<W> = Big::<W>::pow10(Self::PRECISION - 1).sub(&Big::ONE).to_uint();
+
+    pub const ZERO: Self = Self::from_finite_parts(false, Uint::ZERO, 0);
+
+    pub const NEG_ZERO: Self = Self::ZERO.neg();
+
+    pub const ONE: Self = Self::from_finite_parts(false, Uint::ONE, 0);
+
+    pub const NEG_ONE: Self = Self::ONE.neg();
+
+    pub const MAX: Self = Self::from_finite_parts(false, Self::MAX_COEFFICIENT, Self::MAX_EXP);
+
+    pub const MIN: Self = Self::MAX.neg();
+
+    pub const MIN_POSITIVE: Self = Self::from_finite_parts(false, Uint::ONE, Self::EMIN);
+
+    pub const MIN_POSITIVE_SUBNORMAL: Self = Self::from_finite_parts(false, Uint::ONE, Self::MIN_EXP);
+
+    pub const EPSILON: Self = Self::from_finite_parts(false, Uint::ONE, 1 - Self::PRECISION as i32);
+
+    pub const INFINITY: Self = Self::from_bits(uint_from_u32(0b11110).shl(Self::BITS - 6));
+
+    pub const NEG_INFINITY: Self = Self::INFINITY.neg();
+
+    pub const NAN: Self = Self::from_bits(uint_from_u32(0b11111).shl(Self::BITS - 6));
+
+    /// Raw transmutation from bits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn from_bits(bits: Uint<W>) -> Self {
+        let () = Self::VALID;
+        Self { bits }
+    }
+
+    /// Raw transmutation to bits.
+    #[must_use = doc::must_use_op!()]
+    #[inline(always)]
+    pub const fn to_bits(self) -> Uint<W> {
+        self.bits
+    }
+
+    /// Creates a finite `Decimal` with value `(-1)^negative * coefficient * 10^exponent`.
+    ///
+    /// Returns `None` if the coefficient has more than [`PRECISION`](Self::PRECISION) digits, or if the exponent is outside the range of the format, in which case the value cannot be represented exactly with this coefficient and exponent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::types::D64;
+    /// use bnum::Uint;
+    /// use bnum::cast::As;
+    ///
+    /// let a = D64::from_parts(false, 1234u32.as_(), -2).unwrap();
+    /// assert_eq!(a.to_string(), "12.34");
+    /// assert_eq!(D64::from_parts(false, Uint::MAX, 0), None);
+    /// ```
+    #[must_use]
+    pub const fn from_parts(negative: bool, coefficient: Uint<W>, exponent: i32) -> Option<Self> {
+        if coefficient.gt(&Self::MAX_COEFFICIENT) || exponent < Self::MIN_EXP || exponent > Self::MAX_EXP {
+            return None;
+        }
+        Some(Self::from_finite_parts(negative, coefficient, exponent))
+    }
+
+    /// Returns the sign, coefficient and exponent of `self` if it is finite, so that `self` is equal to `(-1)^sign * coefficient * 10^exponent`. Non-canonical encodings (whose coefficient has more than [`PRECISION`](Self::PRECISION) digits) are treated as having a coefficient of zero, as required by IEEE 754.
+    ///
+    /// Returns `None` if `self` is infinite or NaN.
+    #[must_use = doc::must_use_op!()]
+    pub const fn to_parts(self) -> Option<(bool, Uint<W>, i32)> {
+        match self.decode() {
+            Decoded::Finite {
+                negative,
+                coefficient,
+                exponent,
+            } => Some((negative, coefficient, exponent)),
+            _ => None,
+        }
+    }
+
+    /// `coefficient` and `exponent` must be in range
+    pub(crate) const fn from_finite_parts(negative: bool, coefficient: Uint<W>, exponent: i32) -> Self {
+        let biased = uint_from_u32::<W>((exponent - Self::MIN_EXP) as u32);
+        let mut bits = if coefficient.bit_width() <= Self::TRAILING_BITS + 3 {
+            biased.shl(Self::TRAILING_BITS + 3).bitor(coefficient)
+        } else {
+            let trailing = coefficient.bitand(Uint::ONE.shl(Self::TRAILING_BITS + 1).sub(Uint::ONE));
+            uint_from_u32::<W>(0b11)
+                .shl(Self::BITS - 3)
+                .bitor(biased.shl(Self::TRAILING_BITS + 1))
+                .bitor(trailing)
+        };
+        if negative {
+            bits = bits.bitor(Self::SIGN_MASK);
+        }
+        Self::from_bits(bits)
+    }
+
+    pub(crate) const fn from_nan_parts(negative: bool, signaling: bool, payload: Uint<W>) -> Self {
+        let mut bits = Self::NAN.bits.bitor(payload);
+        if signaling {
+            bits = bits.bitor(Uint::ONE.shl(Self::BITS - 7));
+        }
+        if negative {
+            bits = bits.bitor(Self::SIGN_MASK);
+        }
+        Self::from_bits(bits)
+    }
+
+    /// the field of `width` bits starting at bit `start`, which must fit in a `u32`
+    #[inline]
+    const fn field(&self, start: Exponent, width: Exponent) -> u32 {
+        let field = self.bits.shr(start).bitand(Uint::ONE.shl(width).sub(Uint::ONE));
+        let bytes = field.to_le_bytes();
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    pub(crate) const fn decode(self) -> Decoded<W> {
+        let negative = self.is_sign_negative();
+        let top = self.field(Self::BITS - 6, 5);
+        if top >> 1 == 0b1111 {
+            if top & 1 == 0 {
+                return Decoded::Infinite { negative };
+            }
+            let mut payload = self.bits.bitand(Uint::ONE.shl(Self::TRAILING_BITS).sub(Uint::ONE));
+            if payload.gt(&Self::MAX_PAYLOAD) {
+                payload = Uint::ZERO;
+            }
+            return Decoded::Nan {
+                signaling: self.bits.bit(Self::BITS - 7),
+                payload,
+            };
+        }
+        let (biased, mut coefficient) = if top >> 3 == 0b11 {
+            let trailing = self.bits.bitand(Uint::ONE.shl(Self::TRAILING_BITS + 1).sub(Uint::ONE));
+            (
+                self.field(Self::TRAILING_BITS + 1, Self::EXPONENT_BITS),
+                trailing.bitor(Uint::ONE.shl(Self::TRAILING_BITS + 3)),
+            )
+        } else {
+            (
+                self.field(Self::TRAILING_BITS + 3, Self::EXPONENT_BITS),
+                self.bits.bitand(Uint::ONE.shl(Self::TRAILING_BITS + 3).sub(Uint::ONE)),
+            )
+        };
+        if coefficient.gt(&Self::MAX_COEFFICIENT) {
+            coefficient = Uint::ZERO;
+        }
+        Decoded::Finite {
+            negative,
+            coefficient,
+            exponent: biased as i32 + Self::MIN_EXP,
+        }
+    }
+}
+
+/// Classification.
+impl<const W: usize> Decimal<W> {
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        self.bits.bit(Self::BITS - 1)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        matches!(self.decode(), Decoded::Nan { .. })
+    }
+
+    /// Returns `true` if `self` is a signaling NaN.
+    #[must_use]
+    #[inline]
+    pub const fn is_signaling_nan(self) -> bool {
+        matches!(self.decode(), Decoded::Nan { signaling: true, .. })
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        matches!(self.decode(), Decoded::Infinite { .. })
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        matches!(self.decode(), Decoded::Finite { .. })
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        matches!(self.decode(), Decoded::Finite { coefficient, .. } if coefficient.is_zero())
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_subnormal(self) -> bool {
+        matches!(self.classify(), FpCategory::Subnormal)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_normal(self) -> bool {
+        matches!(self.classify(), FpCategory::Normal)
+    }
+
+    #[must_use]
+    pub const fn classify(self) -> FpCategory {
+        match self.decode() {
+            Decoded::Nan { .. } => FpCategory::Nan,
+            Decoded::Infinite { .. } => FpCategory::Infinite,
+            Decoded::Finite {
+                coefficient,
+                exponent,
+                ..
+            } => {
+                if coefficient.is_zero() {
+                    FpCategory::Zero
+                } else if adjusted_exponent(coefficient, exponent) < Self::EMIN as i64 {
+                    FpCategory::Subnormal
+                } else {
+                    FpCategory::Normal
+                }
+            }
+        }
+    }
+
+    /// Returns `true` if `self` and `other` have the same exponent, or are both infinite, or are both NaN. This is the `sameQuantum` operation of IEEE 754.
+    #[must_use]
+    pub const fn same_quantum(self, other: Self) -> bool {
+        match (self.decode(), other.decode()) {
+            (Decoded::Finite { exponent: a, .. }, Decoded::Finite { exponent: b, .. }) => a == b,
+            (Decoded::Infinite { .. }, Decoded::Infinite { .. }) => true,
+            (Decoded::Nan { .. }, Decoded::Nan { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Sign manipulation.
+impl<const W: usize> Decimal<W> {
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self::from_bits(self.bits.bitand(Self::SIGN_MASK.not()))
+    }
+
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub const fn neg(self) -> Self {
+        Self::from_bits(self.bits.bitxor(Self::SIGN_MASK))
+    }
+
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub const fn copysign(self, sign: Self) -> Self {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            self.neg()
+        }
+    }
+}
+
+#[inline]
+const fn uint_from_u32<const W: usize>(n: u32) -> Uint<W> {
+    let bytes = n.to_le_bytes();
+    let mut out = [0; W];
+    let mut i = 0;
+    while i < 4 {
+        out[i] = bytes[i];
+        i += 1;
+    }
+    Uint::from_le_bytes(out)
+}
+
+/// the exponent of the most significant digit of `coefficient * 10^exponent`
+#[inline]
+const fn adjusted_exponent<const W: usize>(coefficient: Uint<W>, exponent: i32) -> i64 {
+    exponent as i64 + Big::from_uint(coefficient).digits() as i64 - 1
+}
+
+impl<const W: usize> Default for Decimal<W> {
+    #[doc = doc::default!()]
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(any(test, feature = "quickcheck"))]
+impl<const W: usize> quickcheck::Arbitrary for Decimal<W> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::from_bits(Uint::arbitrary(g))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{D128, D32, D64};
+    use crate::Uint;
+
+    #[test]
+    fn encoding() {
+        assert_eq!(D32::ONE.to_bits(), 0x32800001u32.as_());
+        assert_eq!(D64::ONE.to_bits(), 0x31c0000000000001u64.as_());
+        assert_eq!(D128::ONE.to_bits(), 0x30400000000000000000000000000001u128.as_());
+        assert_eq!(D64::MAX.to_bits(), 0x77fb86f26fc0ffffu64.as_());
+        assert_eq!(D32::MAX.to_bits(), 0x77f8967fu32.as_());
+        assert_eq!(D64::INFINITY.to_bits(), 0x7800000000000000u64.as_());
+        assert_eq!(D64::NAN.to_bits(), 0x7c00000000000000u64.as_());
+        assert_eq!(D64::MIN_POSITIVE_SUBNORMAL.to_bits(), Uint::ONE);
+        assert_eq!(D64::NEG_ZERO.to_bits(), 0xb1c0000000000000u64.as_());
+
+        assert_eq!(D32::PRECISION, 7);
+        assert_eq!(D64::PRECISION, 16);
+        assert_eq!(D128::PRECISION, 34);
+        assert_eq!(D32::EMAX, 96);
+        assert_eq!(D64::EMAX, 384);
+        assert_eq!(D128::EMAX, 6144);
+    }
+
+    #[test]
+    fn non_canonical() {
+        // the coefficient `2^53 + 2^51 - 2` exceeds `10^16 - 1`, so is treated as zero
+        let a = D64::from_bits(0x6ffffffffffffffeu64.as_());
+        assert!(a.is_zero());
+    }
+
+    #[test]
+    fn non_canonical_payload() {
+        let nan = D64::from_bits(D64::NAN.to_bits().bitor(crate::Uint::MAX.shr(14)));
+        assert!(nan.is_nan() && nan.same_quantum(D64::NAN));
+        assert!(matches!(nan.decode(), super::Decoded::Nan { payload, .. } if payload.is_zero()));
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_parts_round_trip(a: D64) -> bool {
+            match a.to_parts() {
+                Some((negative, coefficient, exponent)) => {
+                    let b = D64::from_parts(negative, coefficient, exponent).unwrap();
+                    b.to_parts() == Some((negative, coefficient, exponent))
+                }
+                None => !a.is_finite(),
+            }
+        }
+    }
+}
@@ -0,0 +1,532 @@
+use super::round::div_pow10_with_half_cmp;
+use super::{Big, Decimal, Decoded};
+use crate::doc;
+use crate::helpers::full_op_impl;
+use crate::{FloatStatus, RoundingMode, Uint};
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<const W: usize> Decimal<W> {
+    #[inline]
+    const fn quiet(self) -> Self {
+        Self::from_bits(self.bits.bitand(Uint::ONE.shl(Self::BITS - 7).not()))
+    }
+
+    /// the result of an operation when at least one of the operands is NaN: the first signaling NaN operand is quietened and returned, otherwise the first quiet NaN operand is returned
+    const fn nan_result(a: Self, b: Self) -> Option<(Self, FloatStatus)> {
+        if a.is_signaling_nan() {
+            return Some((a.quiet(), FloatStatus::INVALID));
+        }
+        if b.is_signaling_nan() {
+            return Some((b.quiet(), FloatStatus::INVALID));
+        }
+        if a.is_nan() {
+            return Some((a, FloatStatus::OK));
+        }
+        if b.is_nan() {
+            return Some((b, FloatStatus::OK));
+        }
+        None
+    }
+
+    #[inline]
+    const fn signed_infinity(negative: bool) -> Self {
+        if negative {
+            Self::NEG_INFINITY
+        } else {
+            Self::INFINITY
+        }
+    }
+
+    /// the sign of an exact zero sum of two operands with the given signs
+    #[inline]
+    const fn zero_sum_sign(a_negative: bool, b_negative: bool, rounding: RoundingMode) -> bool {
+        if a_negative == b_negative {
+            a_negative
+        } else {
+            matches!(rounding, RoundingMode::TowardNegative)
+        }
+    }
+
+    fn add_finite(
+        (a_negative, a, a_exp): (bool, Uint<W>, i32),
+        (b_negative, b, b_exp): (bool, Uint<W>, i32),
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let (a, b) = (Big::from_uint(a), Big::from_uint(b));
+        let (a_exp, b_exp) = (a_exp as i64, b_exp as i64);
+        let min_exp = if a_exp < b_exp { a_exp } else { b_exp };
+        let precision = Self::PRECISION as i64;
+
+        match (a.is_zero(), b.is_zero()) {
+            (true, true) => {
+                let negative = Self::zero_sum_sign(a_negative, b_negative, rounding);
+                return Self::round(negative, Big::ZERO, min_exp, rounding);
+            }
+            (true, false) | (false, true) => {
+                // the result is the non-zero operand, with its exponent lowered towards the preferred exponent as far as the precision allows
+                let (negative, c, exp) = if a.is_zero() {
+                    (b_negative, b, b_exp)
+                } else {
+                    (a_negative, a, a_exp)
+                };
+                let shift = (exp - min_exp).min(precision - c.digits() as i64);
+                return Self::round(negative, c.mul_pow10(shift as u32), exp - shift, rounding);
+            }
+            (false, false) => {}
+        }
+
+        let (a_digits, b_digits) = (a.digits() as i64, b.digits() as i64);
+        let (a_adjusted, b_adjusted) = (a_exp + a_digits - 1, b_exp + b_digits - 1);
+        let same_sign = a_negative == b_negative;
+
+        // order the operands so that `x` has the larger magnitude, to within a factor of ten
+        let ((x_negative, x, x_exp, x_digits, x_adjusted), y_adjusted) = if a_adjusted >= b_adjusted {
+            ((a_negative, a, a_exp, a_digits, a_adjusted), b_adjusted)
+        } else {
+            ((b_negative, b, b_exp, b_digits, b_adjusted), a_adjusted)
+        };
+        if y_adjusted <= x_adjusted - precision - 3 {
+            // `y` is less than a hundredth of a unit in the last place of the result, so its only effect on the rounded result is through its sign. so we replace it by a single unit three places below the last place of `x`
+            let shift = precision + 4 - x_digits;
+            let c = x.mul_pow10(shift as u32);
+            let c = if same_sign {
+                c.add(&Big::ONE)
+            } else {
+                c.sub(&Big::ONE)
+            };
+            return Self::round(x_negative, c, x_exp - shift, rounding);
+        }
+
+        // the exact sum has at most `2 * PRECISION + 3` digits, so can be computed exactly
+        let (a, b) = (
+            a.mul_pow10((a_exp - min_exp) as u32),
+            b.mul_pow10((b_exp - min_exp) as u32),
+        );
+        if same_sign {
+            return Self::round(a_negative, a.add(&b), min_exp, rounding);
+        }
+        match a.cmp(&b) {
+            Ordering::Greater => Self::round(a_negative, a.sub(&b), min_exp, rounding),
+            Ordering::Less => Self::round(b_negative, b.sub(&a), min_exp, rounding),
+            Ordering::Equal => {
+                let negative = Self::zero_sum_sign(a_negative, b_negative, rounding);
+                Self::round(negative, Big::ZERO, min_exp, rounding)
+            }
+        }
+    }
+
+    /// Computes `self + rhs`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    ///
+    /// If the result is exact, its exponent is the smaller of the exponents of `self` and `rhs` (or as close to this as possible).
+    #[must_use = doc::must_use_op!(float)]
+    pub fn add_with_status(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        if let Some(result) = Self::nan_result(self, rhs) {
+            return result;
+        }
+        match (self.decode(), rhs.decode()) {
+            (
+                Decoded::Infinite { negative: a },
+                Decoded::Infinite { negative: b },
+            ) => {
+                if a == b {
+                    (self, FloatStatus::OK)
+                } else {
+                    (Self::NAN, FloatStatus::INVALID)
+                }
+            }
+            (Decoded::Infinite { .. }, _) => (self, FloatStatus::OK),
+            (_, Decoded::Infinite { .. }) => (rhs, FloatStatus::OK),
+            (
+                Decoded::Finite {
+                    negative: a_negative,
+                    coefficient: a,
+                    exponent: a_exp,
+                },
+                Decoded::Finite {
+                    negative: b_negative,
+                    coefficient: b,
+                    exponent: b_exp,
+                },
+            ) => Self::add_finite((a_negative, a, a_exp), (b_negative, b, b_exp), rounding),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Computes `self + rhs`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn add_round(self, rhs: Self, rounding: RoundingMode) -> Self {
+        self.add_with_status(rhs, rounding).0
+    }
+
+    /// Computes `self - rhs`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn sub_with_status(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        let rhs = if rhs.is_nan() { rhs } else { rhs.neg() };
+        self.add_with_status(rhs, rounding)
+    }
+
+    /// Computes `self - rhs`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn sub_round(self, rhs: Self, rounding: RoundingMode) -> Self {
+        self.sub_with_status(rhs, rounding).0
+    }
+
+    /// Computes `self * rhs`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    ///
+    /// If the result is exact, its exponent is the sum of the exponents of `self` and `rhs` (or as close to this as possible).
+    #[must_use = doc::must_use_op!(float)]
+    pub fn mul_with_status(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        if let Some(result) = Self::nan_result(self, rhs) {
+            return result;
+        }
+        let negative = self.is_sign_negative() != rhs.is_sign_negative();
+        match (self.decode(), rhs.decode()) {
+            (Decoded::Infinite { .. }, _) | (_, Decoded::Infinite { .. }) => {
+                if self.is_zero() || rhs.is_zero() {
+                    (Self::NAN, FloatStatus::INVALID)
+                } else {
+                    (Self::signed_infinity(negative), FloatStatus::OK)
+                }
+            }
+            (
+                Decoded::Finite {
+                    coefficient: a,
+                    exponent: a_exp,
+                    ..
+                },
+                Decoded::Finite {
+                    coefficient: b,
+                    exponent: b_exp,
+                    ..
+                },
+            ) => {
+                let product = Big::from_uint(a).mul(&Big::from_uint(b));
+                Self::round(negative, product, a_exp as i64 + b_exp as i64, rounding)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Computes `self * rhs`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn mul_round(self, rhs: Self, rounding: RoundingMode) -> Self {
+        self.mul_with_status(rhs, rounding).0
+    }
+
+    /// Computes `self / rhs`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    ///
+    /// If the result is exact, its exponent is the exponent of `self` minus the exponent of `rhs` (or as close to this as possible).
+    #[must_use = doc::must_use_op!(float)]
+    pub fn div_with_status(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        if let Some(result) = Self::nan_result(self, rhs) {
+            return result;
+        }
+        let negative = self.is_sign_negative() != rhs.is_sign_negative();
+        match (self.decode(), rhs.decode()) {
+            (Decoded::Infinite { .. }, Decoded::Infinite { .. }) => (Self::NAN, FloatStatus::INVALID),
+            (Decoded::Infinite { .. }, _) => (Self::signed_infinity(negative), FloatStatus::OK),
+            (_, Decoded::Infinite { .. }) => (
+                Self::from_finite_parts(negative, Uint::ZERO, Self::MIN_EXP),
+                FloatStatus::OK,
+            ),
+            (
+                Decoded::Finite {
+                    coefficient: a,
+                    exponent: a_exp,
+                    ..
+                },
+                Decoded::Finite {
+                    coefficient: b,
+                    exponent: b_exp,
+                    ..
+                },
+            ) => {
+                let (a, b) = (Big::from_uint(a), Big::from_uint(b));
+                let preferred_exp = a_exp as i64 - b_exp as i64;
+                if b.is_zero() {
+                    if a.is_zero() {
+                        return (Self::NAN, FloatStatus::INVALID);
+                    }
+                    return (Self::signed_infinity(negative), FloatStatus::DIV_BY_ZERO);
+                }
+                if a.is_zero() {
+                    return Self::round(negative, Big::ZERO, preferred_exp, rounding);
+                }
+                // scale the dividend so that the quotient has at least `PRECISION + 1` digits
+                let shift = Self::PRECISION + 1 + b.digits() - a.digits();
+                let (mut quotient, remainder) = a.mul_pow10(shift).div_rem(&b);
+                let mut exp = preferred_exp - shift as i64;
+                if remainder.is_zero() {
+                    // the quotient is exact, so remove trailing zeros to get as close as possible to the preferred exponent
+                    while exp < preferred_exp {
+                        let (q, digit) = quotient.div_rem_small(10);
+                        if digit != 0 {
+                            break;
+                        }
+                        quotient = q;
+                        exp += 1;
+                    }
+                } else {
+                    quotient = quotient.mul_small(10).add(&Big::ONE);
+                    exp -= 1;
+                }
+                Self::round(negative, quotient, exp, rounding)
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Computes `self / rhs`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn div_round(self, rhs: Self, rounding: RoundingMode) -> Self {
+        self.div_with_status(rhs, rounding).0
+    }
+
+    /// Returns `self` rounded according to the given rounding mode so that its exponent is equal to the exponent of `quantum`. Returns the result together with the exception flags raised by the operation. This is the `quantize` operation of IEEE 754.
+    ///
+    /// If the result would need more than [`PRECISION`](Self::PRECISION) digits, or if exactly one of `self` and `quantum` is infinite, the result is NaN and the invalid flag is raised.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn quantize_with_status(self, quantum: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        if let Some(result) = Self::nan_result(self, quantum) {
+            return result;
+        }
+        match (self.decode(), quantum.decode()) {
+            (Decoded::Infinite { .. }, Decoded::Infinite { .. }) => (self, FloatStatus::OK),
+            (Decoded::Infinite { .. }, _) | (_, Decoded::Infinite { .. }) => {
+                (Self::NAN, FloatStatus::INVALID)
+            }
+            (
+                Decoded::Finite {
+                    negative,
+                    coefficient,
+                    exponent,
+                },
+                Decoded::Finite {
+                    exponent: target, ..
+                },
+            ) => {
+                let c = Big::from_uint(coefficient);
+                let digits = c.digits();
+                if target >= exponent {
+                    let drop = (target - exponent) as u32;
+                    let n = if drop > digits + 1 { digits + 1 } else { drop };
+                    let (mut quotient, half_cmp, inexact) = div_pow10_with_half_cmp(c, n, digits);
+                    if rounding.round_up(negative, half_cmp, inexact, quotient.is_odd()) {
+                        quotient = quotient.add(&Big::ONE);
+                    }
+                    let status = if inexact {
+                        FloatStatus::INEXACT
+                    } else {
+                        FloatStatus::OK
+                    };
+                    (
+                        Self::from_finite_parts(negative, quotient.to_uint(), target),
+                        status,
+                    )
+                } else {
+                    let shift = (exponent - target) as u32;
+                    if digits != 0 && digits + shift > Self::PRECISION {
+                        return (Self::NAN, FloatStatus::INVALID);
+                    }
+                    let coefficient = if digits == 0 { c } else { c.mul_pow10(shift) };
+                    (
+                        Self::from_finite_parts(negative, coefficient.to_uint(), target),
+                        FloatStatus::OK,
+                    )
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Returns `self` rounded according to the given rounding mode so that its exponent is equal to the exponent of `quantum`.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn quantize_round(self, quantum: Self, rounding: RoundingMode) -> Self {
+        self.quantize_with_status(quantum, rounding).0
+    }
+
+    /// Returns `self` rounded so that its exponent is equal to the exponent of `quantum`, with ties rounding to even.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::types::D64;
+    ///
+    /// let a: D64 = "2.17".parse().unwrap();
+    /// assert_eq!(a.quantize("0.001".parse().unwrap()).to_string(), "2.170");
+    /// assert_eq!(a.quantize("0.1".parse().unwrap()).to_string(), "2.2");
+    /// assert!(a.quantize("1E-20".parse().unwrap()).is_nan());
+    /// ```
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn quantize(self, quantum: Self) -> Self {
+        self.quantize_round(quantum, RoundingMode::DEFAULT)
+    }
+}
+
+impl<const W: usize> Add for Decimal<W> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        self.add_round(rhs, RoundingMode::DEFAULT)
+    }
+}
+
+full_op_impl!(<const W: usize> Add, AddAssign, Decimal<W>, add, add_assign for Decimal<W>);
+
+impl<const W: usize> Sub for Decimal<W> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self.sub_round(rhs, RoundingMode::DEFAULT)
+    }
+}
+
+full_op_impl!(<const W: usize> Sub, SubAssign, Decimal<W>, sub, sub_assign for Decimal<W>);
+
+impl<const W: usize> Mul for Decimal<W> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        self.mul_round(rhs, RoundingMode::DEFAULT)
+    }
+}
+
+full_op_impl!(<const W: usize> Mul, MulAssign, Decimal<W>, mul, mul_assign for Decimal<W>);
+
+impl<const W: usize> Div for Decimal<W> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        self.div_round(rhs, RoundingMode::DEFAULT)
+    }
+}
+
+full_op_impl!(<const W: usize> Div, DivAssign, Decimal<W>, div, div_assign for Decimal<W>);
+
+impl<const W: usize> Neg for Decimal<W> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl<const W: usize> Neg for &Decimal<W> {
+    type Output = Decimal<W>;
+
+    #[inline]
+    fn neg(self) -> Decimal<W> {
+        (*self).neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{D32, D64};
+    use crate::{FloatStatus, RoundingMode};
+
+    use RoundingMode::*;
+
+    macro_rules! test_vectors {
+        ($($op: ident, $rounding: ident, $a: literal, $b: literal, $expected: literal, $status: expr;)*) => {
+            $(
+                let a: D64 = $a.parse().unwrap();
+                let b: D64 = $b.parse().unwrap();
+                let expected: D64 = $expected.parse().unwrap();
+                let (result, status) = paste::paste! { a.[<$op _with_status>](b, $rounding) };
+                assert_eq!(
+                    (result.to_bits(), status),
+                    (expected.to_bits(), $status),
+                    "{} {} {} with {:?}", $a, stringify!($op), $b, $rounding
+                );
+            )*
+        };
+    }
+
+    // reference values computed with the decimal64 context of Python's `decimal` module
+    #[test]
+    fn decimal64_vectors() {
+        test_vectors! {
+            add, TowardZero, "0E+5", "63.864538344525", "63.864538344525", FloatStatus::OK;
+            add, NearestTiesEven, "0.9", "7.008431103671806E+20", "7.008431103671806E+20", FloatStatus::INEXACT;
+            add, TowardNegative, "sNaN7", "0.0000", "NaN7", FloatStatus::INVALID;
+            add, NearestTiesEven, "1E+16", "5", "1.000000000000000E+16", FloatStatus::INEXACT;
+            add, NearestTiesAway, "1E+16", "5", "1.000000000000001E+16", FloatStatus::INEXACT;
+            add, TowardNegative, "1.23", "-1.23", "-0.00", FloatStatus::OK;
+            add, NearestTiesEven, "1.23", "-1.23", "0.00", FloatStatus::OK;
+            add, NearestTiesEven, "1.0", "2.00", "3.00", FloatStatus::OK;
+            add, NearestTiesEven, "Infinity", "-Infinity", "NaN", FloatStatus::INVALID;
+            sub, NearestTiesEven, "7E-11", "-0E-391", "7.000000000000000E-11", FloatStatus::OK;
+            sub, NearestTiesEven, "9.66E+5", "1.108146000E-383", "966000.0000000000", FloatStatus::INEXACT;
+            sub, TowardPositive, "1E+16", "1E-300", "1.000000000000000E+16", FloatStatus::INEXACT;
+            sub, TowardZero, "1E+16", "1E-300", "9999999999999999", FloatStatus::INEXACT;
+            sub, TowardZero, "5.196378982904380E+384", "-5.196378982904380E+384", "9.999999999999999E+384", FloatStatus::INEXACT.union(FloatStatus::OVERFLOW);
+            mul, TowardNegative, "9.431351881967580E+375", "1E+4", "9.431351881967580E+379", FloatStatus::OK;
+            mul, NearestTiesEven, "1.20", "3.0", "3.600", FloatStatus::OK;
+            mul, TowardZero, "1E+353", "-1E+353", "-9.999999999999999E+384", FloatStatus::INEXACT.union(FloatStatus::OVERFLOW);
+            mul, TowardPositive, "-6.91E-391", "7.471854291007463E-366", "-0E-398", FloatStatus::INEXACT.union(FloatStatus::UNDERFLOW);
+            mul, NearestTiesEven, "6.036485882334015E+374", "-2.1402471427E+13", "-Infinity", FloatStatus::INEXACT.union(FloatStatus::OVERFLOW);
+            mul, NearestTiesEven, "-0", "Infinity", "NaN", FloatStatus::INVALID;
+            div, NearestTiesAway, "1.44378252E+51", "0.005", "2.88756504E+53", FloatStatus::OK;
+            div, NearestTiesEven, "6.00", "2", "3.00", FloatStatus::OK;
+            div, NearestTiesEven, "1", "4", "0.25", FloatStatus::OK;
+            div, NearestTiesEven, "1", "3", "0.3333333333333333", FloatStatus::INEXACT;
+            div, TowardPositive, "2", "3", "0.6666666666666667", FloatStatus::INEXACT;
+            div, TowardZero, "-8.7867491030E-388", "5.700E-378", "-1.541534930350877E-10", FloatStatus::INEXACT;
+            div, NearestTiesEven, "-8E-391", "9E+3", "-8.889E-395", FloatStatus::INEXACT.union(FloatStatus::UNDERFLOW);
+            div, TowardNegative, "0.00041", "-4.75E-391", "-Infinity", FloatStatus::INEXACT.union(FloatStatus::OVERFLOW);
+            div, NearestTiesAway, "0", "0", "NaN", FloatStatus::INVALID;
+            div, TowardPositive, "0.0081089", "-0E-350", "-Infinity", FloatStatus::DIV_BY_ZERO;
+            div, NearestTiesEven, "1", "-Infinity", "-0E-398", FloatStatus::OK;
+            quantize, TowardZero, "7.5E+356", "-5E+352", "7.5000E+356", FloatStatus::OK;
+            quantize, NearestTiesEven, "2.5", "1", "2", FloatStatus::INEXACT;
+            quantize, NearestTiesAway, "2.5", "1", "3", FloatStatus::INEXACT;
+            quantize, NearestTiesEven, "-3.5", "1", "-4", FloatStatus::INEXACT;
+            quantize, NearestTiesEven, "-0.0000076000", "-5.494784637379052E+21", "-0E+6", FloatStatus::INEXACT;
+            quantize, TowardPositive, "4E+350", "-3.3E+3", "NaN", FloatStatus::INVALID;
+        }
+    }
+
+    #[test]
+    fn preferred_exponent() {
+        let a: D32 = "1.50".parse().unwrap();
+        let b: D32 = "2.5".parse().unwrap();
+        assert!((a + b).same_quantum("0.00".parse().unwrap()));
+        assert!((a * b).same_quantum("0.000".parse().unwrap()));
+        assert!((a / b).same_quantum("0.0".parse().unwrap()));
+        assert!((a - a).is_zero() && (a - a).is_sign_positive());
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_add_commutative(a: D64, b: D64) -> bool {
+            let (x, y) = (a + b, b + a);
+            (x.is_nan() && y.is_nan()) || x.to_bits() == y.to_bits()
+        }
+
+        fn quickcheck_mul_commutative(a: D64, b: D64) -> bool {
+            let (x, y) = (a * b, b * a);
+            (x.is_nan() && y.is_nan()) || x.to_bits() == y.to_bits()
+        }
+
+        fn quickcheck_sub_is_add_neg(a: D64, b: D64) -> quickcheck::TestResult {
+            if b.is_nan() {
+                return quickcheck::TestResult::discard();
+            }
+            let (x, y) = (a - b, a + (-b));
+            quickcheck::TestResult::from_bool((x.is_nan() && y.is_nan()) || x.to_bits() == y.to_bits())
+        }
+    }
+}
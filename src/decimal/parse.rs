@@ -0,0 +1,190 @@
+use super::{Big, Decimal};
+use crate::errors::{DecimalErrorKind, ParseDecimalError};
+use crate::RoundingMode;
+use core::str::FromStr;
+
+/// splits off the leading ASCII digits of `s`
+#[inline]
+fn split_digits(s: &[u8]) -> (&[u8], &[u8]) {
+    let len = s.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    s.split_at(len)
+}
+
+impl<const W: usize> Decimal<W> {
+    /// the number of significant digits that are kept exactly when parsing. any further digits are only needed to determine whether the value is exactly halfway between two representable values, so they are replaced by a single non-zero digit if any of them are non-zero
+    const PARSE_DIGITS: u32 = 2 * Self::PRECISION + 2;
+
+    fn parse_nan(negative: bool, signaling: bool, payload: &[u8]) -> Result<Self, ParseDecimalError> {
+        if !payload.iter().all(u8::is_ascii_digit) {
+            return Err(ParseDecimalError {
+                kind: DecimalErrorKind::Invalid,
+            });
+        }
+        let payload = payload.iter().skip_while(|digit| **digit == b'0');
+        if payload.clone().count() >= Self::PRECISION as usize {
+            return Err(ParseDecimalError {
+                kind: DecimalErrorKind::Invalid,
+            });
+        }
+        let payload = payload.fold(Big::<W>::ZERO, |payload, digit| {
+            payload.mul_small(10).add(&Big::from_u32((digit - b'0') as u32))
+        });
+        Ok(Self::from_nan_parts(negative, signaling, payload.to_uint()))
+    }
+}
+
+impl<const W: usize> FromStr for Decimal<W> {
+    type Err = ParseDecimalError;
+
+    /// Parses a decimal number, rounding to the nearest representable value (with ties rounding to even). If the number can be represented exactly, its exponent is preserved, so that e.g. `"1.50"` is parsed to a value with coefficient `150` and exponent `-2`.
+    ///
+    /// The accepted grammar is that of the General Decimal Arithmetic specification: an optional sign, followed by either `Inf`, `Infinity`, `NaN` or `sNaN` (case-insensitive, with the NaNs optionally followed by a decimal payload), or by a decimal number with an optional fractional part and an optional exponent, such as `12`, `-1.50`, `.5e-3` or `6.02E+23`.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        const INVALID: ParseDecimalError = ParseDecimalError {
+            kind: DecimalErrorKind::Invalid,
+        };
+
+        let s = src.as_bytes();
+        let (negative, s) = match s.first() {
+            None => {
+                return Err(ParseDecimalError {
+                    kind: DecimalErrorKind::Empty,
+                });
+            }
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            Some(_) => (false, s),
+        };
+        if s.eq_ignore_ascii_case(b"inf") || s.eq_ignore_ascii_case(b"infinity") {
+            return Ok(if negative { Self::NEG_INFINITY } else { Self::INFINITY });
+        }
+        if s.len() >= 3 && s[..3].eq_ignore_ascii_case(b"nan") {
+            return Self::parse_nan(negative, false, &s[3..]);
+        }
+        if s.len() >= 4 && s[..4].eq_ignore_ascii_case(b"snan") {
+            return Self::parse_nan(negative, true, &s[4..]);
+        }
+
+        let (integer, s) = split_digits(s);
+        let (fraction, s) = match s.split_first() {
+            Some((b'.', s)) => split_digits(s),
+            _ => (&s[..0], s),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(INVALID);
+        }
+        let exponent = match s.split_first() {
+            None => 0,
+            Some((b'e' | b'E', s)) => {
+                let (exponent_negative, s) = match s.split_first() {
+                    Some((b'-', s)) => (true, s),
+                    Some((b'+', s)) => (false, s),
+                    _ => (false, s),
+                };
+                let (exponent_digits, s) = split_digits(s);
+                if exponent_digits.is_empty() || !s.is_empty() {
+                    return Err(INVALID);
+                }
+                let exponent = exponent_digits.iter().fold(0i64, |exponent, digit| {
+                    (exponent * 10 + (digit - b'0') as i64).min(1 << 48)
+                });
+                if exponent_negative {
+                    -exponent
+                } else {
+                    exponent
+                }
+            }
+            Some(_) => return Err(INVALID),
+        };
+
+        let mut coefficient = Big::<W>::ZERO;
+        let mut kept = 0;
+        let mut sticky = false;
+        // the exponent of the last digit kept in `coefficient`
+        let mut exponent = exponent + integer.len() as i64;
+        for digit in integer.iter().chain(fraction.iter()) {
+            let digit = (digit - b'0') as u32;
+            if kept < Self::PARSE_DIGITS {
+                exponent -= 1;
+                if kept != 0 || digit != 0 {
+                    coefficient = coefficient.mul_small(10).add(&Big::from_u32(digit));
+                    kept += 1;
+                }
+            } else if digit != 0 {
+                sticky = true;
+            }
+        }
+        if sticky {
+            coefficient = coefficient.mul_small(10).add(&Big::ONE);
+            exponent -= 1;
+        }
+        let (out, _) = Self::round(negative, coefficient, exponent, RoundingMode::NearestTiesEven);
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::errors::{DecimalErrorKind, ParseDecimalError};
+    use crate::types::{D32, D64};
+    use core::str::FromStr;
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            D64::from_str(""),
+            Err(ParseDecimalError {
+                kind: DecimalErrorKind::Empty
+            })
+        );
+        for s in ["-", ".", "e5", "1e", "1e+", "1.2.3", "--1", "1 ", "infinit", "NaN1x", "sNaN-1", "NaN1234567890123456"] {
+            assert_eq!(
+                D64::from_str(s),
+                Err(ParseDecimalError {
+                    kind: DecimalErrorKind::Invalid
+                }),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn exact() {
+        let a = D64::from_str("-12.340").unwrap();
+        assert_eq!(a.to_parts(), Some((true, 12340u32.as_(), -3)));
+        assert_eq!(D64::from_str(".5e+1").unwrap().to_parts(), Some((false, 5u32.as_(), 0)));
+        assert!(D64::from_str("+INF").unwrap().eq(&D64::INFINITY));
+        assert!(D64::from_str("-nan").unwrap().is_sign_negative());
+        // clamping pads the coefficient with zeros
+        assert_eq!(D32::from_str("1E+96").unwrap().to_parts(), Some((false, 1000000u32.as_(), 90)));
+    }
+
+    #[test]
+    fn rounding() {
+        let parse = |s: &str| D64::from_str(s).unwrap().to_bits();
+        assert_eq!(parse("1.00000000000000050"), parse("1.000000000000000"));
+        assert_eq!(parse("1.00000000000000150"), parse("1.000000000000002"));
+        assert_eq!(parse("1.000000000000000500000000000000000000000000001"), parse("1.000000000000001"));
+        assert_eq!(parse("1E+385"), D64::INFINITY.to_bits());
+        assert_eq!(parse("0.5E-398"), parse("0E-398"));
+        assert_eq!(parse("0.51E-398"), parse("1E-398"));
+        assert_eq!(parse("0E-100000000000000"), parse("0E-398"));
+        assert_eq!(parse("0E+100000000000000"), parse("0E+369"));
+    }
+
+    #[cfg(feature = "alloc")]
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: D64) -> bool {
+            use alloc::string::ToString;
+
+            let s = a.to_string();
+            let b: D64 = s.parse().unwrap();
+            match (a.to_parts(), b.to_parts()) {
+                (Some(x), Some(y)) => x == y,
+                (None, None) => b.to_string() == s,
+                _ => false,
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use super::{Big, Decimal};
+use crate::{FloatStatus, RoundingMode};
+use core::cmp::Ordering;
+
+/// `coefficient / 10^n`, and how the remainder compares to half of `10^n`
+pub(super) const fn div_pow10_with_half_cmp<const W: usize>(
+    coefficient: Big<W>,
+    n: u32,
+    digits: u32,
+) -> (Big<W>, Ordering, bool) {
+    if n == 0 {
+        return (coefficient, Ordering::Less, false);
+    }
+    if n > digits {
+        // `coefficient < 10^digits <= 10^(n - 1)`, which is less than half of `10^n`
+        return (Big::ZERO, Ordering::Less, !coefficient.is_zero());
+    }
+    let (quotient, remainder) = coefficient.div_rem(&Big::pow10(n));
+    let half = Big::pow10(n - 1).mul_small(5);
+    (quotient, remainder.cmp(&half), !remainder.is_zero())
+}
+
+impl<const W: usize> Decimal<W> {
+    /// the result of an operation whose rounded result is too large in magnitude to be finite
+    pub(super) const fn overflow(negative: bool, rounding: RoundingMode) -> Self {
+        let infinite = match rounding {
+            RoundingMode::NearestTiesEven | RoundingMode::NearestTiesAway => true,
+            RoundingMode::TowardZero => false,
+            RoundingMode::TowardPositive => !negative,
+            RoundingMode::TowardNegative => negative,
+        };
+        let out = if infinite { Self::INFINITY } else { Self::MAX };
+        if negative { out.neg() } else { out }
+    }
+
+    /// rounds `(-1)^negative * coefficient * 10^exponent` to a `Decimal`, using the largest exponent less than or equal to `exponent` for which this is exact, or the smallest possible exponent if the result is inexact. `coefficient` must have at most `2 * PRECISION + 4` digits. to round a value which is slightly larger in magnitude than `coefficient * 10^exponent`, append a `1` digit to `coefficient`
+    pub(super) const fn round(
+        negative: bool,
+        coefficient: Big<W>,
+        exponent: i64,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let precision = Self::PRECISION as i64;
+        let digits = coefficient.digits();
+        if digits == 0 {
+            let exponent = if exponent < Self::MIN_EXP as i64 {
+                Self::MIN_EXP
+            } else if exponent > Self::MAX_EXP as i64 {
+                Self::MAX_EXP
+            } else {
+                exponent as i32
+            };
+            return (
+                Self::from_finite_parts(negative, crate::Uint::ZERO, exponent),
+                FloatStatus::OK,
+            );
+        }
+        let tiny = exponent + digits as i64 - 1 < Self::EMIN as i64;
+
+        let mut drop = digits as i64 - precision;
+        if exponent + drop < Self::MIN_EXP as i64 {
+            drop = Self::MIN_EXP as i64 - exponent;
+        }
+        let (mut coefficient, mut exponent, inexact) = if drop > 0 {
+            // `drop` could be very large if the value is far below the subnormal range
+            let n = if drop > digits as i64 + 1 {
+                digits + 1
+            } else {
+                drop as u32
+            };
+            let (mut quotient, half_cmp, inexact) = div_pow10_with_half_cmp(coefficient, n, digits);
+            if rounding.round_up(negative, half_cmp, inexact, quotient.is_odd()) {
+                quotient = quotient.add(&Big::ONE);
+            }
+            let mut exponent = exponent + drop;
+            if matches!(quotient.cmp(&Big::pow10(Self::PRECISION)), Ordering::Equal) {
+                quotient = Big::pow10(Self::PRECISION - 1);
+                exponent += 1;
+            }
+            (quotient, exponent, inexact)
+        } else {
+            (coefficient, exponent, false)
+        };
+
+        if exponent > Self::MAX_EXP as i64 {
+            // the coefficient can be padded with zeros if it has few enough digits, otherwise the result overflows
+            let shift = exponent - Self::MAX_EXP as i64;
+            if coefficient.digits() as i64 + shift > precision {
+                return (
+                    Self::overflow(negative, rounding),
+                    FloatStatus::OVERFLOW.union(FloatStatus::INEXACT),
+                );
+            }
+            coefficient = coefficient.mul_pow10(shift as u32);
+            exponent = Self::MAX_EXP as i64;
+        }
+
+        let mut status = FloatStatus::OK;
+        if inexact {
+            status = FloatStatus::INEXACT;
+            if tiny {
+                status = status.union(FloatStatus::UNDERFLOW);
+            }
+        }
+        (
+            Self::from_finite_parts(negative, coefficient.to_uint(), exponent as i32),
+            status,
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// The error type that is returned when writing the bytes of an integer into a buffer fails because the buffer is too small.
+///
+/// This error can occur when calling methods such as [`write_be_bytes_into`](crate::Integer::write_be_bytes_into) or [`write_le_bytes_signed_into`](crate::Integer::write_le_bytes_signed_into).
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct BufferTooSmallError {
+    pub(crate) required: usize,
+}
+
+impl Error for BufferTooSmallError {}
+
+impl BufferTooSmallError {
+    /// Returns the length that the buffer needed to have for the write to succeed.
+    pub const fn required(&self) -> usize {
+        self.required
+    }
+
+    pub(crate) const fn description(&self) -> &str {
+        "attempt to write integer bytes into buffer that is too small"
+    }
+}
+
+impl Display for BufferTooSmallError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {} (required length {})", super::err_prefix!(), self.description(), self.required)
+    }
+}
@@ -0,0 +1,46 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// Enum to store the various types of errors that can cause reading an integer from a LEB128 encoding to fail.
+///
+/// Used as the return type of the [`kind`](ReadLeb128Error::kind) method of [`ReadLeb128Error`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[non_exhaustive]
+pub enum Leb128ErrorKind {
+    /// The bytes ended before a byte without the continuation bit (the most significant bit) was found.
+    UnexpectedEnd,
+    /// The encoded value is too large or too small to be represented by the target type.
+    Overflow,
+}
+
+/// The error type that is returned when reading an integer from a LEB128 encoding fails.
+///
+/// This error can occur when the [`read_uleb128`](crate::Uint::read_uleb128) or [`read_sleb128`](crate::Int::read_sleb128) methods are called with bytes that end before the encoding is complete, or that encode a value that is out of range for the target type.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct ReadLeb128Error {
+    pub(crate) kind: Leb128ErrorKind,
+}
+
+impl Error for ReadLeb128Error {}
+
+impl ReadLeb128Error {
+    /// Returns the enum [`Leb128ErrorKind`], which indicates the reason that reading the integer failed.
+    pub const fn kind(&self) -> &Leb128ErrorKind {
+        &self.kind
+    }
+
+    pub(crate) const fn description(&self) -> &str {
+        match &self.kind {
+            Leb128ErrorKind::UnexpectedEnd => "attempt to read LEB128 integer from incomplete bytes",
+            Leb128ErrorKind::Overflow => {
+                "attempt to read LEB128 integer out of range of the target type"
+            }
+        }
+    }
+}
+
+impl Display for ReadLeb128Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
@@ -5,8 +5,34 @@ mod macros;
 #[allow(unused_imports)]
 pub use macros::*;
 
+mod buffer;
+pub use buffer::*;
+
+mod leb128;
+pub use leb128::*;
+
 mod parseint;
 pub use parseint::*;
 
+#[cfg(all(feature = "float", any(feature = "alloc", feature = "numtraits")))]
+mod parsefloat;
+#[cfg(all(feature = "float", any(feature = "alloc", feature = "numtraits")))]
+pub use parsefloat::*;
+
+#[cfg(feature = "decimal")]
+mod parsedecimal;
+#[cfg(feature = "decimal")]
+pub use parsedecimal::*;
+
+#[cfg(any(feature = "fixed", feature = "scaled"))]
+mod parsefixed;
+#[cfg(any(feature = "fixed", feature = "scaled"))]
+pub use parsefixed::*;
+
+#[cfg(feature = "ratio")]
+mod parseratio;
+#[cfg(feature = "ratio")]
+pub use parseratio::*;
+
 mod tryfrom;
 pub use tryfrom::*;
@@ -0,0 +1,33 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub(crate) enum DecimalErrorKind {
+    Empty,
+    Invalid,
+}
+
+/// The error type that is returned when parsing a decimal float from an invalid source.
+///
+/// This error can occur when the [`FromStr::from_str`](core::str::FromStr::from_str) method of [`Decimal`](crate::Decimal) is called with an input string that is empty or is not a valid decimal number.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseDecimalError {
+    pub(crate) kind: DecimalErrorKind,
+}
+
+impl Error for ParseDecimalError {}
+
+impl ParseDecimalError {
+    pub(crate) const fn description(&self) -> &str {
+        match self.kind {
+            DecimalErrorKind::Empty => "cannot parse decimal from empty string",
+            DecimalErrorKind::Invalid => "invalid decimal literal",
+        }
+    }
+}
+
+impl Display for ParseDecimalError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
@@ -0,0 +1,35 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub(crate) enum FixedErrorKind {
+    Empty,
+    Invalid,
+    Overflow,
+}
+
+/// The error type that is returned when parsing a fixed point number from an invalid source.
+///
+/// This error can occur when the [`FromStr::from_str`](core::str::FromStr::from_str) method of [`Fixed`](crate::Fixed) or [`ScaledDecimal`](crate::ScaledDecimal) is called with an input string that is empty, is not a valid decimal number, or represents a number outside the range of the type.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseFixedError {
+    pub(crate) kind: FixedErrorKind,
+}
+
+impl Error for ParseFixedError {}
+
+impl ParseFixedError {
+    pub(crate) const fn description(&self) -> &str {
+        match self.kind {
+            FixedErrorKind::Empty => "cannot parse fixed point number from empty string",
+            FixedErrorKind::Invalid => "invalid fixed point literal",
+            FixedErrorKind::Overflow => "fixed point literal out of range for the target type",
+        }
+    }
+}
+
+impl Display for ParseFixedError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
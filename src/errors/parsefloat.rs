@@ -0,0 +1,33 @@
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub(crate) enum FloatErrorKind {
+    Empty,
+    Invalid,
+}
+
+/// The error type that is returned when parsing a float from an invalid source.
+///
+/// This error can occur when the [`FromStr::from_str`](core::str::FromStr::from_str) method of [`Float`](crate::Float) (or, with the `numtraits` feature, the `from_str_radix` method of its `num_traits::Num` implementation) is called with an input string that is empty or is not a valid float literal.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseFloatError {
+    pub(crate) kind: FloatErrorKind,
+}
+
+impl Error for ParseFloatError {}
+
+impl ParseFloatError {
+    pub(crate) const fn description(&self) -> &str {
+        match self.kind {
+            FloatErrorKind::Empty => "cannot parse float from empty string",
+            FloatErrorKind::Invalid => "invalid float literal",
+        }
+    }
+}
+
+impl Display for ParseFloatError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
@@ -0,0 +1,34 @@
+use super::ParseIntError;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub(crate) enum RatioErrorKind {
+    Int(ParseIntError),
+    ZeroDenominator,
+}
+
+/// The error type that is returned when parsing a [`Ratio`](crate::Ratio) from an invalid source.
+///
+/// This error can occur when the [`FromStr::from_str`](core::str::FromStr::from_str) method of [`Ratio`](crate::Ratio) is called with an input string whose numerator or denominator is not a valid integer, or whose denominator is zero.
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseRatioError {
+    pub(crate) kind: RatioErrorKind,
+}
+
+impl Error for ParseRatioError {}
+
+impl ParseRatioError {
+    pub(crate) const fn description(&self) -> &str {
+        match &self.kind {
+            RatioErrorKind::Int(err) => err.description(),
+            RatioErrorKind::ZeroDenominator => "attempt to parse ratio with zero denominator",
+        }
+    }
+}
+
+impl Display for ParseRatioError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{} {}", super::err_prefix!(), self.description())
+    }
+}
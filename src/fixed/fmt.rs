@@ -0,0 +1,154 @@
+use crate::fixed_point::wide::{small, Mag, Wide};
+use super::Fixed;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Fixed<S, N, FRAC, OM> {
+    /// `(fraction * 10, digit)`, where `digit` is the integer part of `fraction * 10` and `fraction * 10` has its integer part removed
+    #[inline]
+    fn next_digit(fraction: Wide<N>) -> (Wide<N>, u8) {
+        let scaled = fraction.mul_small(10);
+        (scaled.low_bits(FRAC), scaled.shr(FRAC).lo.to_le_bytes()[0])
+    }
+
+    /// the decimal digits of the fractional part `fraction` (in units of `2^(-FRAC)`), and whether the integer part must be incremented. `integer_odd` is whether the integer part is odd, which is needed to break ties when `precision` is zero. if `precision` is `None`, the shortest sequence of digits which parses back to `self` is returned, otherwise exactly `precision` digits are returned, rounded to nearest with ties to even
+    fn fraction_digits(fraction: Wide<N>, integer_odd: bool, precision: Option<usize>) -> (Vec<u8>, bool) {
+        let one = Wide::from_lo(Mag::<N>::ONE).shl(FRAC);
+        let mut fraction = fraction;
+        let mut digits = Vec::new();
+        let round_up = match precision {
+            Some(precision) => {
+                for _ in 0..precision {
+                    let (rest, digit) = Self::next_digit(fraction);
+                    digits.push(digit);
+                    fraction = rest;
+                }
+                let last_odd = match digits.last() {
+                    Some(digit) => digit % 2 == 1,
+                    None => integer_odd,
+                };
+                // compare `fraction` with half of `one`
+                match fraction.shl(1).cmp(&one) {
+                    Ordering::Less => false,
+                    Ordering::Equal => last_odd,
+                    Ordering::Greater => true,
+                }
+            }
+            None => {
+                if fraction.is_zero() {
+                    return (digits, false);
+                }
+                // half of the distance between adjacent representable values, scaled by `10^(number of digits)`, in units of `2^(-FRAC)`. generating digits stops as soon as truncating or rounding up gives a string within this distance of `self`, which then parses back to `self`
+                let mut half_ulp = Wide::from_lo(small(5));
+                loop {
+                    let (rest, digit) = Self::next_digit(fraction);
+                    digits.push(digit);
+                    fraction = rest;
+                    let truncate = fraction.cmp(&half_ulp).is_lt();
+                    let round_up = fraction.add(half_ulp).cmp(&one).is_gt();
+                    if truncate && round_up {
+                        break fraction.shl(1).cmp(&one).is_gt();
+                    }
+                    if truncate || round_up {
+                        break round_up;
+                    }
+                    half_ulp = half_ulp.mul_small(10);
+                }
+            }
+        };
+        if !round_up {
+            return (digits, false);
+        }
+        for digit in digits.iter_mut().rev() {
+            if *digit == 9 {
+                *digit = 0;
+            } else {
+                *digit += 1;
+                return (digits, false);
+            }
+        }
+        (digits, true)
+    }
+}
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Display for Fixed<S, N, FRAC, OM> {
+    /// Formats `self` in decimal. By default, the shortest string which parses back to `self` is written. If a precision is specified, exactly that many digits are written after the decimal point, with the value rounded to nearest with ties to even.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let magnitude = Wide::from_lo(self.magnitude());
+        let mut integer = magnitude.shr(FRAC).lo;
+        let (digits, carry) = Self::fraction_digits(magnitude.low_bits(FRAC), integer.bit(0), f.precision());
+        if carry {
+            integer = integer.wrapping_add(Mag::ONE);
+        }
+        let mut s = integer.to_str_radix(10);
+        if !digits.is_empty() {
+            s.push('.');
+            s.extend(digits.iter().map(|digit| (b'0' + digit) as char));
+        }
+        f.pad_integral(!self.is_negative(), "", &s)
+    }
+}
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Debug for Fixed<S, N, FRAC, OM> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::{IFixed, UFixed};
+    use alloc::string::ToString;
+
+    type I16F16 = IFixed<4, 16>;
+    type U0F8 = UFixed<1, 8>;
+    type U8F0 = UFixed<1, 0>;
+    type I8F120 = IFixed<16, 120>;
+
+    fn bits(n: i64) -> I16F16 {
+        I16F16::from_bits(n.as_())
+    }
+
+    #[test]
+    fn shortest() {
+        assert_eq!(bits(0).to_string(), "0");
+        assert_eq!(bits(3 << 15).to_string(), "1.5");
+        assert_eq!(bits(-(3 << 15)).to_string(), "-1.5");
+        assert_eq!(bits(6554).to_string(), "0.1");
+        assert_eq!(bits(1).to_string(), "0.00002");
+        assert_eq!(I16F16::MIN.to_string(), "-32768");
+        assert_eq!(I16F16::MAX.to_string(), "32767.99998");
+        assert_eq!(U0F8::MAX.to_string(), "0.996");
+        assert_eq!(U0F8::DELTA.to_string(), "0.004");
+        assert_eq!(U8F0::MAX.to_string(), "255");
+        assert_eq!(I8F120::DELTA.to_string(), "0.000000000000000000000000000000000001");
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(format!("{:.3}", bits(3 << 15)), "1.500");
+        assert_eq!(format!("{:.0}", bits(3 << 15)), "2");
+        assert_eq!(format!("{:.0}", bits(5 << 15)), "2");
+        assert_eq!(format!("{:.1}", bits(-(1 << 14))), "-0.2");
+        assert_eq!(format!("{:.2}", I16F16::MAX), "32768.00");
+        assert_eq!(format!("{:.20}", bits(1)), "0.00001525878906250000");
+        assert_eq!(format!("{:>8}|{:<+6}|{:08.2}|{:?}", bits(3 << 15), bits(1 << 16), bits(-(3 << 15)), bits(1 << 15)), "     1.5|+1    |-0001.50|0.5");
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: I16F16) -> bool {
+            a.to_string().parse::<I16F16>() == Ok(a)
+        }
+
+        fn quickcheck_round_trip_wide(a: I8F120) -> bool {
+            a.to_string().parse::<I8F120>() == Ok(a)
+        }
+
+        fn quickcheck_round_trip_unsigned(a: UFixed<8, 40>) -> bool {
+            a.to_string().parse::<UFixed<8, 40>>() == Ok(a)
+        }
+    }
+}
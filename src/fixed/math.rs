@@ -0,0 +1,120 @@
+use crate::fixed_point::wide::Wide;
+use super::Fixed;
+use crate::doc;
+use crate::RoundingMode;
+use core::cmp::Ordering;
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Fixed<S, N, FRAC, OM> {
+    /// Computes the square root of `self`, rounding according to the given rounding mode, and returning the wrapped result together with whether the result is not representable. The result can only fail to be representable when it is rounded up past [`MAX`](Self::MAX).
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is negative.
+    #[must_use = doc::must_use_op!()]
+    pub const fn overflowing_sqrt_round(self, rounding: RoundingMode) -> (Self, bool) {
+        if self.is_negative() {
+            panic!(crate::errors::err_msg!("argument of square root must be non-negative"));
+        }
+        // `sqrt(bits * 2^(-FRAC)) = sqrt(bits * 2^FRAC) * 2^(-FRAC)`
+        let square = Wide::from_lo(self.magnitude()).shl(FRAC);
+        let root = square.isqrt();
+        let floor_square = Wide::mul(root, root);
+        // the exact root lies in `[root, root + 1)`, and since `square` is an integer, it is never exactly `root + 1/2`, which would require `square = root^2 + root + 1/4`
+        let half_cmp = match square.cmp(&floor_square.add(Wide::from_lo(root))) {
+            Ordering::Greater => Ordering::Greater,
+            _ => Ordering::Less,
+        };
+        let inexact = !matches!(square.cmp(&floor_square), Ordering::Equal);
+        Self::round_magnitude(false, Wide::from_lo(root), half_cmp, inexact, rounding)
+    }
+
+    /// Computes the square root of `self`, rounding according to the given rounding mode.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is negative.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// If the rounded result is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sqrt_round(self, rounding: RoundingMode) -> Self {
+        Self::resolve_overflow(
+            self.overflowing_sqrt_round(rounding),
+            false,
+            crate::errors::err_msg!("attempt to calculate square root with overflow"),
+        )
+    }
+
+    /// Computes the square root of `self`, rounding to the nearest representable value, with ties rounding to even.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is negative.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// If the rounded result is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn sqrt(self) -> Self {
+        self.sqrt_round(RoundingMode::DEFAULT)
+    }
+
+    /// Computes the square root of `self`, rounding to the nearest representable value with ties rounding to even, and returning `None` if `self` is negative or the rounded result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_sqrt(self) -> Option<Self> {
+        if self.is_negative() {
+            return None;
+        }
+        match self.overflowing_sqrt_round(RoundingMode::DEFAULT) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::{IFixed, RoundingMode, UFixed};
+
+    type I16F16 = IFixed<4, 16>;
+    type U0F32 = UFixed<4, 32>;
+    type U32F32 = UFixed<8, 32>;
+
+    #[test]
+    fn sqrt() {
+        let four = I16F16::from_int(4.as_());
+        assert_eq!(four.sqrt(), I16F16::from_int(2.as_()));
+        // sqrt(2) * 2^16 = 92681.9...
+        let two = I16F16::from_int(2.as_());
+        assert_eq!(two.sqrt().to_bits(), 92682.as_());
+        assert_eq!(two.sqrt_round(RoundingMode::TowardZero).to_bits(), 92681.as_());
+        assert_eq!(I16F16::ZERO.sqrt(), I16F16::ZERO);
+        assert_eq!(I16F16::DELTA.sqrt().to_bits(), 256.as_());
+        assert_eq!((-two).checked_sqrt(), None);
+        // the square root of `1 - 2^(-32)` is just below `1 - 2^(-33)`
+        assert_eq!(U0F32::MAX.sqrt(), U0F32::MAX);
+        assert_eq!(U0F32::MAX.overflowing_sqrt_round(RoundingMode::TowardPositive), (U0F32::ZERO, true));
+        assert_eq!(UFixed::<4, 32, 2>::MAX.sqrt_round(RoundingMode::TowardPositive), UFixed::<4, 32, 2>::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "argument of square root must be non-negative")]
+    fn sqrt_negative() {
+        let _ = (-I16F16::DELTA).sqrt();
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_sqrt(a: u32) -> bool {
+            let a = U32F32::from_bits(a.as_());
+            let root: u64 = a.sqrt_round(RoundingMode::TowardZero).to_bits().as_();
+            let square = (a.to_bits().as_::<u128>()) << 32;
+            let root = root as u128;
+            root * root <= square && (root + 1) * (root + 1) > square
+        }
+    }
+}
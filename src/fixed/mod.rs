@@ -0,0 +1,294 @@
+use crate::{doc, Exponent, Integer, OverflowMode};
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+mod fmt;
+mod math;
+mod ops;
+mod parse;
+
+use crate::fixed_point::wide::{Mag, Wide};
+
+/// Fixed point number type with const-generic bit width, number of fractional bits and overflow behaviour.
+///
+/// A `Fixed<S, N, FRAC, OM>` is stored as an [`Integer<S, N, 0, OM>`](crate::Integer) `bits`, and represents the value `bits * 2^(-FRAC)`. So for example, `Fixed<true, 4, 16>` is the signed Q15.16 format, with one sign bit, fifteen integer bits and sixteen fractional bits. `FRAC` must be at most the bit width of the type, `8 * N`.
+///
+/// Addition and subtraction are exact (up to overflow). Multiplication, division and square roots are computed exactly on twice as many bits as the type has, and then correctly rounded to the nearest representable value, with ties rounding to even. The `*_round` methods allow other rounding modes to be used.
+///
+/// As with [`Integer`](crate::Integer), the behaviour of arithmetic operations on overflow is determined by [`Self::OVERFLOW_MODE`]: results either wrap around, cause a panic, or saturate to [`MIN`](Self::MIN) or [`MAX`](Self::MAX). The `checked_*`, `wrapping_*`, `saturating_*` and `overflowing_*` methods are available regardless of the overflow mode.
+///
+/// # Examples
+///
+/// ```
+/// use bnum::IFixed;
+/// use bnum::prelude::*;
+///
+/// // signed, 256 bits, 128 fractional bits
+/// type Q = IFixed<32, 128>;
+///
+/// let a: Q = "2.5".parse().unwrap();
+/// let b: Q = "-0.125".parse().unwrap();
+/// assert_eq!((a * b).to_string(), "-0.3125");
+/// assert_eq!((a / b).to_string(), "-20");
+/// assert_eq!(Q::from_int(n!(2)).sqrt().to_string(), "1.41421356237309504880168872420969807857");
+/// ```
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Fixed<const S: bool, const N: usize, const FRAC: u32, const OM: u8 = { OverflowMode::DEFAULT as u8 }> {
+    bits: Integer<S, N, 0, OM>,
+}
+
+/// Unsigned fixed point type with const-generic bit width, number of fractional bits and overflow behaviour.
+///
+/// For more details on how the const-generic parameters are interpreted, see the documentation for [`Fixed`].
+pub type UFixed<const N: usize, const FRAC: u32, const OM: u8 = { OverflowMode::DEFAULT as u8 }> = Fixed<false, N, FRAC, OM>;
+
+/// Signed fixed point type with const-generic bit width, number of fractional bits and overflow behaviour.
+///
+/// For more details on how the const-generic parameters are interpreted, see the documentation for [`Fixed`].
+pub type IFixed<const N: usize, const FRAC: u32, const OM: u8 = { OverflowMode::DEFAULT as u8 }> = Fixed<true, N, FRAC, OM>;
+
+/// Associated constants.
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Fixed<S, N, FRAC, OM> {
+    const VALID: () = assert!(
+        FRAC <= Integer::<S, N, 0, OM>::BITS,
+        "the number of fractional bits of a `Fixed` must be at most its bit width"
+    );
+
+    /// The overflow mode used for this type, which is the overflow mode of the underlying [`Integer`](crate::Integer) type.
+    pub const OVERFLOW_MODE: OverflowMode = Integer::<S, N, 0, OM>::OVERFLOW_MODE;
+
+    /// The total number of bits of the type.
+    pub const BITS: Exponent = Integer::<S, N, 0, OM>::BITS;
+
+    /// The number of fractional bits.
+    pub const FRAC_BITS: Exponent = FRAC;
+
+    /// The number of integer bits, including the sign bit if the type is signed.
+    pub const INT_BITS: Exponent = Self::BITS - FRAC;
+
+    pub const ZERO: Self = Self::from_bits(Integer::ZERO);
+
+    /// The smallest positive value, `2^(-FRAC)`.
+    pub const DELTA: Self = Self::from_bits(Integer::ONE);
+
+    /// The value one.
+    ///
+    /// Evaluating this constant causes a compile-time error if one is not representable by the type, i.e. if there are not enough integer bits.
+    pub const ONE: Self = {
+        assert!(
+            Self::INT_BITS > S as Exponent,
+            "one is not representable by this `Fixed` type"
+        );
+        Self::from_bits(Integer::ONE.unbounded_shl(FRAC))
+    };
+
+    pub const MIN: Self = Self::from_bits(Integer::MIN);
+
+    pub const MAX: Self = Self::from_bits(Integer::MAX);
+}
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Fixed<S, N, FRAC, OM> {
+    /// Creates a fixed point value from its underlying representation, so that the value is `bits * 2^(-FRAC)`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn from_bits(bits: Integer<S, N, 0, OM>) -> Self {
+        let () = Self::VALID;
+        Self { bits }
+    }
+
+    /// Returns the underlying representation of `self`, so that the value of `self` is `bits * 2^(-FRAC)`.
+    #[must_use = doc::must_use_op!()]
+    #[inline(always)]
+    pub const fn to_bits(self) -> Integer<S, N, 0, OM> {
+        self.bits
+    }
+
+    /// Converts an integer to a fixed point value, returning `None` if the integer is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_from_int(int: Integer<S, N, 0, OM>) -> Option<Self> {
+        match Self::overflowing_from_int(int) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts an integer to a fixed point value, returning the result wrapped around if the integer is not representable, together with whether this occured.
+    #[must_use = doc::must_use_op!()]
+    pub const fn overflowing_from_int(int: Integer<S, N, 0, OM>) -> (Self, bool) {
+        let bits = int.unbounded_shl(FRAC);
+        let overflow = !bits.unbounded_shr(FRAC).eq(&int);
+        (Self::from_bits(bits), overflow)
+    }
+
+    /// Converts an integer to a fixed point value.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// If the integer is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn from_int(int: Integer<S, N, 0, OM>) -> Self {
+        Self::resolve_overflow(
+            Self::overflowing_from_int(int),
+            int.is_negative_internal(),
+            crate::errors::err_msg!("attempt to convert integer to fixed point with overflow"),
+        )
+    }
+
+    /// Returns the integer part of `self`, rounding towards negative infinity. This is the same as `self.to_bits() >> FRAC`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_int(self) -> Integer<S, N, 0, OM> {
+        self.bits.unbounded_shr(FRAC)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        self.bits.is_zero()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.bits.is_negative_internal()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        self.bits.cmp(&other.bits)
+    }
+
+    /// the magnitude of `self`
+    #[inline]
+    const fn magnitude(self) -> Mag<N> {
+        self.bits.unsigned_abs_internal().force()
+    }
+
+    /// the value with the given sign and magnitude, wrapped to the width of the type, together with whether it is out of range
+    const fn from_magnitude(negative: bool, magnitude: Wide<N>) -> (Self, bool) {
+        let lo = if negative {
+            magnitude.lo.wrapping_neg()
+        } else {
+            magnitude.lo
+        };
+        let out = Self::from_bits(lo.force());
+        let overflow = if !magnitude.hi.is_zero() {
+            true
+        } else if !S {
+            negative && !magnitude.lo.is_zero()
+        } else {
+            let sign_bit = Mag::<N>::ONE.unbounded_shl(Self::BITS - 1);
+            match magnitude.lo.cmp(&sign_bit) {
+                Ordering::Less => false,
+                Ordering::Equal => !negative,
+                Ordering::Greater => true,
+            }
+        };
+        (out, overflow)
+    }
+
+    /// the result of an operation according to the overflow mode, given the wrapped result, whether overflow occurred, and the sign of the exact result
+    #[inline]
+    const fn resolve_overflow((out, overflow): (Self, bool), negative: bool, message: &'static str) -> Self {
+        if !overflow {
+            return out;
+        }
+        match Self::OVERFLOW_MODE {
+            OverflowMode::Wrap => out,
+            OverflowMode::Panic => panic!("{}", message),
+            OverflowMode::Saturate => {
+                if negative {
+                    Self::MIN
+                } else {
+                    Self::MAX
+                }
+            }
+        }
+    }
+
+    /// the saturated result of an operation, given the wrapped result, whether overflow occurred, and the sign of the exact result
+    #[inline]
+    const fn saturate((out, overflow): (Self, bool), negative: bool) -> Self {
+        if !overflow {
+            out
+        } else if negative {
+            Self::MIN
+        } else {
+            Self::MAX
+        }
+    }
+}
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Default for Fixed<S, N, FRAC, OM> {
+    #[doc = doc::default!()]
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(any(test, feature = "quickcheck"))]
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> quickcheck::Arbitrary for Fixed<S, N, FRAC, OM> {
+    #[inline]
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::from_bits(Integer::arbitrary(g))
+    }
+}
+
+// implementation if we don't have alloc, as otherwise can't call assert_eq! (since this requires Debug)
+#[cfg(not(feature = "alloc"))]
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> core::fmt::Debug for Fixed<S, N, FRAC, OM> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Fixed").field("bits", &self.bits).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::{IFixed, UFixed};
+
+    type I16F16 = IFixed<4, 16>;
+    type U0F32 = UFixed<4, 32>;
+    type I16F16s = IFixed<4, 16, 2>;
+
+    #[test]
+    fn from_int() {
+        assert_eq!(I16F16::from_int(3.as_()).to_bits(), (3 << 16).as_());
+        assert_eq!(I16F16::from_int((-3).as_()).to_bits(), (-3 << 16).as_());
+        assert_eq!(I16F16::checked_from_int((1 << 15).as_()), None);
+        assert!(I16F16::checked_from_int((-1 << 15).as_()).is_some());
+        assert_eq!(U0F32::checked_from_int(1.as_()), None);
+        assert_eq!(U0F32::checked_from_int(0.as_()), Some(U0F32::ZERO));
+        assert_eq!(I16F16s::from_int(100000.as_()), I16F16s::MAX);
+        assert_eq!(I16F16s::from_int((-100000).as_()), I16F16s::MIN);
+    }
+
+    #[test]
+    fn to_int() {
+        assert_eq!(I16F16::from_bits((7 << 15).as_()).to_int(), 3.as_());
+        assert_eq!(I16F16::from_bits((-7 << 15).as_()).to_int(), (-4).as_());
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to convert integer to fixed point with overflow")]
+    fn from_int_panics() {
+        let _ = IFixed::<4, 16, 1>::from_int((1 << 20).as_());
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_int_round_trip(a: i16) -> bool {
+            I16F16::from_int(a.as_()).to_int() == a.as_()
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use super::Fixed;
+use crate::doc;
+use crate::fixed_point::wide::{Mag, Wide};
+use crate::fixed_point::{exact_op, rounded_op};
+use crate::helpers::full_op_impl;
+use crate::RoundingMode;
+use core::cmp::Ordering;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Fixed<S, N, FRAC, OM> {
+    exact_op!(bits, from_bits; add, checked_add, wrapping_add, saturating_add, overflowing_add, "+");
+    exact_op!(bits, from_bits; sub, checked_sub, wrapping_sub, saturating_sub, overflowing_sub, "-");
+    rounded_op!(mul, mul_round, wrapping_mul, saturating_mul, overflowing_mul, overflowing_mul_round, "*", "attempt to multiply with overflow");
+    rounded_op!(div, div_round, wrapping_div, saturating_div, overflowing_div, overflowing_div_round, "/", "attempt to divide with overflow");
+
+    /// Computes `self * rhs`, rounding to nearest with ties to even, and returning `None` if the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+
+    /// Computes `self / rhs`, rounding to nearest with ties to even, and returning `None` if `rhs` is zero or the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        match self.overflowing_div(rhs) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+
+    /// the value `(-1)^negative * magnitude * 2^(-shift)` rounded according to `rounding`, wrapped to the width of the type, together with whether the rounded value is out of range
+    const fn round_shr(negative: bool, magnitude: Wide<N>, shift: u32, rounding: RoundingMode) -> (Self, bool) {
+        let (quotient, half_cmp, inexact) = magnitude.shr_with_half_cmp(shift);
+        Self::round_magnitude(negative, quotient, half_cmp, inexact, rounding)
+    }
+
+    /// the value `(-1)^negative * (quotient + r)` rounded according to `rounding`, where `0 <= r < 1` compares with `1/2` according to `half_cmp` and is non-zero iff `inexact`, wrapped to the width of the type, together with whether the rounded value is out of range
+    pub(super) const fn round_magnitude(negative: bool, mut quotient: Wide<N>, half_cmp: Ordering, inexact: bool, rounding: RoundingMode) -> (Self, bool) {
+        if rounding.round_up(negative, half_cmp, inexact, quotient.lo.bit(0)) {
+            quotient = quotient.add(Wide::from_lo(Mag::ONE));
+        }
+        Self::from_magnitude(negative, quotient)
+    }
+
+    /// Computes `self * rhs`, rounding the result according to the given rounding mode, and returning the wrapped result together with whether the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn overflowing_mul_round(self, rhs: Self, rounding: RoundingMode) -> (Self, bool) {
+        let negative = self.is_negative() != rhs.is_negative();
+        let product = Wide::mul(self.magnitude(), rhs.magnitude());
+        Self::round_shr(negative, product, FRAC, rounding)
+    }
+
+    /// Computes `self / rhs`, rounding the result according to the given rounding mode, and returning the wrapped result together with whether the result is not representable.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[must_use = doc::must_use_op!()]
+    pub const fn overflowing_div_round(self, rhs: Self, rounding: RoundingMode) -> (Self, bool) {
+        if rhs.is_zero() {
+            panic!(crate::errors::err_msg!(crate::errors::div_by_zero_message!()));
+        }
+        let negative = self.is_negative() != rhs.is_negative();
+        let quotient = Wide::from_lo(self.magnitude())
+            .shl(FRAC)
+            .div_round(rhs.magnitude(), negative, rounding);
+        Self::from_magnitude(negative, quotient)
+    }
+}
+
+/// Sign manipulation.
+impl<const N: usize, const FRAC: u32, const OM: u8> Fixed<true, N, FRAC, OM> {
+    /// Computes `-self`.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// Overflow occurs only if `self` is [`MIN`](Self::MIN), and the result is the same as for the underlying [`Integer`](crate::Integer) type, so depends on [`Self::OVERFLOW_MODE`].
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn neg(self) -> Self {
+        Self::from_bits(self.bits.neg())
+    }
+
+    /// Computes `-self`, returning `None` if `self` is [`MIN`](Self::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.bits.checked_neg() {
+            Some(bits) => Some(Self::from_bits(bits)),
+            None => None,
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// Overflow occurs only if `self` is [`MIN`](Self::MIN), and the result is the same as for the underlying [`Integer`](crate::Integer) type, so depends on [`Self::OVERFLOW_MODE`].
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self::from_bits(self.bits.abs())
+    }
+
+    /// Computes the absolute value of `self`, returning `None` if `self` is [`MIN`](Self::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.bits.checked_abs() {
+            Some(bits) => Some(Self::from_bits(bits)),
+            None => None,
+        }
+    }
+}
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Add for Fixed<S, N, FRAC, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::add(self, rhs)
+    }
+}
+
+full_op_impl!(<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Add, AddAssign, Fixed<S, N, FRAC, OM>, add, add_assign for Fixed<S, N, FRAC, OM>);
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Sub for Fixed<S, N, FRAC, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::sub(self, rhs)
+    }
+}
+
+full_op_impl!(<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Sub, SubAssign, Fixed<S, N, FRAC, OM>, sub, sub_assign for Fixed<S, N, FRAC, OM>);
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Mul for Fixed<S, N, FRAC, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::mul(self, rhs)
+    }
+}
+
+full_op_impl!(<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Mul, MulAssign, Fixed<S, N, FRAC, OM>, mul, mul_assign for Fixed<S, N, FRAC, OM>);
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Div for Fixed<S, N, FRAC, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::div(self, rhs)
+    }
+}
+
+full_op_impl!(<const S: bool, const N: usize, const FRAC: u32, const OM: u8> Div, DivAssign, Fixed<S, N, FRAC, OM>, div, div_assign for Fixed<S, N, FRAC, OM>);
+
+impl<const N: usize, const FRAC: u32, const OM: u8> Neg for Fixed<true, N, FRAC, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl<const N: usize, const FRAC: u32, const OM: u8> Neg for &Fixed<true, N, FRAC, OM> {
+    type Output = Fixed<true, N, FRAC, OM>;
+
+    #[inline]
+    fn neg(self) -> Fixed<true, N, FRAC, OM> {
+        (*self).neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::{IFixed, RoundingMode, UFixed};
+
+    type I16F16 = IFixed<4, 16>;
+    type I16F16w = IFixed<4, 16, 0>;
+    type I16F16s = IFixed<4, 16, 2>;
+    type U0F32 = UFixed<4, 32>;
+    type I1F31 = IFixed<4, 31>;
+
+    fn bits(n: i64) -> I16F16 {
+        I16F16::from_bits(n.as_())
+    }
+
+    /// `a * b`, rounded to nearest with ties to even, computed with primitive arithmetic
+    fn mul_reference(a: i32, b: i32) -> i64 {
+        let product = a as i64 * b as i64;
+        let (quotient, remainder) = (product >> 16, product & 0xffff);
+        if remainder > 0x8000 || (remainder == 0x8000 && quotient & 1 == 1) {
+            quotient + 1
+        } else {
+            quotient
+        }
+    }
+
+    /// `a / b`, rounded to nearest with ties to even, computed with primitive arithmetic
+    fn div_reference(a: i32, b: i32) -> i64 {
+        let (dividend, divisor) = ((a as i64).unsigned_abs() << 16, (b as i64).unsigned_abs());
+        let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+        let quotient = if 2 * remainder > divisor || (2 * remainder == divisor && quotient & 1 == 1) {
+            quotient + 1
+        } else {
+            quotient
+        };
+        if (a < 0) != (b < 0) {
+            -(quotient as i64)
+        } else {
+            quotient as i64
+        }
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(bits(3 << 15) * bits(5 << 15), bits(15 << 14));
+        // 1.5 * 2^-16 rounds to 2 * 2^-16, 2.5 * 2^-16 rounds to 2 * 2^-16
+        assert_eq!(bits(3 << 15) * bits(1), bits(2));
+        assert_eq!(bits(5 << 15) * bits(1), bits(2));
+        assert_eq!(bits(-(5 << 15)) * bits(1), bits(-2));
+        assert_eq!(bits(5 << 15).mul_round(bits(1), RoundingMode::TowardPositive), bits(3));
+        assert_eq!(bits(-(5 << 15)).mul_round(bits(1), RoundingMode::TowardPositive), bits(-2));
+        assert_eq!(bits(5 << 15).mul_round(bits(1), RoundingMode::NearestTiesAway), bits(3));
+        assert_eq!(I16F16::MAX.checked_mul(bits(2 << 16)), None);
+        assert_eq!(I16F16w::MAX.wrapping_mul(I16F16w::from_bits((2 << 16).as_())), I16F16w::from_bits((-2).as_()));
+        assert_eq!(I16F16s::MAX.mul(I16F16s::MIN), I16F16s::MIN);
+        assert_eq!(U0F32::MAX * U0F32::MAX, U0F32::from_bits(0xfffffffeu32.as_()));
+        assert_eq!(I1F31::MIN.overflowing_mul(I1F31::MIN), (I1F31::MIN, true));
+        assert_eq!(I1F31::MIN.saturating_mul(I1F31::MIN), I1F31::MAX);
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(bits(1 << 16) / bits(3 << 16), bits(21845));
+        assert_eq!(bits(2 << 16) / bits(3 << 16), bits(43691));
+        assert_eq!(bits(-(1 << 16)) / bits(4 << 16), bits(-(1 << 14)));
+        assert_eq!(bits(1 << 16).div_round(bits(3 << 16), RoundingMode::TowardNegative), bits(21845));
+        assert_eq!(bits(-(1 << 16)).div_round(bits(3 << 16), RoundingMode::TowardNegative), bits(-21846));
+        assert_eq!(I16F16::MAX.checked_div(bits(1)), None);
+        assert_eq!(I16F16s::MIN / I16F16s::DELTA, I16F16s::MIN);
+        assert_eq!(I16F16w::from_bits(1.as_()).wrapping_div(I16F16w::from_bits(1.as_())), I16F16w::from_bits(65536.as_()));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        assert_eq!(bits(1).checked_div(I16F16::ZERO), None);
+        let _ = bits(1) / I16F16::ZERO;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to multiply with overflow")]
+    fn mul_overflow_panics() {
+        let _ = IFixed::<4, 16, 1>::MAX.mul(IFixed::<4, 16, 1>::MAX);
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_mul(a: i32, b: i32) -> bool {
+            let expected = mul_reference(a, b);
+            let (out, overflow) = bits(a as i64).overflowing_mul(bits(b as i64));
+            overflow == (i32::try_from(expected).is_err()) && out == bits(expected as i32 as i64)
+        }
+
+        fn quickcheck_div(a: i32, b: i32) -> quickcheck::TestResult {
+            if b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let expected = div_reference(a, b);
+            let (out, overflow) = bits(a as i64).overflowing_div(bits(b as i64));
+            quickcheck::TestResult::from_bool(overflow == (i32::try_from(expected).is_err()) && out == bits(expected as i32 as i64))
+        }
+
+        fn quickcheck_div_unsigned(a: u32, b: u32) -> quickcheck::TestResult {
+            if b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let (dividend, divisor) = ((a as u64) << 16, b as u64);
+            let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+            let expected = quotient + (2 * remainder > divisor || (2 * remainder == divisor && quotient & 1 == 1)) as u64;
+            let a = UFixed::<4, 16>::from_bits(a.as_());
+            let b = UFixed::<4, 16>::from_bits(b.as_());
+            quickcheck::TestResult::from_bool(a.checked_div(b) == u32::try_from(expected).ok().map(|bits| UFixed::<4, 16>::from_bits(bits.as_())))
+        }
+
+        fn quickcheck_saturating_mul(a: i32, b: i32) -> bool {
+            let expected = mul_reference(a, b).clamp(i32::MIN as i64, i32::MAX as i64);
+            bits(a as i64).saturating_mul(bits(b as i64)) == bits(expected)
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::fixed_point::wide::{small, Mag, Wide};
+use super::Fixed;
+use crate::errors::{FixedErrorKind, ParseFixedError};
+use crate::RoundingMode;
+use core::cmp::Ordering;
+use core::str::FromStr;
+
+/// splits off the leading ASCII digits of `s`
+#[inline]
+fn split_digits(s: &[u8]) -> (&[u8], &[u8]) {
+    let len = s.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    s.split_at(len)
+}
+
+impl<const S: bool, const N: usize, const FRAC: u32, const OM: u8> FromStr for Fixed<S, N, FRAC, OM> {
+    type Err = ParseFixedError;
+
+    /// Parses a decimal number, rounding to the nearest representable value (with ties rounding to even).
+    ///
+    /// The accepted grammar is an optional sign, followed by decimal digits with an optional fractional part, such as `12`, `-1.50` or `.5`. An error is returned if the rounded value is out of range for the type.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let s = src.as_bytes();
+        let (negative, s) = match s.first() {
+            None => {
+                return Err(ParseFixedError {
+                    kind: FixedErrorKind::Empty,
+                });
+            }
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            Some(_) => (false, s),
+        };
+        let (integer, s) = split_digits(s);
+        let (fraction, s) = match s.split_first() {
+            Some((b'.', s)) => split_digits(s),
+            _ => (&s[..0], s),
+        };
+        if (integer.is_empty() && fraction.is_empty()) || !s.is_empty() {
+            return Err(ParseFixedError {
+                kind: FixedErrorKind::Invalid,
+            });
+        }
+        const OVERFLOW: ParseFixedError = ParseFixedError {
+            kind: FixedErrorKind::Overflow,
+        };
+
+        let mut int = Mag::<N>::ZERO;
+        for digit in integer {
+            let (lo, carry) = int.carrying_mul(small(10), small(digit - b'0'));
+            if !carry.is_zero() {
+                return Err(OVERFLOW);
+            }
+            int = lo;
+        }
+
+        // compute `floor(fraction * 2^(FRAC + 1))` using Horner's method from the last digit, keeping track of whether any of the discarded remainders are non-zero
+        let mut frac = Wide::<N>::ZERO;
+        let mut sticky = false;
+        for digit in fraction.iter().rev() {
+            let (quotient, remainder) = Wide::from_lo(small(digit - b'0'))
+                .shl(FRAC + 1)
+                .add(frac)
+                .div_rem_small(10);
+            frac = quotient;
+            sticky |= remainder != 0;
+        }
+        let round_bit = frac.lo.bit(0);
+        let half_cmp = match (round_bit, sticky) {
+            (false, _) => Ordering::Less,
+            (true, false) => Ordering::Equal,
+            (true, true) => Ordering::Greater,
+        };
+        let magnitude = Wide::from_lo(int).shl(FRAC).add(frac.shr(1));
+        match Self::round_magnitude(negative, magnitude, half_cmp, round_bit || sticky, RoundingMode::NearestTiesEven) {
+            (out, false) => Ok(out),
+            (_, true) => Err(OVERFLOW),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::errors::{FixedErrorKind, ParseFixedError};
+    use crate::{IFixed, UFixed};
+    use core::str::FromStr;
+
+    type I16F16 = IFixed<4, 16>;
+    type U0F8 = UFixed<1, 8>;
+    type U8F0 = UFixed<1, 0>;
+
+    fn bits(n: i64) -> I16F16 {
+        I16F16::from_bits(n.as_())
+    }
+
+    fn err<T>(kind: FixedErrorKind) -> Result<T, ParseFixedError> {
+        Err(ParseFixedError { kind })
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(I16F16::from_str(""), err(FixedErrorKind::Empty));
+        for s in ["-", ".", "+.", "1.2.3", "--1", "1 ", "1e5", "0x10", "١"] {
+            assert_eq!(I16F16::from_str(s), err(FixedErrorKind::Invalid), "{s}");
+        }
+        for s in ["32768", "-32768.00001", "32767.999993", "1000000000000"] {
+            assert_eq!(I16F16::from_str(s), err(FixedErrorKind::Overflow), "{s}");
+        }
+        assert_eq!(U0F8::from_str("0.999"), err(FixedErrorKind::Overflow));
+        assert_eq!(U0F8::from_str("-0.002"), err(FixedErrorKind::Overflow));
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(I16F16::from_str("1.5").unwrap(), bits(3 << 15));
+        assert_eq!(I16F16::from_str("-.25").unwrap(), bits(-(1 << 14)));
+        assert_eq!(I16F16::from_str("+0003.").unwrap(), bits(3 << 16));
+        assert_eq!(I16F16::from_str("-32768").unwrap(), I16F16::MIN);
+        assert_eq!(I16F16::from_str("0.0000152587890625").unwrap(), I16F16::DELTA);
+        assert_eq!(I16F16::from_str("-0").unwrap(), I16F16::ZERO);
+        assert_eq!(U0F8::from_str("-0.0").unwrap(), U0F8::ZERO);
+        // rounds to zero, so is in range
+        assert_eq!(U0F8::from_str("-0.001").unwrap(), U0F8::ZERO);
+        assert_eq!(U8F0::from_str("255").unwrap(), U8F0::MAX);
+    }
+
+    #[test]
+    fn rounding() {
+        // 2^-17 is exactly halfway between 0 and 2^-16
+        assert_eq!(I16F16::from_str("0.00000762939453125").unwrap(), bits(0));
+        assert_eq!(I16F16::from_str("0.000007629394531250001").unwrap(), bits(1));
+        assert_eq!(I16F16::from_str("0.00002288818359375").unwrap(), bits(2));
+        assert_eq!(I16F16::from_str("-0.00002288818359375").unwrap(), bits(-2));
+        assert_eq!(I16F16::from_str("0.1").unwrap(), bits(6554));
+        assert_eq!(I16F16::from_str("32767.999992").unwrap(), I16F16::MAX);
+        assert_eq!(U8F0::from_str("254.5").unwrap(), U8F0::from_bits(254.as_()));
+        assert_eq!(U8F0::from_str("253.5").unwrap(), U8F0::from_bits(254.as_()));
+        assert_eq!(U0F8::from_str("0.998").unwrap(), U0F8::MAX);
+    }
+}
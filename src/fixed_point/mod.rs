@@ -0,0 +1,107 @@
+//! helpers shared by the fixed point types [`Fixed`](crate::Fixed) and [`ScaledDecimal`](crate::ScaledDecimal)
+
+pub(crate) mod wide;
+
+/// implements an operation which is exact up to overflow by delegating to the underlying integer `$field`, from which `Self` is constructed by `$from`
+macro_rules! exact_op {
+    ($field: ident, $from: ident; $op: ident, $checked: ident, $wrapping: ident, $saturating: ident, $overflowing: ident, $symbol: literal) => {
+        #[doc = concat!("Computes `self ", $symbol, " rhs`.")]
+        ///
+        /// # Overflow behaviour
+        ///
+        /// The result on overflow is the same as for the corresponding operation on the underlying [`Integer`](crate::Integer) type, and so depends on [`Self::OVERFLOW_MODE`].
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $op(self, rhs: Self) -> Self {
+            Self::$from(self.$field.$op(rhs.$field))
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, returning `None` if overflow occurs.")]
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $checked(self, rhs: Self) -> Option<Self> {
+            match self.$field.$checked(rhs.$field) {
+                Some(bits) => Some(Self::$from(bits)),
+                None => None,
+            }
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, wrapping around on overflow.")]
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $wrapping(self, rhs: Self) -> Self {
+            Self::$from(self.$field.$wrapping(rhs.$field))
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, saturating at the numeric bounds on overflow.")]
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $saturating(self, rhs: Self) -> Self {
+            Self::$from(self.$field.$saturating(rhs.$field))
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, returning the wrapped result together with whether overflow occurred.")]
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $overflowing(self, rhs: Self) -> (Self, bool) {
+            let (bits, overflow) = self.$field.$overflowing(rhs.$field);
+            (Self::$from(bits), overflow)
+        }
+    };
+}
+
+pub(crate) use exact_op;
+
+/// implements an operation which is rounded according to a `RoundingMode`, given the `$overflowing_round` method which computes the rounded and wrapped result together with whether it is out of range
+macro_rules! rounded_op {
+    ($op: ident, $round: ident, $wrapping: ident, $saturating: ident, $overflowing: ident, $overflowing_round: ident, $symbol: literal, $message: literal) => {
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, rounding the result to the nearest representable value, with ties rounding to even.")]
+        ///
+        /// # Overflow behaviour
+        ///
+        /// If the result is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $op(self, rhs: Self) -> Self {
+            self.$round(rhs, crate::RoundingMode::DEFAULT)
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, rounding the result according to the given rounding mode.")]
+        ///
+        /// # Overflow behaviour
+        ///
+        /// If the result is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $round(self, rhs: Self, rounding: crate::RoundingMode) -> Self {
+            Self::resolve_overflow(
+                self.$overflowing_round(rhs, rounding),
+                self.is_negative() != rhs.is_negative(),
+                crate::errors::err_msg!($message),
+            )
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, rounding to nearest with ties to even, and wrapping around if the result is not representable.")]
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $wrapping(self, rhs: Self) -> Self {
+            self.$overflowing(rhs).0
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, rounding to nearest with ties to even, and saturating at the numeric bounds if the result is not representable.")]
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $saturating(self, rhs: Self) -> Self {
+            Self::saturate(self.$overflowing(rhs), self.is_negative() != rhs.is_negative())
+        }
+
+        #[doc = concat!("Computes `self ", $symbol, " rhs`, rounding to nearest with ties to even, and returning the wrapped result together with whether the result is not representable.")]
+        #[must_use = crate::doc::must_use_op!()]
+        #[inline]
+        pub const fn $overflowing(self, rhs: Self) -> (Self, bool) {
+            self.$overflowing_round(rhs, crate::RoundingMode::DEFAULT)
+        }
+    };
+}
+
+pub(crate) use rounded_op;
@@ -0,0 +1,191 @@
+use crate::{Exponent, RoundingMode, Uint};
+use core::cmp::Ordering;
+
+/// unsigned integer used for the magnitudes of fixed point and scaled decimal values, which wraps on overflow regardless of the overflow mode of the type
+pub(crate) type Mag<const N: usize> = Uint<N, 0, 0>;
+
+/// unsigned integer with twice as many bits as a `Mag<N>`, used to hold exact intermediate results
+#[derive(Clone, Copy)]
+pub(crate) struct Wide<const N: usize> {
+    pub lo: Mag<N>,
+    pub hi: Mag<N>,
+}
+
+#[inline]
+pub(crate) const fn small<const N: usize>(n: u8) -> Mag<N> {
+    let mut bytes = [0; N];
+    bytes[0] = n;
+    Mag::from_le_bytes(bytes)
+}
+
+#[cfg_attr(not(feature = "fixed"), allow(dead_code))] // some of these methods are only used by `Fixed`
+impl<const N: usize> Wide<N> {
+    pub const ZERO: Self = Self::from_lo(Mag::ZERO);
+
+    #[inline]
+    pub const fn from_lo(lo: Mag<N>) -> Self {
+        Self { lo, hi: Mag::ZERO }
+    }
+
+    #[inline]
+    pub const fn mul(a: Mag<N>, b: Mag<N>) -> Self {
+        let (lo, hi) = a.widening_mul(b);
+        Self { lo, hi }
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+
+    #[inline]
+    pub const fn cmp(&self, rhs: &Self) -> Ordering {
+        match self.hi.cmp(&rhs.hi) {
+            Ordering::Equal => self.lo.cmp(&rhs.lo),
+            ordering => ordering,
+        }
+    }
+
+    pub const fn add(self, rhs: Self) -> Self {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let hi = self.hi.wrapping_add(rhs.hi);
+        Self {
+            lo,
+            hi: if carry { hi.wrapping_add(Mag::ONE) } else { hi },
+        }
+    }
+
+    pub const fn shl(self, n: Exponent) -> Self {
+        if n == 0 {
+            return self;
+        }
+        if n >= Mag::<N>::BITS {
+            return Self {
+                lo: Mag::ZERO,
+                hi: self.lo.unbounded_shl(n - Mag::<N>::BITS),
+            };
+        }
+        Self {
+            lo: self.lo.unbounded_shl(n),
+            hi: self
+                .hi
+                .unbounded_shl(n)
+                .bitor(self.lo.unbounded_shr(Mag::<N>::BITS - n)),
+        }
+    }
+
+    pub const fn shr(self, n: Exponent) -> Self {
+        if n == 0 {
+            return self;
+        }
+        if n >= Mag::<N>::BITS {
+            return Self::from_lo(self.hi.unbounded_shr(n - Mag::<N>::BITS));
+        }
+        Self {
+            lo: self
+                .lo
+                .unbounded_shr(n)
+                .bitor(self.hi.unbounded_shl(Mag::<N>::BITS - n)),
+            hi: self.hi.unbounded_shr(n),
+        }
+    }
+
+    /// `self` with all bits at or above index `n` cleared
+    pub const fn low_bits(self, n: Exponent) -> Self {
+        if n >= Mag::<N>::BITS {
+            let mask = Mag::<N>::MAX.unbounded_shr(2 * Mag::<N>::BITS - n);
+            Self {
+                lo: self.lo,
+                hi: self.hi.bitand(mask),
+            }
+        } else {
+            Self::from_lo(self.lo.bitand(Mag::<N>::MAX.unbounded_shr(Mag::<N>::BITS - n)))
+        }
+    }
+
+    /// `self >> n`, and how the bits shifted out compare to half of `2^n`
+    pub const fn shr_with_half_cmp(self, n: Exponent) -> (Self, Ordering, bool) {
+        if n == 0 {
+            return (self, Ordering::Less, false);
+        }
+        let rest = self.low_bits(n);
+        let half = Self::from_lo(Mag::ONE).shl(n - 1);
+        (self.shr(n), rest.cmp(&half), !rest.is_zero())
+    }
+
+    /// `self * rhs`, wrapping on overflow
+    #[cfg(feature = "alloc")]
+    pub const fn mul_small(self, rhs: u8) -> Self {
+        let (lo, carry) = self.lo.carrying_mul(small(rhs), Mag::ZERO);
+        let hi = self.hi.wrapping_mul(small(rhs)).wrapping_add(carry);
+        Self { lo, hi }
+    }
+
+    /// `(self / rhs, self % rhs)`, using schoolbook division on bytes
+    pub const fn div_rem_small(self, rhs: u8) -> (Self, u8) {
+        let (mut lo, mut hi) = (self.lo.to_le_bytes(), self.hi.to_le_bytes());
+        let mut remainder = 0u16;
+        let mut i = 2 * N;
+        while i > 0 {
+            i -= 1;
+            let byte = if i >= N { &mut hi[i - N] } else { &mut lo[i] };
+            let dividend = (remainder << 8) | *byte as u16;
+            *byte = (dividend / rhs as u16) as u8;
+            remainder = dividend % rhs as u16;
+        }
+        (
+            Self {
+                lo: Mag::from_le_bytes(lo),
+                hi: Mag::from_le_bytes(hi),
+            },
+            remainder as u8,
+        )
+    }
+
+    /// `(self / rhs, self % rhs)`. `rhs` must be non-zero
+    pub const fn div_rem(self, rhs: Mag<N>) -> (Self, Mag<N>) {
+        let (q_hi, mut remainder) = self.hi.div_rem_unchecked(rhs);
+        let mut q_lo = Mag::<N>::ZERO;
+        let mut i = Mag::<N>::BITS;
+        while i > 0 {
+            i -= 1;
+            // `remainder < rhs`, so if the top bit is shifted out then the shifted remainder is larger than `rhs`, and the wrapping subtraction below gives the correct result
+            let carry = remainder.bit(Mag::<N>::BITS - 1);
+            remainder = remainder.unbounded_shl(1);
+            if self.lo.bit(i) {
+                remainder = remainder.bitor(Mag::ONE);
+            }
+            if carry || remainder.ge(&rhs) {
+                remainder = remainder.wrapping_sub(rhs);
+                q_lo = q_lo.bitor(Mag::ONE.unbounded_shl(i));
+            }
+        }
+        (Self { lo: q_lo, hi: q_hi }, remainder)
+    }
+
+    /// `self / rhs` rounded according to `rounding`, where the sign of the exact quotient is given by `negative`. `rhs` must be non-zero
+    pub const fn div_round(self, rhs: Mag<N>, negative: bool, rounding: RoundingMode) -> Self {
+        let (quotient, remainder) = self.div_rem(rhs);
+        // compare the remainder with half of the divisor
+        let half_cmp = remainder.cmp(&rhs.wrapping_sub(remainder));
+        if rounding.round_up(negative, half_cmp, !remainder.is_zero(), quotient.lo.bit(0)) {
+            quotient.add(Self::from_lo(Mag::ONE))
+        } else {
+            quotient
+        }
+    }
+
+    /// `floor(sqrt(self))`, which always fits in a `Mag<N>`
+    pub const fn isqrt(self) -> Mag<N> {
+        let mut root = Mag::<N>::ZERO;
+        let mut i = Mag::<N>::BITS;
+        while i > 0 {
+            i -= 1;
+            let candidate = root.bitor(Mag::ONE.unbounded_shl(i));
+            if !matches!(Self::mul(candidate, candidate).cmp(&self), Ordering::Greater) {
+                root = candidate;
+            }
+        }
+        root
+    }
+}
@@ -0,0 +1,269 @@
+use super::wide::Wide;
+use crate::{Exponent, Uint};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+type Limb = u32;
+type DoubleLimb = u64;
+
+/// Arbitrary precision natural number, stored as little endian limbs with no trailing zero limbs. Used for the exact decimal arithmetic needed by parsing and formatting, where the size of the intermediate values depends on the exponent of the float.
+#[derive(Clone, PartialEq, Eq)]
+pub(super) struct BigNat {
+    limbs: Vec<Limb>,
+}
+
+impl BigNat {
+    /// largest power of five which fits in a limb
+    const LARGEST_POWER_OF_FIVE: (Limb, Exponent) = (1220703125, 13);
+
+    #[inline]
+    pub fn from_u64(value: u64) -> Self {
+        let mut out = Self {
+            limbs: vec![value as Limb, (value >> Limb::BITS) as Limb],
+        };
+        out.normalise();
+        out
+    }
+
+    #[inline]
+    pub fn from_uint<const W: usize>(value: Uint<W>) -> Self {
+        let bytes = value.to_le_bytes();
+        let mut out = Self {
+            limbs: bytes
+                .chunks(core::mem::size_of::<Limb>())
+                .map(|chunk| {
+                    chunk
+                        .iter()
+                        .rev()
+                        .fold(0, |limb, byte| (limb << 8) | *byte as Limb)
+                })
+                .collect(),
+        };
+        out.normalise();
+        out
+    }
+
+    #[inline]
+    fn normalise(&mut self) {
+        while let Some(0) = self.limbs.last() {
+            self.limbs.pop();
+        }
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    #[inline]
+    pub fn bit_width(&self) -> Exponent {
+        match self.limbs.last() {
+            Some(last) => {
+                (self.limbs.len() as Exponent - 1) * Limb::BITS + Limb::BITS - last.leading_zeros()
+            }
+            None => 0,
+        }
+    }
+
+    #[inline]
+    pub fn bit(&self, index: Exponent) -> bool {
+        let limb_index = (index / Limb::BITS) as usize;
+        limb_index < self.limbs.len() && self.limbs[limb_index] & (1 << (index % Limb::BITS)) != 0
+    }
+
+    #[inline]
+    pub fn trailing_zeros(&self) -> Exponent {
+        let mut zeros = 0;
+        for limb in self.limbs.iter() {
+            if *limb != 0 {
+                return zeros + limb.trailing_zeros();
+            }
+            zeros += Limb::BITS;
+        }
+        zeros
+    }
+
+    #[inline]
+    pub fn mul_small(&mut self, rhs: Limb) {
+        self.mul_add_small(rhs, 0);
+    }
+
+    /// sets `self` to `self * rhs + carry`
+    #[inline]
+    pub fn mul_add_small(&mut self, rhs: Limb, mut carry: Limb) {
+        for limb in self.limbs.iter_mut() {
+            let product = *limb as DoubleLimb * rhs as DoubleLimb + carry as DoubleLimb;
+            *limb = product as Limb;
+            carry = (product >> Limb::BITS) as Limb;
+        }
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+        self.normalise();
+    }
+
+    #[inline]
+    pub fn mul_pow5(&mut self, mut exponent: Exponent) {
+        let (power, power_exponent) = Self::LARGEST_POWER_OF_FIVE;
+        while exponent >= power_exponent {
+            self.mul_small(power);
+            exponent -= power_exponent;
+        }
+        self.mul_small((5 as Limb).pow(exponent));
+    }
+
+    #[inline]
+    pub fn mul_pow10(&mut self, exponent: Exponent) {
+        self.mul_pow5(exponent);
+        self.shl(exponent);
+    }
+
+    #[inline]
+    pub fn shl(&mut self, rhs: Exponent) {
+        if self.is_zero() {
+            return;
+        }
+        let limb_shift = (rhs / Limb::BITS) as usize;
+        let bit_shift = rhs % Limb::BITS;
+        if bit_shift != 0 {
+            let mut carry = 0;
+            for limb in self.limbs.iter_mut() {
+                let shifted = (*limb << bit_shift) | carry;
+                carry = *limb >> (Limb::BITS - bit_shift);
+                *limb = shifted;
+            }
+            if carry != 0 {
+                self.limbs.push(carry);
+            }
+        }
+        self.limbs.splice(0..0, core::iter::repeat_n(0, limb_shift));
+    }
+
+    /// shifts right, setting the least significant bit of the result if any of the discarded bits were set (see `Wide::shr_jamming`)
+    #[inline]
+    pub fn shr_jamming(&mut self, rhs: Exponent) {
+        let sticky = self.trailing_zeros() < rhs && !self.is_zero();
+        let limb_shift = ((rhs / Limb::BITS) as usize).min(self.limbs.len());
+        let bit_shift = rhs % Limb::BITS;
+        self.limbs.drain(..limb_shift);
+        if bit_shift != 0 {
+            let mut carry = 0;
+            for limb in self.limbs.iter_mut().rev() {
+                let shifted = (*limb >> bit_shift) | carry;
+                carry = *limb << (Limb::BITS - bit_shift);
+                *limb = shifted;
+            }
+        }
+        self.normalise();
+        if sticky {
+            match self.limbs.first_mut() {
+                Some(limb) => *limb |= 1,
+                None => self.limbs.push(1),
+            }
+        }
+    }
+
+    #[inline]
+    pub fn add(&self, rhs: &Self) -> Self {
+        let (long, short) = if self.limbs.len() >= rhs.limbs.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut out = long.clone();
+        let mut carry = false;
+        for (i, limb) in out.limbs.iter_mut().enumerate() {
+            let rhs_limb = short.limbs.get(i).copied().unwrap_or(0);
+            if i >= short.limbs.len() && !carry {
+                break;
+            }
+            let (sum, overflow1) = limb.overflowing_add(rhs_limb);
+            let (sum, overflow2) = sum.overflowing_add(carry as Limb);
+            *limb = sum;
+            carry = overflow1 || overflow2;
+        }
+        if carry {
+            out.limbs.push(1);
+        }
+        out
+    }
+
+    /// sets `self` to `self - rhs`. `rhs` must not be larger than `self`
+    #[inline]
+    pub fn sub_assign(&mut self, rhs: &Self) {
+        debug_assert!(*self >= *rhs);
+        let mut borrow = false;
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let rhs_limb = rhs.limbs.get(i).copied().unwrap_or(0);
+            if i >= rhs.limbs.len() && !borrow {
+                break;
+            }
+            let (diff, overflow1) = limb.overflowing_sub(rhs_limb);
+            let (diff, overflow2) = diff.overflowing_sub(borrow as Limb);
+            *limb = diff;
+            borrow = overflow1 || overflow2;
+        }
+        self.normalise();
+    }
+
+    /// sets `self` to `self % rhs` and returns `self / rhs`, which must be less than `2^bits`. computed one quotient bit at a time, so this is only efficient for quotients with few bits
+    #[inline]
+    pub fn div_rem_wide<const W: usize>(&mut self, rhs: &Self, bits: Exponent) -> Wide<W> {
+        debug_assert!(bits <= Wide::<W>::BITS);
+        let mut quotient = Wide::ZERO;
+        let mut divisor = rhs.clone();
+        divisor.shl(bits - 1);
+        let mut i = bits;
+        while i > 0 {
+            i -= 1;
+            if *self >= divisor {
+                self.sub_assign(&divisor);
+                quotient.set_bit(i, true);
+            }
+            divisor.shr_jamming(1); // exact, since `divisor` is `rhs << i`
+        }
+        debug_assert!(*self < *rhs);
+        quotient
+    }
+
+    /// sets `self` to `self % rhs` and returns `self / rhs`, which must fit in a decimal digit
+    #[inline]
+    pub fn div_rem_digit(&mut self, rhs: &Self) -> u8 {
+        let mut digit = 0;
+        while *self >= *rhs {
+            self.sub_assign(rhs);
+            digit += 1;
+        }
+        debug_assert!(digit < 10);
+        digit
+    }
+
+    /// returns the least significant `Wide::BITS` bits of `self`
+    #[inline]
+    pub fn to_wide<const W: usize>(&self) -> Wide<W> {
+        let mut out = Wide::ZERO;
+        let mut i = 0;
+        while i < Wide::<W>::BITS.min(self.bit_width()) {
+            out.set_bit(i, self.bit(i));
+            i += 1;
+        }
+        out
+    }
+}
+
+impl PartialOrd for BigNat {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigNat {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
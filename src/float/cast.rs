@@ -1,9 +1,8 @@
 use crate::cast::float::FloatCastHelper;
-use crate::helpers::Zero;
 
 use super::{Float, FloatExponent};
-use crate::Exponent;
 use crate::cast::CastFrom;
+use crate::{Exponent, RoundingMode};
 use crate::{Int, Integer, Uint};
 
 macro_rules! uint_as_float {
@@ -104,10 +103,8 @@ macro_rules! float_as_uint {
 float_as_uint!(Uint<N>, u8, u16, u32, u64, u128, usize);
 
 impl<const W: usize, const MB: usize> FloatCastHelper for Float<W, MB> {
-    const BITS: Exponent = Self::BITS;
     const MANTISSA_DIGITS: Exponent = Self::MANTISSA_DIGITS as Exponent;
     const MAX_EXP: FloatExponent = Self::MAX_EXP;
-    const MIN_SUBNORMAL_EXP: FloatExponent = Self::MIN_SUBNORMAL_EXP;
     const INFINITY: Self = Self::INFINITY;
     const ZERO: Self = Self::ZERO;
 
@@ -122,164 +119,43 @@ impl<const W: usize, const MB: usize> FloatCastHelper for Float<W, MB> {
     }
 }
 
-trait FloatCastFromFloatHelper: FloatCastHelper {
-    const NEG_ZERO: Self;
-    const MIN_SUBNORMAL_EXP: FloatExponent;
-
-    fn round_exponent_mantissa<const TIES_EVEN: bool>(
-        exponent: Self::SignedExp,
-        mantissa: Self::Mantissa,
-        shift: Exponent,
-    ) -> (Self::SignedExp, Self::Mantissa);
-    fn from_normalised_signed_parts(
-        sign: bool,
-        exponent: Self::SignedExp,
-        mantissa: Self::Mantissa,
-    ) -> Self;
-}
-
-macro_rules! impl_float_cast_from_float_helper_for_primitive_float {
-    ($($float_type: ty), *) => {
-        $(
-            impl FloatCastFromFloatHelper for $float_type {
-                const NEG_ZERO: Self = -0.0;
-                const MIN_SUBNORMAL_EXP: <Self as ConvertFloatParts>::SignedExp = Self::MIN_EXP + 1 - Self::MANTISSA_DIGITS as <Self as ConvertFloatParts>::SignedExp;
-
-                #[inline]
-                fn round_exponent_mantissa<const TIES_EVEN: bool>(
-                    mut exponent: Self::SignedExp,
-                    mantissa: Self::Mantissa,
-                    shift: Exponent,
-                ) -> (Self::SignedExp, Self::Mantissa) {
-                    let mut shifted_mantissa = mantissa >> shift;
-                    if !TIES_EVEN {
-                        return (exponent, shifted_mantissa); // if not TIES_EVEN, then we truncate
-                    }
-                    let discarded_shifted_bits =
-                        mantissa & (<$mantissa_type>::MAX >> ($float_bit_width - shift));
-                    if discarded_shifted_bits.bit(shift - 1) {
-                        // in this case, the discarded portion is at least a half
-                        if shifted_mantissa % 2 == 1 || !discarded_shifted_bits.is_power_of_two() {
-                            // in this case, ties to even says we round up. checking if not a power of two tells us that there is at least one bit set to 1 (after the most significant bit set to 1). we check in this order as is_odd is O(1) whereas is_power_of_two is O(N)
-                            shifted_mantissa = shifted_mantissa + 1;
-                            if shifted_mantissa.bit(shift) {
-                                // check for overflow (with respect to the mantissa bit width)
-                                exponent += 1;
-                                shifted_mantissa = shifted_mantissa >> 1;
-                            }
-                        }
-                    }
-                    (exponent, shifted_mantissa)
-                }
-
-                #[inline]
-                fn from_normalised_signed_parts(
-                    sign: bool,
-                    exponent: Self::SignedExp,
-                    mantissa: Self::Mantissa,
-                ) -> Self {
-                    use crate::helpers::Bits;
-
-                    debug_assert!(mantissa == 0 || mantissa.bit_width() == Self::MANTISSA_DIGITS);
-                    if exponent < Self::MIN_EXP - 1 {
-                        let shift = (Self::MIN_EXP - 1 - exponent) as Exponent;
-                        let (out_exponent, out_mantissa) =
-                            Self::round_exponent_mantissa::<true>(Self::MIN_EXP - 1, mantissa, shift);
-
-                        Self::from_signed_parts(sign, out_exponent, out_mantissa)
-                    } else {
-                        Self::from_signed_parts(sign, exponent, mantissa)
-                    }
-                }
-            }
-        )*
-    };
-}
-
-impl_float_cast_from_float_helper_for_primitive_float!(f32, f64);
-
-fn cast_float_from_float<T, U>(f: T) -> U
-where
-    T: FloatCastHelper,
-    U: FloatCastHelper,
-    U::Mantissa: CastFrom<T::Mantissa>,
-    U::SignedExp: CastFrom<T::SignedExp>,
-    T::SignedExp: CastFrom<U::SignedExp>,
-{
-    // deal with zero cases as this means mantissa must have leading one
-    let (sign, mut exponent, mantissa) = f.into_normalised_signed_parts();
-    if mantissa == T::Mantissa::ZERO {
-        return if sign { U::NEG_ZERO } else { U::ZERO };
-    }
-    if exponent == T::MAX_EXP {
-        // the float is either infinity or NaN
-        let out_mantissa = if T::MANTISSA_DIGITS <= U::MANTISSA_DIGITS {
-            U::Mantissa::cast_from(mantissa) << (U::MANTISSA_DIGITS - T::MANTISSA_DIGITS)
-        } else {
-            U::Mantissa::cast_from(mantissa >> (T::MANTISSA_DIGITS - U::MANTISSA_DIGITS))
-        };
-        return U::from_normalised_signed_parts(sign, U::MAX_EXP, out_mantissa);
-    }
-    let out_mantissa = if T::MANTISSA_DIGITS <= U::MANTISSA_DIGITS {
-        // in this case, the mantissa can be converted exactly
-        U::Mantissa::cast_from(mantissa) << (U::MANTISSA_DIGITS - T::MANTISSA_DIGITS)
-    } else {
-        let (e, m) = T::round_exponent_mantissa::<true>(
-            exponent,
-            mantissa,
-            T::MANTISSA_DIGITS - U::MANTISSA_DIGITS,
-        );
-        exponent = e;
-
-        U::Mantissa::cast_from(m)
-    };
-
-    let out_exponent = if T::EXPONENT_BITS <= U::EXPONENT_BITS {
-        // in this case, we will never have overflow
-        U::SignedExp::cast_from(exponent)
-    } else {
-        if T::SignedExp::cast_from(U::MAX_EXP) <= exponent {
-            // exponent is too large to fit into output exponent
-            return if sign { -U::INFINITY } else { U::INFINITY };
-        }
-        if exponent < T::SignedExp::cast_from(U::MIN_SUBNORMAL_EXP) {
-            return if sign { U::NEG_ZERO } else { U::ZERO };
-        }
-        U::SignedExp::cast_from(exponent)
-    };
-    U::from_normalised_signed_parts(sign, out_exponent, out_mantissa)
-}
-
 impl<const W1: usize, const MB1: usize, const W2: usize, const MB2: usize> CastFrom<Float<W2, MB2>>
     for Float<W1, MB1>
 {
     #[inline]
     fn cast_from(value: Float<W2, MB2>) -> Self {
-        cast_float_from_float(value)
+        Self::from_float_round(value, RoundingMode::NearestTiesEven)
     }
 }
 
 macro_rules! primitive_and_big_float_cast {
-    ($($primitive_float_type: ty), *) => {
+    ($($(#[$attr: meta])? $primitive_float_type: ty: $from: ident, $to: ident), *) => {
         $(
+            $(#[$attr])?
             impl<const W: usize, const MB: usize> CastFrom<$primitive_float_type> for Float<W, MB> {
                 #[inline]
                 fn cast_from(value: $primitive_float_type) -> Self {
-                    cast_float_from_float(value)
+                    Self::$from(value)
                 }
             }
 
+            $(#[$attr])?
             impl<const W: usize, const MB: usize> CastFrom<Float<W, MB>> for $primitive_float_type {
                 #[inline]
                 fn cast_from(value: Float<W, MB>) -> Self {
-                    cast_float_from_float(value)
+                    value.$to()
                 }
             }
         )*
     };
 }
 
-primitive_and_big_float_cast!(f32, f64);
+primitive_and_big_float_cast!(
+    #[cfg(nightly)] f16: round_from_f16, to_f16,
+    f32: round_from_f32, to_f32,
+    f64: round_from_f64, to_f64,
+    #[cfg(nightly)] f128: round_from_f128, to_f128
+);
 
 #[cfg(test)]
 mod tests {
@@ -304,12 +180,8 @@ mod tests {
         fn test_cast_float() {
             use crate::cast::As;
             let f1 = FTEST::from_bits(3472883712u32.as_());
-            let f2 = f32::from_bits(3472883712u32);
-            // dbg!(f2);
-            let u1 = u32::cast_from(f1);
-            let u2 = u32::cast_from(f2);
-            // println!("{:?}", u1);
-            // println!("{:?}", u2);
+            let f2 = ftest::from_bits(3472883712u32.as_());
+            assert_eq!(u32::cast_from(f1), u32::cast_from(f2));
         }
 
         // crate::ints::cast::test_cast_to_bigint!(ftest; UTESTD8, UTESTD16, UTESTD32, UTESTD64, TestUint1, TestUint2, TestUint3, TestUint4, TestUint5, TestUint6, TestUint7, TestUint8, ITESTD8, ITESTD16, ITESTD32, ITESTD64, TestInt1, TestInt2, TestInt3, TestInt4, TestInt5, TestInt6, TestInt7, TestInt8);
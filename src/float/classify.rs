@@ -38,6 +38,12 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         !self.is_finite() && self.to_bits().trailing_zeros() < Self::MB
     }
 
+    /// NaNs with the most significant mantissa bit cleared are signaling, the rest are quiet
+    #[inline]
+    pub(crate) const fn is_signaling_nan(self) -> bool {
+        self.is_nan() && !self.bits.bit(Self::MB - 1)
+    }
+
     #[inline]
     pub const fn is_subnormal(self) -> bool {
         let lz = self.abs().to_bits().leading_zeros();
@@ -102,10 +102,12 @@ mod tests {
         testing floats;
 
         test_bignum! {
-            function: <ftest>::max(a: ftest, b: ftest)
+            function: <ftest>::max(a: ftest, b: ftest),
+            skip: a == b && a.is_sign_negative() != b.is_sign_negative() // the primitive result is unspecified for zeros of opposite sign
         }
         test_bignum! {
-            function: <ftest>::min(a: ftest, b: ftest)
+            function: <ftest>::min(a: ftest, b: ftest),
+            skip: a == b && a.is_sign_negative() != b.is_sign_negative() // the primitive result is unspecified for zeros of opposite sign
         }
         #[cfg(nightly)]
         test_bignum! {
@@ -1,21 +1,34 @@
+use super::math::Fixed;
 use super::{Float, FloatExponent, UnsignedFloatExponent};
 use crate::integer::Uint;
 
+/// `floor(log10(2) * 2^96)`
+const LOG10_2_96: i128 = 0x4d104d427de7fbcc47c4acd6;
+
+/// `floor(log10(e) * 2^96)`
+const LOG10_E_96: i128 = 0x6f2dec549b9438ca9aadd557;
+
+/// `floor(n * log10(2) - correction / 2^96)`. since `log10(2)` is irrational and `|n| < 2^32`, `n * log10(2)` is much further from an integer than the error in `LOG10_2_96`, so this is exact when `correction` is zero
+const fn floor_mul_log10_2(n: i128, correction: i128) -> FloatExponent {
+    ((n * LOG10_2_96 - correction) >> 96) as FloatExponent
+}
+
 /// Associated constants.
 impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// The radix or base of the internal representation of the float.
     pub const RADIX: u32 = 2;
 
+    /// Number of significant digits in base 2.
     pub const MANTISSA_DIGITS: u32 = MB as u32 + 1;
 
-    pub const DIGITS: u32 = Uint::<W>::ONE.wrapping_shl(Self::MB).ilog10() as u32;
+    /// Approximate number of significant digits in base 10.
+    pub const DIGITS: u32 = Uint::<W>::ONE.wrapping_shl(Self::MB).ilog10();
 
     pub(crate) const MB_AS_FLOAT_EXP: FloatExponent = Self::MB as FloatExponent;
 
+    /// The difference between `1.0` and the next larger representable number.
     pub const EPSILON: Self = Self::normal_power_of_two(-Self::MB_AS_FLOAT_EXP);
 
-    pub(crate) const HALF_EPSILON: Self =
-        Self::normal_power_of_two(-(Self::MB as FloatExponent + 1));
-
     pub const EXP_BIAS: FloatExponent = (1 << (Self::EXPONENT_BITS - 1)) - 1; // UnsignedFloatExponent::MAX.wrapping_shr(Self::MB + 1) as _;
 
     pub const MIN: Self = {
@@ -27,25 +40,38 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         Self::from_bits(e.bitor(m))
     };
 
+    /// Smallest positive normal value.
     pub const MIN_POSITIVE: Self = Self::from_bits(Uint::ONE.wrapping_shl(Self::MB));
 
     pub const MAX_NEGATIVE: Self = Self::MIN_POSITIVE.neg();
 
     pub const MAX: Self = Self::MIN.abs();
 
+    /// One greater than the minimum possible normal power of 2 exponent.
     pub const MIN_EXP: FloatExponent = -Self::EXP_BIAS + 2;
 
     pub(crate) const MIN_SUBNORMAL_EXP: FloatExponent =
         -Self::EXP_BIAS + 1 - Self::MB as FloatExponent; // TODO: need to check that this fits into FloatExponent
 
+    /// Maximum possible power of 2 exponent.
     pub const MAX_EXP: FloatExponent = Self::EXP_BIAS + 1;
 
     pub const MAX_UNBIASED_EXP: UnsignedFloatExponent =
         (Self::EXP_BIAS as UnsignedFloatExponent) * 2;
 
-    pub const MIN_10_EXP: Self = todo!();
+    /// Minimum `x` for which `10^x` is normal.
+    pub const MIN_10_EXP: FloatExponent = -floor_mul_log10_2(1 - Self::MIN_EXP as i128, 0);
 
-    pub const MAX_10_EXP: Self = todo!();
+    /// Maximum `x` for which `10^x` is finite.
+    pub const MAX_10_EXP: FloatExponent = {
+        // `log10(MAX) = MAX_EXP * log10(2) + log10(1 - 2^-(MB + 1))`, and `-log10(1 - 2^-(MB + 1))` is slightly larger than `log10(e) * 2^-(MB + 1)`
+        let correction = if Self::MB + 1 < 96 {
+            (LOG10_E_96 >> (Self::MB + 1)) + 1
+        } else {
+            1
+        };
+        floor_mul_log10_2(Self::MAX_EXP as i128, correction)
+    };
 
     pub const MAX_SUBNORMAL: Self =
         Self::from_bits(Uint::MAX.wrapping_shr(Self::EXPONENT_BITS + 1));
@@ -103,6 +129,69 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     pub const NEG_ONE: Self = Self::ONE.neg();
 }
 
+/// Mathematical constants.
+///
+/// These are computed at compile time to the precision of the float type and are correctly rounded (to nearest, ties to even).
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// Archimedes' constant (π).
+    pub const PI: Self = Self::from_fixed(false, &Fixed::PI);
+
+    /// The full circle constant (τ = 2π).
+    pub const TAU: Self = Self::from_fixed(false, &Fixed::PI.mul_small(2));
+
+    /// π/2.
+    pub const FRAC_PI_2: Self = Self::from_fixed(false, &Fixed::PI.div_small(2));
+
+    /// π/3.
+    pub const FRAC_PI_3: Self = Self::from_fixed(false, &Fixed::PI.div_small(3));
+
+    /// π/4.
+    pub const FRAC_PI_4: Self = Self::from_fixed(false, &Fixed::PI.div_small(4));
+
+    /// π/6.
+    pub const FRAC_PI_6: Self = Self::from_fixed(false, &Fixed::PI.div_small(6));
+
+    /// π/8.
+    pub const FRAC_PI_8: Self = Self::from_fixed(false, &Fixed::PI.div_small(8));
+
+    /// 1/π.
+    pub const FRAC_1_PI: Self = Self::from_fixed(false, &Fixed::from_int(1).div(&Fixed::PI));
+
+    /// 2/π.
+    pub const FRAC_2_PI: Self = Self::from_fixed(false, &Fixed::from_int(2).div(&Fixed::PI));
+
+    /// 2/sqrt(π).
+    pub const FRAC_2_SQRT_PI: Self =
+        Self::from_fixed(false, &Fixed::from_int(2).div(&Fixed::PI.sqrt()));
+
+    /// sqrt(2).
+    pub const SQRT_2: Self = Self::from_fixed(false, &Fixed::SQRT_2);
+
+    /// 1/sqrt(2).
+    pub const FRAC_1_SQRT_2: Self = Self::from_fixed(false, &Fixed::SQRT_2.div_small(2));
+
+    /// Euler's number (e).
+    pub const E: Self = Self::from_fixed(false, &Fixed::E);
+
+    /// log<sub>2</sub>(10).
+    pub const LOG2_10: Self = Self::from_fixed(false, &Fixed::LN_10.div(&Fixed::LN_2));
+
+    /// log<sub>2</sub>(e).
+    pub const LOG2_E: Self = Self::from_fixed(false, &Fixed::from_int(1).div(&Fixed::LN_2));
+
+    /// log<sub>10</sub>(2).
+    pub const LOG10_2: Self = Self::from_fixed(false, &Fixed::LN_2.div(&Fixed::LN_10));
+
+    /// log<sub>10</sub>(e).
+    pub const LOG10_E: Self = Self::from_fixed(false, &Fixed::from_int(1).div(&Fixed::LN_10));
+
+    /// ln(2).
+    pub const LN_2: Self = Self::from_fixed(false, &Fixed::LN_2);
+
+    /// ln(10).
+    pub const LN_10: Self = Self::from_fixed(false, &Fixed::LN_10);
+}
+
 #[cfg(test)]
 mod tests {
     use crate::Exponent;
@@ -139,11 +228,20 @@ mod tests {
         };
     }
     
+    macro_rules! test_math_constants {
+        {$($constant: ident), *} => {
+            $(
+                test_constant!(F32::$constant == core::f32::consts::$constant);
+                test_constant!(F64::$constant == core::f64::consts::$constant);
+            )*
+        };
+    }
+
     crate::test::test_all! {
         testing floats;
 
         test_constants! {
-            /*NAN, */INFINITY, NEG_INFINITY, MAX, MIN, MIN_POSITIVE, EPSILON, /*MIN_EXP, MAX_EXP,*/ RADIX, MANTISSA_DIGITS, DIGITS
+            /*NAN, */INFINITY, NEG_INFINITY, MAX, MIN, MIN_POSITIVE, EPSILON, MIN_EXP, MAX_EXP, MIN_10_EXP, MAX_10_EXP, RADIX, MANTISSA_DIGITS, DIGITS
         }
         // don't test NAN as Rust f64/f32 NAN bit pattern not guaranteed to be stable across version
 
@@ -172,4 +270,61 @@ mod tests {
         test_constant!(F64::MAX_UNBIASED_EXP == 2046 as Exponent);
         test_constant!(F32::MAX_UNBIASED_EXP == 254 as Exponent);
     }
+
+    test_math_constants! {
+        PI, TAU, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, FRAC_1_PI, FRAC_2_PI,
+        FRAC_2_SQRT_PI, SQRT_2, FRAC_1_SQRT_2, E, LOG2_10, LOG2_E, LOG10_2, LOG10_E, LN_2, LN_10
+    }
+
+    #[test]
+    fn decimal_exponents() {
+        use crate::Float;
+
+        macro_rules! assert_decimal_constants {
+            ($($ty: ty => ($digits: expr, $min_10_exp: expr, $max_10_exp: expr)), *) => {
+                $(
+                    assert_eq!(<$ty>::DIGITS, $digits);
+                    assert_eq!(<$ty>::MIN_10_EXP, $min_10_exp);
+                    assert_eq!(<$ty>::MAX_10_EXP, $max_10_exp);
+                )*
+            };
+        }
+
+        assert_decimal_constants!(
+            Float<2, 10> => (3, -4, 4), // binary16
+            Float<2, 7> => (2, -37, 38), // bfloat16
+            Float<16, 112> => (33, -4931, 4932), // binary128
+            Float<32, 236> => (71, -78912, 78913) // binary256
+        );
+    }
+
+    #[test]
+    fn wide_math_constants() {
+        use crate::{Float, Uint};
+
+        type F128 = Float<16, 112>;
+        type F256 = Float<32, 236>;
+
+        // correctly rounded bit patterns of binary128 and binary256
+        let constants = [
+            (F128::PI, F256::PI, "4000921fb54442d18469898cc51701b8", "40000921fb54442d18469898cc51701b839a252049c1114cf98e804177d4c762"),
+            (F128::TAU, F256::TAU, "4001921fb54442d18469898cc51701b8", "40001921fb54442d18469898cc51701b839a252049c1114cf98e804177d4c762"),
+            (F128::FRAC_PI_3, F256::FRAC_PI_3, "3fff0c152382d73658465bb32e0f567b", "3ffff0c152382d73658465bb32e0f567ad116e158680b6335109aad64fe32f97"),
+            (F128::FRAC_1_PI, F256::FRAC_1_PI, "3ffd45f306dc9c882a53f84eafa3ea6a", "3fffd45f306dc9c882a53f84eafa3ea69bb81b6c52b3278872083fca2c757bd7"),
+            (F128::FRAC_2_SQRT_PI, F256::FRAC_2_SQRT_PI, "3fff20dd750429b6d11ae3a914fed7fe", "3ffff20dd750429b6d11ae3a914fed7fd8688281341d7587cea2e7342b06199d"),
+            (F128::SQRT_2, F256::SQRT_2, "3fff6a09e667f3bcc908b2fb1366ea95", "3ffff6a09e667f3bcc908b2fb1366ea957d3e3adec17512775099da2f590b066"),
+            (F128::FRAC_1_SQRT_2, F256::FRAC_1_SQRT_2, "3ffe6a09e667f3bcc908b2fb1366ea95", "3fffe6a09e667f3bcc908b2fb1366ea957d3e3adec17512775099da2f590b066"),
+            (F128::E, F256::E, "40005bf0a8b1457695355fb8ac404e7a", "400005bf0a8b1457695355fb8ac404e7a79e3b1738b079c5a6d2b53c26c8228d"),
+            (F128::LOG2_10, F256::LOG2_10, "4000a934f0979a3715fc9257edfe9b60", "40000a934f0979a3715fc9257edfe9b5fb699b2d8abfc6f675a9d236d590105d"),
+            (F128::LOG2_E, F256::LOG2_E, "3fff71547652b82fe1777d0ffda0d23a", "3ffff71547652b82fe1777d0ffda0d23a7d11d6aef551bad2b4b1164a2cd9a34"),
+            (F128::LOG10_2, F256::LOG10_2, "3ffd34413509f79fef311f12b35816f9", "3fffd34413509f79fef311f12b35816f922f04d5a618a87a3e69314bcde4d6fa"),
+            (F128::LOG10_E, F256::LOG10_E, "3ffdbcb7b1526e50e32a6ab7555f5a68", "3fffdbcb7b1526e50e32a6ab7555f5a67b8647dc68c048b934404747e5a89ef2"),
+            (F128::LN_2, F256::LN_2, "3ffe62e42fefa39ef35793c7673007e6", "3fffe62e42fefa39ef35793c7673007e5ed5e81e6864ce5316c5b141a2eb7175"),
+            (F128::LN_10, F256::LN_10, "400026bb1bbb5551582dd4adac5705a6", "4000026bb1bbb5551582dd4adac5705a61451c51fd9f3b4bbf21d078c3d0403e"),
+        ];
+        for (a, b, a_bits, b_bits) in constants {
+            assert_eq!(a.to_bits(), Uint::from_str_radix(a_bits, 16).unwrap());
+            assert_eq!(b.to_bits(), Uint::from_str_radix(b_bits, 16).unwrap());
+        }
+    }
 }
@@ -1,6 +1,8 @@
+use super::wide::Wide;
 use super::{Float, FloatExponent, UnsignedFloatExponent};
 use crate::cast::float::ConvertFloatParts;
-use crate::{Exponent, Int, Uint};
+use crate::{Exponent, FloatStatus, Int, Integer, RoundingMode, Uint};
+use core::num::FpCategory;
 
 impl<const W: usize> Uint<W> {
     #[inline]
@@ -9,6 +11,52 @@ impl<const W: usize> Uint<W> {
     }
 }
 
+impl RoundingMode {
+    /// whether a magnitude whose discarded bits have been truncated should instead be rounded away from zero. `round_bit` is the most significant discarded bit, and `sticky_bit` is whether any less significant discarded bits are set
+    #[inline]
+    pub(crate) const fn rounds_away_from_zero(
+        self,
+        negative: bool,
+        odd: bool,
+        round_bit: bool,
+        sticky_bit: bool,
+    ) -> bool {
+        match self {
+            Self::NearestTiesEven => round_bit && (sticky_bit || odd),
+            Self::NearestTiesAway => round_bit,
+            Self::TowardPositive => !negative && (round_bit || sticky_bit),
+            Self::TowardNegative => negative && (round_bit || sticky_bit),
+            Self::TowardZero => false,
+        }
+    }
+
+    /// whether a value too large in magnitude to be represented rounds to infinity, rather than to the largest finite value of the same sign
+    #[inline]
+    pub(crate) const fn overflows_to_infinity(self, negative: bool) -> bool {
+        match self {
+            Self::NearestTiesEven | Self::NearestTiesAway => true,
+            Self::TowardPositive => !negative,
+            Self::TowardNegative => negative,
+            Self::TowardZero => false,
+        }
+    }
+}
+
+/// truncates or zero-extends to `W` bytes
+#[inline]
+const fn resize_uint<const N: usize, const B: usize, const OM: u8, const W: usize>(
+    value: Uint<N, B, OM>,
+) -> Uint<W> {
+    let from = value.to_bytes();
+    let mut bytes = [0; W];
+    let mut i = 0;
+    while i < N && i < W {
+        bytes[i] = from[i];
+        i += 1;
+    }
+    Uint::from_bytes(bytes)
+}
+
 impl<const W: usize, const MB: usize> Float<W, MB> {
     #[inline(always)]
     pub const fn to_bits(self) -> Uint<W> {
@@ -93,24 +141,6 @@ impl<const W: usize, const MB: usize> ConvertFloatParts for Float<W, MB> {
     fn from_signed_parts(sign: bool, exponent: Self::SignedExp, mantissa: Self::Mantissa) -> Self {
         Self::from_signed_parts(sign, exponent, mantissa)
     }
-
-    #[inline]
-    fn round_exponent_mantissa<const TIES_EVEN: bool>(
-        exponent: Self::SignedExp,
-        mantissa: Self::Mantissa,
-        shift: Exponent,
-    ) -> (Self::SignedExp, Self::Mantissa) {
-        Self::round_exponent_mantissa::<TIES_EVEN>(exponent, mantissa, shift)
-    }
-
-    #[inline]
-    fn from_normalised_signed_parts(
-        sign: bool,
-        exponent: Self::SignedExp,
-        mantissa: Self::Mantissa,
-    ) -> Self {
-        Self::from_normalised_signed_parts(sign, exponent, mantissa)
-    }
 }
 
 impl<const W: usize, const MB: usize> Float<W, MB> {
@@ -252,6 +282,7 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         (exponent, shifted_mantissa)
     }
 
+    #[allow(dead_code)] // the inverse of `into_normalised_signed_parts`, rounding to nearest, ties to even
     #[inline]
     pub(crate) const fn from_normalised_signed_parts(
         sign: bool,
@@ -275,8 +306,314 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     pub(crate) const fn signed_biased_exponent(self) -> FloatExponent {
         self.into_signed_biased_parts().1
     }
+
+    /// the sign of an exact zero sum of two operands of opposite sign depends on the rounding mode
+    #[inline]
+    pub(crate) const fn exact_zero_sum(rounding: RoundingMode) -> Self {
+        match rounding {
+            RoundingMode::TowardNegative => Self::NEG_ZERO,
+            _ => Self::ZERO,
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn overflowed(sign: bool, rounding: RoundingMode) -> Self {
+        match (sign, rounding.overflows_to_infinity(sign)) {
+            (false, true) => Self::INFINITY,
+            (true, true) => Self::NEG_INFINITY,
+            (false, false) => Self::MAX,
+            (true, false) => Self::MIN,
+        }
+    }
+
+    /// rounds `mantissa * 2^exponent` to a float, according to the given rounding mode, and returns the exception flags raised by the rounding
+    #[inline]
+    pub(super) const fn from_wide_parts(
+        sign: bool,
+        exponent: FloatExponent,
+        mantissa: Wide<W>,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        if mantissa.is_zero() {
+            let zero = if sign { Self::NEG_ZERO } else { Self::ZERO };
+            return (zero, FloatStatus::OK);
+        }
+        let msb_exponent = exponent + (mantissa.bit_width() - 1) as FloatExponent;
+        if msb_exponent >= Self::MAX_EXP {
+            return (Self::overflowed(sign, rounding), Self::OVERFLOW_STATUS);
+        }
+        let tiny = msb_exponent < Self::MIN_EXP - 1;
+        let out_exponent = if tiny {
+            Self::MIN_EXP - 1 // result is subnormal
+        } else {
+            msb_exponent
+        };
+        let shift = out_exponent - Self::MB_AS_FLOAT_EXP - exponent;
+        if shift <= 0 {
+            // no precision is lost, so the result is exact
+            let out_mantissa = mantissa.shl((-shift) as Exponent).lo;
+            return (
+                Self::from_signed_parts(sign, out_exponent, out_mantissa),
+                FloatStatus::OK,
+            );
+        }
+        let shift = shift as Exponent;
+        let mut out_mantissa = mantissa.shr(shift).lo;
+        let round_bit = shift <= Wide::<W>::BITS && mantissa.bit(shift - 1);
+        let sticky_bit = mantissa.trailing_zeros() < shift - 1;
+        if rounding.rounds_away_from_zero(sign, out_mantissa.bit(0), round_bit, sticky_bit) {
+            out_mantissa = out_mantissa.add(Uint::ONE);
+        }
+        let (out, mut status) =
+            Self::finalise_rounded_parts(sign, out_exponent, out_mantissa, rounding);
+        if round_bit || sticky_bit {
+            status = status.union(FloatStatus::INEXACT);
+            if tiny {
+                status = status.union(FloatStatus::UNDERFLOW);
+            }
+        }
+        (out, status)
+    }
+
+    const OVERFLOW_STATUS: FloatStatus = FloatStatus::OVERFLOW.union(FloatStatus::INEXACT);
+
+    /// mantissa has at most `MB + 2` bits, since rounding away from zero may have carried into the bit above the leading bit
+    #[inline]
+    const fn finalise_rounded_parts(
+        sign: bool,
+        mut exponent: FloatExponent,
+        mut mantissa: Uint<W>,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        if mantissa.bit(Self::MB + 1) {
+            mantissa = mantissa.shr(1);
+            exponent += 1;
+        }
+        if exponent >= Self::MAX_EXP {
+            return (Self::overflowed(sign, rounding), Self::OVERFLOW_STATUS);
+        }
+        (
+            Self::from_signed_parts(sign, exponent, mantissa),
+            FloatStatus::OK,
+        )
+    }
+
+    /// operations with a NaN operand raise the invalid flag only if one of the operands is a signaling NaN
+    #[inline]
+    pub(crate) const fn nan_operands_status(operands: &[Self]) -> FloatStatus {
+        let mut i = 0;
+        while i < operands.len() {
+            if operands[i].is_signaling_nan() {
+                return FloatStatus::INVALID;
+            }
+            i += 1;
+        }
+        FloatStatus::OK
+    }
+
+    /// rounds `mantissa * 2^exponent` to a float, according to the given rounding mode. unlike `from_wide_parts`, the mantissa can be of any width
+    #[inline]
+    pub(crate) const fn from_uint_parts<const N: usize, const B: usize, const OM: u8>(
+        sign: bool,
+        mut exponent: FloatExponent,
+        mut mantissa: Uint<N, B, OM>,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let width = mantissa.bit_width();
+        let mut sticky_bit = false;
+        if width > Self::MB + 2 {
+            // keep one more bit than the precision of the float, and jam the rest into a sticky bit
+            let shift = width - (Self::MB + 2);
+            sticky_bit = mantissa.trailing_zeros() < shift;
+            mantissa = mantissa.shr(shift);
+            exponent += shift as FloatExponent;
+        }
+        let mut mantissa = Wide::from_uint(resize_uint(mantissa)).shl(1); // fits in Uint<W>, since the float has at least one exponent bit
+        mantissa.set_bit(0, sticky_bit);
+        Self::from_wide_parts(sign, exponent - 1, mantissa, rounding)
+    }
+
+    /// Converts an integer to a float, rounding according to the given rounding mode if the integer cannot be represented exactly.
+    #[must_use]
+    #[inline]
+    pub const fn from_integer_round<const S: bool, const N: usize, const B: usize, const OM: u8>(
+        value: Integer<S, N, B, OM>,
+        rounding: RoundingMode,
+    ) -> Self {
+        Self::from_integer_with_status(value, rounding).0
+    }
+
+    /// Converts an integer to a float, rounding according to the given rounding mode if the integer cannot be represented exactly. Returns the result together with the exception flags raised by the conversion.
+    #[must_use]
+    #[inline]
+    pub const fn from_integer_with_status<
+        const S: bool,
+        const N: usize,
+        const B: usize,
+        const OM: u8,
+    >(
+        value: Integer<S, N, B, OM>,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        Self::from_uint_parts(
+            value.is_negative_internal(),
+            0,
+            value.unsigned_abs_internal(),
+            rounding,
+        )
+    }
+
+    /// Converts a ratio to a float, rounding according to the given rounding mode if the ratio cannot be represented exactly.
+    #[cfg(feature = "ratio")]
+    #[must_use]
+    #[inline]
+    pub const fn from_ratio_round<const N: usize>(value: crate::Ratio<N>, rounding: RoundingMode) -> Self {
+        Self::from_ratio_with_status(value, rounding).0
+    }
+
+    /// Converts a ratio to a float, rounding according to the given rounding mode if the ratio cannot be represented exactly. Returns the result together with the exception flags raised by the conversion.
+    #[cfg(feature = "ratio")]
+    #[must_use]
+    pub const fn from_ratio_with_status<const N: usize>(
+        value: crate::Ratio<N>,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let sign = value.is_negative();
+        let denom = value.denom();
+        let (mut quotient, mut remainder) = value.numer().unsigned_abs().div_rem_unchecked(denom);
+        if quotient.bit_width() >= Self::MB + 3 {
+            // the quotient has more bits than are needed for rounding, so the remainder only contributes to the sticky bit
+            quotient.set_bit(0, quotient.bit(0) || !remainder.is_zero());
+            return Self::from_uint_parts(sign, 0, quotient, rounding);
+        }
+        if value.is_zero() {
+            return (Self::ZERO, FloatStatus::OK);
+        }
+        // long division, producing one more bit of the quotient at each step until there are enough bits for rounding
+        let mut mantissa = Wide::from_uint(resize_uint(quotient));
+        let mut exponent: FloatExponent = 0;
+        while mantissa.bit_width() < Self::MB + 3 {
+            // `2 * remainder >= denom` if and only if `remainder >= denom - remainder`, which avoids overflow
+            let bit = remainder.ge(&denom.wrapping_sub(remainder));
+            remainder = if bit {
+                remainder.wrapping_sub(denom.wrapping_sub(remainder))
+            } else {
+                remainder.shl(1)
+            };
+            mantissa = mantissa.shl(1);
+            mantissa.set_bit(0, bit);
+            exponent -= 1;
+        }
+        mantissa.set_bit(0, mantissa.bit(0) || !remainder.is_zero());
+        Self::from_wide_parts(sign, exponent, mantissa, rounding)
+    }
+
+    /// Converts a float with a different number of bits and/or mantissa bits to a float of this type, rounding according to the given rounding mode if the value cannot be represented exactly.
+    #[must_use]
+    #[inline]
+    pub const fn from_float_round<const V: usize, const NB: usize>(
+        value: Float<V, NB>,
+        rounding: RoundingMode,
+    ) -> Self {
+        Self::from_float_with_status(value, rounding).0
+    }
+
+    /// Converts a float with a different number of bits and/or mantissa bits to a float of this type, rounding according to the given rounding mode if the value cannot be represented exactly. Returns the result together with the exception flags raised by the conversion.
+    #[must_use]
+    #[inline]
+    pub const fn from_float_with_status<const V: usize, const NB: usize>(
+        value: Float<V, NB>,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let sign = value.is_sign_negative();
+        match value.classify() {
+            FpCategory::Nan => {
+                // keep the sign and the most significant bits of the payload, and quiet the NaN
+                let (_, _, mantissa) = value.into_raw_parts();
+                let payload = if MB >= NB {
+                    let payload: Uint<W> = resize_uint(mantissa);
+                    payload.shl((MB - NB) as Exponent)
+                } else {
+                    resize_uint(mantissa.shr((NB - MB) as Exponent))
+                };
+                let quiet_bit = Uint::ONE.shl(Self::MB - 1);
+                let nan = Self::from_bits(Self::INFINITY.to_bits().bitor(payload).bitor(quiet_bit));
+                let nan = if sign { nan.neg() } else { nan };
+                (nan, Float::nan_operands_status(&[value]))
+            }
+            FpCategory::Infinite => {
+                let infinity = if sign {
+                    Self::NEG_INFINITY
+                } else {
+                    Self::INFINITY
+                };
+                (infinity, FloatStatus::OK)
+            }
+            FpCategory::Zero => {
+                let zero = if sign { Self::NEG_ZERO } else { Self::ZERO };
+                (zero, FloatStatus::OK)
+            }
+            _ => {
+                let (_, exponent, mantissa) = value.into_signed_parts();
+                Self::from_uint_parts(
+                    sign,
+                    exponent - Float::<V, NB>::MB_AS_FLOAT_EXP,
+                    mantissa,
+                    rounding,
+                )
+            }
+        }
+    }
+}
+
+macro_rules! primitive_float_conversions {
+    ($($float: ident ($uint: ident, $W: literal, $MB: literal): $to: ident, $from: ident => [$(($W_wide: literal, $MB_wide: literal)), *]), *) => {
+        impl<const W: usize, const MB: usize> Float<W, MB> {
+            $(
+                #[doc = concat!("Converts `self` to the nearest [`", stringify!($float), "`], with ties rounding to even. Values which are too large in magnitude to be represented are converted to infinity, and values which are too small are converted to a subnormal number or zero.")]
+                #[must_use]
+                #[inline]
+                pub const fn $to(self) -> $float {
+                    let rounded = Float::<$W, $MB>::from_float_round(self, RoundingMode::NearestTiesEven);
+                    $float::from_bits($uint::from_le_bytes(rounded.to_bits().to_le_bytes()))
+                }
+
+                /// converts a primitive float to the nearest float of this type, with ties rounding to even
+                #[inline]
+                pub(crate) const fn $from(value: $float) -> Self {
+                    let bits = Uint::from_le_bytes(value.to_bits().to_le_bytes());
+                    Self::from_float_round(Float::<$W, $MB>::from_bits(bits), RoundingMode::NearestTiesEven)
+                }
+            )*
+        }
+
+        $(
+            $(
+                impl From<$float> for Float<$W_wide, $MB_wide> {
+                    #[doc = concat!("Converts a [`", stringify!($float), "`] to a float of this type. The conversion is exact, since this type has at least as many exponent bits and mantissa bits as [`", stringify!($float), "`].")]
+                    #[inline]
+                    fn from(value: $float) -> Self {
+                        Self::$from(value)
+                    }
+                }
+            )*
+        )*
+    };
 }
 
+// `From` is only implemented for the standard formats which are at least as wide as the primitive type, as the conversion would be lossy otherwise. conversions between any two formats are available through `CastFrom`, which rounds when needed
+primitive_float_conversions!(
+    f32 (u32, 4, 23): to_f32, round_from_f32 => [(4, 23), (8, 52), (10, 64), (16, 112), (32, 236)],
+    f64 (u64, 8, 52): to_f64, round_from_f64 => [(8, 52), (10, 64), (16, 112), (32, 236)]
+);
+
+// `F32` deliberately doesn't implement `From<f16>`, as otherwise the type of the float literal in e.g. `F32::from(1.0)` couldn't be inferred
+#[cfg(nightly)]
+primitive_float_conversions!(
+    f16 (u16, 2, 10): to_f16, round_from_f16 => [(2, 10), (8, 52), (10, 64), (16, 112), (32, 236)],
+    f128 (u128, 16, 112): to_f128, round_from_f128 => [(16, 112), (32, 236)]
+);
+
 #[cfg(test)]
 macro_rules! test_reversible_conversion {
     ($to: ident, $from: ident ($($param: ident), *) -> $dest_type: ident $(, $prop: path)?) => {
@@ -298,8 +635,11 @@ macro_rules! test_reversible_conversion {
 
 #[cfg(test)]
 mod tests {
+    use crate::RoundingMode;
+    use crate::cast::CastFrom;
     use crate::test::test_bignum;
-    use crate::types::{F32, F64};
+    use crate::Float;
+    use crate::types::{F32, F64, I128, U128};
 
     crate::test::test_all! {
         testing floats;
@@ -320,4 +660,142 @@ mod tests {
         test_reversible_conversion!(into_signed_parts, from_signed_parts(a, b, c) -> FTEST, FTEST::to_bits);
         test_reversible_conversion!(into_normalised_signed_parts, from_normalised_signed_parts(a, b, c) -> FTEST, FTEST::to_bits);
     }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_from_float_round(f: f64) -> quickcheck::TestResult {
+            if f.is_nan() {
+                return quickcheck::TestResult::discard();
+            }
+            let value = F64::from(f);
+            let nearest = F32::from_float_round(value, RoundingMode::NearestTiesEven);
+            let down = F32::from_float_round(value, RoundingMode::TowardNegative);
+            let up = F32::from_float_round(value, RoundingMode::TowardPositive);
+            let exact = F64::from_float_round(nearest, RoundingMode::TowardZero) == value;
+
+            quickcheck::TestResult::from_bool(
+                nearest.to_bits() == F32::from(f as f32).to_bits()
+                    && F64::from_float_round(down, RoundingMode::NearestTiesEven) <= value
+                    && F64::from_float_round(up, RoundingMode::NearestTiesEven) >= value
+                    && (exact || down.next_up().to_bits() == up.to_bits())
+            )
+        }
+
+        fn quickcheck_primitive_float_conversions(a: f32, b: f64) -> bool {
+            let a_wide = Float::<16, 112>::from(a);
+            let b_wide = Float::<16, 112>::from(b);
+            F32::from(a).to_f32().to_bits() == a.to_bits()
+                && F64::from(b).to_f64().to_bits() == b.to_bits()
+                && F64::from(a).to_bits() == F64::from(a as f64).to_bits()
+                && a_wide.to_f32().to_bits() == a.to_bits()
+                && b_wide.to_f64().to_bits() == b.to_bits()
+                && (b.is_nan() || b_wide.to_f32().to_bits() == (b as f32).to_bits())
+                && (b.is_nan() || F64::from(b).to_f32().to_bits() == (b as f32).to_bits())
+                && (a.is_nan() || Float::<2, 10>::from_float_round(F32::from(a), RoundingMode::NearestTiesEven).to_f32() == Float::<2, 10>::cast_from(a).to_f32())
+        }
+
+        fn quickcheck_from_integer_round(u: u128, i: i128) -> bool {
+            F64::from_integer_round(U128::cast_from(u), RoundingMode::NearestTiesEven).to_bits() == F64::from(u as f64).to_bits()
+                && F32::from_integer_round(U128::cast_from(u), RoundingMode::NearestTiesEven).to_bits() == F32::from(u as f32).to_bits()
+                && F64::from_integer_round(I128::cast_from(i), RoundingMode::NearestTiesEven).to_bits() == F64::from(i as f64).to_bits()
+                && F32::from_integer_round(I128::cast_from(i), RoundingMode::NearestTiesEven).to_bits() == F32::from(i as f32).to_bits()
+        }
+    }
+
+    #[cfg(feature = "ratio")]
+    quickcheck::quickcheck! {
+        fn quickcheck_from_ratio_round(a: i32, b: u32, c: i16, d: u16, i: i128) -> quickcheck::TestResult {
+            use crate::cast::As;
+            use crate::Ratio;
+
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            // division of primitive floats is correctly rounded, and these integers are exactly representable
+            let ratio = Ratio::<4>::new(a.as_(), b.as_());
+            let small_ratio = Ratio::<2>::new(c.as_(), d.as_());
+            let int = Ratio::<16>::from_int(i.as_());
+            quickcheck::TestResult::from_bool(
+                F64::from_ratio_round(ratio, RoundingMode::NearestTiesEven).to_bits() == F64::from(a as f64 / b as f64).to_bits()
+                    && F32::from_ratio_round(small_ratio, RoundingMode::NearestTiesEven).to_bits() == F32::from(c as f32 / d as f32).to_bits()
+                    && F64::from_ratio_round(int, RoundingMode::TowardZero).to_bits() == F64::from_integer_round(I128::cast_from(i), RoundingMode::TowardZero).to_bits()
+            )
+        }
+    }
+
+    #[cfg(feature = "ratio")]
+    #[test]
+    fn from_ratio_round() {
+        use crate::cast::As;
+        use crate::{FloatStatus, Ratio};
+
+        type Q = Ratio<16>;
+
+        let third = Q::new(1.as_(), 3u8.as_());
+        let down = F64::from_ratio_round(third, RoundingMode::TowardZero);
+        assert_eq!(down, F64::from(1.0 / 3.0));
+        assert_eq!(F64::from_ratio_round(third, RoundingMode::TowardPositive), down.next_up());
+        assert_eq!(F64::from_ratio_round(-third, RoundingMode::TowardNegative), down.next_up().neg());
+        assert_eq!(F64::from_ratio_with_status(third, RoundingMode::NearestTiesEven).1, FloatStatus::INEXACT);
+        assert_eq!(F64::from_ratio_with_status(Q::new((-3).as_(), 8u8.as_()), RoundingMode::NearestTiesEven), (F64::from(-0.375), FloatStatus::OK));
+        assert_eq!(F64::from_ratio_round(Q::ZERO, RoundingMode::TowardNegative).to_bits(), F64::ZERO.to_bits());
+        // the smallest positive ratio is below half of the smallest positive subnormal half precision float
+        let tiny = Q::new(1.as_(), u128::MAX.as_());
+        assert_eq!(Float::<2, 10>::from_ratio_round(tiny, RoundingMode::NearestTiesEven).to_bits(), Float::<2, 10>::ZERO.to_bits());
+        assert_eq!(Float::<2, 10>::from_ratio_round(tiny, RoundingMode::TowardPositive), Float::<2, 10>::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(F32::from_ratio_round(Q::MAX, RoundingMode::NearestTiesEven), F32::from(i128::MAX as f32));
+    }
+
+    #[test]
+    fn from_integer_round_directed() {
+        let u = U128::cast_from((1u128 << 100) + 1);
+        let down = F64::from_integer_round(u, RoundingMode::TowardZero);
+        assert_eq!(down, F64::from((1u128 << 100) as f64));
+        assert_eq!(F64::from_integer_round(u, RoundingMode::TowardPositive), down.next_up());
+        assert_eq!(F64::from_integer_round(u, RoundingMode::NearestTiesAway), down);
+
+        let i = I128::cast_from(-(1i128 << 100) - 1);
+        assert_eq!(F64::from_integer_round(i, RoundingMode::TowardZero), down.neg());
+        assert_eq!(F64::from_integer_round(i, RoundingMode::TowardNegative), down.neg().next_down());
+        assert_eq!(F32::from_integer_round(U128::MAX, RoundingMode::TowardZero), F32::from(u128::MAX as f32).next_down());
+    }
+
+    #[test]
+    fn narrowing_float_conversions() {
+        type F16 = Float<2, 10>;
+
+        // 2^-24 is the smallest positive subnormal half precision float, so half of it rounds (ties to even) to zero and anything larger rounds up
+        assert_eq!(F16::cast_from(2f32.powi(-25)).to_bits(), F16::ZERO.to_bits());
+        assert_eq!(F16::cast_from(-2f32.powi(-25)).to_bits(), F16::NEG_ZERO.to_bits());
+        assert_eq!(F16::cast_from(2f32.powi(-25).next_up()), F16::MIN_POSITIVE_SUBNORMAL);
+        assert_eq!(F16::cast_from(3.0 * 2f32.powi(-25)), F16::MIN_POSITIVE_SUBNORMAL * F16::TWO);
+        assert_eq!(F16::cast_from(65504.0f32), F16::MAX);
+        assert_eq!(F16::cast_from(65519.99f32), F16::MAX);
+        assert_eq!(F16::cast_from(65520.0f32), F16::INFINITY);
+        assert_eq!(F16::cast_from(f64::NEG_INFINITY), F16::NEG_INFINITY);
+        assert!(F16::cast_from(f32::NAN).is_nan());
+        assert_eq!(F16::MAX.to_f32(), 65504.0);
+        assert_eq!(F16::MIN_POSITIVE_SUBNORMAL.to_f64(), 2f64.powi(-24));
+
+        assert_eq!(F64::MAX.to_f32(), f32::INFINITY);
+        assert_eq!(F64::MIN_POSITIVE_SUBNORMAL.to_f32(), 0.0);
+        assert_eq!(F64::from(f32::from_bits(1) as f64 * 0.75).to_f32(), f32::from_bits(1));
+        assert_eq!(F32::cast_from(Float::<16, 112>::MAX), F32::INFINITY);
+        assert_eq!(Float::<16, 112>::cast_from(F32::MAX).to_f32(), f32::MAX);
+
+        // NaNs keep their sign and the most significant bits of their payload, and are quietened
+        let nan = F64::from(f64::from_bits(0xFFF0_1234_5678_9ABC));
+        assert_eq!(nan.to_f32().to_bits(), 0xFFC0_91A2);
+        assert_eq!(F64::from(nan.to_f32()).to_bits(), F64::from(f64::from_bits(0xFFF8_1234_4000_0000)).to_bits());
+    }
+
+    #[cfg(nightly)]
+    quickcheck::quickcheck! {
+        fn quickcheck_f16_f128_conversions(a: f32, b: f64) -> bool {
+            let (a16, b128) = (a as f16, b as f128);
+            Float::<2, 10>::from(a16).to_f16().to_bits() == a16.to_bits()
+                && Float::<16, 112>::from(b128).to_f128().to_bits() == b128.to_bits()
+                && (a.is_nan() || F32::from(a).to_f16().to_bits() == a16.to_bits())
+                && (b.is_nan() || Float::<16, 112>::from(b).to_f16().to_bits() == (b as f16).to_bits())
+        }
+    }
 }
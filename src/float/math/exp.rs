@@ -0,0 +1,198 @@
+use super::TwoFloat;
+use crate::cast::CastFrom;
+use crate::doc;
+use crate::float::{Float, FloatExponent};
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// `x` such that `exp(y)` overflows for all `y > x`
+    #[inline]
+    fn exp_overflow_bound() -> Self {
+        Self::cast_from(Self::MAX_EXP + 1) * Self::LN_2_PARTS[0]
+    }
+
+    /// `x` such that `exp(y)` underflows to zero for all `y < x`
+    #[inline]
+    fn exp_underflow_bound() -> Self {
+        Self::cast_from(Self::MIN_SUBNORMAL_EXP - 2) * Self::LN_2_PARTS[0]
+    }
+
+    /// splits finite `x` into `k * ln(2) + r`, where `|r|` is at most around `ln(2) / 2`. `|x|` must be small enough that `k` fits into a `FloatExponent`
+    pub(crate) fn reduce_ln_2(x: TwoFloat<W, MB>) -> (FloatExponent, TwoFloat<W, MB>) {
+        let [c0, c1, c2] = Self::LN_2_PARTS;
+        let k = (x.hi / c0).round_ties_even();
+        if k.is_zero() {
+            return (0, x);
+        }
+        let r = x
+            .sub(TwoFloat::two_prod(k, c0))
+            .sub(TwoFloat::two_prod(k, c1))
+            .sub(TwoFloat::from_float(k * c2));
+        (FloatExponent::cast_from(k), r)
+    }
+
+    /// `exp(r) - 1`, where `|r|` is at most around `ln(2) / 2`
+    pub(crate) fn exp_m1_reduced(r: TwoFloat<W, MB>) -> TwoFloat<W, MB> {
+        if r.hi.is_zero() {
+            return r;
+        }
+        // halve the argument `s` times so that the Taylor series converges quickly, then undo the halving with `exp(2x) - 1 = (exp(x) - 1) * (exp(x) + 1)`
+        let target = (TwoFloat::<W, MB>::precision().isqrt() / 2 + 1) as FloatExponent;
+        let s = (r.hi.exponent() + target).max(0);
+        let x = r.mul_pow2(-s);
+        let mut term = x;
+        let mut sum = x;
+        let mut k = 2u32;
+        loop {
+            term = term.mul(x).div_float(Self::cast_from(k));
+            if TwoFloat::is_negligible(term.hi, sum.hi) {
+                break;
+            }
+            sum = sum.add(term);
+            k += 1;
+        }
+        for _ in 0..s {
+            sum = sum.mul(sum.add_float(Self::TWO));
+        }
+        sum
+    }
+
+    /// `exp(x) - 1` for finite `x` small enough that the result does not overflow
+    pub(crate) fn exp_m1_two_float(x: TwoFloat<W, MB>) -> TwoFloat<W, MB> {
+        let (k, r) = Self::reduce_ln_2(x);
+        let em1 = Self::exp_m1_reduced(r);
+        if k == 0 {
+            return em1;
+        }
+        TwoFloat::ONE
+            .add(em1)
+            .mul_pow2(k)
+            .add_float(Self::NEG_ONE)
+    }
+
+    /// `exp(x)`, for finite `x`, rounded to the nearest float
+    pub(crate) fn exp_two_float(x: TwoFloat<W, MB>) -> Self {
+        if x.hi > Self::exp_overflow_bound() {
+            return Self::INFINITY;
+        }
+        if x.hi < Self::exp_underflow_bound() {
+            return Self::ZERO;
+        }
+        let (k, r) = Self::reduce_ln_2(x);
+        TwoFloat::ONE.add(Self::exp_m1_reduced(r)).round_scaled(k)
+    }
+
+    /// Returns `e^(self)`, (the exponential function).
+    #[must_use = doc::must_use_op!(float)]
+    pub fn exp(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_infinite() {
+            return if self.is_sign_negative() {
+                Self::ZERO
+            } else {
+                self
+            };
+        }
+        Self::exp_two_float(TwoFloat::from_float(self))
+    }
+
+    /// Returns `2^(self)`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn exp2(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_infinite() {
+            return if self.is_sign_negative() {
+                Self::ZERO
+            } else {
+                self
+            };
+        }
+        if self > Self::cast_from(Self::MAX_EXP + 1) {
+            return Self::INFINITY;
+        }
+        if self < Self::cast_from(Self::MIN_SUBNORMAL_EXP - 2) {
+            return Self::ZERO;
+        }
+        let k = self.round_ties_even();
+        let r = self - k; // exact, since `k` is the nearest integer to `self`
+        let r = TwoFloat::from_parts(Self::LN_2_PARTS).mul_float(r);
+        TwoFloat::ONE
+            .add(Self::exp_m1_reduced(r))
+            .round_scaled(FloatExponent::cast_from(k))
+    }
+
+    /// Returns `e^(self) - 1` in a way that is accurate even if the number is close to zero.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn exp_m1(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_infinite() {
+            return if self.is_sign_negative() {
+                Self::NEG_ONE
+            } else {
+                self
+            };
+        }
+        if self.is_zero() {
+            return self;
+        }
+        if self > Self::exp_overflow_bound() {
+            return Self::INFINITY;
+        }
+        // below this bound, `e^(self)` is less than half an ulp of `1`
+        if self < Self::cast_from(-(Self::MB_AS_FLOAT_EXP + 3)) * Self::LN_2_PARTS[0] {
+            return Self::NEG_ONE;
+        }
+        Self::exp_m1_two_float(TwoFloat::from_float(self)).round()
+    }
+
+    /// Raises `self` to the power of `n`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn powf(self, n: Self) -> Self {
+        if n.is_zero() || self == Self::ONE {
+            return Self::ONE;
+        }
+        handle_nan!(Self::NAN; self, n);
+        let n_is_integer = n.trunc() == n;
+        let n_is_odd_integer = n_is_integer && (n * Self::HALF).trunc() != n * Self::HALF;
+        if n.is_infinite() {
+            let abs = self.abs();
+            return if abs == Self::ONE {
+                Self::ONE
+            } else if (abs > Self::ONE) == n.is_sign_positive() {
+                Self::INFINITY
+            } else {
+                Self::ZERO
+            };
+        }
+        if self.is_infinite() || self.is_zero() {
+            // for `self = ±inf` or `self = ±0`, the result is `±inf` or `±0`, with the sign negative only if `self` is negative and `n` is an odd integer
+            let out = if self.is_infinite() == n.is_sign_positive() {
+                Self::INFINITY
+            } else {
+                Self::ZERO
+            };
+            return if self.is_sign_negative() && n_is_odd_integer {
+                out.neg()
+            } else {
+                out
+            };
+        }
+        if self.is_sign_negative() && !n_is_integer {
+            return Self::NAN;
+        }
+        let exponent = Self::ln_two_float(self.abs()).mul_float(n);
+        let out = if exponent.hi.is_infinite() {
+            if exponent.hi.is_sign_positive() {
+                Self::INFINITY
+            } else {
+                Self::ZERO
+            }
+        } else {
+            Self::exp_two_float(exponent)
+        };
+        if self.is_sign_negative() && n_is_odd_integer {
+            out.neg()
+        } else {
+            out
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use super::frac_2_pi::{FRAC_2_PI_BITS, FRAC_2_PI_WORDS};
+use crate::float::{Float, FloatExponent};
+use crate::{Exponent, RoundingMode, Uint};
+
+/// number of integer bits of a `Fixed<W>`. all the constants we compute are less than `2^INTEGER_BITS`
+const INTEGER_BITS: Exponent = 4;
+
+/// the `i`-th bit of `FRAC_2_PI_BITS`, which has weight `2^-(i + 1)` in `2/pi`
+#[inline]
+const fn frac_2_pi_bit(i: Exponent) -> bool {
+    FRAC_2_PI_BITS[(i / u32::BITS) as usize] & (1 << (u32::BITS - 1 - i % u32::BITS)) != 0
+}
+
+/// Unsigned fixed point number with `W` 32-bit words (so four times as many bits as a `Float<W, MB>`), the top `INTEGER_BITS` bits of which form the integer part. This has over four times the precision of a `Float<W, MB>`, which is enough to evaluate the series for mathematical constants in const contexts, and then round them (or split them into several floats whose sum represents the constant to around three times the precision of a single float).
+#[derive(Clone, Copy)]
+pub(crate) struct Fixed<const W: usize> {
+    words: [u32; W],
+}
+
+impl<const W: usize> Fixed<W> {
+    const BITS: Exponent = W as Exponent * u32::BITS;
+    const FRACTION_BITS: Exponent = Self::BITS - INTEGER_BITS;
+    const ZERO: Self = Self { words: [0; W] };
+
+    #[inline]
+    const fn bit(&self, index: Exponent) -> bool {
+        self.words[(index / u32::BITS) as usize] & (1 << (index % u32::BITS)) != 0
+    }
+
+    #[inline]
+    const fn set_bit(&mut self, index: Exponent) {
+        self.words[(index / u32::BITS) as usize] |= 1 << (index % u32::BITS);
+    }
+
+    /// index of the most significant set bit, or `None` if `self` is zero
+    const fn highest_bit(&self) -> Option<Exponent> {
+        let mut i = W;
+        while i > 0 {
+            i -= 1;
+            if self.words[i] != 0 {
+                return Some(i as Exponent * u32::BITS + u32::BITS - 1 - self.words[i].leading_zeros());
+            }
+        }
+        None
+    }
+
+    #[inline]
+    pub(crate) const fn from_int(n: u8) -> Self {
+        debug_assert!(n < 1 << INTEGER_BITS);
+        let mut out = Self::ZERO;
+        out.words[W - 1] = (n as u32) << (u32::BITS - INTEGER_BITS);
+        out
+    }
+
+    #[inline]
+    const fn is_zero(&self) -> bool {
+        self.highest_bit().is_none()
+    }
+
+    const fn ge(&self, rhs: &Self) -> bool {
+        let mut i = W;
+        while i > 0 {
+            i -= 1;
+            if self.words[i] != rhs.words[i] {
+                return self.words[i] > rhs.words[i];
+            }
+        }
+        true
+    }
+
+    const fn add(mut self, rhs: &Self) -> Self {
+        let mut carry = 0;
+        let mut i = 0;
+        while i < W {
+            let sum = self.words[i] as u64 + rhs.words[i] as u64 + carry;
+            self.words[i] = sum as u32;
+            carry = sum >> u32::BITS;
+            i += 1;
+        }
+        self
+    }
+
+    /// `rhs` must not be larger than `self`
+    const fn sub(mut self, rhs: &Self) -> Self {
+        let mut borrow = 0;
+        let mut i = 0;
+        while i < W {
+            let (difference, overflow1) = self.words[i].overflowing_sub(rhs.words[i]);
+            let (difference, overflow2) = difference.overflowing_sub(borrow);
+            self.words[i] = difference;
+            borrow = (overflow1 || overflow2) as u32;
+            i += 1;
+        }
+        self
+    }
+
+    /// `|self - rhs|`
+    #[inline]
+    const fn abs_diff(&self, rhs: &Self) -> Self {
+        if self.ge(rhs) {
+            self.sub(rhs)
+        } else {
+            rhs.sub(self)
+        }
+    }
+
+    pub(crate) const fn mul_small(mut self, rhs: u32) -> Self {
+        let mut carry = 0;
+        let mut i = 0;
+        while i < W {
+            let product = self.words[i] as u64 * rhs as u64 + carry;
+            self.words[i] = product as u32;
+            carry = product >> u32::BITS;
+            i += 1;
+        }
+        self
+    }
+
+    /// truncating division
+    pub(crate) const fn div_small(mut self, rhs: u32) -> Self {
+        let mut remainder = 0;
+        let mut i = W;
+        while i > 0 {
+            i -= 1;
+            let dividend = (remainder << u32::BITS) | self.words[i] as u64;
+            self.words[i] = (dividend / rhs as u64) as u32;
+            remainder = dividend % rhs as u64;
+        }
+        self
+    }
+
+    /// truncated product. the product must be less than `2^INTEGER_BITS`
+    pub(crate) const fn mul(&self, rhs: &Self) -> Self {
+        // the full product has `2W` words, of which we store the low `W` in `low` and the high `W` in `high`
+        let mut low = [0u32; W];
+        let mut high = [0u32; W];
+        let mut i = 0;
+        while i < W {
+            let mut carry = 0;
+            let mut j = 0;
+            while j < W {
+                let k = i + j;
+                let word = if k < W { low[k] } else { high[k - W] };
+                let t = word as u64 + self.words[i] as u64 * rhs.words[j] as u64 + carry;
+                if k < W {
+                    low[k] = t as u32;
+                } else {
+                    high[k - W] = t as u32;
+                }
+                carry = t >> u32::BITS;
+                j += 1;
+            }
+            high[i] = carry as u32;
+            i += 1;
+        }
+        // shift right by `FRACTION_BITS = W * 32 - INTEGER_BITS`
+        debug_assert!(high[W - 1] >> (u32::BITS - INTEGER_BITS) == 0);
+        let mut out = Self::ZERO;
+        out.words[0] = (high[0] << INTEGER_BITS) | (low[W - 1] >> (u32::BITS - INTEGER_BITS));
+        let mut i = 1;
+        while i < W {
+            out.words[i] = (high[i] << INTEGER_BITS) | (high[i - 1] >> (u32::BITS - INTEGER_BITS));
+            i += 1;
+        }
+        out
+    }
+
+    /// whether successive values `x` and `y` of a quadratically convergent iteration differ in at most the lowest few bits, in which case `y` is accurate to around that many bits
+    const fn converged(x: &Self, y: &Self) -> bool {
+        match x.abs_diff(y).highest_bit() {
+            Some(bit) => bit < INTEGER_BITS,
+            None => true,
+        }
+    }
+
+    /// `1 / self`, for `self` in `[1/2, 4)`. computed with Newton's iteration `r = r (2 - self r)`, starting from `1/4`
+    const fn recip(&self) -> Self {
+        let two = Self::from_int(2);
+        let mut r = Self::from_int(1).div_small(4);
+        loop {
+            let next = r.mul(&two.sub(&self.mul(&r)));
+            if Self::converged(&r, &next) {
+                return next;
+            }
+            r = next;
+        }
+    }
+
+    /// `self / rhs`, for `rhs` in `[1/2, 4)`
+    pub(crate) const fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.recip())
+    }
+
+    /// `sqrt(self)`, for `self` in `[1, 4)`. computed as `self / sqrt(self)`, where the reciprocal square root is computed with Newton's iteration `y = y (3 - self y^2) / 2`, starting from `1/2`
+    pub(crate) const fn sqrt(&self) -> Self {
+        let three = Self::from_int(3);
+        let mut y = Self::from_int(1).div_small(2);
+        loop {
+            let next = y.mul(&three.sub(&self.mul(&y.mul(&y)))).div_small(2);
+            if Self::converged(&y, &next) {
+                return self.mul(&next);
+            }
+            y = next;
+        }
+    }
+
+    /// `sum_{k >= 0} s^k / ((2k + 1) n^(2k + 1))`, with `s = -1` if `alternating` and `s = 1` otherwise. this is `atan(1 / n)` if `alternating` and `atanh(1 / n)` otherwise
+    const fn arctan_recip(n: u32, alternating: bool) -> Self {
+        let mut power = Self::from_int(1).div_small(n);
+        let mut sum = power;
+        let mut k = 1;
+        loop {
+            power = power.div_small(n * n);
+            let term = power.div_small(2 * k + 1);
+            if term.is_zero() {
+                return sum;
+            }
+            sum = if alternating && k % 2 == 1 {
+                sum.sub(&term)
+            } else {
+                sum.add(&term)
+            };
+            k += 1;
+        }
+    }
+
+    /// `pi = 16 atan(1/5) - 4 atan(1/239)` (Machin's formula)
+    const fn pi() -> Self {
+        Self::arctan_recip(5, true)
+            .mul_small(16)
+            .sub(&Self::arctan_recip(239, true).mul_small(4))
+    }
+
+    pub(crate) const PI: Self = Self::pi();
+
+    /// `ln(2) = 2 atanh(1/3)`
+    pub(crate) const LN_2: Self = Self::arctan_recip(3, false).mul_small(2);
+
+    /// `ln(10) = 3 ln(2) + ln(5/4) = 3 ln(2) + 2 atanh(1/9)`
+    pub(crate) const LN_10: Self = Self::LN_2
+        .mul_small(3)
+        .add(&Self::arctan_recip(9, false).mul_small(2));
+
+    /// `e = sum_{k >= 0} 1 / k!`
+    pub(crate) const E: Self = {
+        let mut term = Self::from_int(1);
+        let mut sum = term;
+        let mut k = 1;
+        loop {
+            term = term.div_small(k);
+            if term.is_zero() {
+                break sum;
+            }
+            sum = sum.add(&term);
+            k += 1;
+        }
+    };
+
+    /// `sqrt(2)`
+    pub(crate) const SQRT_2: Self = Self::from_int(2).sqrt();
+
+    /// `floor(2^shift * 2/pi) mod 2^BITS`. bits of `2/pi` beyond those stored in `FRAC_2_PI_BITS` are taken to be zero
+    const fn frac_2_pi_window(shift: FloatExponent) -> Self {
+        let mut out = Self::ZERO;
+        let mut i = 0;
+        while i < Self::BITS {
+            // bit `i` of the window has weight `2^(i - shift)` in `2/pi`
+            let index = shift - i as FloatExponent - 1;
+            if index >= 0
+                && index < (FRAC_2_PI_WORDS * u32::BITS as usize) as FloatExponent
+                && frac_2_pi_bit(index as Exponent)
+            {
+                out.set_bit(i);
+            }
+            i += 1;
+        }
+        out
+    }
+
+    /// `self * rhs mod 2^BITS`, treating `self` and `rhs` as integers
+    const fn wrapping_mul_int(&self, rhs: &Self) -> Self {
+        let mut out = Self::ZERO;
+        let mut i = 0;
+        while i < W {
+            let mut carry = 0;
+            let mut j = 0;
+            while i + j < W {
+                let t =
+                    out.words[i + j] as u64 + self.words[i] as u64 * rhs.words[j] as u64 + carry;
+                out.words[i + j] = t as u32;
+                carry = t >> u32::BITS;
+                j += 1;
+            }
+            i += 1;
+        }
+        out
+    }
+
+    /// the exact value of a finite float, whose lowest set bit must be representable as a `Fixed`
+    const fn from_float<const MB: usize>(value: Float<W, MB>) -> Self {
+        let (_, exponent, mantissa) = value.into_signed_parts();
+        let mut out = Self::ZERO;
+        if value.is_zero() {
+            return out;
+        }
+        let offset = exponent - MB as FloatExponent + Self::FRACTION_BITS as FloatExponent;
+        let mut i = 0;
+        while i <= MB as Exponent {
+            if mantissa.bit(i) && offset + i as FloatExponent >= 0 {
+                out.set_bit((offset + i as FloatExponent) as Exponent);
+            }
+            i += 1;
+        }
+        out
+    }
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// rounds `value` (negated if `negative`) to the nearest float, with ties rounding to even
+    pub(crate) const fn from_fixed(negative: bool, value: &Fixed<W>) -> Self {
+        let highest_bit = match value.highest_bit() {
+            Some(highest_bit) => highest_bit as FloatExponent,
+            None => return if negative { Self::NEG_ZERO } else { Self::ZERO },
+        };
+        // take the leading `Self::BITS` bits of `value` and jam the rest into the lowest bit. this is enough, since a float has at least two exponent bits, so `Self::BITS >= MB + 3`
+        let start = highest_bit + 1 - Self::BITS as FloatExponent;
+        let mut mantissa = Uint::<W>::ZERO;
+        let mut i = 0;
+        while i < Self::BITS {
+            let index = start + i as FloatExponent;
+            if index >= 0 && value.bit(index as Exponent) {
+                mantissa.set_bit(i, true);
+            }
+            i += 1;
+        }
+        let mut i = 0;
+        while (i as FloatExponent) < start {
+            if value.bit(i) {
+                mantissa.set_bit(0, true);
+                break;
+            }
+            i += 1;
+        }
+        Self::from_uint_parts(
+            negative,
+            start - Fixed::<W>::FRACTION_BITS as FloatExponent,
+            mantissa,
+            RoundingMode::NearestTiesEven,
+        )
+        .0
+    }
+
+    /// the largest finite floats need the bits of `2/pi` with weight down to `2^-(EXP_BIAS - MB + Fixed::<W>::BITS - 2)` to be reduced exactly
+    const ASSERT_FRAC_2_PI_BITS: () = assert!(
+        Self::EXP_BIAS as i64 - MB as i64 + Fixed::<W>::BITS as i64 - 2
+            <= (FRAC_2_PI_WORDS * u32::BITS as usize) as i64,
+        "(bnum) the trigonometric functions are not available for floats with this many exponent bits, since their arguments can't be reduced exactly"
+    );
+
+    /// for finite, positive `self`, returns `(q, negative, r)` such that `self * 2/pi = 4n + q + r`, for some integer `n`, where `|r| <= 1/2` is negative if `negative`, and its magnitude is stored in the `Fixed` as `4|r|`.
+    ///
+    /// this is the Payne-Hanek reduction: writing `self = m * 2^e` for an integer `m`, the bits of `2/pi` with weight at least `2^(2 - e)` only contribute multiples of `4` to the product, so only the following `Fixed::<W>::BITS` bits of `2/pi` are needed. this gives `r` to around `Fixed::<W>::BITS - MB` bits of absolute precision, which is more than enough for twice the precision of a float, even when `self` is very close to a multiple of `pi/2`. the reduction is exact, since `ASSERT_FRAC_2_PI_BITS` ensures that all the bits of `2/pi` needed are stored in `FRAC_2_PI_BITS`
+    pub(crate) const fn mul_frac_2_pi_mod_4(self) -> (u8, bool, Fixed<W>) {
+        const { Self::ASSERT_FRAC_2_PI_BITS };
+        let (_, exponent, mantissa) = self.into_signed_parts();
+        let mut m = Fixed::<W>::ZERO;
+        let mut i = 0;
+        while i <= MB as Exponent {
+            if mantissa.bit(i) {
+                m.set_bit(i);
+            }
+            i += 1;
+        }
+        // the product has its two integer bits at the top, and `Fixed::<W>::BITS - 2` fractional bits
+        let shift = exponent - Self::MB_AS_FLOAT_EXP + Fixed::<W>::BITS as FloatExponent - 2;
+        let mut product = Fixed::<W>::frac_2_pi_window(shift).wrapping_mul_int(&m);
+        let top_bit = Fixed::<W>::BITS - 1;
+        let mut quadrant = (product.bit(top_bit) as u8) << 1 | product.bit(top_bit - 1) as u8;
+        let negative = product.bit(top_bit - 2);
+        if negative {
+            // `r` is at least `1/2`, so round `4n + q + r` up to the next integer
+            quadrant = (quadrant + 1) % 4;
+            product = Fixed::ZERO.sub(&product);
+        }
+        // clearing the integer bits leaves `|r| * 2^(BITS - 2)`, which is `4|r|` as a `Fixed`
+        product.words[W - 1] &= u32::MAX >> 2;
+        (quadrant, negative, product)
+    }
+
+    /// splits `value` into `N` floats, each of which is the nearest float to `value` minus the sum of the previous floats
+    pub(crate) const fn from_fixed_parts<const N: usize>(value: Fixed<W>) -> [Self; N] {
+        let mut parts = [Self::ZERO; N];
+        let mut remainder = value;
+        let mut negative = false;
+        let mut i = 0;
+        while i < N {
+            parts[i] = Self::from_fixed(negative, &remainder);
+            let part = Fixed::from_float(parts[i]);
+            if remainder.ge(&part) {
+                remainder = remainder.sub(&part);
+            } else {
+                remainder = part.sub(&remainder);
+                negative = !negative;
+            }
+            i += 1;
+        }
+        parts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frac_2_pi_bits() {
+        // two extra words absorb the truncation errors of the series for `pi` and of the product
+        const WORDS: usize = FRAC_2_PI_WORDS + 2;
+        const TABLE_BITS: Exponent = FRAC_2_PI_WORDS as Exponent * u32::BITS;
+        const FRACTION_BITS: Exponent = Fixed::<WORDS>::FRACTION_BITS;
+
+        let mut table = Fixed::<WORDS>::ZERO;
+        for i in 0..TABLE_BITS {
+            if frac_2_pi_bit(i) {
+                table.set_bit(FRACTION_BITS - 1 - i);
+            }
+        }
+        // computed at runtime, since this is too slow to evaluate at compile time. inverting `pi` to this precision is slower still, so instead we check that `pi * table` is just below `2`: the table is `2/pi` truncated to `TABLE_BITS` bits exactly when `2 - pi * table` lies in `[0, pi * 2^-TABLE_BITS)`. the tolerance is much larger than the truncation errors of `pi` and of the product, but much smaller than the gap between consecutive tables
+        let pi = Fixed::<WORDS>::pi();
+        let product = pi.mul(&table);
+        let two = Fixed::from_int(2);
+        let mut tolerance = Fixed::ZERO;
+        tolerance.set_bit(FRACTION_BITS - TABLE_BITS - 16);
+        let mut ulp = Fixed::ZERO;
+        ulp.set_bit(FRACTION_BITS - TABLE_BITS);
+
+        assert!(two.add(&tolerance).ge(&product), "table is greater than 2/pi");
+        let error = if two.ge(&product) {
+            two.sub(&product)
+        } else {
+            Fixed::ZERO
+        };
+        assert!(pi.mul(&ulp).ge(&error.add(&tolerance)), "table is less than 2/pi by more than its last bit");
+    }
+}
@@ -0,0 +1,1050 @@
+//! The bits of `2/pi` needed by the Payne-Hanek argument reduction of the trigonometric functions, most significant first, so that the `i`-th bit (counting from the most significant bit of the first word) has weight `2^-(i + 1)`. These are too many to compute at compile time, so are tabulated here, and checked against `Fixed::pi` in the tests. The table was generated with the following Python script:
+//!
+//! ```python
+//! from mpmath import mp
+//! WORDS = 8256
+//! BITS = WORDS * 32
+//! mp.prec = BITS + 128
+//! x = int(mp.floor(2 / mp.pi * mp.mpf(2) ** BITS))
+//! words = [(x >> (32 * (WORDS - 1 - i))) & 0xFFFFFFFF for i in range(WORDS)]
+//! for i in range(0, WORDS, 8):
+//!     print("    " + " ".join(f"{w:#010x}," for w in words[i:i + 8]))
+//! ```
+
+/// number of 32-bit words of `FRAC_2_PI_BITS`. this is enough to reduce the arguments of the trigonometric functions exactly for all floats with at most 19 exponent bits (such as binary256) which are at most 84 bytes wide. the trigonometric functions of wider formats fail to compile
+pub(super) const FRAC_2_PI_WORDS: usize = 8256;
+
+pub(super) static FRAC_2_PI_BITS: [u32; FRAC_2_PI_WORDS] = [
+    0xa2f9836e, 0x4e441529, 0xfc2757d1, 0xf534ddc0, 0xdb629599, 0x3c439041, 0xfe5163ab, 0xdebbc561,
+    0xb7246e3a, 0x424dd2e0, 0x06492eea, 0x09d1921c, 0xfe1deb1c, 0xb129a73e, 0xe88235f5, 0x2ebb4484,
+    0xe99c7026, 0xb45f7e41, 0x3991d639, 0x835339f4, 0x9c845f8b, 0xbdf9283b, 0x1ff897ff, 0xde05980f,
+    0xef2f118b, 0x5a0a6d1f, 0x6d367ecf, 0x27cb09b7, 0x4f463f66, 0x9e5fea2d, 0x7527bac7, 0xebe5f17b,
+    0x3d0739f7, 0x8a5292ea, 0x6bfb5fb1, 0x1f8d5d08, 0x56033046, 0xfc7b6bab, 0xf0cfbc20, 0x9af4361d,
+    0xa9e39161, 0x5ee61b08, 0x6599855f, 0x14a06840, 0x8dffd880, 0x4d732731, 0x06061556, 0xca73a8c9,
+    0x60e27bc0, 0x8c6b47c4, 0x19c367cd, 0xdce8092a, 0x8359c476, 0x8b961ca6, 0xddaf44d1, 0x5719053e,
+    0xa5ff0705, 0x3f7e33e8, 0x32c2de4f, 0x98327dbb, 0xc33d26ef, 0x6b1e5ef8, 0x9f3a1f35, 0xcaf27f1d,
+    0x87f12190, 0x7c7c246a, 0xfa6ed577, 0x2d30433b, 0x15c614b5, 0x9d19c3c2, 0xc4ad414d, 0x2c5d000c,
+    0x467d862d, 0x71e39ac6, 0x9b006233, 0x7cd2b497, 0xa7b4d555, 0x37f63ed7, 0x1810a3fc, 0x764d2a9d,
+    0x64abd770, 0xf87c6357, 0xb07ae715, 0x175649c0, 0xd9d63b38, 0x84a7cb23, 0x24778ad6, 0x23545ab9,
+    0x1f001b0a, 0xf1dfce19, 0xff319f6a, 0x1e666157, 0x9947fbac, 0xd87f7eb7, 0x652289e8, 0x3260bfe6,
+    0xcdc4ef09, 0x366cd43f, 0x5dd7de16, 0xde3b5892, 0x9bde2822, 0xd2e88628, 0x4d58e232, 0xcac616e3,
+    0x08cb7de0, 0x50c017a7, 0x1df35be0, 0x1834132e, 0x62128301, 0x48835b8e, 0xf57fb0ad, 0xf2e91e43,
+    0x4a48d367, 0x10d8ddaa, 0x425faece, 0x616aa428, 0x0ab499d3, 0xf2a6067f, 0x775c83c2, 0xa3883c61,
+    0x78738a5a, 0x8cafbdd7, 0x6f63a62d, 0xcbbff4ef, 0x818d67c1, 0x2645ca55, 0x36d9cad2, 0xa8288d61,
+    0xc277c912, 0x1426049b, 0x4612c459, 0xc444c5c8, 0x91b24df3, 0x1700ad43, 0xd4e54929, 0x10d5fdfc,
+    0xbe00cc94, 0x1eeece70, 0xf53e1380, 0xf1ecc3e7, 0xb328f8c7, 0x9405933e, 0x71c1b309, 0x2ef3450b,
+    0x9c12887b, 0x20ab9fb5, 0x2ec29247, 0x2f327b6d, 0x550c90a7, 0x721fe76b, 0x96cb314a, 0x1679e279,
+    0x4189dff4, 0x9794e884, 0xe6e29731, 0x996bed88, 0x365f5f0e, 0xfdbbb49a, 0x486ca467, 0x42727132,
+    0x5d8db815, 0x9f09e5bc, 0x25318d39, 0x74f71c05, 0x30010c0d, 0x68084b58, 0xee2c90aa, 0x4702e774,
+    0x24d6bda6, 0x7df77248, 0x6eef169f, 0xa6948ef6, 0x91b45153, 0xd1f20acf, 0x3398207e, 0x4bf56863,
+    0xb25f3edd, 0x035d407f, 0x89852952, 0x55c06437, 0x10d86d32, 0x4832754c, 0x5bd4714e, 0x6e5445c1,
+    0x090b69f5, 0x2ad56614, 0x9d072750, 0x045ddb3b, 0xb4c576ea, 0x17f9877d, 0x6b49ba27, 0x1d296996,
+    0xacccc654, 0x14ad6ae2, 0x9089d988, 0x50722cbe, 0xa4049407, 0x777030f3, 0x27fc00a8, 0x71ea49c2,
+    0x663de064, 0x83dd9797, 0x3fa3fd94, 0x438c860d, 0xde41319d, 0x39928c70, 0xdde7b717, 0x3bdf082b,
+    0x3715a080, 0x5c93805a, 0x921110d8, 0xe80faf80, 0x6c4bffdb, 0x0f903876, 0x185915a5, 0x62bbcb61,
+    0xb989c7bd, 0x401004f2, 0xd2277549, 0xf6b6ebbb, 0x22dbaa14, 0x0a2f2689, 0x76836433, 0x3b091a94,
+    0x0eaa3a51, 0xc2a31dae, 0xedaf1226, 0x5c4dc26d, 0x9c7a2d97, 0x56c0833f, 0x03f6f009, 0x8c402b99,
+    0x316d07b4, 0x3915200c, 0x5bc3d8c4, 0x92f54bad, 0xc6a5ca4e, 0xcd37a736, 0xa9e69492, 0xab6842dd,
+    0xde6319ef, 0x8c76528b, 0x6837dbfc, 0xaba1ae31, 0x15dfa1ae, 0x00dafb0c, 0x664d64b7, 0x05ed3065,
+    0x29bf5657, 0x3aff47b9, 0xf96af3be, 0x75df9328, 0x3080abf6, 0x8c6615cb, 0x040622fa, 0x1de4d9a4,
+    0xb33d8f1b, 0x5709cd36, 0xe9424ea4, 0xbe13b523, 0x331aaaf0, 0xa8654fa5, 0xc1d20f3f, 0x0bcd785b,
+    0x76f92304, 0x8b7b7217, 0x8953a6c6, 0xe26e6f00, 0xebef584a, 0x9bb7dac4, 0xba66aacf, 0xcf761d02,
+    0xd12df1b1, 0xc1998c77, 0xadc3da48, 0x86a05df7, 0xf480c62f, 0xf0ac9aec, 0xddbc5c3f, 0x6dded01f,
+    0xc790b6db, 0x2a3a25a3, 0x9aaf0093, 0x53ad0457, 0xb6b42d29, 0x7e804ba7, 0x07da0eaa, 0x76a1597b,
+    0x2a12162d, 0xb7dcfde5, 0xfafedb89, 0xfdbe896c, 0x76e4fca9, 0x0670803e, 0x156e85ff, 0x87fd073e,
+    0x28336761, 0x86182aea, 0xbd4dafe7, 0xb36e6d8f, 0x3967955b, 0xbf3148d7, 0x8416df30, 0x432dc735,
+    0x6125ce70, 0xc9b8cb30, 0xfd6cbfa2, 0x00a4e46c, 0x05a0dd5a, 0x476f21d2, 0x1262845c, 0xb9496170,
+    0xe0566b01, 0x52993755, 0x50b7d51e, 0xc4f1335f, 0x6e13e430, 0x5da92e85, 0xc3b21d36, 0x32a1a4b7,
+    0x08d4b1ea, 0x21f716e4, 0x698f77ff, 0x2780030c, 0x2d408da0, 0xcd4f99a5, 0x20d3a2b3, 0x0a5d2f42,
+    0xf9b4cbda, 0x11d0be7d, 0xc1db9bbd, 0x17ab81a2, 0xca5c6a08, 0x17552e55, 0x0027f014, 0x7f8607e1,
+    0x640b148d, 0x4196debe, 0x872afdda, 0xb6256b34, 0x897bfef3, 0x059ebfb9, 0x4f6a68a8, 0x2a4a5ac4,
+    0x4fbcf82d, 0x985ad795, 0xc7f48d4d, 0x0da63a20, 0x5f57a4b1, 0x3f149538, 0x800120cc, 0x86dd71b6,
+    0xdec9f560, 0xbf11654d, 0x6b0701ac, 0xb08cd0c0, 0xb2485551, 0x0efb1ec3, 0x72953b06, 0xa33540c0,
+    0x7bdc06cc, 0x45e0fa29, 0x4ec8cad6, 0x41f3e8de, 0x647cd864, 0x9b31bed9, 0xc397a4d4, 0x5877c5e3,
+    0x6913daf0, 0x3c3aba46, 0x18465f75, 0x55f5bdd2, 0xc6926e5d, 0x2eaced44, 0x0e423e1c, 0x87c461e9,
+    0xfd29f3d6, 0xe7ca7c22, 0x35916fc5, 0xe0088dd7, 0xffe26a6e, 0xc6fdb0c1, 0x0893745d, 0x7cb2ad6b,
+    0x9d6ecd7b, 0x723e6a11, 0xc6a9cff7, 0xdf7329ba, 0xc9b55100, 0xb70db2e2, 0x24ba7460, 0x7de58ad8,
+    0x742c150d, 0x0c188194, 0x667e1629, 0x01767a9f, 0xbefdfdef, 0x4556367e, 0xd913d9ec, 0xb9ba8bfc,
+    0x97c427a8, 0x31c36ef1, 0x36c59456, 0xa8d8b5a8, 0xb40ecccf, 0x2d891234, 0x576f8956, 0x2ce3ce99,
+    0xb920d6aa, 0x5e6b9c2a, 0x3ecc5f11, 0x4a0bfdfb, 0xf4e16d3b, 0x8e2c86e2, 0x84d4e9a9, 0xb4fcd1ee,
+    0xefc9352e, 0x61392f44, 0x2138c8d9, 0x1b0afc81, 0x6a4afbd8, 0x1c2f84b4, 0x538c994e, 0xcc2254dc,
+    0x552ad6c6, 0xc096190b, 0xb8701a64, 0x9569605a, 0x26ee523f, 0x0f117f11, 0xb5f4f5cb, 0xfc2dbc34,
+    0xeebc34cc, 0x5de8605e, 0xdd9b8e67, 0xef3392b8, 0x17c99b58, 0x61bc57e1, 0xc6835110, 0x3ed84871,
+    0xdddd1c2d, 0xa118af46, 0x2c21d7f3, 0x59987ad9, 0xc0549efa, 0x864ffc06, 0x56ae79e5, 0x36228922,
+    0xad38dc93, 0x67aae855, 0x3826829b, 0xe7caa40d, 0x51b13399, 0x0ed7a948, 0x0569f0b2, 0x65a7887f,
+    0x974c8836, 0xd1f9b392, 0x214a827b, 0x21cf98dc, 0x9f405547, 0xdc3a74e1, 0x42eb67df, 0x9dfe5fd4,
+    0x5ea4677b, 0x7aacbaa2, 0xf6552388, 0x2b55ba41, 0x086e5986, 0x2a218347, 0x39e6e389, 0xd49ee540,
+    0xfb49e956, 0xffca0f1c, 0x8a59c52b, 0xfa94c5c1, 0xd3cfc50f, 0xae5adb86, 0xc5476243, 0x853b8621,
+    0x94792c87, 0x61107b4c, 0x2a1a2c80, 0x12bf4390, 0x2688893c, 0x78e4c4a8, 0x7bdbe5c2, 0x3ac4eaf4,
+    0x268a67f7, 0xbf920d2b, 0xa365b193, 0x3d0b7cbd, 0xdc51a463, 0xdd27dde1, 0x6919949a, 0x9529a828,
+    0xce68b4ed, 0x09209f44, 0xca984e63, 0x8270237c, 0x7e32b90f, 0x8ef5a7e7, 0x561408f1, 0x212a9db5,
+    0x4d7e6f51, 0x19a5abf9, 0xb5d6df82, 0x61dd9602, 0x36169f3a, 0xc4a1a283, 0x6ded727a, 0x8d39a9b8,
+    0x825c326b, 0x5b2746ed, 0x34007700, 0xd255f4fc, 0x4d590180, 0x71e0e13f, 0x89b295f3, 0x64a8f1ae,
+    0xa74b38fc, 0x4ceab2bb, 0x47270bab, 0xc3a734ba, 0x6052dd34, 0xf8563aeb, 0x7e8a31bb, 0x365895b7,
+    0x47f7a994, 0xc3aad392, 0x251e7f3e, 0xd8974ebb, 0xa94fd8ae, 0x01e661b4, 0x393d8ea5, 0x23aa3306,
+    0x8e1633b5, 0x3bb1881d, 0x3a9d4013, 0xd0cc1be5, 0xf862e73b, 0xf28f39b5, 0xbf0bc235, 0x22747ea2,
+    0x47c0d52d, 0x1f19add3, 0x9094df93, 0x11d0b42b, 0x25496db2, 0xe264b25e, 0xf1353bc6, 0xa41a4ad0,
+    0xaac92e64, 0xe8865730, 0x91982cfb, 0x311b1a08, 0x728bbdce, 0xe160e142, 0xeb641dd0, 0xbba3e559,
+    0xd4597b8c, 0x2a4483f3, 0x32baf848, 0x672c8d1b, 0x2fa9b050, 0xf3ddf9f5, 0x73db61b4, 0xfe233e6c,
+    0x41a6eea3, 0x18775a26, 0xbc5e5cce, 0xa70894dc, 0x57e20196, 0xf1e839be, 0x48515d2d, 0x2f4e9555,
+    0xd96ec2e7, 0xd7556304, 0xe0c02e0e, 0xfc40a0bb, 0xf9b37125, 0xa7222dfb, 0xf619d883, 0x8c1c6619,
+    0xe6b20d55, 0xbb513779, 0xe809af91, 0x490d73de, 0x0b0da5ce, 0x7f58ac19, 0x34724667, 0x7a1a139e,
+    0x26bc4555, 0xe7585cb5, 0x711d1448, 0x6991480d, 0x6056adab, 0xd62f6496, 0xee0c212f, 0xf35d6d88,
+    0xa6768495, 0x651eab9e, 0x0a4ddefe, 0x57101083, 0x6a39f8ea, 0x319e381d, 0xeac8b1ca, 0xc96b37f2,
+    0x1ed505e9, 0x9847439f, 0xc56c0331, 0xb73b8bf8, 0x86e56a8d, 0xc3436230, 0xe793cfd5, 0x6a8f2d73,
+    0x30051af0, 0x21a09fcb, 0x7415a1d5, 0x6b236ff7, 0x252f4bc7, 0xb8a5917f, 0xac595c55, 0xde212c38,
+    0xb132965c, 0xff503662, 0x62fa7b16, 0xf4d9a62a, 0xcfe7f074, 0x03d4d604, 0x6fd91631, 0xb1bfcbb4,
+    0x505bd7c8, 0x0ce1946b, 0xd6434fd9, 0x1cdf4543, 0x5f3453e2, 0xb5aac9ae, 0xc8131485, 0xf9d2bfba,
+    0xdb9e76f5, 0xb9af15cf, 0xca318214, 0xb56de9fe, 0x4d50fc35, 0xf5aed5a2, 0xd0c1c960, 0x57192eb6,
+    0xe91d9207, 0xd144aea3, 0xc6343566, 0x26d5b431, 0x61e237f1, 0xa2209eff, 0x958e2349, 0x379835f4,
+    0xa64bdc02, 0xc2be13be, 0x80a00b72, 0xa3115c5f, 0x1e1bd10d, 0xb4d3869e, 0x8596976b, 0x2ac91f8a,
+    0x26c23070, 0xf0041412, 0xfc9fa5f7, 0x2a389c68, 0x78e2aa76, 0x50cfe155, 0x9274934e, 0x380a92f7,
+    0x5533f0a6, 0x3db43999, 0x71e2b755, 0xa98a7c00, 0x8f19ac54, 0xd22ea0b4, 0xf5f3e060, 0xc849ffd2,
+    0x69ae52ce, 0x7a5fdde9, 0xce06fb0a, 0xe8a50cce, 0xea9d3e37, 0x66ddb834, 0xf50da090, 0x846f884a,
+    0xe3d5099a, 0x032eae2d, 0xfcb40afb, 0x9b33e281, 0xdd1b16ba, 0xd8c0afd9, 0x6b97b52d, 0xc99c277f,
+    0x5951d521, 0xccd6b649, 0x6b584562, 0xb3baf2a1, 0xa5c47ca2, 0xcfa9b93d, 0x7b7b8948, 0x3d3896b0,
+    0x3cc79cb1, 0xd0825d88, 0xedb7d383, 0x390c6e66, 0xe912dc11, 0x20340de7, 0x82a0fee6, 0x667881dc,
+    0x0ae80abf, 0xcd28c1b7, 0xacde18c4, 0xf8237666, 0xb9959db4, 0x2c07faab, 0xd6038a55, 0x7056aeeb,
+    0xf5b0bd95, 0xe94be00b, 0x9eb3a61e, 0x23f24ad6, 0x2c9ac4f1, 0xad75c412, 0xf93b6e0b, 0x4c2ed46c,
+    0x0ed2e55e, 0x16558786, 0x80fbf676, 0xb41e698e, 0xc3ae285d, 0x4977c92a, 0x16374e19, 0x3906f911,
+    0xc91aaa27, 0x467f0da5, 0x933f69d6, 0xa2578ec2, 0x9f9faa8b, 0x4add9bcf, 0xbb5c1d0b, 0xe88dd533,
+    0xfede36f7, 0xef60b308, 0xb1cc0182, 0x9ebb5915, 0x17b1cc96, 0xb7eef0cd, 0xe267ca73, 0xd1a01943,
+    0xb8a37496, 0x28ea6a37, 0x26262e92, 0x8185ea61, 0x5fdeeb61, 0xd7e2cfa7, 0xaeb7948d, 0x28b48c7b,
+    0x1c4c02f1, 0x5f9d850b, 0x8a2db016, 0xcdd5638e, 0x33f4b3b4, 0x5c292f51, 0x2b04b90d, 0x6fd83995,
+    0xdeadc51d, 0x8262ac03, 0x09a2a916, 0x025aaf0a, 0xd3e4275f, 0x590b067e, 0x774b63e8, 0xab012afe,
+    0xfc81441b, 0x67bfb19f, 0x6d4d7955, 0x7ffa4d3b, 0x0b29c026, 0x4dd64734, 0xa1ad8633, 0xe708725b,
+    0xdf8a0fbe, 0xfc685251, 0xe13b665d, 0x171871a8, 0x41a28f4e, 0x2e14db2f, 0xa42505c0, 0x2c474738,
+    0x60373048, 0x512ddf2c, 0xab5aa559, 0x238ba27b, 0xc2460006, 0xdb6951b1, 0xe0f13419, 0x79ab9bc2,
+    0x00cbe2ca, 0x690fa693, 0x4f31f325, 0xf2fef910, 0xb32d6fcc, 0x0215d378, 0x6cffaee9, 0x5d2616fe,
+    0x23843f2c, 0x828acdf4, 0xd2499ee3, 0xceb09f06, 0xfb3942e0, 0xd6c4e24c, 0x163a6888, 0xe07fd00c,
+    0xb22dd531, 0xbc9b63c5, 0x3c94eb2d, 0x8ffa1e1a, 0x7fba45e5, 0x7b7c2624, 0xe78a92ac, 0x2996341d,
+    0x1352f7d5, 0xa42191d1, 0x231a2c39, 0x09576f11, 0x161fffc6, 0x9032a3fe, 0xd8e43e97, 0xdf96da4d,
+    0xf9a472a2, 0x37db66be, 0xab208b01, 0xbdba8f4a, 0xa0b4c1e8, 0xe4038614, 0x695a01d9, 0xd1914e00,
+    0xe8de7422, 0x38f4d580, 0x42160ff0, 0xda4af8a0, 0xf7527e5e, 0xcb981d04, 0xbedfa47a, 0x1f4045dc,
+    0x68477f52, 0x8c7ef7f0, 0x58b5b4a4, 0xbbae0a35, 0x51267e26, 0x5db7c59b, 0xbc2a68e9, 0xd37e1ad6,
+    0x1d19fb56, 0xb25c68ed, 0xa71aefc9, 0xbe937af4, 0x599520f5, 0xdaeb0755, 0x08c71f46, 0xbde8fac7,
+    0x829495c9, 0x3aaafbed, 0xbbf1be26, 0x3e4f0dde, 0xfdafb99e, 0xeeb0762b, 0xac49bdcf, 0x73b8d068,
+    0x92510aed, 0x848c2176, 0x7f3a59d4, 0x2cde0fea, 0x912a6c2f, 0xb60a4920, 0x9fe95da2, 0xb596bb00,
+    0x56624519, 0x9c8b2fea, 0xf0c42cfc, 0x3e6c8afd, 0xf40ff29c, 0x022d8021, 0x4a8cd63e, 0xad13ba94,
+    0x9befec37, 0x07a4d18a, 0x0a432544, 0x5aba761f, 0x06cca16d, 0x4d1536ab, 0x5e0ce333, 0xd4c2913f,
+    0x281bf746, 0x4239e78b, 0x12aff801, 0x75f844f9, 0x832ce5b7, 0x8d6da4ea, 0x4f0aed6c, 0x1bd83f1d,
+    0x2efc7631, 0x8bd928fd, 0xdc3bf172, 0xe2bcb977, 0x44afe05b, 0x819cd975, 0x1f7254b9, 0xe4e059ab,
+    0x67652e1b, 0x7dfbacd9, 0x04e72e0b, 0x2a99948b, 0x16b9a380, 0xeaf9d1f3, 0x5399cb58, 0xde762c86,
+    0xa36b0214, 0xb7d9f208, 0x34e2aa7c, 0x39917735, 0xebaf8053, 0x7add36c8, 0xa562cd07, 0xd485e85e,
+    0x0a57d5ad, 0x78298160, 0x2ac3e45b, 0x1305f3a3, 0xef26db0d, 0x0910214a, 0x27790cd1, 0xe3c2182d,
+    0xf51c3c20, 0x01c11d39, 0x2986c73c, 0x43c14264, 0x74927ce6, 0xce03dba3, 0xb72ade5a, 0xd28afb5d,
+    0xf8886f40, 0x60ad413e, 0x90e0083b, 0xb550001e, 0x7da01b40, 0x9a139aad, 0x4a378687, 0xc6c56a3d,
+    0xf68cc38b, 0x4ab7ae39, 0xc9dad17e, 0x6178851b, 0xa345d3ab, 0x0da7ed4f, 0x9f4038ca, 0xabc87d9a,
+    0x6a007a58, 0x4c63e151, 0x4d4dde06, 0x3f368501, 0xef295fa2, 0x5965ca67, 0x31bc830f, 0x846b554e,
+    0x0bd44502, 0x0f5605da, 0x490db5e3, 0x3fa7404a, 0x2717c4e1, 0x7059ca13, 0xfb742c2b, 0x7a1c2dd7,
+    0xe754a954, 0x5d040274, 0x654a19da, 0xdfc79923, 0xe89dc953, 0xedd05cea, 0xc48360a7, 0xd19a1800,
+    0xa909cd37, 0x65585975, 0x5e196dca, 0xc7e07141, 0xf2a86b8d, 0xf49dde9d, 0xd9a4b034, 0xbc48a10c,
+    0x19a4b52a, 0x2a47c599, 0x32de0df6, 0x7f67d7ae, 0x3393c90f, 0x913f7754, 0x971ec1c6, 0x297725aa,
+    0x0d0aa6fa, 0x0a70e816, 0xf4f7caf4, 0xcf7711ef, 0x5e960a92, 0x9895960f, 0xba2e9b3c, 0x5c6382a0,
+    0xcb52513b, 0xb0fc856f, 0xf7afa8dd, 0x087b7451, 0xd9279c56, 0xc9173c0a, 0x80996aaf, 0xdbdbb81f,
+    0xeba9f6c2, 0xe1491602, 0xa9769411, 0xce869a53, 0x955c8635, 0x26c3446b, 0x9fbe0ab7, 0x9cfa9cc8,
+    0xb4f49457, 0xbc07ceaf, 0x01dfb6b9, 0xb36dfda0, 0x437fafbf, 0xf66dfac8, 0x82d11f4e, 0xd74fd9ee,
+    0xc335b12c, 0xc9c58468, 0xe0af4ace, 0x0d5539f5, 0x91d18d78, 0xc5f2328f, 0x8a9e2907, 0x8d4d40b8,
+    0x18318773, 0x73cb9567, 0x5bc8d0de, 0x6e60996b, 0x5b5113c5, 0x77aaab80, 0xb387ddc9, 0x2a7c6a13,
+    0x291dcd99, 0x67044bae, 0xd431b3cf, 0x7ee28772, 0x8ef52123, 0x10bcf765, 0x1c112c7d, 0x0c5dc625,
+    0xce02299b, 0x0c65b3ab, 0xd63e7e13, 0xa0e4b378, 0x7662d7ec, 0x9f2859c0, 0x58d8fa0b, 0x8bd66872,
+    0xc98e2d3a, 0xabe08cb0, 0x38ee5afa, 0xe0a69c94, 0x71813c0d, 0x290f115b, 0x4d544800, 0x6f08b20a,
+    0x02c29155, 0xe70276e9, 0xa12220b9, 0x59a61a47, 0xff278141, 0xb892ddd0, 0xc29c0eb3, 0x9fe63ee0,
+    0xd06504df, 0xdefa62dc, 0x44ef8378, 0x42e941ce, 0x8fe00ddd, 0xf0a9b0e4, 0xdafea204, 0x8ae861c4,
+    0xc8e81b61, 0xe81d087c, 0x1b97db9b, 0x381750fb, 0x63b5c309, 0xb178f2a6, 0x9e935792, 0x8c6ae15f,
+    0x4096e673, 0x9a5ae3d7, 0x1d7f7825, 0x5bd41424, 0x8c0b8c6a, 0x1c704468, 0x19f54a03, 0x562ebfb5,
+    0x5a26349d, 0xbf9c18b6, 0xb4fd1661, 0x5fbaa6f2, 0xa67015f9, 0x056a4aaa, 0xa1b621f2, 0x4a82594c,
+    0x0ded1cd0, 0xcf12b832, 0x25c0a48b, 0x606c84de, 0xcc16eef4, 0x09cfbbe4, 0xbb68bfe0, 0x2a158db4,
+    0x9e8280d1, 0x37ec11cc, 0x67e18f26, 0x3f374050, 0x7ecd1438, 0xebcefea2, 0x2bd40ae8, 0x5dbaf302,
+    0xdf0cb729, 0x40b91252, 0xf6cf17d5, 0xe815f0a6, 0xec7ebc24, 0x4c11f412, 0xe32c7ba8, 0x44579966,
+    0x188a0f98, 0x4002ee63, 0x4b861eb8, 0xa5600233, 0x335a491a, 0x48d9937c, 0x3b66d173, 0x2ce8b5a3,
+    0x1d5eb73f, 0xa018a355, 0x9f59272e, 0xccb718c9, 0xcf48c488, 0x6a9f133d, 0x9623750b, 0xaa9396eb,
+    0x7bfba3de, 0x4d2b6b66, 0x6153da73, 0x521ff187, 0xdbce4244, 0x32b790f1, 0x03423764, 0xe6c80f01,
+    0x5e1a2fb6, 0x68f9f0e6, 0xcdfdc09d, 0x4cbe9234, 0x75ccecc7, 0x41a79ee4, 0x2a254539, 0xf4d32db4,
+    0x8a265f13, 0xb43c4699, 0x5fb00be0, 0x81f4e89c, 0x1265f5c8, 0xb4f61544, 0x1fbfaa99, 0xfd22bda1,
+    0xf32fdefc, 0xe7a64382, 0x51c1e2b4, 0x6e82ad8b, 0x82f8a691, 0xa9487b6a, 0x02050c45, 0x3f6f3167,
+    0xd367f91a, 0x1945f973, 0x9db3d39d, 0x41ebc5e2, 0x48299ae2, 0x7a82fe99, 0xddd3f274, 0xb8f982ae,
+    0x429012ff, 0xa72607b5, 0x34cf467c, 0x0c25353b, 0x7490f251, 0x1166c314, 0x9fbaaf8c, 0xbbf2e077,
+    0xd915eea5, 0xd16aa52d, 0x229a5a52, 0xbab7297a, 0x21764f0a, 0x4c436e58, 0x89bdef81, 0x306997a8,
+    0xd9f9886a, 0xcdf7b529, 0xe4856944, 0x5bcc78b4, 0x76bcc798, 0x42aad92c, 0x30a8a996, 0x462e338c,
+    0x7e711bce, 0xec61f51d, 0x1abcca05, 0xcaffb606, 0x77cf7c8d, 0xb728e1f7, 0xef93e570, 0xe66a6aaf,
+    0x7f1dad75, 0xc3cb9e00, 0xfbbac147, 0xb5199b14, 0x74c6925d, 0x4af903dc, 0xcc92514c, 0x77bbf59a,
+    0x9c3581c8, 0x1549d6cd, 0x570e1468, 0xe5123a56, 0x66fa597d, 0xc908cf32, 0x2f6041fe, 0x8dd2e30b,
+    0xc53f3b8e, 0x42fa1370, 0xf957369e, 0x2d2738dc, 0xf6defd86, 0xfe8fefa9, 0xa6eb61d1, 0x747dd947,
+    0x8b9ec181, 0xfe19cc7e, 0x2a7e0194, 0x65ebf9a0, 0x82758fd7, 0x210010ff, 0xa3f36146, 0xb61c8273,
+    0x24a4c418, 0xa7a55046, 0x03e3bbba, 0x606c0912, 0xf3d1bc88, 0x6358a8a2, 0x47c1433e, 0x8348e84d,
+    0x25c8b0e0, 0x83b47c9d, 0xe804e305, 0x067d611c, 0x42f22b49, 0x90cfe863, 0x4242aa10, 0xf4d41b09,
+    0x548a2e5a, 0xa0fe0b34, 0x9614f1e6, 0xb6558e02, 0x75649570, 0x2f7620de, 0x09579d37, 0x0788dfb8,
+    0xa07413bb, 0x11c31f04, 0xd9368aa6, 0x3589fa22, 0xdf9095e6, 0xf2d69516, 0xb05d1773, 0x8d7c5066,
+    0xe35b4a04, 0x463a6ff6, 0x8991e537, 0x2ac5fdc7, 0xba151d9e, 0x123ad423, 0x9b205188, 0xb3d3c431,
+    0xf40d491a, 0x375ddcee, 0xffdbecc8, 0x7bf7a375, 0x42f36f15, 0x88e314a5, 0xb29a99bf, 0x407f26b6,
+    0xde778643, 0xda7fdba6, 0x43622429, 0x1959ed1c, 0xe7972e32, 0x71cbadaf, 0x66766346, 0xe9bf11bc,
+    0xcaae96f3, 0x7ac00896, 0xb4b4b3f2, 0xb210c15e, 0x44d78199, 0x752c0864, 0x7d157e6f, 0x032cc614,
+    0x89bbbcc7, 0xa87364f3, 0x0bd5037b, 0x5d2e9c05, 0x300f6ebf, 0x9af82b38, 0x73bbe297, 0xfbcfbdc2,
+    0x5b39a283, 0x20eeb945, 0x96c932dd, 0x142da1d4, 0xd2c96bfa, 0x91fbadd0, 0xbd86adc5, 0x0bb2bddd,
+    0x9e77ea90, 0xba0297b1, 0x06ed5b1c, 0xd405563c, 0xf25ed6cf, 0x0aff0fea, 0xb6c2b445, 0x2c3becf1,
+    0x8c5a570f, 0xb0df21c2, 0xfae15eed, 0xa6f29e2a, 0x5a6e44f7, 0x5ea55e65, 0xf5733fa4, 0xe17637e6,
+    0xc4121e0f, 0xa9db7dca, 0x9dde894d, 0x3d14e00b, 0x7642980c, 0xced6610c, 0xa0c49ebd, 0xcf873d94,
+    0x35d83ff1, 0x4c3adfae, 0x376b61a1, 0x2bd24251, 0x08157c7f, 0xd6deef6a, 0x134e3c20, 0x490b6c99,
+    0x9eeb87b7, 0x2d113266, 0xfec137e8, 0xd6257ad7, 0x276e71c0, 0x84b042c1, 0xfbf06a70, 0xf327606f,
+    0x1138da27, 0x17ee4097, 0x96272392, 0x286c41b7, 0xdc1047df, 0xdf55c37c, 0x2d3bcf93, 0xa4544623,
+    0x4b0fb68c, 0x180624c1, 0xec66fc6d, 0x40650020, 0x14d03369, 0xabbf0e08, 0xa72a5409, 0x2e54d93a,
+    0x5f18d4b1, 0x6cdaea86, 0x4f63d035, 0x2aabc8db, 0x20328c16, 0x748753f4, 0x32ddc59e, 0xf64a0862,
+    0xbe99a364, 0xd62e5259, 0xc55c9f25, 0x699960b3, 0x884b727c, 0xe9d24d36, 0x996b761d, 0x86ed115a,
+    0x2cc538f9, 0xc43f8959, 0xe974e751, 0xb3b55060, 0x4d94a922, 0x015f190e, 0x1ba55884, 0xbc3a9574,
+    0x5d009a73, 0xf29ca7bb, 0x4d719e27, 0x014c42f3, 0xd41228b2, 0x8d922070, 0x15b9904f, 0x5cc2bb94,
+    0xea6916fd, 0xd9c40ed0, 0x81b58661, 0x9a9fb813, 0x33b7b2aa, 0x6e2a70fa, 0xcfd7bc4a, 0x2579943f,
+    0x22aa8760, 0x7e9c99e8, 0xf12435b2, 0x67412f6b, 0x96dca153, 0x6ef06051, 0x35dee9c6, 0x40125bdf,
+    0x7e30f9d4, 0x16c5487e, 0x1a047431, 0xc77e38a0, 0x361b0f5c, 0x2b1134f0, 0xcf5b57cb, 0x5324a4e7,
+    0x30f5d11c, 0x9c250e77, 0xbdf692ff, 0x13668416, 0xe0198abd, 0x358d8d8e, 0x957ba0bf, 0x81a1dffe,
+    0xb68aec18, 0x142d5e9b, 0xcb3e14d2, 0x0ecbe46b, 0x516138d0, 0x4bf547fa, 0xba86e737, 0xe026f0c2,
+    0x19e7a884, 0x5465d136, 0x677b2ac1, 0x2a8933c9, 0x70494456, 0x5da9844f, 0x016b59c2, 0xbd989db4,
+    0x2617be05, 0x941ae303, 0xf40f976a, 0x36db46c0, 0x9dd99532, 0xd65ab49e, 0x310bf6e1, 0xbb834689,
+    0x23415595, 0xe93aedb9, 0x4b1638fe, 0x2b4aa3f6, 0xda0d685c, 0x136d83be, 0x50c1188a, 0xe8ce261b,
+    0x2c276d8d, 0xd95709d0, 0xcf752e59, 0x825d8c91, 0xa2d63ea2, 0x3b478aa6, 0x052362cb, 0xef3eb435,
+    0x8680533b, 0x586dfe51, 0x0b71bb6d, 0x796d0d51, 0x650cec1c, 0x0675550c, 0x2140eee6, 0x2590bccf,
+    0xef71f65b, 0xb2febf99, 0xda2a2aed, 0x4f6a44d3, 0x71a7434c, 0xbc2ed852, 0x1d2cd6a7, 0x944d148d,
+    0xe71bb2c6, 0x7430ab2e, 0x92b0604c, 0x76eafccb, 0xcd0f511e, 0xfd46c9a3, 0xe0c073f2, 0x74309b0b,
+    0xaefd4955, 0x2a88688a, 0xb4258baa, 0x7840bd5a, 0x60f7aac6, 0x51227491, 0xf73cfdfb, 0x0d6608c4,
+    0x474ec579, 0x2f7ff898, 0xd83e0abe, 0x86c1660b, 0x5152fd9c, 0x6fe62d16, 0x45ee1baa, 0xf0022dab,
+    0xde834041, 0x27cfa9e8, 0x8484e3f2, 0x1f26d3f7, 0x9ab66094, 0xe0aee633, 0xaf4b366a, 0x8f9e42b2,
+    0xe19e7354, 0x7add580a, 0x4cd42cd8, 0x86699e44, 0x38127365, 0xbbfe2a12, 0x887b3079, 0x17042101,
+    0x2acd6528, 0xd985c22f, 0x008b4391, 0x47ef7bae, 0x5e004640, 0x51e5c20d, 0xed523d98, 0xbad6ed39,
+    0x59f76490, 0x50469716, 0xbebf6531, 0xd27c3899, 0xc6ead65b, 0x67735256, 0xd1677a7e, 0x2ab9477a,
+    0x98f33f5a, 0x9ec84fe1, 0xf14a726e, 0x396c4a74, 0x8dbf0cc0, 0x11073690, 0x90edf433, 0x34f658ff,
+    0xd146d413, 0xe8381497, 0x169db6cd, 0x8a000d3f, 0xc134c78f, 0xe24dddd0, 0x7f21646b, 0x214f1264,
+    0xf69d7af3, 0xe283d149, 0xc3a6f110, 0x38fa0be6, 0x7fe813d8, 0x4a1540fe, 0x746a191f, 0x3260fed6,
+    0x0f59f922, 0xac396cbe, 0x3addadec, 0x40fd464c, 0x26266c0b, 0x6df8082b, 0xac3480ac, 0xe12d3558,
+    0x608e6f00, 0x78d39bf2, 0xce2b316f, 0xa206ee1d, 0x4c273875, 0x2a6a7568, 0x2e330a42, 0xf2f3e1ac,
+    0x677e8d82, 0x2d628712, 0x137d8155, 0x1a139f7d, 0x98d663e8, 0x35607752, 0xd11f27d8, 0x4de7da3e,
+    0x316c5206, 0xd0e31071, 0xd23f4175, 0xad2277c5, 0x22d20bf9, 0xfc1edc16, 0xec9198b1, 0xcf0962e4,
+    0x4a64da17, 0xe3ad3bcb, 0x19dc7caf, 0xb6a3b52d, 0x892f4bb2, 0xf9187459, 0xe0f84061, 0x704e8636,
+    0x61a19ebe, 0xd50ee67a, 0x060f9aef, 0x0d321133, 0x5c5042b7, 0xf773b397, 0xe3871d54, 0x66f258cf,
+    0xa6052780, 0x88034391, 0x49ee583e, 0xbbe97443, 0xea268fff, 0x0c3208fd, 0x1ab04181, 0xff468823,
+    0x82e2fd59, 0x06be5fd3, 0xd556b16e, 0x60b8b6aa, 0x1fa7f1dc, 0x54fbfd8e, 0xeb2638ce, 0xa53097f1,
+    0xcbdfe15c, 0xe8747544, 0xb045e8f7, 0xce4470b9, 0x1c30f3b8, 0x90343bca, 0xcd380062, 0x33f55726,
+    0x366c7e91, 0x02fc2bdd, 0xb5361018, 0xe391c36a, 0x9a0e5274, 0xc52c2171, 0xd6f4c21e, 0xfaa6c2fd,
+    0xfef20b81, 0x1f1d2752, 0x893a10aa, 0x4422e0fb, 0x92a1def6, 0x2679bd13, 0xdb1bfe32, 0x3118c789,
+    0x8ce498a8, 0x465fb8df, 0x394f5852, 0x57462cc6, 0x2193c03d, 0x618d0fbb, 0x95e71d87, 0x7af0d6e3,
+    0x241fece1, 0x9ee9bdea, 0x1e7f3b12, 0xa547ef89, 0x3339cde4, 0xa167b06a, 0xe3673f62, 0x56bd9813,
+    0x5ea0ddb3, 0x0d7517c9, 0x6a397c52, 0x87e29ae6, 0x1d88d51a, 0xdaec4bec, 0xd33d4dc7, 0xec7ab8d8,
+    0x5df2161c, 0x259093fa, 0xd6b5f836, 0x6c325815, 0x724eab48, 0x4dec0a5f, 0x37de0b12, 0x4409e378,
+    0xb2dc5827, 0x6e631501, 0xbfcc5b4a, 0x2a9d3c11, 0x6d66a142, 0x0839198d, 0x5e64bb9e, 0x93c92cd3,
+    0x28f7b630, 0x78cae18f, 0xb4472415, 0x330d41bb, 0x19ca15bc, 0x75307e7f, 0x43a3d40b, 0x25615af6,
+    0x6714e241, 0xe71faee1, 0x2ac58cfd, 0xf1474062, 0x3469161f, 0x5b26587a, 0xe92810cf, 0x1a28a8ec,
+    0xfb8833b2, 0x9b03d0ba, 0xacd3aeb0, 0x9c17e50c, 0xf5db34bc, 0x2690cfd4, 0xfb294542, 0x38f18675,
+    0xcc238199, 0xdcf0758b, 0x0d7bbd62, 0xd7580581, 0x1b92d902, 0x0d621d06, 0xe7b0a142, 0x1c6341cb,
+    0x3b723c07, 0xe661062c, 0xa94d3051, 0x91c1aafb, 0x089ead39, 0x7b657531, 0x82799308, 0xc047b21f,
+    0x0a29bf21, 0xa291b3ee, 0x7868227a, 0xd8e4a56b, 0x3198f89e, 0x1d26c82e, 0xc6b81366, 0x812178bc,
+    0x77c1198a, 0x2b8dcd66, 0x26e5b6fb, 0x93720289, 0xecf27783, 0xc285a49c, 0xcb519d25, 0x18b65413,
+    0x5514783b, 0xfed03633, 0xaaf70eac, 0x51b4a7ca, 0xa14d8349, 0x5385f170, 0x15e6f44a, 0x48c59515,
+    0x46ae89b2, 0x14370e62, 0x7f0c2bac, 0x13dffbaf, 0xd6e57def, 0xb2cfdd83, 0x57b53c7d, 0xb4da3c11,
+    0x466ddc60, 0xbffe51c5, 0x45d65252, 0x76030753, 0xd8cfceed, 0x1f9fc188, 0x74682f49, 0x62f5b540,
+    0x4542a6dd, 0xba5afbb6, 0x207420d3, 0x382a0479, 0x3c4ba965, 0x0a0f36a1, 0x48c2a42b, 0x60c4106f,
+    0xf03fd65c, 0x86c93e8a, 0x2b5775c5, 0x9918f026, 0x6a1978ee, 0x871e7b36, 0xbf1ead6c, 0xd0fda000,
+    0x9efa374b, 0x1a7a01af, 0x0ad21a1c, 0xda9af546, 0x40a31373, 0x158a3f55, 0x0f346d48, 0xcbf9ce83,
+    0x423fb7df, 0x7a1f9151, 0xdb743e07, 0x2b34f772, 0x576dd279, 0x24ea4cdf, 0xa15ececb, 0xed6ea2ba,
+    0x610f473a, 0x98770ebb, 0xfa5ddab5, 0x020da757, 0xacdb999a, 0x307b566a, 0x44fbdb67, 0xe87ff940,
+    0x83abe3fc, 0x026ac074, 0x77082a03, 0x870313d6, 0x7fb7fc39, 0xb5c19919, 0xdb13b33d, 0x37bbb3f3,
+    0x60a52528, 0x1587d310, 0xad94f4e9, 0xadbb2f66, 0x54887e65, 0x7838d0ce, 0x5448048e, 0x239a6009,
+    0x7a3d4d7a, 0x2cea296d, 0x068d4cac, 0x54a9947a, 0xdf33b366, 0x63a8638e, 0xfbfdc2aa, 0xd38bac56,
+    0xa2915cfe, 0x3269cd18, 0x185b4830, 0x12416f53, 0x8ea928f5, 0xa214accc, 0xe2c1c0fe, 0x5e5769a4,
+    0x7d4d2ec0, 0x4b1cbe82, 0x7b4f28be, 0xfd82ccda, 0x295dff2c, 0xe0b80f8e, 0x0b6b5e1b, 0xc9ebff17,
+    0xee0ca059, 0xf55a4e09, 0xb2f6756e, 0x3931ac13, 0x392b36d2, 0xa73a6dec, 0x74b39ec9, 0x93520951,
+    0xab238899, 0x5843b949, 0x59e6ac8c, 0xef2438df, 0x2cb58c09, 0xe29e9ba7, 0xb20c0f6e, 0x4d32abca,
+    0xef087783, 0x622b0722, 0x6c5c988c, 0xb84d4b25, 0x22c43798, 0x934565a0, 0x09926765, 0x60f84145,
+    0x0d66d2af, 0xe544bc10, 0xfb144f77, 0xb019b9b2, 0xf13b83c8, 0xfb52cd08, 0x15ffa6b3, 0x0dc34997,
+    0x57ba0717, 0x1db47952, 0x5055d9ab, 0x6ccdf356, 0xeb2357a4, 0xdd0da73e, 0x4bbbcf7a, 0x0b7f7338,
+    0x253f977c, 0x30125b00, 0x8c55dd9a, 0x464d3514, 0x55e6c111, 0xdae5315f, 0x1df03d99, 0xc3565094,
+    0xad29bd7a, 0xfc926265, 0x5422b4cd, 0xa9e220ae, 0x5a86496e, 0x220f5aed, 0xe6ce591d, 0x774a64ce,
+    0x3a74e212, 0xd9a67245, 0x6b5e0fd5, 0x4d33fdb9, 0xd686885d, 0x856748d1, 0xf9a2e012, 0x5139bcaf,
+    0x33c4c8ad, 0xbc3f27ae, 0x2467759b, 0x50b0d0c7, 0xf3d7bc0a, 0x48c9b669, 0xabc12bdf, 0x65609406,
+    0x13abf6b9, 0x25bf9444, 0x8e73f586, 0x29708ff6, 0x7ac694db, 0x7f549d41, 0x8e7a81a2, 0x47b5b24b,
+    0xb9070c45, 0xd687035e, 0xe16fa12d, 0xdf21ff21, 0x1d9aaa70, 0x9efb4542, 0x48372fd4, 0x0ef83e88,
+    0x438d6011, 0xf0c80c48, 0x75811e22, 0x35367e7b, 0x8f35db96, 0xcb46b870, 0xede2b871, 0xbfd4f3b9,
+    0x4906e14a, 0xbb00d0b6, 0x713947bb, 0x600a0aec, 0x8325941e, 0x7ff3d12c, 0x60080fde, 0x47838d54,
+    0x7f69fe17, 0x6e22ffff, 0x45c3b807, 0x65f30056, 0xc3b54450, 0x3ba90ae1, 0x02f60f63, 0xa65368f8,
+    0xa6040f38, 0x79b9e203, 0xba66fdb9, 0x177a12f0, 0x9994658c, 0x646470e2, 0xe40de99c, 0xe20074df,
+    0xb50b905a, 0x1ea30b3f, 0x5cebed7c, 0xd0fe1af4, 0x726b70a3, 0xa7de2c1e, 0x0e3c2eea, 0x79461961,
+    0xd4495fad, 0x0f911c3d, 0x301e691f, 0xcf38cacb, 0x6f17c072, 0x81a391b8, 0x24149b89, 0xc5edf399,
+    0x7a48ca68, 0x73fa4687, 0x87b8cb33, 0xbf8e12b4, 0x6d401bc6, 0x203349ed, 0x39017861, 0xc7350bd6,
+    0xf0563626, 0x0d2efbd1, 0x51409672, 0x858ee74b, 0xa0998aab, 0xa4132253, 0xe6fc0ce5, 0x684f94ae,
+    0x6f3b9864, 0x83c8ddb5, 0x3945c27a, 0x0586c2c6, 0xfd7464df, 0x930b03e3, 0x6c6f748b, 0x80ea4447,
+    0xb556778d, 0x9b42e986, 0x60942547, 0xe8e3009f, 0x041c7dde, 0x183b3426, 0xa8b84fea, 0x6a40d766,
+    0xb8b9d660, 0x348870ee, 0xad08f578, 0x718a2a7d, 0xb724f30f, 0xcef13f01, 0x5506578a, 0x35b72393,
+    0xdfcaf49b, 0x76dd67b9, 0xb888c579, 0xcde35a51, 0xfbc975ec, 0x0a38ee5a, 0xc32dbd9a, 0x881e2122,
+    0xc326749f, 0x3affec54, 0x86ae4295, 0xbf959ebb, 0x5e82a74e, 0xee99e69f, 0xb49040b4, 0x173f5446,
+    0x9a25d959, 0xdb7e7b98, 0xe4ae6d8b, 0x2e96e58c, 0x0645d486, 0x24a11cb1, 0x7234dcb4, 0x3c745f42,
+    0x913df72c, 0xbd413d01, 0x99c914c6, 0xfea8d488, 0xef84d726, 0xe7405c96, 0xae2c7415, 0xa4667c84,
+    0xf874f212, 0x7b5dfa3c, 0x28ac4af9, 0x72b26f1b, 0x126dba4b, 0x68c86a56, 0xdc13184e, 0x7e16a9c6,
+    0x95e4fb95, 0x81c0353c, 0x162b6cea, 0x1cad60b0, 0x51210e2d, 0x998ffa7c, 0x96ad1bf7, 0x2aceb61d,
+    0x519c8f78, 0x02160b5d, 0x3dd4d25e, 0xd0ae7813, 0xe32a057a, 0x7100b0e4, 0x132781c0, 0x6574b1b0,
+    0x2fe3634d, 0x204eebe2, 0x1462d030, 0x62134e82, 0x8d5ff1ee, 0x5c74fd4c, 0xd4891bf3, 0xa57cc1d2,
+    0xb996f6c1, 0x7f91cadd, 0xd00010f6, 0x95c7e457, 0xe0139cc6, 0xc61084c6, 0x38b82819, 0x0deac6e1,
+    0xf7581a3c, 0x9dca68df, 0x15c7a3aa, 0x1113c4ec, 0x879f1694, 0xdb38fa11, 0xe384e250, 0xb1241cd3,
+    0x9f7434d5, 0x64893621, 0xea76005e, 0x3da2fc13, 0x2c8e787f, 0x23860957, 0xfde0f8e0, 0x1dcd287a,
+    0xaf98ff61, 0x6263e3ba, 0x26774182, 0xc4581a1e, 0x812a4d04, 0x5a9da93a, 0xe254ce95, 0x07c7230c,
+    0x6e92f201, 0x1c17a9a9, 0xa8d92c74, 0x6596cb5c, 0x172a2347, 0x78161165, 0x1d45d6d8, 0xfb0218d7,
+    0xda8810a0, 0x865b5077, 0x0bd524a8, 0xba089692, 0x2207aba4, 0x5484d616, 0x28af340f, 0x1636acce,
+    0xc878c19f, 0xb94cf601, 0x33cb83fd, 0x75166851, 0xe1560cd3, 0x05cc5a00, 0x953693d9, 0x10995bed,
+    0xde874d43, 0x03c64e77, 0x42972567, 0xd482762e, 0xc6427685, 0x3f89f147, 0xcfa46dd0, 0xcb2be393,
+    0x9d039b5b, 0xe6f299c5, 0xc56e095b, 0x4890dec9, 0x319d94e3, 0x51d1db1c, 0xb89517bf, 0x979eb6fd,
+    0x9419891e, 0xca10b034, 0x2350a42d, 0x331475d3, 0xe2b03f63, 0x98e95dcb, 0x94fa7776, 0x580c677f,
+    0x818335b3, 0xbc48edb1, 0xdd8f8d83, 0xd7186e5e, 0xe06fb151, 0x3afbc902, 0xcd5766d3, 0x9b726484,
+    0x74742866, 0x97b921fa, 0xe1c192ba, 0x01f0ae72, 0x552c87cc, 0x3e1d6545, 0x3d8d83ec, 0x2493feaf,
+    0x974f275c, 0xb7e8909b, 0x18860673, 0x1b4027eb, 0x0ea66c23, 0xb140bee5, 0x421c81ed, 0xf6eeeefc,
+    0x0e51ea2c, 0xb7448adf, 0x6e50f64d, 0x15192572, 0xb05f8188, 0xdef3b92c, 0x981d6280, 0x7ea25d89,
+    0x4d0ad319, 0x5d98a08a, 0xf2b79696, 0xffa3674e, 0x8b4d137c, 0xbee01c79, 0x560f9aeb, 0x11bdb079,
+    0x31d26e24, 0xf993a20f, 0xb6a34ce8, 0xae310eff, 0x8309d168, 0x11e30a3d, 0x6a98232a, 0x9a932bcb,
+    0x7f2187de, 0x4d8e854f, 0x17833df7, 0xc51930a8, 0xe7a928c1, 0x876c2e7e, 0xc155d05a, 0xac4d47a7,
+    0x5df0851f, 0x543bc4a6, 0x7418844d, 0x83d63765, 0xa2776de8, 0x6db946b7, 0xcbfd4b7e, 0x5858321d,
+    0xbe2b9545, 0x32250bd9, 0x2df94ebb, 0x1178846e, 0xee0eb22a, 0x433390c2, 0x969400d9, 0xaf6594d6,
+    0xe4ac4357, 0xe1391b45, 0x1b2cacc8, 0xed2f06bd, 0x2bcd5421, 0x496c1e97, 0xb173e49a, 0x238861ee,
+    0x67fd1be3, 0x0e53ad45, 0x7d408bba, 0x51844a66, 0x1d97a8d3, 0x6577f6c5, 0xe0f650b9, 0x98b8594d,
+    0x2c5cd122, 0xf1ecad16, 0x14c3d05d, 0xd9413853, 0xfaa0842d, 0xffff7aaf, 0x0530006d, 0x7a672b64,
+    0x1076ef91, 0xe6de3371, 0x14137c21, 0xf501b761, 0x43db1cb6, 0x8e626aa5, 0x022d8298, 0x665a2052,
+    0x08d99ae6, 0x30924aa8, 0xfd32819f, 0x44f58369, 0x3a14e2af, 0x11b92d52, 0x2283aa95, 0x616a7ef9,
+    0x053ea352, 0x24de67f7, 0x1e25222b, 0xa2f7a622, 0xf3f3833a, 0xa700f413, 0xfc8d6f92, 0x58278794,
+    0xc783701f, 0xa546213a, 0x7297a933, 0xfd35a757, 0x6b98dd30, 0xbc045ae1, 0xbbbeacaa, 0x99b416da,
+    0x8162a94b, 0x1f3be9bd, 0x49a105ff, 0x3bccd303, 0x4769d26b, 0x810b04c4, 0xf7cfd208, 0x64bd83f1,
+    0x1c90809d, 0x06d4ae6d, 0xf3fc3253, 0xea18f64e, 0x6978500c, 0x5c917359, 0x8f1b1b6a, 0xd6a40b33,
+    0x9ca60a3c, 0xa76c8315, 0xbb4ecffe, 0x99bed0af, 0xe5567d58, 0x46fe7d04, 0x119311d3, 0xe8579e70,
+    0x8343a4d5, 0x33baf6cb, 0x4bc87559, 0x9498ddb8, 0x6f362780, 0xfe074f7e, 0x68bc5203, 0x73a32e09,
+    0x99c118fe, 0x1fd16660, 0x345f0a98, 0x278705ff, 0xa85e60ca, 0x38456331, 0x49cfe2e9, 0x70c7824a,
+    0xa205b908, 0x8cbfd2c6, 0xefd954be, 0x25670775, 0xfb8e897e, 0x75310a73, 0xe77196ea, 0x18a3f97e,
+    0x7d8e8ad7, 0x83728454, 0x98e14ed9, 0x0fbe5377, 0xd6153520, 0x037c54e0, 0x88e4bac2, 0x35c05b50,
+    0x9eda80c8, 0x826874fc, 0x5913a18b, 0xaec5a03f, 0xc3572af0, 0x48bb9f26, 0x0b283c7d, 0xcc39098f,
+    0xa602efd7, 0x8df97edc, 0x9a3a0a04, 0xb391684b, 0x243698d9, 0x5ce75f48, 0x7b0b6f8c, 0x72ead11c,
+    0x44c14edf, 0x42d41442, 0x2a3b5feb, 0xd1714c9a, 0x92a334e0, 0x90bec836, 0xcd1daefb, 0x7e8f5582,
+    0xba9243f5, 0x05ab7817, 0x5eb5dff6, 0x4ff823bc, 0x3baf98d9, 0x2f1b65e4, 0xd24210bb, 0xa550a482,
+    0x366755f4, 0xa76b8709, 0x642805ee, 0xf82732b2, 0x4f8b8a58, 0xa3867065, 0x573d2cd2, 0x0e35d815,
+    0x23f14963, 0x43ec3ee0, 0x18c3b11a, 0xe61d499a, 0x534c7213, 0x759fad76, 0x58c454fa, 0xdd647aa8,
+    0xbb2f20e7, 0x8928b156, 0x1a84a6ef, 0x7d993fba, 0x4753a756, 0xe80e5a51, 0xbdf4ff3b, 0x0a241f3b,
+    0x33412ceb, 0xf8ab5c00, 0xe768e2ac, 0xd24e32e8, 0x80d7a4de, 0x78193a7a, 0xeae7bfbc, 0x90daba45,
+    0xf83f16d0, 0x0eea7290, 0xfe347b81, 0xd9ec075a, 0xa93b7fd0, 0x5fecec83, 0x698a7fca, 0xebec1d65,
+    0xc893de8a, 0xb8077445, 0xbddd44a4, 0x03f5a188, 0x220bd8f7, 0x378b341a, 0x5ef1d03e, 0x9cd051d5,
+    0x2fa991a2, 0xbabf2ad0, 0x1c9cc8a0, 0x5c89656d, 0xdba3c420, 0xe5c96b8c, 0x7f95a6cb, 0x6b8c5580,
+    0x5ccf5a84, 0xe878a004, 0xb81b5e64, 0x81f2fff7, 0x5cb473a1, 0x7322d148, 0x1ce97be7, 0xbf52fea7,
+    0x82972126, 0xce9524d6, 0xb761e65e, 0x874f4a0d, 0xd555f09f, 0x20e88b81, 0x8209f467, 0xfc51b467,
+    0x38a0eac3, 0xe6585c6a, 0x094bf350, 0x27bf8ee8, 0x11093a33, 0x780f72dd, 0x51113dcd, 0xe4376413,
+    0x3861bd3e, 0xf02b98a6, 0x5c15e369, 0x0504b312, 0x682b9463, 0x5b808f75, 0x1d45d4e9, 0x0e25e8b1,
+    0x6aefb0d0, 0xf59e1b3d, 0xe080255b, 0xcbac0e23, 0xaecd038e, 0x89ef2642, 0xc17b21a7, 0x805c3856,
+    0x9c830bd0, 0x870c9540, 0x770d4b4b, 0x4c3d49a5, 0x06d85fc1, 0x042c9ba4, 0x87131bf2, 0x2c8c314d,
+    0xc487f115, 0xaf4e18c7, 0xb501d141, 0x466af075, 0x23ac8d76, 0x1c77862c, 0x433bcbbf, 0xd6cdaf06,
+    0x00a6b5ef, 0x7f55d628, 0x2573590d, 0x9a75f6cb, 0xedeb0a0b, 0x25463c26, 0x8916f28f, 0x8c58ef7f,
+    0x42325d67, 0x4162324e, 0xa8c8afb3, 0xd3d8d444, 0xce1ce5b8, 0x7c94f664, 0x8ff05ce0, 0x2c0ff630,
+    0x46c7a2a4, 0x97ef98f8, 0x8bbcd23d, 0x21d75093, 0xd14a887e, 0xa8d0afde, 0x2fec4836, 0xa1596c04,
+    0x39845339, 0xf2569975, 0x1ba2e2d8, 0x99d2d0e0, 0x5e4a7e02, 0x3372fe49, 0x7a716005, 0x8d4ed39b,
+    0x1c017b1e, 0xc1c14e84, 0x18ee1a85, 0xae9b9bd6, 0x94922e9e, 0x50f8a93c, 0x863aa98d, 0x2104dfbd,
+    0x7540cc44, 0xd2f024fa, 0x9a2fb43f, 0x3bac4333, 0x48899653, 0x369fa701, 0x5c6d6989, 0x44b9b03d,
+    0x3601989a, 0x9a9b61e1, 0x6372bfc3, 0xae554a42, 0xf80909f4, 0x32c45e32, 0xa8d42abc, 0xff502513,
+    0x26655575, 0x90f25292, 0xd287c1f6, 0x43b5e9d0, 0xabd169f3, 0x7cec8874, 0x41972172, 0xe95871c9,
+    0x56315fc1, 0x94055a86, 0xa13910a8, 0x6e364ebe, 0xfb3f07b3, 0x9d7b84c9, 0x55e9fd8e, 0x13a28196,
+    0x6ae56daa, 0xf1065510, 0x982e1031, 0x9a112584, 0xedf23910, 0xac90e50a, 0x2eee1336, 0xa3f16cdc,
+    0x7506d5c9, 0xcf99e170, 0xf424274d, 0xaadb13fc, 0x8204b122, 0xa7a5295d, 0xff6847ec, 0xade26fe0,
+    0xead7c477, 0x6488a80e, 0xc95a6768, 0xf87d3555, 0xe38003b1, 0xf29226c7, 0x19822828, 0xb54daaa2,
+    0xf3d23909, 0x96b6c906, 0x1e045ddc, 0x34cb9496, 0x66757dd7, 0x575f5899, 0x585b0ed0, 0x1810b161,
+    0x46c3cbb1, 0x5b52d872, 0xdd0f7d8b, 0x62d69343, 0x934ad472, 0xeb4e7df6, 0x9ac65739, 0x6a700628,
+    0x309a25a5, 0x7e4fd609, 0xc9a830b2, 0x109dcc92, 0xe7e2861c, 0xe6e3efd8, 0x364f1511, 0xdb5f3839,
+    0x213c918a, 0xf60585ea, 0x713efea0, 0xb44b27e3, 0x2107ef57, 0xb5205394, 0xdffdcc67, 0x670372c9,
+    0x4eb124bf, 0x62db6d3a, 0x667f5697, 0xe98f9468, 0x3f784878, 0x801143e4, 0x6c6cf196, 0x85ed60b2,
+    0xb63e71ef, 0x508b53e1, 0x179d71f8, 0xc4017ce8, 0x18a0efc7, 0xfdc30e0d, 0xc106262e, 0x7e82f251,
+    0x61272d47, 0x222e1e58, 0x771d42c0, 0xb7094d15, 0x41d50742, 0x4aaeb431, 0x98c7b908, 0xac4f6038,
+    0x1237ed1f, 0xd25a0d31, 0x095fdffa, 0x869771ed, 0x2c2b7a3c, 0x4fd28540, 0x4a1d1881, 0x5a4e55f4,
+    0xdb06cc68, 0x7afa77c1, 0x1da780c9, 0x44fd568b, 0xb3e5aef3, 0x42167dee, 0x943f4a18, 0xc99840f7,
+    0x22e034da, 0x8f77b64d, 0x804cf03d, 0x05e9cc3d, 0x98eeec4a, 0x54d28ba6, 0x2023dd67, 0x742f2f32,
+    0x33f03625, 0x15d07124, 0xc289e64b, 0x4a590631, 0x64d9865d, 0xfeb509ae, 0xbd12b46b, 0xb64e8de8,
+    0x39fcba3c, 0xa50ddfdc, 0xaa20b603, 0xa55dc9cc, 0xf7da7e73, 0xac5e895b, 0x72051361, 0xc46b939e,
+    0xd6daa831, 0xbb40106b, 0xec730ef5, 0x93c33b0d, 0xb4a0301d, 0xd8807f21, 0xdead4a32, 0xe8a6207c,
+    0x806e28b4, 0x1b05be54, 0x22332aa6, 0x9bb8d717, 0xbfdff8d7, 0xdcf0dd3c, 0xe076971a, 0xbafa332a,
+    0xd6f81355, 0xb4a684b5, 0xac1be712, 0x5c41e708, 0xe0ed3b05, 0x205510f0, 0x56b3268e, 0xbc35ae64,
+    0x47e62233, 0x13f24267, 0x65c45f9e, 0x07a064a7, 0x7d7d4091, 0x7b156cc6, 0x73998ec3, 0xb959840b,
+    0xcc2522d4, 0x1a964460, 0x71a0a240, 0xfae353f9, 0xadfaf026, 0xbbfcbfe1, 0x83f42933, 0xd928ff8f,
+    0xa23cdefc, 0x161e6212, 0xb4b12619, 0x166744ee, 0x64efa089, 0xbfc93cec, 0x178c69d9, 0xf0099c82,
+    0x695f1226, 0x93537982, 0xa7b2ef95, 0x038fdeca, 0xc883165c, 0x5025c2e8, 0x665a5e19, 0x3cf1e038,
+    0x712f56af, 0xf70170a1, 0xda74c1a1, 0x673e8c24, 0x4a68ada6, 0x63da708c, 0x42fd9fc0, 0xebfdf93b,
+    0x91fd87c1, 0xec4561ea, 0x23393159, 0x8063ea5e, 0xe17d1ddb, 0xfc47eb0c, 0x0f67f07a, 0x13478c69,
+    0x4a8f1be9, 0xd6ccbe1d, 0x8b1374c6, 0x5ac99885, 0x140773f2, 0x2dc72312, 0x19be4073, 0x96c27f7a,
+    0xcb78bbdf, 0x70274205, 0x6665bcd4, 0x0961f6f4, 0xac93ef9e, 0x5bf373a2, 0xdf5af550, 0xeeca77c1,
+    0x436e11c6, 0x1bfe6c9a, 0x020b13df, 0x601447ad, 0x520250bd, 0x80b7226b, 0xda61519b, 0xc2cd2ca4,
+    0x02498664, 0x41748ce5, 0xbab9eb13, 0x58788a80, 0xe3737822, 0xb53c743b, 0xe39e9b61, 0x91af2fa8,
+    0xbb1d6226, 0xe63135d2, 0xe62d5cc4, 0x3305f0da, 0xcc84cc94, 0xfe083adc, 0xdd0cc299, 0x7c9e9b5d,
+    0x8f93356c, 0x1ef1350b, 0xe20a979c, 0xcdd5c016, 0x1bee10c8, 0x5e2555a4, 0x991cc2a2, 0x1fcba923,
+    0x9ab11a95, 0x68c5956c, 0x0bf0990e, 0x369b50ae, 0x84cdd5ef, 0xe0360cda, 0x21951156, 0x3b5b22db,
+    0x7750de0f, 0x6d0853c5, 0xada51526, 0xbb7a2dba, 0x2bf6b407, 0x29bb54a4, 0xfd8cab0f, 0x7187df9b,
+    0x2166932b, 0x80bff61e, 0xfc2f96fc, 0x9f5ffddd, 0x1bc262c6, 0x3bcd6d24, 0x07532cb7, 0x406cfaa9,
+    0xe913a4ac, 0xc7d312e6, 0xd75a4aa6, 0xeb6eb1f0, 0x39e69db5, 0x279de6ce, 0x8a1c5536, 0x064a8819,
+    0x9f986e85, 0xb0f19279, 0x95aac6a5, 0x3b80b674, 0x75843414, 0x676a0e08, 0x774772e1, 0x59a9ee3b,
+    0xfa012647, 0x9293c382, 0xc3cb2a54, 0x02de350f, 0x5a05ba19, 0x7996ad3c, 0xb030929e, 0x0e9018a6,
+    0x01f52b93, 0xf06e88c9, 0x39a220f5, 0x21fa2230, 0x8b9bcb00, 0x42fd1994, 0x082a76a4, 0x255e7896,
+    0x14df5b7a, 0xdbbd1d67, 0xae0c239b, 0x35bd1821, 0x5c792d6c, 0xc4b501e9, 0x658ef2a0, 0x164d5a80,
+    0xc62e50a0, 0x0ddf26fb, 0x02ca068f, 0x950b9d3e, 0xfc13a189, 0x958ef13e, 0xd76d81a0, 0x61aa273b,
+    0x68c031ba, 0x3157c21c, 0x86c57009, 0x6ad975c4, 0x67ec0ab9, 0xdda5a4e4, 0x8771022d, 0x8cbdc153,
+    0x6789bc4d, 0x5e2d21df, 0x34996113, 0x44102f53, 0x519170aa, 0x32a30b1e, 0x69fab3ab, 0x7331579a,
+    0xb0e2fc79, 0x3c516f62, 0xd6a06e2f, 0x6b08a5ad, 0x07004cb3, 0x7b0335ca, 0xc8367e23, 0xe8f211fc,
+    0x79891f93, 0xf86ed6e7, 0x89bbed00, 0x8b7b0aea, 0xe82f7b23, 0xd1e6fc89, 0x67e036cc, 0x730fd0a5,
+    0x705004fc, 0xe3500bcc, 0x45add587, 0xfc533a0d, 0xf9f4f065, 0x8bfe5659, 0x3d6229ca, 0xf84a5267,
+    0x1f568523, 0x83a39efd, 0xce159781, 0x9d2c5322, 0x61c00701, 0xbe69ada0, 0x1b97a0c0, 0x4c82ad1a,
+    0xaec36423, 0xc949b396, 0x8b158106, 0x5372bbe9, 0xd8db29ef, 0x072b6059, 0x3e450759, 0xb9612fdc,
+    0x36a44e8e, 0xcc0dd6b8, 0x99a31698, 0xeeff32a6, 0x7ddab8a3, 0xe8cc22d4, 0x5e7f6162, 0xea278099,
+    0x7737aa33, 0xc0bf1882, 0xc686b27f, 0xcb88bc2f, 0x2cd0d8d6, 0x44546479, 0x89d380c0, 0xe5812456,
+    0xeadb2960, 0x013121cf, 0x35971e09, 0x1695df50, 0x22e65b5d, 0xf5f79a2f, 0x2dc9f297, 0x3e0bf8f2,
+    0xe6c4b630, 0x9cfee056, 0x8f2c6f48, 0x7e721db6, 0x388de382, 0xb62c276f, 0x137eb95b, 0xc11ff348,
+    0x51a98e12, 0x93e537d1, 0xa7268e53, 0xee6aca3c, 0x1850a0d3, 0x42c1a79f, 0x0cfb6892, 0x642e7d20,
+    0xfd059afc, 0x88fba686, 0xe897ab93, 0x10a237d1, 0xf571d507, 0x432a17e6, 0x0ef1e641, 0x5a6cf5cb,
+    0xff2f02a7, 0xfd92750b, 0xe0a95073, 0xeb202df1, 0xe47ba5fd, 0x1c63fb52, 0xa164dfd3, 0xf4821275,
+    0x26e27825, 0xfad83da0, 0xf14d3c45, 0x42742661, 0xfbd42dce, 0x4df9fa3e, 0x61e8cf4e, 0xc0db2069,
+    0x390041ff, 0x7bc6949b, 0x738b0bb4, 0x61fe826b, 0x76b87cfa, 0x92511e52, 0x8ec473ce, 0x15462edb,
+    0x7f64a4b6, 0xf4908479, 0xdf4d1413, 0x5f84b4b5, 0x2adc399e, 0xfb19718a, 0xf7ce5d7f, 0x8257ec41,
+    0x1913d6c0, 0x6ac7bb67, 0xfd01a1e2, 0x419c5ebe, 0x4f60a8de, 0xfa913a0e, 0x8f95a8d8, 0x2f97ae0f,
+    0x04945384, 0x24a067f3, 0x12f4a31a, 0xf340ab7b, 0x71d9e923, 0x81426bd0, 0x546a53c1, 0x6a2f8a0c,
+    0x74f126c6, 0x9d7a6ede, 0x01aac1c6, 0x7c536e17, 0x63f7be58, 0x8975b7c6, 0x64c984f4, 0xd48d8bf6,
+    0xf774cf30, 0x8089e79d, 0x3499fe18, 0xf1129822, 0x0cf04afe, 0x76f6c185, 0x484a9ca3, 0x19e9a2d5,
+    0xe7263438, 0x0bf92838, 0x1a8bfb72, 0x38e0134e, 0x7657b98c, 0xd946ef18, 0x4a43fafb, 0x5282a079,
+    0x2100b45e, 0x34a1e199, 0x62469dec, 0xbac35857, 0xb96f3c67, 0x4448ba19, 0xab9a8ab1, 0x6b0b48bd,
+    0x4613c413, 0x180834a6, 0xbfce84ff, 0x7783f11b, 0x6b158fb4, 0x26477aa1, 0xa7508940, 0x906d0a44,
+    0xc7a48e51, 0x49c46078, 0xa06b9194, 0x35de8973, 0xdf386c2f, 0x00a7561d, 0xf53d3627, 0xfd2e26e6,
+    0x9c078d7c, 0x146a8de7, 0xf98d2316, 0x4a372bdd, 0x5d4ab37e, 0x9447f948, 0x3e324990, 0x83cc7a87,
+    0xbdfae65a, 0x465ff330, 0x8b2339f9, 0x737292c2, 0x5ba71e2a, 0x4291130e, 0xa2e5c58a, 0x7538a3a5,
+    0x6e829ddb, 0x83840115, 0x620236d3, 0x9ceb74f8, 0xf3a54183, 0xcbd3c379, 0xaa53781a, 0x7a73670a,
+    0xfb572683, 0x71834480, 0xca2110bf, 0x1733f320, 0x590f05a7, 0xff9a8933, 0x3fc2591d, 0xc6fc0f50,
+    0xd3a4e6f0, 0x7f8e9c12, 0x2a076c8a, 0xe183e038, 0xc438c1df, 0xdccfce23, 0xd0bce80b, 0xf3e0e0dc,
+    0xd3ba6ab0, 0xf8fdd597, 0x900ce875, 0x5511c207, 0x8de35daf, 0xf9b8a0ae, 0x4a144b1a, 0x2058ac3f,
+    0xbecf8e2b, 0x8e861fd7, 0xf3262063, 0xeb4eac20, 0x7e297b9a, 0x3af971eb, 0x1d4a537f, 0x6868aaf6,
+    0x0188c464, 0xc79c737a, 0x67727266, 0x17c71164, 0xd8d16ae8, 0x681cdc63, 0x6725408a, 0x9e7ab1c2,
+    0x79029eff, 0x8a639553, 0x2a887d64, 0x4a0f0987, 0x24f6fe48, 0x13d1bf7e, 0x43697000, 0xc1ed8431,
+    0x2e56d866, 0x308b8879, 0xb3d9bfdb, 0x4b7359f8, 0x55b87658, 0xe84b46f5, 0x3ba74af5, 0x66c0781c,
+    0xe381a37e, 0x7c0fc321, 0x3284028e, 0x6a4b983b, 0xee6090b6, 0x84399d67, 0x72432f3c, 0xc68a4bbf,
+    0xa4bf2a38, 0x2b3028fd, 0xb4d1115f, 0xada82552, 0xa5f8c051, 0x1d8b076b, 0x0a8aab20, 0x05afc2fb,
+    0x51b11f46, 0x79d9f645, 0x41e8d931, 0x2352db69, 0xbcf5ed19, 0xfcbbda51, 0x15e26ce4, 0x05752305,
+    0xaa8e6fa7, 0x64f91f3c, 0xce4cf449, 0x4f971ff5, 0x28cb68a7, 0x3b5ca29e, 0x3d1f252b, 0x046a9e53,
+    0xd4efc9f4, 0x27ba3fc7, 0x88940be2, 0xa79ae6b8, 0x5b210bff, 0xd39b4b3b, 0xea904c4e, 0x201f146c,
+    0xc030cb4d, 0x639878fe, 0x46ddbd3b, 0xe0be1326, 0x4448f97e, 0x1199ead5, 0xae47e9dd, 0x0b1c6d6a,
+    0x58f3a7b5, 0x64ec26fa, 0x5d29fe06, 0x78a8cd3a, 0x36deba19, 0xe069c5c6, 0x196415ba, 0xc7bb9655,
+    0x3762bfd9, 0x98909dbe, 0x351ce6ec, 0xdd14f411, 0x2157dafa, 0x16ecc975, 0x244bb36d, 0x52225305,
+    0x4079c625, 0xb0bae8a1, 0x23cf87c3, 0x8a63188e, 0x0822e060, 0x112ecfe9, 0x58a245cc, 0xca420a3b,
+    0xe14388ad, 0x35ff73ab, 0xaf733155, 0xd8bb13aa, 0x7e86c596, 0xf9c13d8b, 0xeaf114c6, 0xaf6b8f07,
+    0x16101cb9, 0xf0f01f6e, 0x139d0c2a, 0x23a04d03, 0x2bea2832, 0x07491fb5, 0xee9e46c0, 0x1797f409,
+    0x760b0d7a, 0x2f6faf20, 0x7cdbc4fd, 0x88794c38, 0xbaec551f, 0x61fcf0fb, 0xeccf885a, 0x5cd0eab9,
+    0x9da34f91, 0xefde69fa, 0x38a728be, 0xf417a437, 0x7567fdb6, 0x5849b99d, 0x85047ad3, 0x628a411d,
+    0x28016190, 0x27181125, 0x21da5b9f, 0x91afa3c8, 0x395a9e9b, 0xf1b8a0b9, 0xb60a860d, 0x8e323d03,
+    0x46e735a5, 0xc7961195, 0xa7a855e6, 0x5c290059, 0x0211a5bc, 0xcb7c91be, 0xf91a0538, 0x7d833b0a,
+    0x5167d4b7, 0x33206086, 0x4bcacace, 0xbecbb8ed, 0x3ccd5c9c, 0x5c107db1, 0x7eaaf962, 0x340b5f60,
+    0xa7d3bbc3, 0x293d0dd2, 0xda7fe81d, 0xf3a423c2, 0x60850476, 0x535792ed, 0x362984dd, 0x381a254e,
+    0x9c61242a, 0x7c4b4bae, 0x5b4c5198, 0x105205e5, 0x6ac8aa22, 0x01439c4c, 0x4e315177, 0x9e064419,
+    0x32694a09, 0x59b0f37a, 0x879879ea, 0xba00249b, 0x8c352584, 0x6f0dd5cc, 0x1acf2dfd, 0x99b79688,
+    0x8b0f4bad, 0xd816aedb, 0x6dbfc288, 0x7f0979b3, 0x6092b76d, 0xf425667c, 0xe77edaf6, 0x12d1ecd3,
+    0xf94126a0, 0x69bb8e27, 0x2f45c828, 0xeb35c369, 0x97e3a45d, 0x4c8b0505, 0xa011a1aa, 0x1f0f83f0,
+    0x073d7614, 0xceb379d9, 0xa9f2a310, 0xd7e77b42, 0xac275842, 0xeb25b38c, 0xb75e9571, 0x55489c7d,
+    0x9531931b, 0x576ea4bc, 0xcf6bd543, 0x3da00b08, 0xaf6f24af, 0xbb99ce6e, 0x29b2512f, 0x0ffe5e15,
+    0xeeaef232, 0xc0fec25b, 0x2cd26c2c, 0x6a6765bc, 0x2f634399, 0x0f4cab86, 0xd746ba08, 0xbd799224,
+    0x42dbddae, 0x40d2ae75, 0x9585c9a9, 0x1e29834f, 0xd849d374, 0xbbf50837, 0xf6dd4a35, 0xabe73199,
+    0xc76c76f2, 0x3f793b45, 0xa4c738e9, 0x42692d04, 0x6bf80c4e, 0x5ceea1ac, 0xbe4db13f, 0xa61a57cf,
+    0xbc97c869, 0x709a573c, 0xffa9f0cd, 0x05252ac4, 0xf4185c42, 0x5f70942f, 0xd7493b58, 0x32273ac1,
+    0x6e34539f, 0x1ef8add0, 0xaf1495ad, 0xef267271, 0x93776a6d, 0xfc29e715, 0xa31a8430, 0x0bc752ff,
+    0x4f590442, 0x8e65b408, 0xb4182aab, 0xbd0bad9a, 0x8327241c, 0x65ee1ac5, 0x2ae61f03, 0x7d182b44,
+    0x4ed73f65, 0xb1808e0b, 0x8b797362, 0xd12ca82d, 0xe1fafd60, 0x807c1f68, 0x24cc9314, 0x25f9dbcb,
+    0xfaf83b56, 0x15c16e8e, 0x20bf3474, 0x86b861ac, 0x9fa29416, 0xb0ec4f70, 0x9a6927d0, 0xdf232cc2,
+    0x77e1a962, 0xd6308497, 0x7ca01539, 0x887ce2d9, 0xdebe3204, 0xf07656f8, 0xffb110d5, 0xc9a8e506,
+    0xc10b801d, 0x69521393, 0xc16ca3b2, 0x4a0a6062, 0x44f45678, 0x0107e5ff, 0x53a73d01, 0xda8dfe8c,
+    0x18416438, 0x53edbf88, 0x25a69d8d, 0x4559c48c, 0x0f086ba3, 0x29cbb42a, 0xf11c7788, 0x69f3d355,
+    0x7f0f2fb8, 0x9729c252, 0xeea94689, 0x8522a4d9, 0x7f5abde2, 0xf689b3d3, 0x341d2153, 0x14c6109e,
+    0x5f6518c6, 0x4897dd3f, 0x6cb3ae28, 0xbb807a1a, 0xf6fcbd4e, 0xe2c6fe46, 0x8a3cc39f, 0x95700c7e,
+    0x2a07e10d, 0xaf28b648, 0xabedd96c, 0x864d18ae, 0x32bf2b3d, 0x2c160ef9, 0xfd79c042, 0x210fcbf8,
+    0x9b8a16fc, 0x42503b41, 0xb105916b, 0x573d5324, 0x4fbf8206, 0xbbbc94c9, 0x5d64ba00, 0xa4b1e174,
+    0x6f1463ef, 0x49600db4, 0xd3b34615, 0x74653574, 0xe5c8936e, 0xaea3e60f, 0x6aa8e6b3, 0x5140a728,
+    0x21175af6, 0x525b0868, 0xc916a8f4, 0xc3648072, 0xb81772a1, 0x5994e938, 0x9adab6b4, 0x2324c9e6,
+    0xe29ed1dc, 0xbd298fc5, 0x4d30d444, 0xbe36861c, 0x64c3e6b5, 0xec928181, 0x56fc774e, 0x116b505b,
+    0x726a21df, 0x9c34c8c2, 0x8df604fc, 0xe4227005, 0xe81a29fb, 0x91a66553, 0xe0a1de4a, 0xfd1925f7,
+    0x5e335ad2, 0xa99b96b0, 0xa5911b2b, 0x22f0621a, 0x900334b8, 0x65d9676b, 0x02c1ecba, 0x73c231a8,
+    0x8ff7603e, 0x90514376, 0x8d5d3703, 0xebdc61e7, 0x0b028112, 0xa682854a, 0x5fed613a, 0xdb20fd6f,
+    0x230c75c9, 0xe3d5090f, 0xfbf0b5cb, 0x3b5a0ba6, 0x645aa573, 0xdd56e319, 0x7a647f49, 0xcf27be32,
+    0x70b82ec8, 0x44d1cdfd, 0xde51fc6a, 0x3593ec1e, 0x5096d3c9, 0x63f6e2e4, 0x1d9d465d, 0x88cd6db8,
+    0xb65d65d5, 0xa7fc3185, 0x79316f5c, 0x5eaea47e, 0xe73cf49d, 0x6a33d0a7, 0xe640a183, 0x9e572b52,
+    0x75d7e876, 0xc63b7000, 0x68d9f68a, 0xcd97d8cb, 0xfd92e263, 0xcfd1874d, 0x2e131195, 0x9677d44e,
+    0xbcd15ab4, 0xf552f164, 0xc4fa3d5a, 0x39e10d95, 0xad90bfd6, 0xd17c1596, 0x6429cf9a, 0x2b9fcdc8,
+    0xa1b92b6b, 0x7ba2caaf, 0x10b9875a, 0xa8a97597, 0xae6695d1, 0x19485b65, 0xad5f203a, 0xf909128e,
+    0x891ce211, 0x85571da7, 0x2439ef4d, 0x6bac6d09, 0x288ec9fb, 0xf7ca8512, 0xa14ff01a, 0x4e842b91,
+    0xfb1b3cf5, 0x07aff6c2, 0xd5561108, 0x5091fc7c, 0x7291aa2d, 0xa13f7afa, 0x3203aa93, 0x079b1289,
+    0xb6a3105f, 0x7a484059, 0x4f8ee174, 0x0686957b, 0x2e1726f4, 0x13d09f76, 0x0ef228e4, 0xd3c685c6,
+    0x2c0d5829, 0xabcb0517, 0x283b6161, 0xe59ae8b1, 0x20b27866, 0x3235f5cd, 0x10bc4548, 0xf813a731,
+    0xdb98d37b, 0xc0d0c3f9, 0x1aa7288f, 0x41172ba9, 0xedf01fc5, 0x7be76fb8, 0x2674c179, 0xe49c0eb5,
+    0xa7c11086, 0x915697ed, 0x644ecb04, 0x8c4d3cdf, 0x57b93901, 0x1a331c15, 0x3b72a3d6, 0x7baed6fc,
+    0x472ce601, 0x897ee8db, 0xac3ba7d5, 0x1f921bac, 0x70769c16, 0x941d550c, 0xfa0d576d, 0xc0939fad,
+    0x80b84c36, 0x7d55be4d, 0xe85ed3b2, 0x632fd1fb, 0x9877a2ea, 0xfc2b55a7, 0xc5d53876, 0xbe464e8a,
+    0x7487de0e, 0xd0f94b2c, 0x149ca4b4, 0x1061968f, 0x52d7bb6c, 0x42374cdc, 0x6d4805c0, 0x87c968cd,
+    0x8ec68d60, 0x2711a124, 0xe573cf83, 0x8f79a78d, 0xd3baacce, 0xdc14a2d6, 0x79ae74a9, 0x4c3c1429,
+    0x4f4f5255, 0x9af73eb8, 0x800dd3dd, 0x0a2a83bb, 0x68c05b56, 0xac5335a4, 0x6482cce7, 0x449ea02e,
+    0xb5a9a315, 0xe8c0ac93, 0xea5bf389, 0xfac53fee, 0xa1cd898a, 0x7c72b177, 0x8ba5d517, 0x175f847b,
+    0xaf66d79e, 0x13e1ecbc, 0x890e9055, 0xc904cc2c, 0x96c844b2, 0xc4ca36fa, 0xd6c7e61b, 0x1eeecc04,
+    0xcf899720, 0x46909f38, 0x625072be, 0x2cfc9a78, 0xb38c12ac, 0x23bfb204, 0xa0851612, 0xf5a00dc3,
+    0x69b490f0, 0x98a20958, 0xb50059b0, 0xfffee590, 0x6c4ea8f5, 0xbcab5c83, 0xe0ef1d6c, 0xac2011dd,
+    0x194114d3, 0x487a661d, 0x42b66319, 0x4c093cfb, 0xf655d767, 0xe76c3b04, 0xab8ab625, 0x8abf4ca1,
+    0x1544b830, 0x6acb52f7, 0x28905b7e, 0xf8972b22, 0x03458888, 0x89dfc434, 0xc416895e, 0xf56839dc,
+    0xaa72a981, 0xce3b0a32, 0x72e68552, 0xb811a993, 0xb3b2b790, 0x43240905, 0xfa8e678d, 0xc070ed93,
+    0xc9994b1d, 0xa72bb4a2, 0x21e9993c, 0x9acedb85, 0x869a86d0, 0x5e14e405, 0x50ce1caa, 0xe5896d09,
+    0xf5568abc, 0x982b53af, 0x07852fe3, 0x09fbff96, 0x97c551ba, 0x61403c4f, 0xb62679ef, 0xed79efd9,
+    0x286421ec, 0xb41acdfb, 0xaab7e662, 0xf8adc71f, 0xc5fa40d3, 0x7a2eee02, 0x1a55c1ea, 0xda0c2c2f,
+    0x414c7497, 0x39859a18, 0xc07b87f0, 0xb768b34c, 0x18ba0d28, 0x71da004e, 0x869fa4fa, 0x595b9407,
+    0x387a746f, 0x8827d661, 0x4786a32f, 0x47f8e657, 0x9457ea6c, 0x951404c5, 0xdc901909, 0x243eadbf,
+    0x32331c4a, 0x570123c9, 0x5c5b8977, 0x6476d450, 0x68ea1380, 0xae9856e3, 0xe82ed924, 0x56449eb2,
+    0x90d1c8d1, 0xeb70fb7d, 0x31daf183, 0x8c3b2a7e, 0xaa63310e, 0x3dd17507, 0x35eb6082, 0x458e167b,
+    0xef9a3a59, 0x2be7c139, 0x5c484387, 0x65da15cb, 0xb501ae97, 0x624cf724, 0x9db58034, 0xd00cb438,
+    0x5429fc81, 0x20045ea3, 0xcf19f8d2, 0x96fdb0e3, 0x8dc4e7a2, 0xccf81619, 0xae1e42d5, 0x5dd3d699,
+    0x010a41d8, 0x35dcefbf, 0x0876f03e, 0xe43a857f, 0xcec2ac90, 0x3800c9dc, 0x173abf51, 0xe83995de,
+    0xefd538b1, 0x82a964f5, 0x4fe93bfb, 0x41cec2f1, 0x69a58d53, 0xd7d0f126, 0xea5b1529, 0x34b5c03e,
+    0xc33fcac2, 0x8f23ab88, 0xa5a8ec0c, 0x17d6b409, 0x2bab2f98, 0x967e1ef7, 0x718545e4, 0xf0d5bd8c,
+    0xef830e88, 0x9ec8745b, 0xf8a7c29e, 0x8cf51920, 0x7e79714e, 0xd18a4d84, 0x6b999e48, 0x118bcdd2,
+    0x90bd7e45, 0xbdb6d4b5, 0x076c55a5, 0xaf601bdf, 0x7bb12a75, 0x92b55875, 0x8d0fcab7, 0x2c413249,
+    0x046c45bf, 0x4ee38564, 0xb14a902f, 0x6f1ec707, 0x7f02dffd, 0x6c24aa82, 0xbd063e70, 0xdd00d921,
+    0x4e741cd0, 0x5297979d, 0xbd67d46c, 0x31cf4b92, 0x1834e021, 0x8fd65402, 0xa86ecaaf, 0x197b03ca,
+    0xd50061ee, 0x1d0a7c7c, 0x6f0f5881, 0x20034f31, 0xcadcf704, 0x26e76fa1, 0xd083d5c6, 0xec0e4af9,
+    0x90f9141f, 0xe5267900, 0xd3b2f791, 0xd60a2f2d, 0xb0d89913, 0x3f128ef5, 0x69dfd81e, 0xdf539432,
+    0x5aa54387, 0xa7dcb534, 0xf24adf53, 0x67789ef6, 0x7f115a6d, 0xd3b84bd5, 0x7ba19a98, 0xed18fc9b,
+    0xdf7b1e73, 0x8451c27e, 0x5c8d436d, 0x2fcf1ebe, 0x47dd90ec, 0x240695a5, 0xb704d2f5, 0xb93232ed,
+    0xfc621399, 0x7f1ee06b, 0xfe7358b9, 0x1a27e4e8, 0x405eff57, 0x39dbb8be, 0x2bc2cb6e, 0x45ee2e2e,
+    0x41c54efb, 0xa21f11c2, 0x4f78c91b, 0x5bc189c0, 0xc8043c11, 0xe73a21f5, 0xd447d50a, 0x8af9e307,
+    0x579b2f5f, 0xf12cacf9, 0x6ca8c8f8, 0x8b7f8a6b, 0x7552cc4e, 0xf049fc2d, 0xe38a42ae, 0x94fef886,
+    0x318731ee, 0x067ff61b, 0x739f3ec2, 0xb808735a, 0x2565b64e, 0xc85e1cbe, 0xaca1c2d4, 0x2fa165e8,
+    0x388b3125, 0x3d374a28, 0x0140bb09, 0x39147dcb, 0x55402251, 0x9bcff2dc, 0xcf1aed8b, 0xc8106b21,
+    0xa77f6ee9, 0x0f1fd0b4, 0xc31ae57e, 0xd4ef3878, 0xf0dbe164, 0xf71de08a, 0x69e82cf6, 0x3ab8b908,
+    0x1018897d, 0xa9732650, 0x089801e8, 0xb76038e6, 0xd2bc9af8, 0x8039ef24, 0xcd5572e1, 0xaa541a25,
+    0x1bde8079, 0x2a596060, 0x9a3d0da8, 0x9b6f4a34, 0x118e3a48, 0x1264c048, 0xe3bf7185, 0x04b448c2,
+    0x3c869693, 0x1325d552, 0xeeb462a8, 0xa5a0ed6d, 0x9822279b, 0x402dcb89, 0x70c20982, 0xa069e12a,
+    0x752bba02, 0x0c9c2801, 0xb9b860a3, 0xcfb7817c, 0xea243d20, 0xc38a5d98, 0xe85f4be7, 0xf7d7c7e3,
+    0xd07aa9c0, 0x9b62f8ea, 0xd706e309, 0x9957de79, 0xd91c696b, 0x15cfe040, 0x8271641a, 0x98ba4078,
+    0x59bbe181, 0xcc6dc96e, 0x7d4068e3, 0x4c9c7a1a, 0x7f296e4e, 0xe50b1659, 0x233cb45c, 0x6c01c2c9,
+    0xc25895d3, 0xd80f4cbd, 0x0c03890e, 0x176b5f85, 0xc5161063, 0xaf83dd00, 0xfe3ee97b, 0x2ffe6992,
+    0xe0c85618, 0xac42d78a, 0x23acfb1f, 0xf453f51f, 0xfb47bf5e, 0xbd539443, 0x2540b6a0, 0x432b97dc,
+    0x537313bf, 0x8c046ad1, 0x39197ec5, 0xd25a58fd, 0xa34cd4dd, 0x89d8beaa, 0x6bad2b97, 0xe88a1616,
+    0x22f10a61, 0x67b1fcef, 0xd08c45a0, 0xe5dcd269, 0x3a14bf87, 0xf97199bc, 0xfa6f8f38, 0xe03eca49,
+    0x1995018c, 0xf3f3fd35, 0xd0547791, 0x0c79da5a, 0x40a64cf5, 0xea5e98fe, 0x974e208b, 0xba4afc24,
+    0x393ed253, 0x1086c1bc, 0xab499286, 0x2592bdef, 0xffeca9b3, 0x16474925, 0x9b6c678f, 0x7858af98,
+    0x8646e934, 0xd82491c7, 0x1ec9ee58, 0x7236b826, 0xe2a6f4ef, 0x0fca2e20, 0xc23d70e2, 0xd492a478,
+    0xd3663d46, 0xcbedc7e1, 0x551c26fd, 0x75d42a06, 0x449c0f22, 0x56da3ad0, 0x5c4efdf0, 0x9f6d3155,
+    0x44ce926c, 0x383d77e2, 0x56aad7bc, 0xf60425d5, 0xa959c659, 0x0c4558fe, 0x75b21ba0, 0xf03fef93,
+    0xec0a90ea, 0x37073550, 0x5a11729a, 0x739a0345, 0x812b57ca, 0xedc7ad13, 0xdf71cc66, 0xb49c8d13,
+    0x491787db, 0xee39bc2d, 0x89fb9eec, 0x3b6369ea, 0xb5b1485b, 0x5ba2ca64, 0xde7559e1, 0x190e4e5c,
+    0xe28d41ec, 0xa1b243c4, 0x2961e6e6, 0x6d6c5743, 0x00184ccc, 0x05571c93, 0x67bebde0, 0xbd2c7095,
+    0x033031da, 0xe867a94f, 0x02af102c, 0x10b6559a, 0xf6422d32, 0x5768af97, 0xde439554, 0x4ccedfa8,
+    0x2564e3f1, 0x280c865b, 0x908360ca, 0x09b006ab, 0xcc49f268, 0xbda9078c, 0x0bcc48c3, 0xd958e457,
+    0x7c28d1f2, 0x6ab2c6f5, 0x5b4a332f, 0xebd1759c, 0xb9f68f0d, 0x84d2f3fb, 0xc73e0d25, 0x840dd44c,
+    0x5c88c702, 0x9ef99a77, 0x808caf31, 0x41d50be4, 0x05db7ea5, 0xfb6f62e3, 0x1e88a25d, 0x9c8563e7,
+    0xf0abd56d, 0xf2dcd879, 0x0416bb25, 0x7efdae86, 0x0d7c90f1, 0xe388e487, 0x52ce9ac8, 0x3c661fe5,
+    0x24c600f1, 0xa3f0a9d4, 0x3b3bed0f, 0xc6d70314, 0xd783b046, 0xdea8109a, 0xf6f738c4, 0x92a1f0eb,
+    0x33251058, 0x94762f28, 0x5565de7f, 0x558d8eb6, 0xb587ec1c, 0x1059b10f, 0x66d8c7ba, 0x321c79b0,
+    0x198cbf22, 0x41505161, 0xad87a559, 0xd8273482, 0x4a8f0505, 0x507d7ff7, 0xbc558880, 0x10b9ce09,
+    0x37926e39, 0x950d04b7, 0x692b7cd0, 0x915f0397, 0xa2d108c1, 0xb3ebc824, 0x691863a8, 0x83f27f88,
+    0x66f4dd09, 0x511afa84, 0xc36c7ed0, 0xf983fb7a, 0x5e7fb12b, 0xcb29b2e7, 0xc504cf34, 0x1997241b,
+    0xcb7c5441, 0x382b4805, 0x93b8087c, 0xd1c2154b, 0x5d31ef86, 0x4118d450, 0x9b9f66de, 0xc6d984aa,
+    0x79da1fc5, 0xaf0728eb, 0x71af82e7, 0xb0e2c34d, 0xd310ce95, 0xb0fcab8e, 0x968010ea, 0x1367a661,
+    0x23311e1f, 0xe68d6176, 0x6d82b9fb, 0x7c80b3b7, 0x7a507c54, 0x699a7ef1, 0x442fc604, 0x902bb061,
+    0x07f591d4, 0x40db69fb, 0x6e3da730, 0xe61a26a4, 0xd0914ca4, 0x2ed91e38, 0xefd4a51a, 0x7ed45fac,
+    0x85c40d71, 0x41c06189, 0xaa84aaac, 0xca8d7832, 0x3314bf39, 0xe99ead44, 0xc94ed484, 0x6c38fa86,
+    0xaf925b63, 0xd3b679e9, 0xe9b6b988, 0x2e59e9e9, 0xe97c98fe, 0x8f59c648, 0xc7ffc9c1, 0x4ac4059e,
+    0x39f9b6ff, 0x03083694, 0xec796344, 0x4864379c, 0x15ec2280, 0x76c2073f, 0x7b2aa990, 0xd64d5c2b,
+    0x0707de18, 0xa474dfa8, 0x0a094658, 0xf6d67eb3, 0xc4506664, 0x4342ccd4, 0xb216e8ae, 0xead8e84b,
+    0x89e50e3b, 0xfcfe033f, 0x386c80bc, 0x02fba793, 0xad9eb48f, 0xd7e43c7e, 0xfa78074f, 0x74d3039f,
+    0xcb2ac323, 0xa8975b86, 0x6f2f4cc1, 0x962b0f78, 0x1f23dee1, 0x5172bb29, 0x1b3d8b35, 0x4135c4e3,
+    0x4860824d, 0xfac63589, 0xb20fb9ec, 0xf978ce26, 0x9f832a6c, 0xb734288e, 0xea9d5fd9, 0x8616c559,
+    0x38cbfafa, 0xcf7b7159, 0x8e3f0f08, 0xa9b4cb85, 0x1b2be4dc, 0x2bffad33, 0x5b0b873c, 0xc434a735,
+    0x490cba7b, 0x17671883, 0x7b0ac387, 0xcf2b7ec9, 0x3d7e99e3, 0x951b48bd, 0x47843530, 0xccf47793,
+    0xf7626255, 0x654ff936, 0xd76fe1aa, 0x1f8a985e, 0x5b8ffcf9, 0x89c497eb, 0x2fe470b7, 0x5b0ddb17,
+    0x5f44fc42, 0x20d61216, 0x786a9bec, 0x61296c2f, 0x65c22b86, 0xa6dc5bea, 0x9013db05, 0x54d412f7,
+    0x803a0d48, 0x1b03f0db, 0x90838808, 0x60e334e1, 0x77a0021e, 0x6e33fb14, 0x42ae66a0, 0x206243be,
+    0x88d5dae1, 0x437f5dbf, 0x88707f3a, 0x05fb177a, 0xa537eb04, 0x2595ddcb, 0xcb9abf0e, 0xa11c7b13,
+    0xb8e4ab06, 0xff004bc9, 0x2903c16a, 0xa2fe23ac, 0x2c5b3899, 0x431a5c21, 0x536b3999, 0xd385dc21,
+    0x2e0435ec, 0x710ceff6, 0x2172b068, 0xb3ca6fa1, 0x0a9b8f0e, 0x8011b4a7, 0x4a9776de, 0x76f8ed30,
+    0x1feff8a0, 0x4fbd7765, 0x98d93db7, 0x9169e5b0, 0x66f767fd, 0xb727e84c, 0x6b0f102c, 0x1bc6cc82,
+    0x255b7652, 0x8e591c21, 0x2551800e, 0x11754981, 0x2d454c90, 0xbf33d00e, 0x55614b1d, 0x0e10464d,
+    0xe8b33f14, 0x1598e171, 0xcc4a0f2f, 0xe4d35897, 0xa046cc4e, 0x6a78aadb, 0xa3c4e91b, 0xb8e9eb6b,
+    0xb2cd034d, 0x4e6a8656, 0xf6732bdc, 0xc3003b9e, 0xb98495ba, 0x8b9728cd, 0x25efe4b9, 0x3839d564,
+    0xb86ae451, 0x785da288, 0x5ade4d23, 0x437509ff, 0xc13f52e9, 0xe59537c1, 0xa05f26ed, 0x167622b3,
+    0xbe1634de, 0x7f11b760, 0x414da3cd, 0x0e8f5339, 0x9d542718, 0x1a89420f, 0x6fb6001d, 0x786482a3,
+    0x033a3fdd, 0xc440b04a, 0x5af87329, 0xd36a6830, 0x0b2691b5, 0x82ff40ab, 0xe4b16836, 0xf41e524e,
+    0xb8842dc7, 0x438b846c, 0x01c315a4, 0x8a56439b, 0xc46bb717, 0x2d4186f7, 0xbbd1b084, 0xa6659502,
+    0x4f68ff00, 0x4cee839b, 0x5ea98566, 0x02fe241e, 0xa987b550, 0xa14aaf7d, 0xcbb38380, 0x7269360d,
+    0x0d1b736a, 0xd601e270, 0x31e1c2aa, 0xa1f30ef9, 0x5e4e644f, 0xf1993077, 0x6a6476a3, 0xeba7e63b,
+    0x0d88f5c3, 0x5974dc5f, 0x7e91e0d4, 0x8d9890db, 0xdff7f133, 0xc8794abf, 0xdd6ca63c, 0x61de538b,
+    0xbcec7e4c, 0xc497a0c2, 0xf7f3665c, 0x74ef453b, 0x4a863b45, 0x00f92d37, 0x2821c854, 0x06c99c64,
+    0x89076859, 0x2c837ec8, 0xc462de04, 0x60ae98b5, 0xc36ab1d6, 0x3ee9918a, 0xa356b6d0, 0x977b0bfa,
+    0x41cca814, 0x8cb3202f, 0x1db9f542, 0x2d508cb6, 0xfaa7bf4c, 0xf5492ce7, 0x63d58ebc, 0xf3e0b6f0,
+    0x5ff69349, 0x6d4d00d6, 0xc2f84f6e, 0xf67cb640, 0x6909cfd7, 0x8eba4b7c, 0xd30598e8, 0x2ac8e0b3,
+    0x0fea6e10, 0x5f1780c3, 0x660a6e19, 0x88b69340, 0xdd6d3d2e, 0xccc5e1d7, 0x630c2f11, 0xc0c38256,
+    0x22aaed5f, 0x88ff84e8, 0x0b8d8f4c, 0x1422f0e4, 0xc4f08723, 0xb537f4f4, 0x3a76fccc, 0xfcc40bdd,
+    0x2f211995, 0xca01ba80, 0xcb4f07e1, 0x254e3fd8, 0xad3dbab0, 0x54a45a4d, 0x0d490c4f, 0x8a343f05,
+    0x9b31e651, 0x1fb79b89, 0x6b3e2720, 0x88285fbe, 0xff810bc5, 0x9a40edbb, 0xdc2d4012, 0x551b46d2,
+    0xce69920e, 0xb1ed5a68, 0x4b48704d, 0x397727f6, 0x8c26c6ba, 0x20c95271, 0xb1ef682c, 0x66ae07cb,
+    0x638af592, 0xf3496ce9, 0x435d27a2, 0x8bba667a, 0xcea16d16, 0xacb51f2a, 0x9ef8387e, 0x7689a1a0,
+    0x3503b8a8, 0x2e792a0d, 0xc95e3cd5, 0xbd356922, 0x434aa43f, 0x33de9f2c, 0xef85173d, 0xaef53496,
+    0x38c24484, 0x1ee389e5, 0xcbbef43f, 0x566b68dd, 0x973015f3, 0x037cf98a, 0x5dd9f317, 0x9a2cb27b,
+    0x03169b52, 0xaeee2ac6, 0xd22e458d, 0xab9c7235, 0xd9b48c82, 0xe870f7c7, 0x340922de, 0xdcb65bf9,
+    0xf0d532f4, 0x925d67e7, 0xbdbc9d8c, 0xf72b8b06, 0x8dc2c50a, 0x6ff2bf75, 0xc2aaa065, 0x661bfbed,
+    0x9fda0732, 0x80952899, 0x46bc32bf, 0x790bb79d, 0xdecf794c, 0x9f8c037d, 0x816b8ef6, 0x5a36a6d9,
+    0x3d4b9339, 0x2caf35a0, 0xc3445849, 0xd9615532, 0x6b48e0dd, 0x3637c389, 0x5e055a46, 0x26ca29e9,
+    0x817013fc, 0x6e36caca, 0xdf316684, 0xb6d8ddd8, 0x4b59ab03, 0xeea0b673, 0xe5d4e280, 0xbebb9e9e,
+    0xf5a141fa, 0x15327385, 0x73617e40, 0x9ff83f09, 0x78f03765, 0x5c1943ef, 0x0660adbd, 0x0554bbca,
+    0x954291c3, 0x7163a247, 0x7b504b84, 0x7ce8a9f8, 0xaa9b9063, 0x38b3cc8a, 0xd99d2048, 0x27e3db04,
+    0x1f506b35, 0x6eb4f1b2, 0xc451da8f, 0xacac95e8, 0x092d926a, 0x43b18fe9, 0x6718f876, 0x396fb66c,
+    0xc6f3cb13, 0x8c09c89b, 0xe0a1ca65, 0x894612dd, 0x87327263, 0x5726cc90, 0x9b2d9895, 0x9697ae00,
+    0x9f4f5b76, 0x2029bb1e, 0xc6460e56, 0x575a18db, 0xe2768d8f, 0xe6d64839, 0x38442fdd, 0xf7617c4e,
+    0x0e0c5ef6, 0x18a41c60, 0xdb9af8b1, 0x0cedfea2, 0xbb2e7e8b, 0xd7f04930, 0x98085707, 0xd88130fe,
+    0x9b819e8f, 0xfe4f4398, 0x12f1f338, 0x416eb039, 0x83770ee2, 0x7a9b78a2, 0x08ebd462, 0xa1ef5ff6,
+    0x960a2d4f, 0xd5d609f7, 0x2fa7f46b, 0x19a6cb6c, 0x84780a4e, 0x0837d011, 0x871dcb1b, 0xa737b9c7,
+    0xb4826ebe, 0x3de4eb49, 0x984f6857, 0x99f70950, 0x770e2d44, 0x6396777f, 0x5dd42fdb, 0x52826e67,
+    0x6b5e94e2, 0x8a0ebeb8, 0xda828288, 0x9d5f4a94, 0x64a2acf1, 0x6d98ea6a, 0x449c5cb6, 0xc3705fa9,
+    0x6f5761fc, 0x928c686f, 0x5dab8bcb, 0xbda92c78, 0xe358b41c, 0xf9c448df, 0x655e2002, 0x58e4d2ad,
+    0x85499cdd, 0xaccc9bd7, 0xbc5e5e1d, 0x27da6e59, 0xb1131c31, 0xca4e0f7d, 0xbac074a8, 0xd76afb39,
+    0x8cfd6100, 0x810d1381, 0xc7f0eb62, 0x0e704dbc, 0xd8dddcb4, 0x383aab02, 0xa63af122, 0xea47e064,
+    0xb58bcc11, 0x1ceeefd0, 0x9c11e8ea, 0xc321b34d, 0xfff53df6, 0xc03fbe50, 0xe5dc497f, 0xe32cd0bc,
+    0x9f4abde3, 0x1ec6d473, 0x40816286, 0x14d83bbe, 0xfdcc7005, 0x54f13f72, 0x6423b5d9, 0xbe4400bd,
+    0x74d963b4, 0x627c3269, 0x0b9065f4, 0x08ae3547, 0x5f995273, 0x60988f92, 0x9ecdc297, 0xb8bcdf72,
+    0x384ae04a, 0x6b31f3fa, 0x4119e31e, 0x5c28dc23, 0x486ea224, 0x12a7c49c, 0x766cf1f4, 0xa5540cd6,
+    0x192b0344, 0xed07454c, 0x05965989, 0x1c6f3838, 0x6c6dd5f0, 0x09ad857c, 0x409a6f98, 0x64f1fe04,
+    0xc4f6b399, 0x68973069, 0x89d56789, 0x620d7d42, 0x4e2b2bf0, 0x8a15988b, 0x2e59da25, 0x74c9787f,
+    0x9cec299c, 0x37826fbf, 0x8e75c838, 0x45d7326e, 0x0e65ffbd, 0xa7ce5026, 0x39234e2d, 0x9d9b2464,
+    0x613f435b, 0xca7d52a3, 0x97deb1c6, 0x6a43e64f, 0x6a2a8ad9, 0xed402c65, 0x0ac60335, 0x11316c03,
+    0x5e519425, 0x811895ef, 0xf38405b4, 0x244847ed, 0x5bae73bb, 0x8c5674bb, 0xa07edda5, 0x8a338c1a,
+    0xa3f05fe7, 0xd88792d6, 0xa714582c, 0x8e4c59e9, 0xb0456bb5, 0x20c99ba3, 0xa07165d7, 0x00d414f5,
+    0x2c55d989, 0x945162a7, 0xe351b917, 0x582b7ddf, 0x2034e095, 0xe3d0d119, 0xef9b347b, 0xd074df02,
+    0xcf633a74, 0xa549c556, 0x4d3a3aa2, 0x04bc051c, 0xe8838535, 0xb96477dc, 0x37c70223, 0x5dbf5e06,
+    0x3aff4227, 0x3d2de475, 0x13bfe1d3, 0x448c2424, 0x4c0f55e7, 0xad39a639, 0x2e95c152, 0x2dfac4fb,
+    0x24109302, 0xb9308354, 0xae6f68bf, 0xf477a3d3, 0x632191e6, 0x1a7179ee, 0xdba84f27, 0x295283fd,
+    0x255276b8, 0x5b5f6ad1, 0x9804742b, 0x73007c7b, 0x6c1bfc75, 0x9927369f, 0xd8d8b519, 0xa16ac6e0,
+    0x169d3d2c, 0x8201cf97, 0x20a764a2, 0xe7b85705, 0x03b73cb1, 0x3b0f5eab, 0x90adf409, 0x4f75de71,
+    0x34a44ecb, 0xa1a0e9b4, 0xe0c096fb, 0x3a4b9c4a, 0x328b0cb4, 0x7a54adfa, 0x3c61b0d5, 0x9c8acc86,
+    0x7f9f33af, 0xb9fb192f, 0x667e06e6, 0xf9c6ac22, 0x7363edbf, 0xf8762f09, 0x017811a9, 0xed49f982,
+    0xa6b99d76, 0x3bfeed20, 0xc92fbdae, 0x87c5562a, 0x9da181cb, 0x695c4bbf, 0x3f2c7e7b, 0x7319e908,
+    0x6c142100, 0x3861bc9f, 0x78024817, 0x2bba3aec, 0xcac28d4c, 0x9d046904, 0xa71d7099, 0x83cf5839,
+    0x2616c3cb, 0x18e32288, 0x8b5a1d55, 0xd7448482, 0x63f8cdf5, 0x1421f310, 0xc39d81b9, 0x76c3fdb6,
+    0x35dc5e92, 0x564eb722, 0x40a7c1af, 0xb4112917, 0xea6e6393, 0x4d88b07d, 0x314a8483, 0x99d7057c,
+    0x64f0a7ef, 0xd234a752, 0xc2545b15, 0x2196fe80, 0xaa722ee0, 0xf88f2519, 0xbdfaff5c, 0xe9816c32,
+    0x7242b60f, 0x2f8f839c, 0x6c667bc3, 0x251213c0, 0x69f74c01, 0x9e26aee9, 0x04603fc8, 0x94cd3385,
+    0x922ca753, 0x6c7c9b1c, 0x0529420a, 0xc0728d98, 0xe73f0f0f, 0xf2c0ee40, 0x1c01a9d7, 0xd31eb940,
+    0xb90f0993, 0xdd38d0a9, 0xabff95a7, 0x97cb2e82, 0x8ec4d4f0, 0x37844995, 0x069a110a, 0x41ce082b,
+    0xab941092, 0x0ade58e9, 0x8cb78439, 0x31d646ef, 0x83043db6, 0x8885dc8e, 0x6aaa4bc1, 0xf49c42b5,
+    0xf3ed808c, 0x53c388bc, 0xf436d9db, 0x02aa25d2, 0xd959e4aa, 0xe57ff218, 0x993801cb, 0x03c227b6,
+    0xb7bb38cf, 0xfe2c2902, 0x93f67a81, 0x4050b96b, 0xa178aa03, 0xb0621077, 0xae961262, 0x565404b5,
+    0x2a226d76, 0xf03d921c, 0x9c95bacc, 0x594c7a3d, 0x006d72d8, 0x04a81629, 0x78d09e9d, 0x5c52e834,
+    0xf412c70e, 0xddde8c8d, 0x807c51f1, 0x913d5da5, 0x10972201, 0x2aa3b97a, 0xed7cc815, 0xc23327d7,
+    0xbdfdaca7, 0xd83a6ca0, 0x0b3cb85a, 0xb1126700, 0x86434673, 0x20b8a2e4, 0x9951d80d, 0x9f326b65,
+    0x1804b81a, 0x42aa169e, 0x5367ee56, 0x322f04cf, 0x62c7ed7f, 0x3a149cda, 0x90f65096, 0xf30a69c5,
+    0xd7b63aab, 0x409c4e15, 0xe38f24f3, 0x05037ff4, 0xc13a2c5d, 0xbe0f7c6f, 0x29a7617f, 0x864d5cef,
+    0x8ee094c0, 0x9ac20f07, 0x14235bab, 0xa2757168, 0x9e19980b, 0x415fadce, 0xef27b29e, 0x41cd8e9f,
+    0x04fe8949, 0xeeca8028, 0x2359b4af, 0x4addbf3f, 0x4f6c689e, 0x423c0f14, 0x1a0d35c9, 0x777ad185,
+    0xf3b2217c, 0x42107d5f, 0xc1ce3bfc, 0x2a2cec3a, 0xa79f71eb, 0xb2b281c0, 0x14586c9e, 0x234831d3,
+    0x0fdde54e, 0x55a9df9f, 0x1babccf0, 0x84e093a8, 0xf84d9e18, 0x6783e898, 0x6a731f1d, 0xcf15ef40,
+    0xd5cab65e, 0x68190177, 0x19a95088, 0xbd883cbb, 0xb2240537, 0x2a4bc940, 0x059dcd96, 0xebddc9a7,
+    0x4c6a284a, 0x0efaf5b4, 0xb1ab2ed0, 0xb5e80023, 0x418ee8d3, 0xbf075769, 0xa97766fb, 0x9145359e,
+    0x3e049df4, 0x7e1798fd, 0x74e8e744, 0xb0813735, 0xa5aabec7, 0x1033e822, 0x3f4cf847, 0x95845dd7,
+    0x331fe82d, 0xb4c1b2d7, 0x573c14f4, 0xbef1c73a, 0xfee58fcd, 0x04dadccf, 0x62ec8254, 0xa3396bfe,
+    0xf44a3941, 0x3c8e25be, 0xd87262af, 0x24ddef14, 0x09ce5075, 0xee707566, 0x971fedfa, 0x2441f0b6,
+    0x21ac843a, 0xaff4df91, 0x408937dc, 0x1bf1cb45, 0x2428420c, 0xc6229dba, 0x1f0f0223, 0x23b21bd1,
+    0x61c7b44a, 0xb4ef1160, 0xcfd4aee1, 0x3702072c, 0xecc2a4e6, 0x760704ee, 0x55d94497, 0xfbf05a24,
+    0x8e0ca767, 0x0b9ac778, 0x94d3c8a8, 0x9eab431a, 0xf4435707, 0x8060f37b, 0x8d89293e, 0xf5679fba,
+    0xb87a6f2f, 0xbf3359d2, 0xeb7f2a0e, 0x14ad7612, 0x18ad3b5a, 0x0f907d94, 0xbc63a192, 0xf0761b02,
+    0xdce45452, 0xf4542ce1, 0xcc78b973, 0x66dae511, 0xc96c0167, 0xe31157eb, 0x2f695e07, 0xa4a92f05,
+    0x9dd35ea5, 0xece5ea96, 0xe9f6c7e7, 0x3aaca203, 0x83408a17, 0x0b78a67c, 0xeb154c38, 0xd2346332,
+    0xcc20823d, 0x7c65304a, 0xdac6e88f, 0xcd5eb0ed, 0xe8fe6624, 0xfc4707b3, 0x3a12c1ca, 0xeb294acd,
+    0xd2e402bf, 0xd03dde91, 0x3a5a5d91, 0xb4ef6fd8, 0x86bc1c64, 0xe7a89716, 0x8f30688e, 0xcde219ef,
+    0x41fc27cb, 0xfcfca48c, 0xafdda2e1, 0xdf3bf697, 0x3ea35768, 0x46b906a4, 0x30d37122, 0x1cee9ae1,
+    0xe028a550, 0xd615cc01, 0xaa2cc6e4, 0x550998b8, 0x068c77b5, 0xbf2038d7, 0xb58e9653, 0x1844737f,
+    0xbc0a4b50, 0xb96df665, 0x808acd79, 0xd7776eb1, 0xc680b654, 0x3e52affb, 0x0da0fda0, 0x226d6a41,
+    0x16198e78, 0xa666a9dd, 0xd65a22c9, 0x9f7c6b0f, 0xfc87964c, 0x29f3cca6, 0xc33de405, 0x63eec130,
+    0x84ee8d6c, 0xb1615fb6, 0x115c1176, 0x7a8ac94f, 0x18d9d399, 0x41cd11e2, 0x1e0d6246, 0x532f322e,
+    0xf587cdff, 0x97efbe9c, 0x939a8a43, 0x07c9e0c8, 0xf935f078, 0xc6bf2440, 0x3603b0ef, 0xa8066290,
+    0x5487cb2d, 0xa6e7fc33, 0x3685c58a, 0xb29a962c, 0x6a62caf7, 0x25d63eb3, 0xb938d786, 0x2493949b,
+    0x1b689397, 0x6cd00b51, 0x9aa0385c, 0xf34b6fc7, 0xb25eb84b, 0xe898f61f, 0x0d46835f, 0xcc881d44,
+    0x15f0cbd8, 0x61848a99, 0x6890f9b5, 0x51aae053, 0x0cf5beb7, 0x66a25cb2, 0x1bb68857, 0x99c7dcdd,
+    0x57fedc8f, 0xbe063b21, 0xb521ef5b, 0xe74cd075, 0x19f6969a, 0xccd8206c, 0x402068ee, 0xfa66c426,
+    0x68e6fcc2, 0x4878d987, 0xa2b6bd92, 0x6f4023fe, 0x8ceac42f, 0x24d35ee6, 0x11721a0e, 0x27aa09a8,
+    0x594e669a, 0x1104c8bf, 0x718d6c4f, 0x76bf3513, 0x3a673e77, 0xffe5868f, 0x72bdac5e, 0x871b25c9,
+    0xeb9f48e1, 0x755e81a2, 0x6b6cfbb7, 0xf21ac2b0, 0xda577c61, 0x5ba37e88, 0x69477231, 0xd207f5ad,
+    0xbdf3c487, 0x11ecbc31, 0x6c3341b7, 0xd9b377ce, 0xdb92ff58, 0x24366568, 0xe1d2af4f, 0x8d040613,
+    0x6b662a4b, 0xd31dd367, 0x7e34b85a, 0xbe78dcaa, 0xe8010885, 0x0c231e22, 0x69c2c2bf, 0x753e0c6f,
+    0x41189b13, 0xe320b87e, 0x17f9eb52, 0x84b79fe6, 0x22bd020e, 0x20c73d1f, 0x06141e4a, 0x40de8a8d,
+    0x665dc0bc, 0xd5d06643, 0xd7e3769f, 0x5591b14d, 0x8fa3d8e7, 0x223586cc, 0x0788fb56, 0x6276afe8,
+    0xb7f120f9, 0xd92bdf4e, 0xdd10a3b6, 0xf941619f, 0x7c23399a, 0x066aa57b, 0x2b6531e2, 0x9fd19612,
+    0x17543b4c, 0xa9d6a4a9, 0x33c90c8d, 0xbe8bc026, 0x40e7d00e, 0xc3be2554, 0xd353e83b, 0x3eda4045,
+    0x68c5d6d0, 0xc9f750af, 0x297cffe7, 0x1281712a, 0x2e2f01ee, 0xd1482dcc, 0x9cb5f3d7, 0xbaedcc53,
+    0x8ff68b60, 0xf5d791e4, 0xd855283d, 0x6c113ce2, 0x7a3532d6, 0x0f5ebfd2, 0x0297afa0, 0xbeff58b6,
+    0x202558f3, 0x12409d91, 0xb9cc80f6, 0xb443d00c, 0x17e0e554, 0x68709a5f, 0x8d5c6b68, 0x094b1e14,
+    0x9da6982e, 0x9ae092c5, 0x252b4df6, 0x64e1621b, 0xd04f5fdd, 0x7f962c70, 0xf9031d88, 0xd997d3ea,
+    0xc9bb68f2, 0x981c920c, 0x179b46c4, 0xfe75bdb6, 0x96edda95, 0xcfcc9652, 0x402f8ee1, 0x327bbc76,
+    0x46a5847c, 0x9837fdf2, 0x85a72549, 0x915ce5e5, 0x3d56961d, 0x61b4d18d, 0x75da8e1f, 0x0394b649,
+    0x53a672c5, 0xd466ed59, 0x5a58f203, 0x0e1cc033, 0x0dbd2d24, 0x48472078, 0x7f3181ae, 0x7dbd8076,
+    0x3176a72b, 0x8bf22fea, 0xecad00e1, 0x124d2917, 0xfafd47bb, 0x2c2c860d, 0xf82832c8, 0x719f2abf,
+    0x5ab369d7, 0x535c73de, 0x66d71c29, 0xcd1a9f28, 0xa76bb1b2, 0x864854c9, 0xa0500889, 0xd6e89012,
+    0xc867e288, 0x5a5be993, 0x7aa1e6d2, 0xdb6b339c, 0xac1d927e, 0xca2d78f5, 0xd6178d11, 0x8f1e7857,
+    0x7c26e686, 0xba75bf2d, 0x71048495, 0xefe8c077, 0xa6c070c9, 0x4b2fadb0, 0x831ca739, 0x1027149c,
+    0x4f0ac0c0, 0x885eb295, 0x1d644191, 0x1cd8f48a, 0xec4ce212, 0x15e61208, 0x8b56e4b1, 0x3bcb3e45,
+    0xdeee9f9d, 0x7508ad7b, 0x4211a25d, 0x16dd0424, 0x84bf38bc, 0x3601e003, 0xfbbf6864, 0x70509ca9,
+    0x0f618d5e, 0x6b5ef262, 0x6562b7c9, 0xc3ebeef4, 0x20f893dc, 0xe332ea51, 0xf81ac171, 0x655b8b3b,
+    0x07d7190c, 0x5ef50f50, 0x40ccfe4a, 0xa5c9df5d, 0xf567a32d, 0x8b259941, 0x94dd6cce, 0xa2f21da2,
+    0x570df3b8, 0x0bd69b73, 0x8b7edd58, 0x479fc8e5, 0xb32e657b, 0x1e97a1cf, 0x5ca9731f, 0xd4aaaeb7,
+    0x05ca93db, 0x135a15f8, 0x8886c6c9, 0x9e910009, 0x2bbfe1ee, 0x19c86fa9, 0x7f2a8b0b, 0x098a4689,
+    0x5ea48fa3, 0x23268c53, 0xb55d28f7, 0xc0c00df6, 0xfc3c89eb, 0xc2d0ac34, 0x527c63e4, 0x3369e91a,
+    0x1705dd93, 0x5103988a, 0x792b6ea7, 0x0d69dd0f, 0xb5c77ed3, 0xfccdb1cc, 0x3656e6bb, 0xf1d80851,
+    0x1faa0988, 0x309386c6, 0x122c3b21, 0x8814816f, 0xd9af2625, 0xcb094888, 0x263ad6e8, 0xa4ab346f,
+    0x19091e72, 0x8f20ad7b, 0x91735c09, 0xe5a80f97, 0x5db359a3, 0x9dbe85bc, 0x927b801a, 0xdb8ad2c1,
+    0x5355403b, 0xea3eff44, 0x94ae5505, 0x06fdd362, 0xfe56dac7, 0x6f138d89, 0x7ac1921e, 0x06311ef8,
+    0x8eed6dfc, 0x596fe59f, 0x295c0c72, 0xecae4271, 0x80dd00cd, 0x18b93825, 0x803c5c59, 0x63db43bf,
+    0x62feba1a, 0x3fae0763, 0x50dce22a, 0xc05c08dc, 0x3d7e7f51, 0x6a65e241, 0x405ddced, 0x4d4ab022,
+    0x42f3ac91, 0x78d0e989, 0xd5655c6d, 0x3304e937, 0x9b524246, 0x0cb914f4, 0x7d59fabc, 0x01e8ebd9,
+    0xab332164, 0x53318a39, 0x3500f1a5, 0xe6633eb1, 0xdeb67a4a, 0x72ab53c3, 0x87ba4d5d, 0xbfe5d5c2,
+    0x36e36f62, 0xef313f25, 0x4fa22ebb, 0xbd0c9795, 0x0de37128, 0x6ddf6c40, 0x3b1acb4e, 0xa1f7417a,
+    0x297ae39f, 0xbafd57e7, 0xffbc12a4, 0x309043cc, 0xeb9b7748, 0xd5e5791c, 0xda527a3a, 0x2d6a906b,
+    0x0564c0c1, 0x9cc05054, 0xb6f0bfd6, 0x37233ad2, 0xf9adc3b9, 0xe1fba21d, 0xe483c064, 0xfb35a0c5,
+    0xe8033693, 0x4c65aede, 0x0a804d97, 0xe997662d, 0x61272346, 0x4ab79e4f, 0xf33523cf, 0x36d6015b,
+    0x8abd85ca, 0x9c28ab07, 0xa8fa6868, 0x28d7de9c, 0x9ffb4710, 0xc234c967, 0x98bfdff8, 0x7d82b4ea,
+    0x635aef23, 0x5cd23ad3, 0x42b714a2, 0x82803d97, 0xc166d109, 0x60a0a9a1, 0x34506810, 0x42408659,
+    0xa086f224, 0xf3cb381a, 0x45120dee, 0x824f3194, 0x1fe004a9, 0x58a4f20f, 0xe32329b6, 0xcd2664a8,
+    0x20ec8ab9, 0x797520b5, 0x52c88f4e, 0x299493c8, 0x0f68aebb, 0xee82dce9, 0x039b4b41, 0x7a2e054f,
+    0x007873b5, 0xf753ef2d, 0x35bfa3cd, 0xcfd1729d, 0xc3e2b5f8, 0x44f77b65, 0x4f2a2b2f, 0x9d3d65c0,
+    0xefb1d56a, 0xa00be4b7, 0x27f39532, 0xb6562c0e, 0x5b65210c, 0x7f21e566, 0x83407927, 0xa2e4994a,
+    0xbdc70ad1, 0x9c4a3fc6, 0xfdb861ee, 0xc28dff42, 0x860fc47c, 0xce7e3a5b, 0xe4c5da6a, 0x966a7d6a,
+    0x2c939c9e, 0xe1deb098, 0x61816cc2, 0x55cdaf37, 0x2daf1d1b, 0xbf6df345, 0xe1333772, 0xf824addc,
+    0x08c3a7e6, 0x7d9f3ded, 0x5a108ae9, 0x8edae9f6, 0x437c8bbf, 0x3e7bdd17, 0x49639127, 0x65da3215,
+    0xe69303a0, 0x055b6be6, 0x129199d0, 0x6c2fa298, 0x19c106af, 0x1a4d507b, 0x8aa612c0, 0xe5f4d77a,
+    0xa4aaf420, 0xfca761d6, 0x9e281435, 0x9f69d658, 0xe88e023c, 0x9191bbdd, 0x33fb368a, 0x2366b217,
+    0x4e4cf3f0, 0x1ee97ac8, 0x7a51ecff, 0x301f3163, 0xabb9a2eb, 0x1fb3857a, 0x49d1f1e2, 0x2827ea8e,
+    0xa802840f, 0xbe830331, 0x471bb7fb, 0x21ab2e93, 0x72823a17, 0x375b1796, 0xf9a1ba5f, 0xa21c2fdb,
+    0x3ff74a2f, 0x8b07f873, 0xa6e6a188, 0x624b736e, 0xeaa59a30, 0x094ed69d, 0x47d06470, 0x9c9dd359,
+    0x91594159, 0x9630ae88, 0x40032fd5, 0xfac3bb3a, 0x189565ac, 0x3936833d, 0x56af1b44, 0x1ce1488d,
+    0x7488f122, 0xea76b1c4, 0x44b3ffb2, 0xfd00bf8c, 0xe08aaa75, 0x20a31ed7, 0x80a7d239, 0x4c3f0790,
+    0xa4c03720, 0x58b6cb2e, 0x9739f179, 0x9144b3e4, 0x252a4bc1, 0x5c287845, 0xf380dbfa, 0xd21b051c,
+    0x96768657, 0xa10c8b16, 0x9b596283, 0xf6316227, 0x312d4744, 0x13ed2772, 0x9a15476d, 0xc2b601e4,
+    0x7e7a04a5, 0x04066a38, 0xc4a7229d, 0xf10192bf, 0x5317d123, 0xc25a1d84, 0xf958a6a0, 0xea182fc5,
+    0x25b54d4f, 0xc0c6a087, 0x2ab02395, 0xc2725879, 0xda9bfd0d, 0x9cfeb7f3, 0x79a3677c, 0x92f7f8b7,
+    0xa7e5a1fb, 0xe316ad2c, 0x21a40c5f, 0xda3c22ae, 0xe2e3a698, 0x4cfe9636, 0xfd3a8042, 0x5787370e,
+    0x6441b66f, 0x6ced8ab8, 0x74475636, 0x55ccbc1c, 0x0d171599, 0x8a398005, 0xdd020ec9, 0x328597d0,
+    0x87f9a32f, 0x67a78387, 0xc1576ca8, 0x54bc32f1, 0x17486644, 0x8266a177, 0x5a38e339, 0x1315298d,
+    0xa79a6773, 0x157e3580, 0x6865f68e, 0x490a581d, 0xdc9bc87a, 0x03566c1c, 0xc6d37725, 0x8f039b43,
+    0xd64f9dab, 0x03fb69b3, 0x8ffdd377, 0xff7b58d4, 0xbb9d2d91, 0x86b6f665, 0x1031b7f1, 0x048e4366,
+    0x60797b79, 0x33ad8a09, 0xdffd1a33, 0x2540f1d4, 0x98b7ab50, 0x8602dd55, 0x4413826b, 0xb6c9836d,
+    0xeaa2dbea, 0xec2ae499, 0xf634156c, 0xdf8de48f, 0x4eee03ae, 0x3616a55b, 0x81810586, 0x985d65a8,
+    0xe1df79a7, 0x4275fb95, 0x264ae473, 0x878376a1, 0x15da638d, 0xe74e0f37, 0x4d51db2c, 0x60b7cb38,
+    0x54cf0ecb, 0xdd47338e, 0xe6a6ab1e, 0x9aab38e3, 0xd0200a14, 0x4c4dcb10, 0x15a2a51c, 0x0a213d9e,
+    0xa442e236, 0xc8a39fca, 0xc925049c, 0x06774f0b, 0x55970215, 0x85a3d5c8, 0xb4a8f34f, 0xc4ca310d,
+    0xbfcbda1d, 0x61a02cfd, 0x4d364fb7, 0x270947b9, 0x5c90aed6, 0x2074d6c8, 0x3491dc5c, 0xf1b2f90b,
+    0x7ed35bf4, 0x278fc359, 0x44370592, 0x59a3beab, 0x05302a22, 0x20738be2, 0x07c782f6, 0x5e503d72,
+    0x0951911c, 0x835e5108, 0xab68879f, 0xc5745c2c, 0x0f7eb479, 0xce965fa8, 0x69debe67, 0x14aa7c64,
+    0x92b90de4, 0x60c2b0e0, 0x1937fad2, 0xf4366d15, 0x5535cc1d, 0x9be95b6d, 0xc3e190e3, 0xdc72b159,
+    0x548dec59, 0xa5183499, 0x18cf9e8e, 0x5f728a86, 0xd2005d0e, 0x27f14c6b, 0x5416095b, 0x3dc83e50,
+    0x69d1fa12, 0x56df598e, 0x8bbda67d, 0x6bd26dfc, 0xd76c2ce8, 0x84168c80, 0x244ea6e8, 0x2810e13a,
+    0xa1d88f92, 0xb5155046, 0xe4c11a39, 0x5a2df9f4, 0x8665a612, 0xb9b7f4f0, 0x9a5b73df, 0xa61244d8,
+    0xd6a537aa, 0x80f1464a, 0xa354389c, 0xc40a8649, 0x2f4c1306, 0x7ea62745, 0x3bc3da29, 0xc1d69c48,
+    0xc8fc6804, 0x66d394d6, 0x87fbffb5, 0x9e570d02, 0x68d3a279, 0x5d4a3c83, 0x98b1cc27, 0xda0a33b7,
+    0x85d047be, 0xf92c2a7b, 0x966c5416, 0x77ac3775, 0x757460a8, 0xae5a416c, 0xa710d7da, 0x7aa27162,
+    0x1cb2fe1d, 0xb865ec82, 0x948dbe42, 0x53b64d93, 0xd7d162f5, 0xc342da04, 0xe1831020, 0x76e1973e,
+    0x5d9323a1, 0x14c33468, 0xda0efe79, 0xa8b53a70, 0x8bcf5116, 0x6609bc4c, 0xe750fbe8, 0xbe7855cc,
+    0xbb18ab8f, 0xbe576031, 0x5c8a9507, 0x72bda9eb, 0xb4969758, 0x970e8e77, 0x98740aac, 0x7c616e4b,
+    0xb25bd360, 0xe223243a, 0xecc7d572, 0x2da1d9cb, 0xfa221b5c, 0x00d2c4cd, 0x2a619399, 0x49acfc9f,
+    0x036fe7cf, 0x3b4ff1f1, 0xee18febc, 0xd42a9366, 0x387f4b5f, 0xd4f366b2, 0x2491d54c, 0x1ad51bd1,
+    0x5d6bbcb7, 0xb0637a1d, 0x5049c830, 0xa8f786dd, 0x8406ae63, 0xa46245ec, 0xe340e596, 0x508bbbc7,
+    0x54857260, 0x65cac3ab, 0xf50a9af5, 0x9c4ec014, 0xa926049b, 0x993060ec, 0xb7326735, 0x232e3bcc,
+    0x5fd23f71, 0xc7b49a47, 0xb5c11de4, 0xed3312d1, 0xc0b09b55, 0x7fda9029, 0xb48658ed, 0xeba40dad,
+    0x6b88e1c8, 0xa088f4a4, 0xe3a0a634, 0x7c99d063, 0xfdb54736, 0x32af2624, 0x5a4e5b13, 0xf56251e3,
+    0x750808e5, 0xcf5bbce0, 0x12282752, 0xd91c8e15, 0x6be41aac, 0x4569477c, 0x7e860e5a, 0xa0faa804,
+    0xe861cd9b, 0x33aa2e76, 0xad10767d, 0xa3a685b1, 0x67850e99, 0x911572cd, 0x4de24c05, 0xe60fd337,
+    0x0fe26195, 0x39440e0f, 0xbd9b86bf, 0x91ceb0d4, 0xe1218091, 0xf41a9ac9, 0xbca1dec3, 0x89260269,
+    0xaa6457d3, 0x08d8600d, 0x373ce669, 0xa8d607c2, 0x7a497d91, 0x1d96cdbe, 0xe129304c, 0xf54b9663,
+    0x2accb565, 0xfb99af85, 0x4ec3f278, 0x3356d439, 0xd0651cf0, 0x80f1c989, 0xf8b53bec, 0x75ca7223,
+    0x4aa7551b, 0x9100972a, 0xfdab3635, 0x46c8944e, 0x77c3595f, 0xe09b2c7f, 0xb9a7ada0, 0xc21115e1,
+    0x3ef2d00c, 0x09cdedb4, 0xfc1dee4c, 0xfc0d5969, 0xdb9fe367, 0x2e9a4b42, 0xb195a920, 0xccf935de,
+    0x0a263324, 0xc04386b8, 0x786e7b92, 0x1eb721a0, 0x3b6f27ed, 0x8e51ca31, 0xd7b3adc5, 0x50f438b4,
+    0x31039798, 0x4835842e, 0x62cad349, 0xc8ad91ee, 0x8add4607, 0xa325dc57, 0x1d8c3442, 0xeb12f7e3,
+    0xb4bb031e, 0x3b7a4ef9, 0x088fc1b6, 0x33b03aa1, 0xd10a7c7e, 0xb6eca531, 0xe99dc35b, 0xb81d1c61,
+    0x124e315f, 0x3c993719, 0x6d3632b1, 0x1d018a83, 0x48d5ee49, 0x6dc7f708, 0x4285b370, 0xbc05d9eb,
+    0x3f5f55a1, 0x4afe7912, 0x0d48c73b, 0x9cc9f9a4, 0x06b5c822, 0xc1005068, 0x5f27ca91, 0x526d3345,
+    0x1fbd6ddc, 0xb9f0dc7d, 0x4822df32, 0x98a45be4, 0x3c9de882, 0xd7c3ec1a, 0x6c050a27, 0x40712899,
+    0xc94d80e6, 0x12202e43, 0x27e02a7b, 0x928aec48, 0xe95cbf3a, 0xa94e7ad3, 0x50c9e539, 0xf54e3f0e,
+    0xf7bb3953, 0x51914fb1, 0x09a3fc94, 0xf7121cec, 0x5de4ca90, 0x666b4272, 0xa59268ae, 0x9cef95a9,
+    0x8ba75abc, 0x4688db1e, 0x64fa8deb, 0xa2cef548, 0xf2043039, 0xd391d5c6, 0x4fc2a65c, 0xbc554571,
+    0x2954cf7b, 0xa79667cc, 0x918c452b, 0x125ea153, 0x978a6dff, 0xac9460d2, 0xa7416d76, 0x37c1e296,
+    0x6e5c918a, 0x932853f4, 0x6bc20553, 0x65eebe87, 0xbe59ee5f, 0x7fed8269, 0x2a4e0971, 0xd34b7914,
+    0x8fc3ab46, 0xa34c151c, 0x37e6f883, 0xa164bf86, 0xce35681f, 0x3dfb6f35, 0x09b01384, 0xec21fda8,
+    0x0d0d4fe3, 0x994fbde4, 0x97dc1cc0, 0x62381184, 0x26d355bd, 0xc0842e37, 0xd2426bf9, 0x560be379,
+    0xfbf7e3b7, 0x8181d88f, 0x363034e5, 0xcdad9f28, 0xc7597664, 0x10c15004, 0x58ee77ed, 0x1c9fb276,
+    0x48595277, 0x65960cc2, 0x274ebef7, 0xe8209b7c, 0x7062ffbc, 0x5346f688, 0x38208b83, 0x28760bfa,
+    0x1c9b8cf7, 0x56501991, 0xc6b870e4, 0x76696f35, 0x396b2228, 0x4624a1fc, 0x449ea4a9, 0x5656db83,
+    0x613c01ea, 0x585f20c3, 0xa6c840a7, 0xffed54e0, 0x67e74fd1, 0x351fe3ef, 0x07abd1e0, 0x711f24c1,
+    0xadf2f384, 0xfa4bcc97, 0x690627a4, 0x75e97fc2, 0xed07c00d, 0x558bd4ba, 0x18c395df, 0x4333a13e,
+    0xfafdb0f3, 0xdab30b81, 0xd7dacb2c, 0x4af2a3bd, 0x405efc69, 0x176ac0e8, 0x1092718e, 0x4307df73,
+    0xe9164308, 0xa4667132, 0xde027fca, 0x9b786e50, 0xca124c6f, 0xc19a1ed8, 0xd5378ec7, 0x3dab9f1f,
+    0x03efcb8c, 0xd543f78b, 0xd3f28366, 0xc674da89, 0x0edcae18, 0xef8f1a90, 0xdc19bf61, 0xa0b8fd05,
+    0x4759db22, 0xb2df3b2b, 0x528fc8b4, 0x98ace20b, 0x0fed59fd, 0xaceb988e, 0xa3a29261, 0x71cc09f5,
+    0x7a5afc51, 0x8de987b1, 0x0b8112b3, 0xf9ba67aa, 0x3d876c59, 0x4bb861fd, 0x4e7b8380, 0x3bbe6b90,
+    0xfa4e2d77, 0x386329a1, 0xbd76c767, 0x64e94788, 0x82afebeb, 0x841d63e2, 0x5da8f9c8, 0x3faf097c,
+    0x5d35a255, 0x89c77bb3, 0x8d9fd2d3, 0x89979223, 0x703a2962, 0xaf0aebf0, 0xbb99d0c2, 0x9a9eb7c0,
+    0x751a8927, 0xea3ffd7a, 0x14f5e838, 0x03f65abe, 0xa6eb9134, 0x3f4d6f55, 0x474e3ca4, 0xb00007d5,
+    0x2105350f, 0xff3fc99b, 0xc32a5ca8, 0x7f55fceb, 0x7b989e1b, 0x3ceb678f, 0xc48f3f5c, 0x95863834,
+    0xd59b9860, 0x9e99a101, 0xa9965d7e, 0x0c550606, 0x470c8cec, 0xb63fb557, 0x057d8bad, 0xec7e4657,
+    0xaeea9026, 0xe7276660, 0xa9eebb0d, 0x80da8b19, 0x6f5a458e, 0x59dea2cd, 0xd7098d89, 0xd3451b3a,
+    0x54a3c2b6, 0x73bef48e, 0xc5b9361a, 0xede2e6b6, 0xf1eb7150, 0xdf01ce75, 0x6bf95831, 0xcee3c740,
+    0x385e12b8, 0xfa5fdb6a, 0xacf34a24, 0xdcd2bac2, 0x680a8c43, 0x1b8e34ad, 0x17359898, 0xb8392be8,
+    0xc1018f78, 0x9d3c6b12, 0x702bd6e4, 0xe0d8b50b, 0x64f7cb05, 0x4115a73e, 0xbf767136, 0x2b61b376,
+    0x7e4549f5, 0xc8c7171a, 0x256bf9ea, 0x6c36ba32, 0xefa48cf5, 0x6bfc7a43, 0x66da1cb3, 0x52a27b6f,
+    0xcf015dea, 0x0d5230e7, 0x796f17b7, 0xd83e90e6, 0x4f101305, 0x6caba7c6, 0xa358c1ac, 0x9bd385c9,
+    0xfa335a10, 0x8bc2aae2, 0xb911ca78, 0x06068c36, 0xad793a1a, 0x66960b7f, 0xeff417db, 0x65c4f138,
+    0x6c351f92, 0x0a6695fd, 0xc470c846, 0x30b5ffb8, 0x8420016c, 0x89fc3b83, 0xa8660fba, 0x9f4951de,
+    0x5b61a3ae, 0xb904c0ce, 0x43d7dfb1, 0x414d69c5, 0x198baeef, 0x2b6e6849, 0xe4fb39a8, 0x2c7640dd,
+    0x8c2ade87, 0x31387858, 0x369582e3, 0x8f472976, 0xb7353dd5, 0x1d8d5584, 0x7b221d89, 0x9b295586,
+    0x8d8cc480, 0x5b25c05a, 0x1afb6d0a, 0x22fc8dfd, 0x1293dce6, 0x9c650e32, 0xbdd80358, 0xc6162e56,
+    0x841ebe44, 0x367a96be, 0xf15812d0, 0x5b3e61e0, 0x0d3e4fcc, 0xb3c0471e, 0x8cd77f12, 0x42af505f,
+    0x1cdaecb0, 0x1c993e04, 0x111c3ffe, 0xfe9b1f78, 0x92cc7db1, 0x2ca9467e, 0x17fb20f2, 0x2d719877,
+    0x4cd6acd2, 0xab254a24, 0x4f420e40, 0x0a32732b, 0x77d48375, 0x7c4615e6, 0x574a3f9d, 0x63b489b9,
+    0x52d5224b, 0x291c5629, 0xd4577664, 0x7591a334, 0xd46f8599, 0x9767faa3, 0xa28a7de2, 0x4d853d92,
+    0x40f52a5e, 0x144321c5, 0xff067ef4, 0x3be40417, 0x9a337666, 0x6a2319bc, 0x85d0f8c0, 0xad758936,
+    0x2e9e9512, 0x8cdfdc57, 0x04a2213e, 0x1a7d23ad, 0xf71e8c95, 0xf0d396a6, 0x86a40a4d, 0x5f62aa3d,
+    0x0d1e3961, 0x7a8c730b, 0xe42e5269, 0x0865f6ec, 0xec660100, 0x995edc71, 0xe47ce5ef, 0x3c8e27c0,
+    0xa7f6f68a, 0x9bac3687, 0x7b00b649, 0x33d3bbd1, 0x8d7af1f5, 0xf68f7d89, 0x1eb2cc56, 0xf3ee49e9,
+    0x744fe91b, 0x5a3c5e84, 0xece67270, 0x7f17dc7c, 0xae0cda04, 0x93e20765, 0xeb27fed1, 0x9308920b,
+    0x84fe262f, 0xafa160ca, 0x5a3587e8, 0x1b029fad, 0xfdd74b92, 0xc9a9ce00, 0x19f601e3, 0xfa37ee39,
+    0x079edf5f, 0xb636c2aa, 0x6e8581b1, 0x6b113e88, 0xe85f2073, 0x1e17b81b, 0xed1dfb48, 0x6cef2bb5,
+    0x9f8b6560, 0xb7d2b1eb, 0x9d066536, 0x6d45bb33, 0x736ec259, 0xcf1185a8, 0x9657d0e9, 0xd3405400,
+    0x41fe2ad8, 0x76b34f3a, 0xc86708ac, 0x858da698, 0xa979ff06, 0x9f91d401, 0xe0f0c7ea, 0x0524a607,
+    0x1cfa3e7e, 0x8d21aeb9, 0x9872d38a, 0x4f3861d1, 0x49b5b732, 0x5a989e77, 0x1c07f8aa, 0x9a47d0ca,
+    0xa3cb37c9, 0x02756a4a, 0xd19e3914, 0x999fb53f, 0x1213fbfb, 0x0d6bfb35, 0x44f5326b, 0xb4ffd640,
+    0xae4776a8, 0x9988f4f3, 0x84600b51, 0xb07cf7f6, 0xd94697bb, 0x4ed906c0, 0x8aae1c8c, 0xa64fda20,
+    0xda846f0f, 0x03c294ad, 0x8323b17e, 0xcb83d11e, 0x7b337883, 0x29339133, 0x7c6cc75c, 0x52957708,
+    0x7a64a158, 0xedac49f8, 0xe78db99f, 0x7a5faebd, 0x864ac7b3, 0x8525c270, 0x18d8fb79, 0xa1b7ba3e,
+    0x8f5d25af, 0x6b3c471f, 0x2e469a39, 0x4e927a77, 0x4bab6c17, 0x5cbc708a, 0x1d2e3063, 0x0814a127,
+    0x44a6736a, 0x2f637d4d, 0xe3573933, 0x08623725, 0x52957db8, 0x43dd4bc9, 0x0b1be8f7, 0x93b5acdd,
+    0x75b44e7a, 0x73cdf366, 0xc23d767c, 0xec5e4eab, 0x22f29c2f, 0xcbf23fde, 0xcdf92294, 0x24230c02,
+    0x6ce11bb5, 0xe8b15c2f, 0x28b2f79d, 0xc33806bd, 0x31b7592a, 0x155b7aa7, 0x4dd5e408, 0x3007edcf,
+    0x60d26715, 0x9d1c6a68, 0x1d6b0c22, 0xca935262, 0xc2287e36, 0x65cab982, 0x1d29af35, 0x7abdb726,
+    0xb17d151d, 0x784ab7c3, 0x193673a4, 0xcc0f66dc, 0x862e25af, 0x22d168d2, 0x8bf5f3c8, 0x56bb84ab,
+    0x52e4e08a, 0xcaa54638, 0xabd77fcc, 0xc0d8e79e, 0xe1d15150, 0xafb76817, 0x10dfd4d8, 0x5a1612cb,
+    0x0ba62e34, 0x4e77ca3e, 0x6d9ab162, 0x69bcbf84, 0x4dd6387d, 0x2282dbb1, 0xaf4c8a97, 0x19f1ae6e,
+    0xb5eaf541, 0xa09654ad, 0x1fccaa4f, 0xdb4e35a4, 0x3efa3665, 0x3d150b9b, 0x22485459, 0xf435090f,
+    0x4016043a, 0x2a68d2e1, 0xa78e8ee7, 0xd398f783, 0x4800dbf7, 0x571d9ab5, 0x900ea047, 0x3ac412ee,
+    0xe9880a56, 0x71747c09, 0x9c3b76fc, 0x6a90fd1d, 0x76876bbc, 0x669cc272, 0x4407e964, 0x9ae59d20,
+    0x7f36a883, 0x588ed7f8, 0xc1dd4948, 0xe3ccf815, 0xd17ebc04, 0x71bc8237, 0xf47ccb92, 0x51c46571,
+    0x221ea09d, 0x93348c0b, 0xf597369a, 0x5188646f, 0x626fcd0a, 0x8f54a955, 0x6b02b4a9, 0x98fe4333,
+    0x7b2eac16, 0xb08b7a55, 0x347a48d5, 0x9567dc84, 0x928b9884, 0x7990e3d0, 0x0ac6b7d2, 0x2752ab4a,
+    0xb9a4c100, 0xe81a0093, 0xa276de8f, 0xe7d296c8, 0x38548f78, 0xcd0e3667, 0x301f27a3, 0x9bc31f27,
+    0x1e9d45a6, 0x38849a71, 0x8da00175, 0x174c72b6, 0x9879053a, 0x3ebcca14, 0xa2a8b1eb, 0x20589607,
+    0xe578c30e, 0x1ce52ce9, 0x7070eed0, 0x64deb923, 0xa51d9768, 0x6fcbf94b, 0x089f4225, 0x79ba7064,
+    0xe3c1a0e9, 0x0b8e7f99, 0x9d037f26, 0x9ff09574, 0x2bfc77c0, 0xe2e79663, 0xc1729181, 0xffa7eb79,
+    0x2ddf39a9, 0xad8ba070, 0x2ff4e29f, 0x108b8382, 0x827be0b2, 0xfe3656ee, 0x862aed64, 0x9f3c6a64,
+    0x908c2d34, 0xd56d80cd, 0xb941e344, 0x7f6e6709, 0xa0cc3911, 0x9b31cfa4, 0x22d24912, 0x30d2a982,
+    0x863a4fb2, 0xeaea1dc2, 0xa583669e, 0xd3204198, 0x4b1b53f4, 0x0081ee5e, 0x65233478, 0x9f4ed5ee,
+    0xfea6c760, 0x5365f68e, 0xd768b1c7, 0xdf49edb2, 0x2f26bfdf, 0x2089ab3e, 0x64e1c742, 0x7d41482f,
+    0x9f10402f, 0x7786df09, 0x7f250b09, 0x9ada7d37, 0x43679789, 0xe09c25b9, 0x6310a638, 0xf2c44214,
+    0x6b836e5b, 0xa0cf8e6c, 0xed69589e, 0x29dcf17a, 0x5942c49a, 0x9b724b3c, 0xbdd04db9, 0xeed3105c,
+    0x0b938009, 0x1067c9e3, 0x59243f73, 0x45f15791, 0x771408a6, 0x211d1e9e, 0x456c69e0, 0x4a841a15,
+    0x0fd946d1, 0xf0200260, 0x302bb523, 0x53a9ffdb, 0x49ffecf1, 0x95ae964e, 0x1be7d2be, 0x6d3ab22d,
+    0x4809dfd8, 0xfa021473, 0x4a9700d1, 0x6768019f, 0x7ba19e13, 0xe4f9cc77, 0x55e5f587, 0xf728d026,
+    0x1fe037c3, 0x9cef443e, 0x83693480, 0x3b201a44, 0xc9de1f9b, 0x2a228a44, 0x4e79aeaf, 0x5e135751,
+    0xca0ca80e, 0x9f77a906, 0x7788215e, 0xeabc2efa, 0xd3886c6c, 0x942a5c02, 0xc5b65f25, 0x6533da0f,
+    0x5b8294ad, 0x491c2022, 0x0d99c86f, 0x60b8efb0, 0x91e6bfee, 0xe341f6e1, 0x087f14c1, 0xf2382fee,
+    0x0537c6e0, 0x871575bb, 0x69fd4f64, 0xe037bfa6, 0xeea6c744, 0x8452b00b, 0x51bd0cf5, 0xe9b21d73,
+    0x6329bf4c, 0x78dd1051, 0xdae22b2d, 0x6dbdfd4b, 0x07a7f1c1, 0xa1a456cd, 0xe2223c7d, 0x55d50979,
+    0xd1d27ffc, 0xc49928ed, 0xce943c7f, 0xf7369305, 0x99b35596, 0x2f1740c6, 0xe304df55, 0x728c4c46,
+    0x59573398, 0x67ac914c, 0x7a11bc08, 0x73786a18, 0x459a2d39, 0xaa25c257, 0x8d3df0ed, 0xdf7053a7,
+    0xe48c7f66, 0x06a079c4, 0x7fad44e8, 0xfd4fa240, 0x86f25a72, 0x0640967f, 0x620fd3c2, 0xb26c6fb8,
+    0x4c693c87, 0xf957efc9, 0xc98face7, 0x1aa8c0a9, 0xe50b5889, 0x6f3bd808, 0x2853dead, 0x3e38881d,
+    0x3e6e77cb, 0xb6e17d4f, 0x64ccd2e6, 0x9a33e123, 0xc79a0eb1, 0x08c6ff12, 0x1f0a83d2, 0xbad76f24,
+    0x48a979a4, 0x6fd83e80, 0xeb426d76, 0xa3e781c3, 0x8191f8f0, 0x7a6d13a4, 0x05dbd011, 0x74a76e84,
+    0x06832344, 0xd838f0f6, 0x3157c8b8, 0x95098b5f, 0xcabd5149, 0x14ee999f, 0x0f0fad9a, 0x8dec908f,
+    0xecd48ea7, 0xb949d520, 0xd5a0f6e4, 0x1cc10083, 0x59ba1b31, 0x452be801, 0x5eca2000, 0x698d7b64,
+    0x3ec631fd, 0xa375aea1, 0xbf40b404, 0x97a46df9, 0x2daa4412, 0xd0ea615a, 0xd9a097c0, 0xe9526048,
+    0x814b6aea, 0xd23474ac, 0x7dcfd32d, 0xed9822fe, 0x54bb4278, 0xd5d972b7, 0xded7a2f1, 0x62eebdfc,
+    0xb801581f, 0x1b1ac13b, 0xfdad8877, 0xb3b4d790, 0x00ef0ba6, 0x8c6338cd, 0xb26cab55, 0xa7197c9f,
+    0xcfa9a480, 0x5ccf5302, 0xeeca9802, 0x62ee5d36, 0x074102a8, 0x7ba865e4, 0xd2d0c1fa, 0xd7df9b58,
+    0x2913b621, 0x431364a8, 0xb5af7133, 0x2433fda3, 0xccbde3fd, 0x5928d72f, 0x843f410b, 0x23c2cf68,
+    0x1311dd7e, 0x52cc6012, 0xc86f1812, 0x76b9dcf0, 0x610e50fe, 0xcf27d20e, 0xfd6fbdc7, 0x9768cf52,
+    0x4f1f32a1, 0xea9a22fc, 0xd0c38753, 0xc83b1bcf, 0x1c4b209b, 0x5fafc1eb, 0x6ca7d55a, 0x9b8d2a48,
+    0x6b6bed92, 0x11a2791e, 0xc61d0ed4, 0x341c8ef0, 0xae33f16c, 0xc3228ad2, 0x7620c79c, 0xc50d298b,
+    0x5ab8be62, 0x4411c630, 0x04d0e526, 0x01861a56, 0xa590914d, 0xbbb8fbea, 0xa23733c6, 0x6ff26006,
+    0xea76eaf3, 0x7b301f73, 0xc8fc9d37, 0x5135904d, 0x046e2810, 0xb768bd42, 0x0332ddbe, 0xd8493aa9,
+    0xb4230af0, 0x53571e78, 0x2abf3f96, 0xe3bae1f7, 0xf7850599, 0x20e09ba8, 0xbc3df1d2, 0x2d34b571,
+    0xf659a5b9, 0xe76ac545, 0x5de1c66f, 0x5817988c, 0x409a5d54, 0x233e4547, 0x0cb7fe06, 0xca0931db,
+    0xa824a6ad, 0x7a753d05, 0xca293d19, 0x80d5f99d, 0x3f0580e8, 0x30b3afa9, 0x3371ac20, 0x2d4dcb4c,
+    0x38a2bbb3, 0x90a42281, 0x3bfbf476, 0xdc13283c, 0x007a17ed, 0x7b1a9707, 0xa15dc9f7, 0x40512bac,
+    0x455d1176, 0x637ffa07, 0xdc471831, 0x3a78d7f9, 0xd6067109, 0xc77aef6a, 0x4dcb8b8f, 0xee1402f2,
+    0x114ee4e8, 0x6359edb5, 0x10e4838e, 0x0da4d3d3, 0x241d826c, 0x8acf41bb, 0x831af64b, 0x8a6840ca,
+    0x23c857ac, 0x869e6df6, 0x4c461fc2, 0x6f8de74a, 0x9706bb33, 0x6d655845, 0x5f198a66, 0x38ac594b,
+    0x9b3633d3, 0x2be38920, 0xbf8bb237, 0x4098172e, 0x557d99fd, 0x0bd8e4c1, 0x9bf9ab9f, 0x68051e3f,
+    0x42a938a7, 0x3eeaa569, 0xb176c895, 0xf4228f7f, 0x4791cdcd, 0x7c3bbba1, 0x319fab20, 0xb1b0f0f0,
+    0xd7f9829a, 0x6a91dfbb, 0xa80765ba, 0x376b1232, 0x7233110b, 0xd174dc8c, 0xf6c4d6a9, 0xa6e2215d,
+    0xd9ac868d, 0x0fb58e01, 0x295bfb42, 0x794bff53, 0x939eb3a7, 0x2fa2a614, 0x390d8674, 0xc9d75885,
+    0x5947967b, 0x55586270, 0x5c9a7b54, 0x9654c475, 0xa5fdb7fd, 0x8b6663fc, 0x5e6ad163, 0xf8616920,
+    0x1ecb0c51, 0x1653ae88, 0xa01e87b5, 0x9b303cd5, 0xd8f08fc7, 0x3abaed2c, 0x3c767926, 0x3ce677c1,
+    0xedeec726, 0x7ac6b31d, 0x038660f5, 0xeb7bd987, 0xb60cf51c, 0xb7a20b10, 0x4e83ad84, 0xac04b0fc,
+    0xa23477d9, 0x7cecfe5d, 0x66bf1f5f, 0xbf2ce5f4, 0x6a0aa012, 0x5816751b, 0x383431a6, 0x186a6ba1,
+    0xacc07ce2, 0x1a5adf6f, 0xa9dc00b2, 0xb0a7219a, 0x62ccd3aa, 0x124d1675, 0x00a8abcd, 0xe23e9e57,
+    0xf344837a, 0x39c06d5a, 0xe2d065ec, 0x23f091a3, 0xacdafcc4, 0x2fd746b8, 0xef4e46b9, 0x91a46569,
+    0x5be4180f, 0xfefb0d19, 0x22f2e215, 0xd05487f5, 0xb9984a9a, 0xe5919cd7, 0x14dc000e, 0x010652a1,
+    0xd932609c, 0x9bd54c28, 0xbe228f42, 0xcb4bec23, 0x662555cb, 0xf706c5e8, 0x5232219f, 0x32039b01,
+    0xdf2e4619, 0x2b817b9b, 0xe92cc585, 0x231bf675, 0x5814021d, 0x474d61cd, 0xde75c7c9, 0xa4d1ae18,
+    0xe595295b, 0x8e8e479e, 0x2ba14c9e, 0xf2cd6b0f, 0x4cbc5d29, 0x26883e65, 0x8a5be48e, 0xce582d00,
+    0x5558181f, 0x289753b3, 0x680fb5de, 0xbf1da7d8, 0x10570acd, 0xa49ec2a7, 0x2eabb523, 0xd42b0ab4,
+    0xbcf05570, 0x5c770497, 0xb0389bdb, 0x6bbb43e1, 0x22e64d81, 0x525093b5, 0xae2a3f03, 0xefb69092,
+    0xaf07370e, 0xbb577f36, 0x2a9e43c9, 0x72542bfb, 0x38eee2e0, 0xb96491e2, 0xdc9b26b4, 0x60f08049,
+    0xba4431ff, 0x90312f8a, 0x2fc22329, 0xae7c8fab, 0x499869c6, 0x452c0fce, 0x5d094cd2, 0xf92ff713,
+    0xd1d4319b, 0xc32a9a5d, 0x1b7dd783, 0x06d3658b, 0x9ce4caa0, 0x05d59cae, 0x678f97d2, 0x08b2441d,
+    0x5973e1ec, 0xf572002e, 0x15aef863, 0xf86a7286, 0x231f23da, 0x59bcfb9c, 0x515d8168, 0xc70123c8,
+    0xc3aa24fe, 0x77051005, 0x3db54ff9, 0x32954617, 0x17fab328, 0x07bd60a5, 0x214db5dd, 0x4ca8f963,
+    0x948bdab5, 0xe191996c, 0xbe831976, 0xb45242c0, 0x973d152f, 0x116b7cb3, 0x2ae49cd0, 0xbee4ddd8,
+    0x8d108469, 0x40509001, 0x5928f48e, 0xea5de33f, 0x08e932c4, 0xdbf65754, 0xb7b1fd19, 0x94af65e9,
+    0xfb06a96d, 0xa8b80c8f, 0x436e2754, 0x4927f1c1, 0x519e2975, 0x4311d46b, 0x75f42b80, 0x3631fa65,
+    0xd73403f2, 0x0a1f3d41, 0x1553f36b, 0x13136516, 0x85e6df5e, 0x5f6ad3e3, 0x19eabfe7, 0x90b7ab4c,
+    0x11c867c2, 0x51e59755, 0x9c250c43, 0x7229306d, 0x7bb9a5b1, 0x0e9f7757, 0xfad5c13d, 0x5c4285e7,
+    0x3ff7b191, 0x20ee0ef0, 0x3b76c740, 0x2e8c2db1, 0x28ef1246, 0x198ff3e6, 0x836d3039, 0x91aef270,
+    0x4dc21ded, 0x6f4eef98, 0x4876da2b, 0x2b96df36, 0xa34137cc, 0xbc18cac5, 0x722594cc, 0x3c6a7c46,
+    0x7e73c6df, 0x00c87a11, 0x08a1e473, 0x674e694a, 0x9a946a28, 0x22e57ff1, 0x98e8d47c, 0x8d59470b,
+    0x13615fb2, 0x3aa10ff0, 0x4fe603d7, 0xbd90d314, 0xe21ba2f9, 0x477ce746, 0x09e5c370, 0x5418e5bf,
+    0x588b636b, 0x4b758828, 0xa8341654, 0x1bb29654, 0x5b7e0c0f, 0xfc7ff07a, 0xa10a0ecc, 0x3ec76937,
+    0x7c84aa63, 0x0f85d7ba, 0xe207c5a0, 0x7eeb45dd, 0xd1d40aa2, 0x08627e80, 0x07d8e39b, 0xcc460a3e,
+    0xf830c8c1, 0x25335b38, 0x77d7c1dd, 0x2273a71a, 0xa83d741f, 0x279c74b8, 0x20e66ef4, 0xbb173cbd,
+    0x14ea8c07, 0xe090c7dc, 0x9ac8ecd2, 0x181de0a8, 0xd2bba266, 0x3002e60c, 0x60de3ed1, 0xad04397f,
+    0x0bd79ee7, 0x5223c9ee, 0xc804b004, 0x00c56f2f, 0x04428e3a, 0x8ffeb0fe, 0x54ff68a1, 0xdc244dbc,
+    0x3df220f3, 0x0054ddaa, 0x31207ada, 0x3d0e6e8f, 0xa190cfe0, 0x2d52915a, 0x8d18e752, 0x62678cf6,
+    0x7e0cd1ed, 0x8e7cb42b, 0xad1c124e, 0x493692a8, 0x43a26d22, 0x63c9b0f9, 0xf1c49676, 0x4e7f70d8,
+    0x6e314cc4, 0x2453d899, 0xcbbc144c, 0x99dec3d1, 0xcdb43a3c, 0x2f73fc78, 0x16458e8a, 0x81143945,
+    0xf61f8caa, 0x9d3a839a, 0xdc64db09, 0x77cbc7d5, 0x130218e3, 0x338b4742, 0x48f86600, 0x000b2bee,
+    0x0ed2c231, 0x4f65e061, 0x6cae7372, 0x6c6b9aa3, 0x4039127b, 0x47029754, 0x37002ea0, 0xdf496aa8,
+    0x9b83c84d, 0xc51ba1b4, 0x71917f9e, 0x9c66a5f4, 0x6f26c131, 0x267339cd, 0x01ee5798, 0x37f3d5df,
+    0xfa2b9ede, 0xd86fb94b, 0x25810ca7, 0xb15f4879, 0x64599fae, 0x5b1ad96e, 0xf6c7166b, 0x4a3aafd8,
+    0x96c25bfd, 0x2da5fcd9, 0xf44fca17, 0x014c43bf, 0xd1e5fc93, 0x11377eda, 0x56ff8104, 0xae712263,
+    0x3f793e17, 0x81602859, 0x26e88236, 0x6d6bcefb, 0xc6bc3f9e, 0x78f810b4, 0x7a147009, 0x4c4250de,
+    0xaecdecbf, 0x87fdf0fe, 0x400193f7, 0xe10663ff, 0xec45c108, 0x8d420553, 0x9c33e59d, 0x004db40d,
+    0xee011756, 0x91041dc3, 0xfd5d5917, 0xca29f602, 0x24e72211, 0xe4f85a1d, 0xcf8120c1, 0x73431da0,
+    0x6021a0a3, 0x548b721b, 0x2121b90a, 0xfbe9bb1e, 0x23daca42, 0x550f032c, 0x79af510c, 0xd59772f3,
+    0xcd41935d, 0x4a940b4b, 0x315da44e, 0x155c9923, 0x2a679292, 0x3763f703, 0x165637db, 0x71f420d8,
+    0x2ce4f4d2, 0x67696800, 0xdb8ab73a, 0x363755de, 0xd3364d75, 0x61edb2a7, 0x69ae2857, 0x269446ec,
+    0x8938d072, 0x8e6bd4ec, 0xa1aa4f7d, 0x933c6145, 0x2667e370, 0x84fc14ed, 0x54749074, 0xc08049de,
+    0x2bf4c001, 0x2b9ae878, 0x9531c4cc, 0x380f285c, 0x8b4428fb, 0x430a136c, 0x9e8b72ae, 0xb698384c,
+    0xf64f97f1, 0x2e815c91, 0x8813c902, 0x9d0f57c9, 0x9cd5cec3, 0x6b5c4ad6, 0x109e7c96, 0x98149e5b,
+    0x48a480a1, 0xea427c73, 0xb0f0e9c6, 0x80553de9, 0x90710ca4, 0x0574366e, 0x3228f652, 0x6febe75f,
+    0xba9fd94a, 0xdfece736, 0xb3d90d3c, 0x5d783384, 0xd1098af7, 0xcc4ad0da, 0xa801eb7f, 0x24cb0244,
+    0x59bee30c, 0x77212328, 0x984726f0, 0x9bdd3c3a, 0xe5ba0d8c, 0x005f59ee, 0x2015b94f, 0x7bba9b9b,
+    0xf3ecec78, 0xecffd3a7, 0x0500b7dd, 0xc06f3226, 0x21445b63, 0x54badd21, 0x242900eb, 0xbe084b38,
+    0xada2af9d, 0xf961cec5, 0x450b665d, 0x09cba5fe, 0x080e666d, 0x12f28bc5, 0x2a49bdf1, 0xb343aa1b,
+    0xf5ba169c, 0x1cca3de9, 0x109d63c9, 0xfe0babe0, 0xc2f83cb7, 0x16608561, 0x1be732e7, 0x50cf61c9,
+    0x74ebaac8, 0x6d850904, 0x879eb2e4, 0x4087be16, 0x237d66f2, 0x8efe75ef, 0xe2b51ce2, 0x4c18657d,
+    0x7061a67a, 0x9e71e849, 0x689f0095, 0xf2949e44, 0xfcdfc5b7, 0x452c935e, 0xaaf84c31, 0x7180fc79,
+    0x04daaad7, 0x731cd628, 0x2f94ca06, 0x7d63fa9e, 0xada1911d, 0x5fc4ec62, 0xcb18c5bd, 0x354a3daa,
+    0x508c763d, 0xf78f7e0f, 0x7e137583, 0x73aab5d3, 0x63fb8606, 0x3edd889f, 0xc2826d14, 0x9c75d689,
+    0x560188d4, 0x6bc45547, 0x6af76938, 0x010b7422, 0xbf3785d6, 0x19f7446c, 0x770866f6, 0xdff655ac,
+    0x8ea9beed, 0xfec88c83, 0xef61e46d, 0xd7acd928, 0x39ca184d, 0xdbb9510b, 0xb90b53a5, 0x0c62d9c6,
+    0xd936d5a9, 0x633a3ba0, 0x82ca2486, 0x312747fd, 0x80ddc19b, 0x716a5fe7, 0x8f5f8955, 0xfae2adef,
+    0x3a591ae6, 0xf347754b, 0x08a7f584, 0x38cd8600, 0x3a8757f7, 0x03ab24cb, 0x2b3646f8, 0xbe937ae8,
+    0xabcbb7f0, 0x2e794a10, 0x19370ede, 0x0aa1ea24, 0x94ec0647, 0x27702f7d, 0x9ae0f150, 0x3a7546ab,
+    0x8424d871, 0x1f180c46, 0x90766bc8, 0xc08bf845, 0xc9640792, 0x9209cc43, 0xbc80049d, 0x84fef33e,
+    0xa156ebf8, 0x9165ba55, 0x0b7d2fb5, 0x63eddfd5, 0xf664e0ad, 0xe7786631, 0xad55595f, 0x16dc0411,
+    0x0e2c0784, 0x6cc4ade5, 0xe96c88d1, 0x324cc1f8, 0x605ac137, 0x0bad5015, 0xeb97b6a2, 0x3726bf48,
+    0x45b31e90, 0xeb30fccd, 0xf9685e21, 0xfd655741, 0x6b8f130c, 0x076f2cc0, 0x296a834e, 0xea64c64c,
+    0x987096ba, 0xad5ff24a, 0x3bc667e3, 0x16ee7175, 0x1199583a, 0x6d235e78, 0x6978eaf2, 0xdd899bf9,
+    0xe213db4f, 0xf0bc404e, 0xba852bff, 0x28d3d349, 0x8b80341b, 0xb96ee7fa, 0x56bf37ab, 0x56c02079,
+    0xb245bd2f, 0x8fefc7e0, 0xacf03e82, 0x6745a51b, 0x5e5b499c, 0x20153fed, 0x81651266, 0x5f5c0cd0,
+    0x34a81da3, 0x2391b8d5, 0x15ac8365, 0xe45c2e41, 0xe645d959, 0x28ac6df2, 0x771c5c7b, 0x8a56c571,
+    0x258044ea, 0x102aa0a5, 0xb387e899, 0x25ce62a9, 0x738f5580, 0xeff4acce, 0xeb8e69d8, 0x858d0efc,
+    0xf22286d1, 0xd576b7c9, 0x29d0546e, 0x9c603aa2, 0x48309b4f, 0x16fcaf92, 0xd786dbad, 0xf5cc3b1c,
+    0x18d12c1a, 0x0540b540, 0xe05593f0, 0x48528378, 0x7db6d34e, 0x0a4ae044, 0x85270ad7, 0xb7595e2b,
+    0x2f272565, 0x2fc60004, 0x25ff6c8e, 0xdb8bac0e, 0xa84e831e, 0xbc1c308c, 0x3805748e, 0x5a156c29,
+    0x82bb215d, 0x04a14d25, 0xab027c64, 0x1e054127, 0x06386567, 0xdcdf2d75, 0xc4d834ff, 0xf158bed0,
+    0x02abdea7, 0x3602f501, 0xac84dbf5, 0x738da773, 0x9b6a827b, 0x46cc31ee, 0xbb3c6838, 0xa89d5395,
+    0x9162abbe, 0x2f8be49d, 0xd1c3acc8, 0xf0561011, 0x5162bd51, 0x34ef58c6, 0x9ab4de33, 0x420666ef,
+    0x9951388d, 0x052a0db7, 0xeae21638, 0x2d5c0c1e, 0x4e1514d3, 0xabe53c1b, 0xf8c551f9, 0x8baa5d25,
+    0x66254b03, 0x8346893e, 0x8b9b362e, 0xdac82b59, 0xd9a32a88, 0x61c91d14, 0x3a891066, 0xc5c4f9f9,
+    0xdae86d8e, 0xc7efef5f, 0x1259b24f, 0x152a01a2, 0x325e5fef, 0xe191c8b2, 0xb13b9fa7, 0xeb34a7de,
+    0x36c25ee0, 0x76c5031b, 0x46a32227, 0xaa0a30a4, 0x483ca1d2, 0x5505e894, 0xb9b4ffd7, 0x50a0a125,
+    0x1223ebe1, 0x0023e0fc, 0xe88ad08a, 0x7246f672, 0x5f4733d2, 0x7e8aab77, 0x343dc2f9, 0x16b06ba5,
+    0xc4d85fc4, 0x27cd54e9, 0x843e5ed0, 0xded6fe96, 0x5acd5077, 0xd7dd2fb1, 0xfba5bd4a, 0x58029c78,
+    0xc2ab8209, 0xed65716a, 0x385cc430, 0x91b32783, 0x89aa1990, 0xe3ce5c7c, 0x9e840655, 0x931a2d5c,
+    0xa08fc0f2, 0xf050d30e, 0x93ea74cf, 0xd6cbd7cd, 0xabc8ffa8, 0x19de81f2, 0x71378c4f, 0x6119e183,
+    0x0adc5302, 0x114736bf, 0x1dbf94e1, 0xe63d99ed, 0x2efb07ae, 0xa1516d78, 0xd2daa07e, 0x2edd9190,
+    0x0c516dea, 0xf6f16b8c, 0x5f8f63e8, 0xca1695d3, 0x5e392bdb, 0x2408031d, 0x9de2af82, 0x5444d0d8,
+    0x6ea7478e, 0x39b178cf, 0x14ded56a, 0x21ea4391, 0x3029b2bd, 0xf466dec4, 0x2767e36c, 0x09180309,
+    0x2e02d371, 0xf7b536c2, 0x7b2d9fb1, 0xc3460fe4, 0xc7ba7080, 0xfc0580d7, 0x437cabc2, 0x309a4d5b,
+    0x14280f10, 0xa8cc30f0, 0x63960f59, 0x2de60e3e, 0xd567a71a, 0x5acb6410, 0x129e3683, 0x094b211d,
+    0xe642af06, 0x878bd39b, 0xf60812d6, 0xdbf5832f, 0x0d30848f, 0xfa458bef, 0x1dbf69a0, 0xd2879d7d,
+    0xef37ddbe, 0xf2e4961b, 0x924b5646, 0x1520a4a3, 0xef4e0dbd, 0x88169c6a, 0x6870b1af, 0x877fe03d,
+    0xb0f8e565, 0xcaa79427, 0xba1bc4f3, 0xc0298da8, 0xb56a40b4, 0x9893f297, 0x891b498a, 0xf75c8b43,
+    0x304f594b, 0x6f63da40, 0x20b43e95, 0xb5319b75, 0x4799775f, 0x6b8ec0ae, 0xce7d6bee, 0x4f1ae023,
+    0x9edf62e8, 0xb55d0c94, 0x274065c6, 0xfb3841ec, 0x010884cf, 0x1523021c, 0xfbd38e52, 0x73fb07aa,
+    0x0bb49bf4, 0x9b6e6899, 0x0d9b3414, 0x10fbc5c1, 0x98acb0a2, 0xea5ee250, 0x0cb25e1b, 0x12396a9d,
+    0xae0cd549, 0x087cef67, 0xd435580a, 0xaaa147c0, 0xb7645ec5, 0xfc547b0b, 0x44222705, 0x3b07791e,
+    0x96205f0b, 0x501add88, 0x6ecd92e0, 0xf2ff87fa, 0x4cc25ef4, 0x9851918d, 0xcbba2ec0, 0xb2a0036e,
+    0x62a56f89, 0xa3f154c7, 0xadecff58, 0xfcd986fa, 0xcceef11b, 0xc64fa759, 0xa2b64ea7, 0x3a224dc3,
+    0xa76ae74a, 0x2e983fe4, 0x54f0ecdb, 0x8301d38e, 0xdd9d46a1, 0xf3aa0f55, 0x92109c3c, 0x9538b2af,
+    0x6b090af3, 0x94ee0152, 0x60ae983c, 0x1e2c7c61, 0x4cb527cc, 0x5cd7f0a2, 0xd025e28d, 0xcb8d9f60,
+    0x33f6b1e7, 0xfefa3e03, 0xf7fb24ed, 0xadac07a8, 0x8ee0cff6, 0xd45dfafb, 0x29028db5, 0xb0df253d,
+    0x4097f77d, 0x500e3993, 0x80cd5f62, 0x7770b44f, 0xd2a8b349, 0xd725c76e, 0x0240f5cd, 0x8744b929,
+    0x25c53640, 0xcc3e8b1f, 0x886057bb, 0x2f7e8df7, 0x3cc69308, 0x8ef19ab8, 0xe06f4dac, 0x2291fcf2,
+    0xfa7508cc, 0x525cf01e, 0x7b902d6a, 0x0b0cc13e, 0x29a87d9b, 0x137a780b, 0xe94f1b3f, 0x3a560f0e,
+    0x545c5c79, 0x3f5c5319, 0xe8994dee, 0xf53ecdbf, 0xa7732a91, 0x38e4ba35, 0x2f615ddf, 0x8356ee4a,
+    0xc4407548, 0x47d8d88d, 0xd1bdd24a, 0x01c1a01f, 0xc8ea57ac, 0x0d89d9cc, 0xc9ff54e4, 0x7836a7a5,
+    0xb2858f38, 0x70f36a96, 0xaa60ca51, 0x9a9a540c, 0xe637372b, 0xeefc7096, 0x051cb9ce, 0xc07efde9,
+    0x6ef52373, 0x7f4115a1, 0x839cca3c, 0xd2b3a60d, 0x19c39b85, 0xf011e772, 0x6aaba5a1, 0xb05e1fd5,
+    0x4485add6, 0xb1054efa, 0x39a3f55b, 0x96121da9, 0xa434da17, 0xd6645375, 0xcaf7dfd5, 0x28b3d39f,
+    0x34273488, 0xaa2e8152, 0x51bad9b1, 0x4651e190, 0x8ec144be, 0xe5bc38fd, 0x8049ed93, 0x0a3edec9,
+    0xba9bce6a, 0x1e53bfd7, 0xbd05ff30, 0x376818d6, 0x4b925118, 0x31213002, 0xf5e48259, 0xa9b632d1,
+    0x935f3c27, 0xe0d66157, 0x1a881894, 0x1006c835, 0x43105099, 0xba431adc, 0x71f05306, 0xcff2c3ef,
+    0x1d8813dd, 0xd519724f, 0x5a5bd7bd, 0xd7847fb7, 0xfb590120, 0x7e23b39a, 0xdf08d164, 0x65a35710,
+    0x2274a14b, 0x7cc99c10, 0x1f946d06, 0xf14e2bcd, 0xfcb010f8, 0x77a10ae5, 0xc6c844d5, 0x0f337292,
+    0x6655d3df, 0x3ac08596, 0x186410ba, 0xa0099c75, 0x6d6f650a, 0xbd7060ec, 0x0ef9c595, 0x0914a113,
+    0xaef0453a, 0xa80150ea, 0x32a7fb65, 0x84e3a2d9, 0x55f97461, 0x9ccca038, 0xb3e6f768, 0x31318884,
+    0x598a13f0, 0xfadb954e, 0x92041ce5, 0x7f44ff46, 0x66f3c11c, 0xe0709469, 0xea7bbc6f, 0xb6c66936,
+    0x243eb6ea, 0x81c0dc91, 0x1964df46, 0x2f210d66, 0x523c7fc6, 0xdad3ceac, 0x92fac56e, 0x93abf201,
+    0x5205b0b4, 0xd04413c4, 0xceb35e77, 0x0971f780, 0xd2587902, 0xc90ee490, 0x6567ee24, 0x3f53382c,
+    0x5e50acb1, 0xfa1f3efc, 0xa327cc18, 0x577e1edb, 0x7c5ff74e, 0xcc83d1dd, 0x903b50db, 0x2ab7bf06,
+    0x1efed0f6, 0x20fd835d, 0x594de8f3, 0x8dd241bb, 0xe8afd05d, 0xfd4260d0, 0x739bc608, 0x3ca72b9b,
+    0xb2d31e3e, 0x0c8c4b83, 0x2619f05a, 0xced0099a, 0xa2cb03c9, 0x562b8f73, 0xa886e9f3, 0x876c9d1a,
+    0xb3c15daf, 0x0b29e68e, 0xebc187bb, 0x4e39938f, 0x721eaa6f, 0x57d11ad1, 0x05ae7a1e, 0x9382152b,
+    0xebd08d38, 0xf48fe5ab, 0x814a5e01, 0x461d6600, 0x026bdf9c, 0xf1e2380e, 0xc9656374, 0x24fdb5f9,
+    0x45147289, 0x1becdb67, 0x2abcc9ad, 0x0563efc7, 0xdf0752cc, 0x5bb57ba4, 0xe0de3045, 0x9b35ffd8,
+    0xf5b2ac36, 0x1364407a, 0x474454e3, 0x87d692b6, 0xdfd68f8a, 0x4dd0af3a, 0x4e59d588, 0x545e856c,
+    0x512f5ba8, 0x3f8978f6, 0xe8c0cf65, 0x07755149, 0xc5d5992d, 0xfa91ac9b, 0x5a72f4bc, 0xdd6abcda,
+    0xa64b39e5, 0x061d352e, 0x2ff242b4, 0x51394cca, 0xa1aac754, 0x106d19a9, 0x463b9739, 0x3daeef2b,
+    0xf8c28c97, 0x1c715477, 0xcb4185cb, 0x458c55b8, 0x7889075f, 0x0337454d, 0xf697d435, 0xda60f35d,
+    0x1a5c161c, 0xf8c54fc9, 0xb1515474, 0x4c99e7cb, 0xc2dde313, 0xe5245ded, 0x3a8f1b3e, 0xec1c013c,
+    0xd43828cd, 0x2f93e037, 0xf7c0aedd, 0x18e620e1, 0xc64f5d68, 0x6d883b0d, 0xf0d1c67b, 0x9791a453,
+    0xbaecc89a, 0x6ce5f9f6, 0xf952f9b1, 0x1716735b, 0x229c4b4f, 0x79641889, 0x3054a778, 0xb0f989ff,
+    0xed70beb6, 0xc05245fc, 0x72bf13bf, 0x7e2e3540, 0x7cd51903, 0x1a1e524d, 0x2fed4a77, 0x9682a4af,
+    0xb372d483, 0xd26668f4, 0x3715148c, 0x4fc791ba, 0x373072fd, 0xfa914dad, 0x4b9226b9, 0x981101b3,
+    0x40ec1a86, 0x5521f563, 0xee68d818, 0x3c9c5062, 0x6c12912a, 0xac211ca8, 0x73dbd05f, 0xf97f2f85,
+    0x3f1dac97, 0x7ac86ef4, 0x7623a1c3, 0xc20b35e6, 0xdad77dee, 0x920e7c52, 0x68a599cf, 0x0fe4e9c1,
+    0xf76d7f79, 0x4693e920, 0x4ae2f680, 0x8afe527e, 0x72baa41a, 0xd4c199e3, 0x228aa399, 0x5b890ac4,
+    0x94fbda2a, 0x5b32d2ff, 0x3996e1fb, 0xc75b91c1, 0x2a3de708, 0x22d39dd6, 0x7c3821b1, 0xa196636b,
+    0x0abfd18b, 0x6bc1d6b2, 0x057e6619, 0x5d81ef37, 0x8ba0529e, 0xf2ae9ce5, 0x7dbf9fb8, 0x31c42037,
+    0x325ae856, 0x2a888d83, 0xca72175a, 0xa1829fe7, 0xc67bbc14, 0x6bd9fb8a, 0x73200a7a, 0xde6aea7d,
+    0x94c087b2, 0xebb445ee, 0xd15af731, 0xe175b305, 0x1217e89f, 0x793ac89b, 0x13191786, 0xd3a5fd0e,
+    0x25a2cf72, 0x8035fc9e, 0xf2fb41db, 0x9f0d61d5, 0x59f02fe8, 0x7df43206, 0x64d97bda, 0x7b1196b1,
+    0x19b3a9d3, 0xf0641553, 0xc9381f73, 0xdb53000e, 0x91d6ee0d, 0x03b4f85c, 0xe0e41620, 0x55b1ce9d,
+    0xdedaf785, 0x3953e567, 0x387a3087, 0x9208c47e, 0xfcbad9e0, 0x84903b13, 0xcae0797b, 0x0ad86359,
+    0x53697ca1, 0x15f8d39e, 0x68d90dec, 0x504a6e9a, 0xd6c0207b, 0x64a14443, 0xd796f5b5, 0x177cd5d4,
+    0x25cfb9f8, 0xca25ddc4, 0x8995d560, 0x99c9da00, 0x30af1765, 0xd49000f1, 0xa3133488, 0xda6970eb,
+    0xe6035a7f, 0xb25846bf, 0x8ebed433, 0x21c2de43, 0xa043030e, 0x2434b1b6, 0xcebce776, 0xfe48193b,
+    0xd9bba2e1, 0xb2477d39, 0xfb0a9ec5, 0x080d7193, 0xfa5d5374, 0x8336cde7, 0x08e67120, 0x8328c7ac,
+    0x9d67f2db, 0x052ccd88, 0xbb5a29ae, 0xddb5f37f, 0x16efc2e7, 0xb7947a62, 0xc431c9d1, 0x449ac01c,
+    0x09c0bc57, 0x20fadebc, 0x1c3facb2, 0xd610d904, 0x1e271b85, 0x4e170652, 0x66955f31, 0x90a88914,
+    0x51098b50, 0x2a2a1ac4, 0x82ec7dba, 0x1e0fc294, 0x366f9cf9, 0xcc6f5f5d, 0xef5df15a, 0x2e60cda7,
+    0xd0d9544f, 0x203ed32f, 0x4c60df09, 0xa3f1b5b2, 0x9bb41c4f, 0xc8c90a5a, 0xe24f54c0, 0xbf7e0a18,
+    0xc34d5c1f, 0x26d77a60, 0x0719e170, 0x7efe8e68, 0x62f3931a, 0x03396a0c, 0x1df1cc7d, 0xb528dc9f,
+    0xa17f44d3, 0x59028e48, 0xe3f6b9a7, 0x608069c4, 0x5d4f4261, 0xb20c8419, 0xd8bebda2, 0xf3bd10c4,
+    0xbd171f22, 0x52833f05, 0xdde71013, 0xde5da70c, 0x645f3f28, 0x0411b346, 0x23b0b458, 0xf890646b,
+    0x804f5363, 0xaec7dec3, 0x488ca943, 0x428a5584, 0xd1a51496, 0xf410e8bf, 0xeb714005, 0xfbf778f8,
+    0xc7a6f80a, 0x272495a8, 0x707e3f02, 0xb32e83af, 0x93e5dd8d, 0xf2951e62, 0x02ae189a, 0x1eb3a2fe,
+    0x46781153, 0x03a16cb0, 0xa48ad2ff, 0xa2ec4ec6, 0xc7817597, 0x69e54de9, 0xfb4e5f8a, 0x03669296,
+    0xd3e2eaab, 0xe9206b8b, 0x071d375e, 0x06ee4717, 0x134b906a, 0x979d7360, 0x38732464, 0x4dc6e2c0,
+    0x42299e45, 0x5b8383bc, 0xd4150f97, 0x22d4544f, 0x0bd45eef, 0x6a429323, 0xda0aa5a3, 0x0f3f55b2,
+    0x30ffe7ed, 0x94b2628c, 0xb43f3b21, 0x7a83283a, 0x6fb3aa5f, 0xe46590b9, 0x7de45bbb, 0x3a7b90a0,
+    0x9a3d9f04, 0x166b2a71, 0xb700c67c, 0xf12d5f33, 0xc49c0244, 0x30170251, 0xdcfa030b, 0x3e410f0f,
+    0x36540b46, 0x7d841d3c, 0xdcd07480, 0x79ff417f, 0x1529549f, 0xae86f950, 0xb05cd5b2, 0xbf463eee,
+    0x4eda074a, 0xd868792c, 0xa7d933ff, 0x9184a5ad, 0x9471deb0, 0x43f394cf, 0xf424ae39, 0x63e1f864,
+    0x23ec9162, 0xf3493d9d, 0xb8e68c94, 0xb831960e, 0xe2811b3e, 0xb6921a73, 0x1cc843cf, 0x99420440,
+    0x1d8ec675, 0x854732de, 0x68f8723d, 0xda845d7a, 0x530c40ba, 0xe1d92f89, 0xa2edd2af, 0xa11e9561,
+    0x88c4003c, 0x7b4519c9, 0xdd2ed233, 0x401228a9, 0x34249821, 0xf97bd557, 0x80557f3f, 0xf6934ad8,
+    0x92111ad3, 0xb125aa72, 0xf33bcd38, 0xe1cc41c0, 0x802de5af, 0x301f61b3, 0x6230f7f5, 0x897c17a0,
+    0x0d82d99b, 0x3d25dd17, 0xf954cc59, 0x460c11f7, 0xce275d4f, 0x2b8498ce, 0xfd1c3b26, 0x1d0bfa2c,
+    0xcdcf897d, 0xc5165e78, 0xcbc7073d, 0xe70095ce, 0x3690398b, 0xd3ac36ff, 0x1dc34317, 0xb9fcdf9f,
+    0xcfcf5aaf, 0xb012ceac, 0x24a5d373, 0x7ab55322, 0x73cce8f0, 0xe4045ae2, 0x1eaf3308, 0x1d0337ad,
+    0x3d403412, 0xc03be6a3, 0x6ea06878, 0xd9bec062, 0x740cc6d1, 0x4dec2f83, 0xdacb0a9c, 0x8fb360d3,
+    0x18c35ed3, 0x55788f40, 0x4aa3732e, 0x6573c579, 0xe2292096, 0xf7478375, 0xfeb2e83b, 0xa274203e,
+    0x29541382, 0x8e82385c, 0x33171552, 0x63d5c0ff, 0xab80355a, 0xd92b43a3, 0xc3640536, 0x97defe56,
+    0x8f60cf2c, 0xd65743d8, 0xe2124ece, 0x086a9503, 0x8f440c99, 0xbdd85f26, 0xd8e4a9f4, 0x64339e76,
+];
@@ -0,0 +1,146 @@
+use super::TwoFloat;
+use crate::cast::CastFrom;
+use crate::doc;
+use crate::float::{Float, FloatExponent};
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// `(k, m)` with `exp(x) = m * 2^k`, for finite `x` which is not large enough to overflow
+    fn exp_parts(x: Self) -> (FloatExponent, TwoFloat<W, MB>) {
+        let (k, r) = Self::reduce_ln_2(TwoFloat::from_float(x));
+        (k, TwoFloat::ONE.add(Self::exp_m1_reduced(r)))
+    }
+
+    /// whether `|x|` is large enough that `exp(|x|) / 2` overflows
+    #[inline]
+    fn cosh_overflows(x: Self) -> bool {
+        x.abs() > Self::cast_from(Self::MAX_EXP + 2) * Self::LN_2_PARTS[0]
+    }
+
+    /// the exponent above which `x^2` is treated as negligible compared to `1` in `sqrt(x^2 + 1)`, or at which `x^2` could overflow
+    #[inline]
+    fn large_square_exponent() -> FloatExponent {
+        (Self::MB_AS_FLOAT_EXP + 1).min((Self::MAX_EXP - 1) / 2 - 1)
+    }
+
+    /// `ln(2x)` for finite, positive `x`
+    #[inline]
+    fn ln_double(x: Self) -> TwoFloat<W, MB> {
+        Self::ln_two_float(x).add(TwoFloat::from_parts(Self::LN_2_PARTS))
+    }
+
+    /// Hyperbolic sine function.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn sinh(self) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        if Self::cosh_overflows(self) {
+            return Self::INFINITY.copysign(self);
+        }
+        let abs = self.abs();
+        let out = if abs < Self::ONE {
+            // `sinh(x) = (e + e / (e + 1)) / 2`, where `e = exp(x) - 1`, avoids the cancellation in `exp(x) - exp(-x)` for small `x`
+            let em1 = Self::exp_m1_two_float(TwoFloat::from_float(abs));
+            em1.add(em1.div(em1.add_float(Self::ONE))).round_scaled(-1)
+        } else {
+            let (k, m) = Self::exp_parts(abs);
+            m.sub(m.recip().mul_pow2(-2 * k)).round_scaled(k - 1)
+        };
+        out.copysign(self)
+    }
+
+    /// Hyperbolic cosine function.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn cosh(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_infinite() || Self::cosh_overflows(self) {
+            return Self::INFINITY;
+        }
+        let (k, m) = Self::exp_parts(self.abs());
+        m.add(m.recip().mul_pow2(-2 * k)).round_scaled(k - 1)
+    }
+
+    /// Hyperbolic tangent function.
+    ///
+    /// This is computed as `e / (e + 2)`, where `e = exp(2|x|) - 1` is evaluated with roughly twice the precision of `Self`, so the error is less than one ulp and the result is almost always correctly rounded.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn tanh(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return self;
+        }
+        // above this bound, `1 - tanh(x) = 2 / (exp(2x) + 1)` is less than a quarter of an ulp of `1`
+        let bound = Self::cast_from(Self::MB_AS_FLOAT_EXP + 3) * Self::LN_2_PARTS[0] * Self::HALF;
+        let abs = self.abs();
+        if abs > bound {
+            return Self::ONE.copysign(self);
+        }
+        // `tanh(x) = e / (e + 2)`, where `e = exp(2x) - 1`
+        let em1 = Self::exp_m1_two_float(TwoFloat::from_float(abs).mul_pow2(1));
+        em1.div(em1.add_float(Self::TWO))
+            .round()
+            .copysign(self)
+    }
+
+    /// Inverse hyperbolic sine function.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn asinh(self) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        let abs = self.abs();
+        let out = if abs.exponent() >= Self::large_square_exponent() {
+            Self::ln_double(abs)
+        } else {
+            // `asinh(x) = ln(1 + x + x^2 / (1 + sqrt(1 + x^2)))`, which avoids cancellation for small `x`
+            let square = TwoFloat::two_prod(abs, abs);
+            let denominator = TwoFloat::ONE.add(square.add_float(Self::ONE).sqrt());
+            Self::ln_1p_two_float(square.div(denominator).add_float(abs))
+        };
+        out.round().copysign(self)
+    }
+
+    /// Inverse hyperbolic cosine function.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn acosh(self) -> Self {
+        handle_nan!(self; self);
+        if self < Self::ONE {
+            return Self::NAN;
+        }
+        if self.is_infinite() {
+            return self;
+        }
+        let out = if self.exponent() >= Self::large_square_exponent() {
+            Self::ln_double(self)
+        } else {
+            // `acosh(x) = ln(1 + t + sqrt(t * (x + 1)))`, where `t = x - 1`, which avoids cancellation for `x` close to `1`
+            let t = TwoFloat::two_sum(self, Self::NEG_ONE);
+            let root = t.mul(TwoFloat::two_sum(self, Self::ONE)).sqrt();
+            Self::ln_1p_two_float(t.add(root))
+        };
+        out.round()
+    }
+
+    /// Inverse hyperbolic tangent function.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn atanh(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return self;
+        }
+        let abs = self.abs();
+        if abs > Self::ONE {
+            return Self::NAN;
+        }
+        if abs == Self::ONE {
+            return Self::INFINITY.copysign(self);
+        }
+        // `atanh(x) = ln(1 + 2x / (1 - x)) / 2`
+        let ratio = TwoFloat::from_float(abs)
+            .mul_pow2(1)
+            .div(TwoFloat::two_sum(Self::ONE, abs.neg()));
+        Self::ln_1p_two_float(ratio)
+            .round_scaled(-1)
+            .copysign(self)
+    }
+}
@@ -0,0 +1,152 @@
+use super::TwoFloat;
+use crate::cast::CastFrom;
+use crate::doc;
+use crate::float::{Float, FloatExponent};
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// `sum_{k >= 0} s^k u^(2k + 1) / (2k + 1)`, with `s = -1` if `alternating` and `s = 1` otherwise. this is `atan(u)` if `alternating` and `atanh(u)` otherwise, and converges quickly for small `|u|`
+    pub(crate) fn odd_power_series(u: TwoFloat<W, MB>, alternating: bool) -> TwoFloat<W, MB> {
+        let u2 = if alternating {
+            u.sqr().neg()
+        } else {
+            u.sqr()
+        };
+        let mut power = u;
+        let mut sum = u;
+        let mut k = 1u32;
+        loop {
+            power = power.mul(u2);
+            let term = power.div_float(Self::cast_from(2 * k + 1));
+            if TwoFloat::is_negligible(term.hi, sum.hi) {
+                return sum;
+            }
+            sum = sum.add(term);
+            k += 1;
+        }
+    }
+
+    /// splits finite, positive `x` into `k` and `ln(m)`, where `x = m * 2^k` and `m` is in `[sqrt(1/2), sqrt(2))`
+    fn ln_parts(x: Self) -> (FloatExponent, TwoFloat<W, MB>) {
+        let (_, mut k, mantissa) = x.into_normalised_signed_parts();
+        let mut m = Self::from_signed_parts(false, 0, mantissa);
+        if m * m > Self::TWO {
+            m *= Self::HALF;
+            k += 1;
+        }
+        // `ln(m) = 2 atanh(u)`, where `u = (m - 1) / (m + 1)`. `m - 1` is exact, since `m` is in `[1/2, 2]`
+        let u = TwoFloat::from_float(m - Self::ONE).div(TwoFloat::two_sum(m, Self::ONE));
+        (k, Self::odd_power_series(u, false).mul_pow2(1))
+    }
+
+    /// `k * ln(2)`
+    fn ln_2_multiple(k: FloatExponent) -> TwoFloat<W, MB> {
+        let [c0, c1, c2] = Self::LN_2_PARTS;
+        let k = Self::cast_from(k);
+        TwoFloat::two_prod(k, c0)
+            .add(TwoFloat::two_prod(k, c1))
+            .add_float(k * c2)
+    }
+
+    /// `ln(x)` for finite, positive `x`
+    pub(crate) fn ln_two_float(x: Self) -> TwoFloat<W, MB> {
+        let (k, ln_m) = Self::ln_parts(x);
+        if k == 0 {
+            return ln_m;
+        }
+        Self::ln_2_multiple(k).add(ln_m)
+    }
+
+    /// `ln(x.hi + x.lo)` for finite, positive `x`, using `ln(hi + lo) = ln(hi) + ln(1 + lo/hi)`, where `|lo/hi|` is small enough that `ln(1 + lo/hi)` is approximately `lo/hi`
+    fn ln_of_two_float(x: TwoFloat<W, MB>) -> TwoFloat<W, MB> {
+        Self::ln_two_float(x.hi).add_float(x.lo / x.hi)
+    }
+
+    /// `ln(1 + x)` for `x > -1`
+    pub(crate) fn ln_1p_two_float(x: TwoFloat<W, MB>) -> TwoFloat<W, MB> {
+        if x.hi.abs() < Self::QUARTER {
+            // `ln(1 + x) = 2 atanh(u)`, where `u = x / (2 + x)`. this avoids the cancellation in `1 + x` for small `x`
+            let u = x.div(x.add_float(Self::TWO));
+            return Self::odd_power_series(u, false).mul_pow2(1);
+        }
+        Self::ln_of_two_float(TwoFloat::ONE.add(x))
+    }
+
+    /// handles the special cases of logarithms (NaN, negative, zero and infinite arguments), returning `None` if `self` is finite and positive
+    #[inline]
+    fn log_special_cases(self) -> Option<Self> {
+        if self.is_nan() {
+            Some(self)
+        } else if self.is_zero() {
+            Some(Self::NEG_INFINITY)
+        } else if self.is_sign_negative() {
+            Some(Self::NAN)
+        } else if self.is_infinite() {
+            Some(self)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the natural logarithm of `self`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn ln(self) -> Self {
+        if let Some(out) = self.log_special_cases() {
+            return out;
+        }
+        Self::ln_two_float(self).round()
+    }
+
+    /// Returns the logarithm of `self` with respect to an arbitrary base.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn log(self, base: Self) -> Self {
+        if self.log_special_cases().is_some()
+            || base.log_special_cases().is_some()
+            || base == Self::ONE
+        {
+            return self.ln() / base.ln();
+        }
+        Self::ln_two_float(self)
+            .div(Self::ln_two_float(base))
+            .round()
+    }
+
+    /// Returns the base 2 logarithm of `self`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn log2(self) -> Self {
+        if let Some(out) = self.log_special_cases() {
+            return out;
+        }
+        // keeping `k` separate means exact powers of two give exact results
+        let (k, ln_m) = Self::ln_parts(self);
+        ln_m.div(TwoFloat::from_parts(Self::LN_2_PARTS))
+            .add_float(Self::cast_from(k))
+            .round()
+    }
+
+    /// Returns the base 10 logarithm of `self`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn log10(self) -> Self {
+        if let Some(out) = self.log_special_cases() {
+            return out;
+        }
+        Self::ln_two_float(self)
+            .div(TwoFloat::from_parts(Self::LN_10_PARTS))
+            .round()
+    }
+
+    /// Returns `ln(1 + self)` (the natural logarithm of `1 + self`) more accurately than if the operations were performed separately.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn ln_1p(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_zero() || self == Self::INFINITY {
+            return self;
+        }
+        if self == Self::NEG_ONE {
+            return Self::NEG_INFINITY;
+        }
+        if self < Self::NEG_ONE {
+            return Self::NAN;
+        }
+        Self::ln_1p_two_float(TwoFloat::from_float(self)).round()
+    }
+}
@@ -1,7 +1,18 @@
 use super::Float;
 use crate::doc;
 
+mod exp;
+mod fixed;
+mod frac_2_pi;
+mod hyperbolic;
+mod log;
+mod roots;
 mod sqrt;
+mod trig;
+mod two_float;
+
+pub(crate) use fixed::Fixed;
+pub(crate) use two_float::TwoFloat;
 
 /*
 All functions:
@@ -9,7 +20,7 @@ mul_add, div_euclid, rem_euclid, powi, powf, exp, exp2, ln, log, log2, log10, cb
 */
 
 /*
-TODO: acos, acosh, asin, asinh, atan, atan2, atanh, cbrt, cos, cosh, exp, exp2, exp_m1, gamma, hypot, ln, ln_1p, ln_gamma, log, log10, log2, midpoint, mul_add, powf, recip, round_ties_even, tan, tanh, to_degrees, to_radians,
+TODO: gamma, ln_gamma, midpoint
 */
 
 /// Mathematical functions.
@@ -23,16 +34,12 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 
     #[must_use = doc::must_use_op!(float)]
     pub fn sqrt(self) -> Self {
-        self.sqrt_internal()
+        self.sqrt_round(crate::RoundingMode::DEFAULT)
     }
 
-    #[cfg(nightly)]
     #[must_use = doc::must_use_op!(float)]
     #[inline]
-    pub fn div_euclid(self, rhs: Self) -> Self
-    where
-        [(); W * 2]:,
-    {
+    pub fn div_euclid(self, rhs: Self) -> Self {
         let div = (self / rhs).trunc();
         if self % rhs < Self::ZERO {
             return if rhs > Self::ZERO {
@@ -55,13 +62,12 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         }
     }
 
-    #[cfg(nightly)]
+    /// Raises `self` to an integer power.
+    ///
+    /// This uses square-and-multiply, so is faster than [`powf`](Self::powf) but is not correctly rounded: each of the `O(log2(|n|))` multiplications rounds, so the relative error can grow to a few ulps for large `|n|`. Negative powers are computed as the reciprocal of `self^|n|`, so intermediate overflow or underflow can make a result with a subnormal magnitude flush to zero or infinity.
     #[must_use = doc::must_use_op!(float)]
     #[inline]
-    pub fn powi(mut self, n: i32) -> Self
-    where
-        [(); W * 2]:,
-    {
+    pub fn powi(mut self, n: i32) -> Self {
         if n == 0 {
             return Self::ONE;
         }
@@ -69,10 +75,9 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
         let mut y = Self::ONE;
         while n_abs > 1 {
             if n_abs & 1 == 1 {
-                // out = out * self;
-                y = y * self;
+                y *= self;
             }
-            self = self * self;
+            self *= self;
             n_abs >>= 1;
         }
         if n.is_negative() {
@@ -85,7 +90,178 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
     use crate::test::test_bignum;
+    use crate::types::{F128, F256, F32, F64};
+    use quickcheck::TestResult;
+
+    /// whether `a` and `b` are equal or adjacent floats (or both NaN)
+    fn within_one_ulp(a: f64, b: f64) -> bool {
+        if a.is_nan() || b.is_nan() {
+            return a.is_nan() && b.is_nan();
+        }
+        a == b || (a.is_sign_negative() == b.is_sign_negative() && a.to_bits().abs_diff(b.to_bits()) <= 1)
+    }
+
+    /// `F32` results are compared with the `f64` results rounded to `f32`, which are correctly rounded except in very rare cases, so must match exactly. `F64` results are compared with the `f64` results, which are not always correctly rounded, so must be within one ulp. functions for which the primitive `f64` implementation is less accurate than this are only tested for `F32`
+    macro_rules! test_against_primitive {
+        (@f32 $function: ident ($($param: ident), *), $skip: expr) => {
+            paste::paste! {
+                quickcheck::quickcheck! {
+                    fn [<quickcheck_f32_ $function>]($($param: f32), *) -> TestResult {
+                        $(let $param = $param as f64;)*
+                        if $skip {
+                            return TestResult::discard();
+                        }
+                        let big = F32::$function($(F32::from($param as f32)), *);
+                        let primitive = <f64>::$function($($param), *) as f32;
+                        TestResult::from_bool(big.to_f32().to_bits() == primitive.to_bits() || (big.is_nan() && primitive.is_nan()))
+                    }
+                }
+            }
+        };
+        (@f64 $function: ident ($($param: ident), *), $skip: expr) => {
+            paste::paste! {
+                quickcheck::quickcheck! {
+                    fn [<quickcheck_f64_ $function>]($($param: f64), *) -> TestResult {
+                        if $skip {
+                            return TestResult::discard();
+                        }
+                        let big = F64::$function($(F64::from($param)), *);
+                        TestResult::from_bool(within_one_ulp(big.to_f64(), <f64>::$function($($param), *)))
+                    }
+                }
+            }
+        };
+        ($($ty: ident), *; $function: ident $params: tt, skip: $skip: expr) => {
+            $(
+                test_against_primitive!(@$ty $function $params, $skip);
+            )*
+        };
+        ($($ty: ident), *; $function: ident $params: tt) => {
+            test_against_primitive!($($ty), *; $function $params, skip: false);
+        };
+    }
+
+    /// the primitive `asinh` and `acosh` overflow to infinity for large arguments
+    const INVERSE_HYPERBOLIC_BOUND: f64 = 1e150;
+
+    test_against_primitive!(f32, f64; exp(x));
+    test_against_primitive!(f32, f64; exp2(x));
+    test_against_primitive!(f32, f64; exp_m1(x));
+    test_against_primitive!(f32, f64; ln(x));
+    test_against_primitive!(f32, f64; log2(x));
+    test_against_primitive!(f32, f64; log10(x));
+    test_against_primitive!(f32, f64; ln_1p(x));
+    test_against_primitive!(f32; log(x, b));
+    test_against_primitive!(f32, f64; powf(x, y));
+    test_against_primitive!(f32, f64; cbrt(x));
+    test_against_primitive!(f32, f64; hypot(x, y));
+    test_against_primitive!(f32, f64; recip(x));
+    test_against_primitive!(f32, f64; sin(x));
+    test_against_primitive!(f32, f64; cos(x));
+    test_against_primitive!(f32, f64; tan(x));
+    test_against_primitive!(f32, f64; asin(x));
+    test_against_primitive!(f32, f64; acos(x));
+    test_against_primitive!(f32, f64; atan(x));
+    test_against_primitive!(f32, f64; atan2(y, x));
+    test_against_primitive!(f32; sinh(x));
+    test_against_primitive!(f32, f64; cosh(x));
+    test_against_primitive!(f32; tanh(x));
+    test_against_primitive!(f32; asinh(x), skip: x.abs() > INVERSE_HYPERBOLIC_BOUND);
+    test_against_primitive!(f32; acosh(x), skip: x > INVERSE_HYPERBOLIC_BOUND);
+    test_against_primitive!(f32; atanh(x));
+    test_against_primitive!(f32, f64; to_degrees(x));
+    test_against_primitive!(f32, f64; to_radians(x));
+
+    #[test]
+    fn trig_large_arguments() {
+        // at least `2^MB` in magnitude, so every float is an integer, and reducing modulo `pi/2` needs many more bits of `pi` than the float has
+        let cases = [2f64.powi(52), 1e22, 1e40, 1e100, 1e300, f64::MAX];
+        for x in cases.into_iter().flat_map(|x| [x, -x]) {
+            let big = F64::from(x);
+            assert!(within_one_ulp(big.sin().to_f64(), x.sin()), "sin({x:e})");
+            assert!(within_one_ulp(big.cos().to_f64(), x.cos()), "cos({x:e})");
+            assert!(within_one_ulp(big.tan().to_f64(), x.tan()), "tan({x:e})");
+        }
+        // the double closest to a multiple of `pi/2`, which is `k pi/2 + 4.68716592425462761e-19` for some odd `k`
+        let x = F64::from(6381956970095103.0 * 2f64.powi(797));
+        assert_eq!(x.cos().to_f64(), -4.687165924254628e-19);
+        assert_eq!(x.sin().to_f64(), 1.0);
+
+        let cases = [2f32.powi(23), 1e22, 1e30, f32::MAX];
+        for x in cases.into_iter().flat_map(|x| [x, -x]) {
+            let big = F32::from(x);
+            assert_eq!(big.sin().to_f32(), (x as f64).sin() as f32, "sin({x:e})");
+            assert_eq!(big.cos().to_f32(), (x as f64).cos() as f32, "cos({x:e})");
+            assert_eq!(big.tan().to_f32(), (x as f64).tan() as f32, "tan({x:e})");
+        }
+        let (sin, cos) = F128::MAX.sin_cos();
+        assert!((sin * sin + cos * cos - F128::ONE).abs() <= F128::EPSILON * F128::TWO);
+        // the bits of `x`, `sin(x)` and `cos(x)`, correctly rounded from values computed with 300000-bit precision, for `x = 2^20000`, `x = 1.5 * 2^100000` and `x = F256::MAX`
+        let cases = [
+            (
+                "44e1f00000000000000000000000000000000000000000000000000000000000",
+                "3fffefffed62a4b1a8530ae6fe3bf0679278b9c8daa4933bebc1b36820c6842a",
+                "3fff7142029d86506a03773a520f4fca97cf9d0eb78abc92d85c5372b1e474dc",
+            ),
+            (
+                "5869f80000000000000000000000000000000000000000000000000000000000",
+                "bfffea2dfdfbffe94f7e6e945a83ebe0982e949a3636da437bed720f3a9d2392",
+                "3fffe266db2bbf8e960de0c1c04662bdadace46674e3b2b5a9759eab8a160415",
+            ),
+            (
+                "7fffefffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "3fffe8c2227bc0c62c0225d6ac3a9e51421945912aa79fbc3d8d8147dd67470e",
+                "3fffe4461409ea97cec20e9fb0504305289feb03f5720767a45fc5e48fe1dbd4",
+            ),
+        ];
+        let from_hex = |hex| F256::from_bits(crate::Uint::from_str_radix(hex, 16).unwrap());
+        for (x, sin, cos) in cases {
+            let x = from_hex(x);
+            assert_eq!(x.sin().to_bits(), from_hex(sin).to_bits(), "sin({x:?})");
+            assert_eq!(x.cos().to_bits(), from_hex(cos).to_bits(), "cos({x:?})");
+            assert_eq!(x.neg().sin().to_bits(), from_hex(sin).neg().to_bits(), "sin(-{x:?})");
+        }
+    }
+
+    #[test]
+    fn tanh_reference_values() {
+        // correctly rounded values computed with 300-bit precision. the primitive `tanh` is not correctly rounded for the first six of these, so is not used as a reference for `F64`
+        let cases = [
+            (-0.5383386708844, -0.49172937257888605),
+            (0.5475040176503683, 0.4986471874819583),
+            (-0.3631868515247947, -0.34801800559601),
+            (-0.999492323738693, -0.7613808625020146),
+            (-0.1882476041886818, -0.186055024945274),
+            (0.42894486590247016, 0.4044391407325276),
+            (1e-5, 9.999999999666668e-6),
+            (18.0, 0.9999999999999996),
+        ];
+        for (x, expected) in cases {
+            assert_eq!(F64::from(x).tanh().to_f64(), expected, "tanh({x:e})");
+        }
+    }
+
+    #[test]
+    fn powi_exact() {
+        // every intermediate product is exactly representable, so no rounding occurs
+        for base in 2u32..=10 {
+            let mut expected = 1.0f64;
+            for n in 0..=(53.0 / (base as f64).log2()) as i32 {
+                assert_eq!(F64::from(base as f64).powi(n).to_f64(), expected, "{base}^{n}");
+                expected *= base as f64;
+            }
+        }
+        for n in 1..=1022 {
+            assert_eq!(F64::TWO.powi(-n).to_f64(), 2f64.powi(-n), "2^-{n}");
+            assert_eq!(F64::HALF.powi(n).to_f64(), 0.5f64.powi(n), "0.5^{n}");
+        }
+        assert_eq!(F64::TWO.powi(1024).to_f64(), f64::INFINITY);
+        assert_eq!(F64::NEG_ONE.powi(i32::MIN).to_f64(), 1.0);
+        assert_eq!(F64::NEG_ONE.powi(i32::MAX).to_f64(), -1.0);
+    }
 
     crate::test::test_all! {
         testing floats;
@@ -96,11 +272,20 @@ mod tests {
         test_bignum! {
             function: <ftest>::sqrt(f: ftest)
         }
-        // test_bignum! {
-        //     function: <ftest>::div_euclid(f1: ftest, f2: ftest)
-        // }
         test_bignum! {
-            function: <ftest>::rem_euclid(f1: ftest, f2: ftest)
+            function: <ftest>::div_euclid(f1: ftest, f2: ftest),
+            cases: [
+                (-1.0, ftest::MIN_POSITIVE / 3.0),
+                (-ftest::MIN_POSITIVE * 0.7, ftest::MIN_POSITIVE / 3.0)
+            ]
+        }
+        test_bignum! {
+            function: <ftest>::rem_euclid(f1: ftest, f2: ftest),
+            cases: [
+                (-2.758e-198f64 as ftest, 6.329e-309f64 as ftest),
+                (-1.0, -ftest::MIN_POSITIVE / 3.0),
+                (-ftest::MIN_POSITIVE * 0.7, ftest::MIN_POSITIVE / 3.0)
+            ]
         }
         // test_bignum! {
         //     function: <ftest>::powi(f: ftest, n: i32)
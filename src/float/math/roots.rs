@@ -0,0 +1,57 @@
+use super::TwoFloat;
+use crate::cast::CastFrom;
+use crate::doc;
+use crate::float::Float;
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// Returns the cube root of `self`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn cbrt(self) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        // write `|self| = m * 2^(3q)`, where `m` is in `[1, 8)`, so that `cbrt(|self|) = cbrt(m) * 2^q`
+        let (_, exponent, mantissa) = self.into_normalised_signed_parts();
+        let q = exponent.div_euclid(3);
+        let m = Self::from_signed_parts(false, exponent.rem_euclid(3), mantissa);
+
+        // estimate `cbrt(m) = exp(ln(m) / 3)`, then refine with a Newton step: `y - (y - m / y^2) / 3`
+        let y = Self::exp_m1_two_float(Self::ln_two_float(m).div_float(Self::cast_from(3u8)))
+            .add_float(Self::ONE);
+        let correction = y
+            .sub(TwoFloat::from_float(m).div(y.sqr()))
+            .div_float(Self::cast_from(3u8));
+        y.sub(correction).round_scaled(q).copysign(self)
+    }
+
+    /// Computes the length of the hypotenuse of a right-angle triangle given legs of length `self` and `other`, i.e. `sqrt(self^2 + other^2)`, without unnecessary overflow or underflow.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn hypot(self, other: Self) -> Self {
+        if self.is_infinite() || other.is_infinite() {
+            return Self::INFINITY;
+        }
+        handle_nan!(Self::NAN; self, other);
+        let (a, b) = if self.abs() >= other.abs() {
+            (self.abs(), other.abs())
+        } else {
+            (other.abs(), self.abs())
+        };
+        if b.is_zero() {
+            return a;
+        }
+        // scale so that `a` is in `[1, 2)`, which means the squares can neither overflow nor underflow (unless `b` is negligible compared to `a`)
+        let k = a.exponent();
+        let (a, b) = (a.mul_pow2(-k), b.mul_pow2(-k));
+        TwoFloat::two_prod(a, a)
+            .add(TwoFloat::two_prod(b, b))
+            .sqrt()
+            .round_scaled(k)
+    }
+
+    /// Takes the reciprocal (inverse) of a number, `1/x`.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE / self
+    }
+}
@@ -1,84 +1,49 @@
 use super::Float;
-use crate::cast::CastFrom;
-use crate::{
-    Int, Uint,
-    float::{FloatExponent, UnsignedFloatExponent},
-};
+use crate::float::FloatExponent;
+use crate::float::wide::Wide;
+use crate::{FloatStatus, RoundingMode, doc};
 
 impl<const W: usize, const MB: usize> Float<W, MB> {
-    // TODO: could also use the sqrt algorithm on Uint with twice as many bits, compare performance with this implementation from libm
-    pub(super) fn sqrt_internal(self) -> Self {
-        handle_nan!(self; self);
-        if self.is_zero() {
-            return self;
-        }
-        let bits = self.to_bits();
-        if bits == Self::INFINITY.to_bits() {
-            return Self::INFINITY;
-        }
-        if self.is_sign_negative() {
-            return Self::NAN;
-            /*let u = Uint::MAX << (Self::MB - 1);
-            return Self::from_bits(u);*/
+    #[inline]
+    fn sqrt_internal(self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        let (_, exp, mant) = self.into_normalised_signed_parts();
+
+        // value is mant * 2^(exp - MB), where mant has MB + 1 bits. we shift mant left so that it has 2 * MB + 3 or 2 * MB + 4 bits and the power of two becomes even, so that the integer square root has one more bit than the precision
+        let mut shift = Self::MB + 2;
+        let mut scaled_exp = exp - Self::MB_AS_FLOAT_EXP - shift as FloatExponent;
+        if scaled_exp % 2 != 0 {
+            shift += 1;
+            scaled_exp -= 1;
         }
+        let (root, inexact) = Wide::from_uint(mant).shl(shift).isqrt_rem();
+
+        // a non-zero remainder is jammed into an extra sticky bit
+        let mut mantissa = root.shl(1);
+        mantissa.set_bit(0, inexact);
+        Self::from_wide_parts(false, scaled_exp / 2 - 1, mantissa, rounding)
+    }
 
-        let tiny = Self::from_bits(Uint::cast_from(0b11011u8) << Self::MB); // TODO: may not work for exponents stored with very few bits
+    /// Computes the square root of `self`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn sqrt_round(self, rounding: RoundingMode) -> Self {
+        self.sqrt_with_status(rounding).0
+    }
 
-        let mut ix = bits.cast_signed();
-        let mut i: FloatExponent;
-        let mut m = (bits >> Self::MB).cast_to_unsigned_float_exponent() as FloatExponent;
-        if m == 0 {
-            /* subnormal x */
-            i = 0;
-            while (ix & (Int::ONE << Self::MB)).is_zero() {
-                ix <<= 1;
-                i = i + 1;
-            }
-            m -= i - 1;
+    /// Computes the square root of `self`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn sqrt_with_status(self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        if self.is_nan() {
+            return (self, Self::nan_operands_status(&[self]));
         }
-        m -= Self::EXP_BIAS; /* unbias exponent */
-        ix = (ix & (Uint::MAX >> (Self::BITS - Self::MB)).cast_signed()) | (Int::ONE << Self::MB);
-        if m & 1 == 1 {
-            /* odd m, double x to make it even */
-            ix += ix;
+        if self.is_zero() {
+            return (self, FloatStatus::OK);
         }
-        m >>= 1; /* m = [m/2] */
-
-        /* generate sqrt(x) bit by bit */
-        ix += ix;
-        let mut q = Int::ZERO;
-        let mut s = Int::ZERO;
-        let mut r = Uint::ONE << (Self::MB + 1); /* r = moving bit from right to left */
-
-        let mut t: Int<W>;
-        while !r.is_zero() {
-            t = s + r.cast_signed();
-            if t <= ix {
-                s = t + r.cast_signed();
-                ix -= t;
-                q += r.cast_signed();
-            }
-            ix += ix;
-            r = r >> 1u8;
+        if self.is_sign_negative() {
+            return (Self::NAN, FloatStatus::INVALID);
         }
-
-        /* use floating add to find out rounding direction */
-        let mut z: Self;
-        if !ix.is_zero() {
-            z = Self::ONE - tiny; /* raise inexact flag */
-            if z >= Self::ONE {
-                z = Self::ONE + tiny;
-                if z > Self::ONE {
-                    q += crate::n!(0b10);
-                } else {
-                    q += q & crate::n!(0b1); // kenobi
-                }
-            }
+        if self.is_infinite() {
+            return (Self::INFINITY, FloatStatus::OK);
         }
-
-        ix = (q >> 1u8) + ((Uint::MAX << (Self::MB + 1 + 2)) >> 2u8).cast_signed();
-        ix += (Uint::cast_from_unsigned_float_exponent(m as UnsignedFloatExponent) << Self::MB)
-            .cast_signed();
-        Self::from_bits(ix.cast_unsigned())
+        self.sqrt_internal(rounding)
     }
 }
@@ -0,0 +1,285 @@
+use super::TwoFloat;
+use crate::cast::CastFrom;
+use crate::doc;
+use crate::float::{Float, FloatExponent};
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// `pi * 2^n`
+    #[inline]
+    fn pi_two_float(n: FloatExponent) -> TwoFloat<W, MB> {
+        TwoFloat::from_parts(Self::PI_PARTS).mul_pow2(n)
+    }
+
+    /// splits finite `x` into `k * pi/2 + r`, where `|r|` is at most around `pi/4`, returning `k mod 4` and `r`. the reduction is exact (see `mul_frac_2_pi_mod_4`)
+    fn reduce_frac_pi_2(x: Self) -> (u8, TwoFloat<W, MB>) {
+        let abs = x.abs();
+        if abs <= Self::PI_PARTS[0].mul_pow2(-2) {
+            return (0, TwoFloat::from_float(x));
+        }
+        let (quadrant, negative, fraction) = abs.mul_frac_2_pi_mod_4();
+        let [hi, lo] = Self::from_fixed_parts(fraction);
+        let r = TwoFloat { hi, lo }.mul_pow2(-2).mul(Self::pi_two_float(-1));
+        let r = if negative { r.neg() } else { r };
+        if x.is_sign_negative() {
+            // `-|x| = -k * pi/2 - r = (4 - k) * pi/2 - r - 2pi`
+            ((4 - quadrant) % 4, r.neg())
+        } else {
+            (quadrant, r)
+        }
+    }
+
+    /// `sum_{k >= 0} (-1)^k x^(2k + start) / (2k + start)!`, which is `sin(x)` if `start == 1` and `cos(x)` if `start == 0`. this converges quickly for `|x| <= pi/4`
+    fn sin_cos_series(x: TwoFloat<W, MB>, start: u32) -> TwoFloat<W, MB> {
+        let x2 = x.sqr().neg();
+        let mut term = if start == 0 { TwoFloat::ONE } else { x };
+        let mut sum = term;
+        let mut n = start + 1;
+        loop {
+            term = term
+                .mul(x2)
+                .div_float(Self::cast_from(n))
+                .div_float(Self::cast_from(n + 1));
+            if TwoFloat::is_negligible(term.hi, sum.hi) {
+                return sum;
+            }
+            sum = sum.add(term);
+            n += 2;
+        }
+    }
+
+    /// `(sin(x), cos(x))` for finite `x`
+    fn sin_cos_two_float(x: Self) -> (TwoFloat<W, MB>, TwoFloat<W, MB>) {
+        let (quadrant, r) = Self::reduce_frac_pi_2(x);
+        let sin = Self::sin_cos_series(r, 1);
+        let cos = Self::sin_cos_series(r, 0);
+        match quadrant {
+            0 => (sin, cos),
+            1 => (cos, sin.neg()),
+            2 => (sin.neg(), cos.neg()),
+            _ => (cos.neg(), sin),
+        }
+    }
+
+    /// `atan(x)` for `|x| <= 1`
+    fn atan_two_float(mut x: TwoFloat<W, MB>) -> TwoFloat<W, MB> {
+        if x.hi.is_zero() {
+            return x;
+        }
+        // reduce the argument with `atan(x) = 2 atan(x / (1 + sqrt(1 + x^2)))` until it is small enough that the series converges quickly
+        let target = -((TwoFloat::<W, MB>::precision().isqrt() / 2 + 1) as FloatExponent);
+        let mut halvings = 0;
+        while x.hi.exponent() > target {
+            let denominator = TwoFloat::ONE.add(TwoFloat::ONE.add(x.sqr()).sqrt());
+            x = x.div(denominator);
+            halvings += 1;
+        }
+        Self::odd_power_series(x, true).mul_pow2(halvings)
+    }
+
+    /// `atan(|y| / |x|)` for finite, non-zero `x` and `y`
+    fn atan_ratio(y: TwoFloat<W, MB>, x: TwoFloat<W, MB>) -> TwoFloat<W, MB> {
+        let (y, x) = (y.abs(), x.abs());
+        if y.hi <= x.hi {
+            Self::atan_two_float(y.div(x))
+        } else {
+            Self::pi_two_float(-1).sub(Self::atan_two_float(x.div(y)))
+        }
+    }
+
+    /// `asin(x)` for `|x| < 1`
+    fn asin_two_float(x: Self) -> TwoFloat<W, MB> {
+        let abs = x.abs();
+        // `1 - x^2 = (1 - |x|) * (1 + |x|)` avoids cancellation when `|x|` is close to `1`
+        let cos = TwoFloat::two_sum(Self::ONE, abs.neg())
+            .mul(TwoFloat::two_sum(Self::ONE, abs))
+            .sqrt();
+        let out = Self::atan_ratio(TwoFloat::from_float(abs), cos);
+        if x.is_sign_negative() {
+            out.neg()
+        } else {
+            out
+        }
+    }
+
+    /// Computes the sine of `self` (in radians).
+    ///
+    /// The argument is reduced modulo `pi/2` exactly. This is supported for all floats with at most 19 exponent bits which are at most 84 bytes wide (such as [`F256`](crate::types::F256)); using this method with a float type whose exponent range is too wide for the reduction is a compile-time error.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn sin(self) -> Self {
+        if !self.is_finite() {
+            return Self::NAN;
+        }
+        if self.is_zero() {
+            return self;
+        }
+        Self::sin_cos_two_float(self).0.round()
+    }
+
+    /// Computes the cosine of `self` (in radians).
+    ///
+    /// The argument is reduced modulo `pi/2` exactly. This is supported for all floats with at most 19 exponent bits which are at most 84 bytes wide (such as [`F256`](crate::types::F256)); using this method with a float type whose exponent range is too wide for the reduction is a compile-time error.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn cos(self) -> Self {
+        if !self.is_finite() {
+            return Self::NAN;
+        }
+        Self::sin_cos_two_float(self).1.round()
+    }
+
+    /// Computes the tangent of `self` (in radians).
+    ///
+    /// The argument is reduced modulo `pi/2` exactly. This is supported for all floats with at most 19 exponent bits which are at most 84 bytes wide (such as [`F256`](crate::types::F256)); using this method with a float type whose exponent range is too wide for the reduction is a compile-time error.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn tan(self) -> Self {
+        if !self.is_finite() {
+            return Self::NAN;
+        }
+        if self.is_zero() {
+            return self;
+        }
+        let (sin, cos) = Self::sin_cos_two_float(self);
+        sin.div(cos).round()
+    }
+
+    /// Simultaneously computes the sine and cosine of `self` (in radians). Returns `(sin(self), cos(self))`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn sin_cos(self) -> (Self, Self) {
+        if !self.is_finite() {
+            return (Self::NAN, Self::NAN);
+        }
+        if self.is_zero() {
+            return (self, Self::ONE);
+        }
+        let (sin, cos) = Self::sin_cos_two_float(self);
+        (sin.round(), cos.round())
+    }
+
+    /// Computes the arcsine of `self`. The return value is in radians, in the range `[-pi/2, pi/2]`, or NaN if `self` is outside the range `[-1, 1]`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn asin(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return self;
+        }
+        let abs = self.abs();
+        if abs > Self::ONE {
+            return Self::NAN;
+        }
+        if abs == Self::ONE {
+            return Self::pi_two_float(-1).round().copysign(self);
+        }
+        Self::asin_two_float(self).round()
+    }
+
+    /// Computes the arccosine of `self`. The return value is in radians, in the range `[0, pi]`, or NaN if `self` is outside the range `[-1, 1]`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn acos(self) -> Self {
+        handle_nan!(self; self);
+        let abs = self.abs();
+        if abs > Self::ONE {
+            return Self::NAN;
+        }
+        if self == Self::ONE {
+            return Self::ZERO;
+        }
+        if self == Self::NEG_ONE {
+            return Self::pi_two_float(0).round();
+        }
+        Self::pi_two_float(-1)
+            .sub(Self::asin_two_float(self))
+            .round()
+    }
+
+    /// Computes the arctangent of `self`. The return value is in radians, in the range `[-pi/2, pi/2]`.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn atan(self) -> Self {
+        handle_nan!(self; self);
+        if self.is_zero() {
+            return self;
+        }
+        let out = if self.is_infinite() {
+            Self::pi_two_float(-1)
+        } else {
+            Self::atan_ratio(TwoFloat::from_float(self), TwoFloat::ONE)
+        };
+        out.round().copysign(self)
+    }
+
+    /// Computes the four quadrant arctangent of `self` (`y`) and `other` (`x`), in radians.
+    ///
+    /// - `x = 0`, `y = 0`: `0`
+    /// - `x >= 0`: `arctan(y/x)` -> `[-pi/2, pi/2]`
+    /// - `y >= 0`: `arctan(y/x) + pi` -> `(pi/2, pi]`
+    /// - `y < 0`: `arctan(y/x) - pi` -> `(-pi, -pi/2)`
+    #[must_use = doc::must_use_op!(float)]
+    pub fn atan2(self, other: Self) -> Self {
+        let (y, x) = (self, other);
+        handle_nan!(Self::NAN; y, x);
+        // the angle of `(|x|, |y|)`, which is in `[0, pi/2]`
+        let angle = if y.is_zero() {
+            TwoFloat::ZERO
+        } else if x.is_zero() {
+            Self::pi_two_float(-1)
+        } else if y.is_infinite() {
+            if x.is_infinite() {
+                Self::pi_two_float(-2)
+            } else {
+                Self::pi_two_float(-1)
+            }
+        } else if x.is_infinite() {
+            TwoFloat::ZERO
+        } else {
+            // for tiny ratios, `atan(y/x) = y/x` to well within half an ulp, but the quotient must be computed scaled up so that its lower part is not lost to underflow
+            let scale = TwoFloat::<W, MB>::precision() as FloatExponent;
+            let bound = (Self::MIN_EXP + scale).min(-scale);
+            if x.is_sign_positive() && y.exponent() - x.exponent() + 1 < bound {
+                return TwoFloat::from_float(y.abs().mul_pow2(scale))
+                    .div_float(x)
+                    .round_scaled(-scale)
+                    .copysign(y);
+            }
+            // scale so that the larger of `|x|` and `|y|` is in `[1, 2)`, so that the division doesn't lose precision to underflow
+            let k = y.exponent().max(x.exponent());
+            Self::atan_ratio(
+                TwoFloat::from_float(y.mul_pow2(-k)),
+                TwoFloat::from_float(x.mul_pow2(-k)),
+            )
+        };
+        let angle = if x.is_sign_negative() {
+            Self::pi_two_float(0).sub(angle)
+        } else {
+            angle
+        };
+        angle.round().copysign(y)
+    }
+
+    /// `x * factor`, rounded to the nearest float. if `x` is small, it is scaled up first, so that the lower parts of the product do not lose precision to underflow
+    fn mul_scaled(x: Self, factor: TwoFloat<W, MB>) -> Self {
+        if x.is_zero() || !x.is_finite() {
+            return x * factor.hi;
+        }
+        let scale = TwoFloat::<W, MB>::precision() as FloatExponent;
+        let k = if x.exponent() < Self::MIN_EXP + scale {
+            scale
+        } else {
+            0
+        };
+        TwoFloat::from_float(x.mul_pow2(k))
+            .mul(factor)
+            .round_scaled(-k)
+    }
+
+    /// Converts radians to degrees.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn to_degrees(self) -> Self {
+        let factor = TwoFloat::from_float(Self::cast_from(180u8)).div(Self::pi_two_float(0));
+        Self::mul_scaled(self, factor)
+    }
+
+    /// Converts degrees to radians.
+    #[must_use = doc::must_use_op!(float)]
+    pub fn to_radians(self) -> Self {
+        let factor = Self::pi_two_float(0).div_float(Self::cast_from(180u8));
+        Self::mul_scaled(self, factor)
+    }
+}
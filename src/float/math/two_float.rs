@@ -0,0 +1,242 @@
+use super::fixed::Fixed;
+use crate::float::wide::Wide;
+use crate::float::{Float, FloatExponent};
+use crate::{Exponent, RoundingMode, Uint};
+
+/// Unevaluated sum `hi + lo` of two floats, with `|lo| <= ulp(hi) / 2`, which has roughly twice the precision of a single float. The mathematical functions are evaluated in this format, so that the final rounding to a single float is almost always correct.
+#[derive(Clone, Copy)]
+pub(crate) struct TwoFloat<const W: usize, const MB: usize> {
+    pub hi: Float<W, MB>,
+    pub lo: Float<W, MB>,
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    pub(crate) const PI_PARTS: [Self; 3] = Self::from_fixed_parts(Fixed::PI);
+    pub(crate) const LN_2_PARTS: [Self; 3] = Self::from_fixed_parts(Fixed::LN_2);
+    pub(crate) const LN_10_PARTS: [Self; 3] = Self::from_fixed_parts(Fixed::LN_10);
+
+    /// `self * 2^n`, rounded to the nearest float, with ties rounding to even. this only rounds if the result is subnormal
+    #[inline]
+    pub(crate) const fn mul_pow2(self, n: FloatExponent) -> Self {
+        if !self.is_finite() || self.is_zero() {
+            return self;
+        }
+        let (sign, exponent, mantissa) = self.into_signed_parts();
+        Self::from_wide_parts(
+            sign,
+            exponent - Self::MB_AS_FLOAT_EXP + n,
+            Wide::from_uint(mantissa),
+            RoundingMode::NearestTiesEven,
+        )
+        .0
+    }
+
+    /// the unbiased exponent of a finite, non-zero float, such that `2^exponent <= |self| < 2^(exponent + 1)`
+    #[inline]
+    pub(crate) const fn exponent(self) -> FloatExponent {
+        let (_, exponent, _) = self.into_normalised_signed_parts();
+        exponent
+    }
+}
+
+impl<const W: usize, const MB: usize> TwoFloat<W, MB> {
+    pub const ZERO: Self = Self::from_float(Float::ZERO);
+    pub const ONE: Self = Self::from_float(Float::ONE);
+
+    #[inline]
+    pub const fn from_float(hi: Float<W, MB>) -> Self {
+        Self { hi, lo: Float::ZERO }
+    }
+
+    #[inline]
+    pub const fn from_parts(parts: [Float<W, MB>; 3]) -> Self {
+        Self {
+            hi: parts[0],
+            lo: parts[1],
+        }
+    }
+
+    /// exact sum of two floats
+    #[inline]
+    pub fn two_sum(a: Float<W, MB>, b: Float<W, MB>) -> Self {
+        let hi = a + b;
+        if !hi.is_finite() {
+            return Self::from_float(hi);
+        }
+        let b_virtual = hi - a;
+        let a_virtual = hi - b_virtual;
+        let lo = (a - a_virtual) + (b - b_virtual);
+        Self { hi, lo }
+    }
+
+    /// exact sum of two floats, where `|a| >= |b|` or `a` is zero
+    #[inline]
+    fn fast_two_sum(a: Float<W, MB>, b: Float<W, MB>) -> Self {
+        let hi = a + b;
+        if !hi.is_finite() {
+            return Self::from_float(hi);
+        }
+        let lo = b - (hi - a);
+        Self { hi, lo }
+    }
+
+    /// exact product of two floats, unless the product underflows
+    #[inline]
+    pub fn two_prod(a: Float<W, MB>, b: Float<W, MB>) -> Self {
+        let hi = a * b;
+        if !hi.is_finite() {
+            return Self::from_float(hi);
+        }
+        let lo = a.mul_add(b, -hi);
+        Self { hi, lo }
+    }
+
+    #[inline]
+    pub const fn neg(self) -> Self {
+        Self {
+            hi: self.hi.neg(),
+            lo: self.lo.neg(),
+        }
+    }
+
+    #[inline]
+    pub const fn abs(self) -> Self {
+        if self.hi.is_sign_negative() {
+            self.neg()
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    pub fn add(self, rhs: Self) -> Self {
+        let s = Self::two_sum(self.hi, rhs.hi);
+        let t = Self::two_sum(self.lo, rhs.lo);
+        let s = Self::fast_two_sum(s.hi, s.lo + t.hi);
+        Self::fast_two_sum(s.hi, s.lo + t.lo)
+    }
+
+    #[inline]
+    pub fn add_float(self, rhs: Float<W, MB>) -> Self {
+        let s = Self::two_sum(self.hi, rhs);
+        Self::fast_two_sum(s.hi, s.lo + self.lo)
+    }
+
+    #[inline]
+    pub fn sub(self, rhs: Self) -> Self {
+        self.add(rhs.neg())
+    }
+
+    #[inline]
+    pub fn mul(self, rhs: Self) -> Self {
+        let p = Self::two_prod(self.hi, rhs.hi);
+        let cross = self.hi.mul_add(rhs.lo, self.lo * rhs.hi);
+        Self::fast_two_sum(p.hi, p.lo + cross)
+    }
+
+    #[inline]
+    pub fn mul_float(self, rhs: Float<W, MB>) -> Self {
+        let p = Self::two_prod(self.hi, rhs);
+        Self::fast_two_sum(p.hi, self.lo.mul_add(rhs, p.lo))
+    }
+
+    #[inline]
+    pub fn div(self, rhs: Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        if !q1.is_finite() || q1.is_zero() {
+            return Self::from_float(q1);
+        }
+        let r = self.sub(rhs.mul_float(q1));
+        let q2 = r.hi / rhs.hi;
+        let r = r.sub(rhs.mul_float(q2));
+        let q3 = r.hi / rhs.hi;
+        Self::fast_two_sum(q1, q2).add_float(q3)
+    }
+
+    #[inline]
+    pub fn div_float(self, rhs: Float<W, MB>) -> Self {
+        self.div(Self::from_float(rhs))
+    }
+
+    #[inline]
+    pub fn recip(self) -> Self {
+        Self::ONE.div(self)
+    }
+
+    #[inline]
+    pub fn sqr(self) -> Self {
+        self.mul(self)
+    }
+
+    /// square root of a non-negative value, using one Newton step from the correctly rounded square root of `hi`
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        if self.hi.is_zero() || !self.hi.is_finite() {
+            return Self::from_float(self.hi.sqrt());
+        }
+        let s = self.hi.sqrt();
+        let r = self.sub(Self::two_prod(s, s));
+        Self::fast_two_sum(s, r.hi / (s + s))
+    }
+
+    /// `self * 2^n`, which is exact unless either part is subnormal
+    #[inline]
+    pub const fn mul_pow2(self, n: FloatExponent) -> Self {
+        Self {
+            hi: self.hi.mul_pow2(n),
+            lo: self.lo.mul_pow2(n),
+        }
+    }
+
+    /// rounds `(hi + lo) * 2^n` to the nearest float, with ties rounding to even, handling overflow and subnormal results with a single rounding
+    pub fn round_scaled(self, n: FloatExponent) -> Float<W, MB> {
+        if !self.hi.is_finite() || self.hi.is_zero() {
+            return self.hi.mul_pow2(n);
+        }
+        let (sign, hi_exponent, hi_mantissa) = self.hi.into_signed_parts();
+        // shift the mantissa of `hi` to the top half of a `Wide`, so that `lo` can be added to it with more than enough guard bits
+        let shift = Uint::<W>::BITS;
+        let mut mantissa = Wide::from_uint(hi_mantissa).shl(shift);
+        if !self.lo.is_zero() {
+            let (lo_sign, lo_exponent, lo_mantissa) = self.lo.into_signed_parts();
+            let offset = lo_exponent - hi_exponent + shift as FloatExponent;
+            let lo_mantissa = Wide::from_uint(lo_mantissa);
+            let lo_mantissa = if offset >= 0 {
+                lo_mantissa.shl(offset as Exponent)
+            } else {
+                lo_mantissa.shr_jamming(offset.unsigned_abs())
+            };
+            mantissa = if lo_sign == sign {
+                mantissa.add(lo_mantissa)
+            } else {
+                mantissa.sub(lo_mantissa)
+            };
+        }
+        Float::from_wide_parts(
+            sign,
+            hi_exponent - Float::<W, MB>::MB_AS_FLOAT_EXP - shift as FloatExponent + n,
+            mantissa,
+            RoundingMode::NearestTiesEven,
+        )
+        .0
+    }
+
+    #[inline]
+    pub fn round(self) -> Float<W, MB> {
+        self.round_scaled(0)
+    }
+
+    /// the number of bits of precision that the series expansions need to achieve
+    #[inline]
+    pub const fn precision() -> Exponent {
+        2 * (MB as Exponent + 1) + 4
+    }
+
+    /// whether `term` is negligible relative to `sum` at the precision given by `precision`
+    #[inline]
+    pub fn is_negligible(term: Float<W, MB>, sum: Float<W, MB>) -> bool {
+        term.is_zero()
+            || (!sum.is_zero()
+                && term.exponent() < sum.exponent() - Self::precision() as FloatExponent)
+    }
+}
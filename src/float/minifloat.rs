@@ -0,0 +1,633 @@
+use super::{Float, FloatExponent};
+use crate::cast::CastFrom;
+use crate::{Exponent, RoundingMode, Uint};
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::marker::PhantomData;
+use core::ops::Neg;
+
+/// How a minifloat format encodes non-finite values.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum NonFinite {
+    /// As in IEEE 754: the largest biased exponent encodes the infinities (when the mantissa is zero) and NaNs (when the mantissa is non-zero).
+    Ieee,
+    /// There are no infinities, and the only NaNs are the values whose exponent and mantissa bits are all set. The largest biased exponent is otherwise used for finite values. This is the encoding of E4M3 in the OCP 8-bit floating point specification.
+    NanOnly,
+    /// Every bit pattern is finite: there are no infinities or NaNs. This is the encoding of the 6-bit and 4-bit formats in the OCP microscaling specification.
+    None,
+}
+
+/// Describes the layout of a [`MiniFloat`] format.
+///
+/// The exponent bias is always `2^(EXPONENT_BITS - 1) - 1`. The format must fit in 16 bits, and `EXPONENT_BITS` must be between `1` and `8` inclusive, so that every value of the format can be represented exactly by an `f32`. Formats whose `NON_FINITE` is [`NonFinite::Ieee`] must have at least one mantissa bit, so that they can encode NaN. These requirements are checked at compile time.
+pub trait MiniFloatFormat {
+    /// The number of exponent bits.
+    const EXPONENT_BITS: u32;
+
+    /// The number of explicitly stored mantissa bits.
+    const MANTISSA_BITS: u32;
+
+    /// How non-finite values are encoded.
+    const NON_FINITE: NonFinite = NonFinite::Ieee;
+
+    /// Whether conversions into the format saturate: if `true`, finite values whose magnitude is too large to represent are rounded to the largest finite value of the same sign. If `false`, they are rounded to infinity, or to NaN if the format has no infinities. Formats whose `NON_FINITE` is [`NonFinite::None`] always saturate.
+    const SATURATING: bool = false;
+}
+
+/// The bfloat16 (brain floating point) format: the upper 16 bits of an `f32`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BFloat16;
+
+impl MiniFloatFormat for BFloat16 {
+    const EXPONENT_BITS: u32 = 8;
+    const MANTISSA_BITS: u32 = 7;
+}
+
+/// The E4M3 format from the OCP 8-bit floating point specification, which has no infinities and whose largest finite value is `448`. Conversions into this format do not saturate.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct E4M3;
+
+impl MiniFloatFormat for E4M3 {
+    const EXPONENT_BITS: u32 = 4;
+    const MANTISSA_BITS: u32 = 3;
+    const NON_FINITE: NonFinite = NonFinite::NanOnly;
+}
+
+/// The E5M2 format from the OCP 8-bit floating point specification, which follows the IEEE 754 conventions. Conversions into this format do not saturate.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct E5M2;
+
+impl MiniFloatFormat for E5M2 {
+    const EXPONENT_BITS: u32 = 5;
+    const MANTISSA_BITS: u32 = 2;
+}
+
+/// A floating point number of at most 16 bits whose layout and handling of non-finite values is described by the format `F`.
+///
+/// Unlike [`Float`], the width of a `MiniFloat` need not be a whole number of bytes, and it can describe formats without infinities or NaNs. `MiniFloat`s are a storage format: arithmetic should be performed by converting to `f32` (which is exact) and back (which is correctly rounded).
+#[repr(transparent)]
+pub struct MiniFloat<F> {
+    bits: u16,
+    format: PhantomData<F>,
+}
+
+impl<F> Clone for MiniFloat<F> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F> Copy for MiniFloat<F> {}
+
+impl<F: MiniFloatFormat> MiniFloat<F> {
+    const VALID: () = {
+        assert!(
+            F::EXPONENT_BITS >= 1 && F::EXPONENT_BITS <= 8,
+            "minifloat formats must have between 1 and 8 exponent bits"
+        );
+        assert!(
+            1 + F::EXPONENT_BITS + F::MANTISSA_BITS <= 16,
+            "minifloat formats must have at most 16 bits"
+        );
+        assert!(
+            !matches!(F::NON_FINITE, NonFinite::Ieee) || F::MANTISSA_BITS >= 1,
+            "IEEE minifloat formats must have at least one mantissa bit"
+        );
+    };
+
+    /// The total number of bits of the format.
+    pub const BITS: u32 = 1 + F::EXPONENT_BITS + F::MANTISSA_BITS;
+
+    /// The exponent bias of the format.
+    pub const EXP_BIAS: i32 = (1 << (F::EXPONENT_BITS - 1)) - 1;
+
+    const SIGN_MASK: u16 = 1 << (F::EXPONENT_BITS + F::MANTISSA_BITS);
+    const MANTISSA_MASK: u16 = (1 << F::MANTISSA_BITS) - 1;
+    const MAX_BIASED_EXP: u16 = (1 << F::EXPONENT_BITS) - 1;
+
+    /// the bit pattern of a positive NaN, or of positive zero if the format has no NaNs
+    const NAN_MAGNITUDE: u16 = match F::NON_FINITE {
+        NonFinite::Ieee => (Self::MAX_BIASED_EXP << F::MANTISSA_BITS) | (1 << (F::MANTISSA_BITS - 1)),
+        NonFinite::NanOnly => Self::SIGN_MASK - 1,
+        NonFinite::None => 0,
+    };
+
+    /// the bit pattern of positive infinity, or of the largest finite value if the format has no infinities
+    const INFINITY_MAGNITUDE: u16 = match F::NON_FINITE {
+        NonFinite::Ieee => Self::MAX_BIASED_EXP << F::MANTISSA_BITS,
+        _ => Self::MAX_MAGNITUDE,
+    };
+
+    const MAX_MAGNITUDE: u16 = match F::NON_FINITE {
+        NonFinite::Ieee => ((Self::MAX_BIASED_EXP - 1) << F::MANTISSA_BITS) | Self::MANTISSA_MASK,
+        NonFinite::NanOnly => Self::SIGN_MASK - 2,
+        NonFinite::None => Self::SIGN_MASK - 1,
+    };
+
+    pub const ZERO: Self = Self::from_bits(0);
+
+    pub const NEG_ZERO: Self = Self::from_bits(Self::SIGN_MASK);
+
+    pub const ONE: Self = Self::from_bits((Self::EXP_BIAS as u16) << F::MANTISSA_BITS);
+
+    pub const MAX: Self = Self::from_bits(Self::MAX_MAGNITUDE);
+
+    pub const MIN: Self = Self::from_bits(Self::SIGN_MASK | Self::MAX_MAGNITUDE);
+
+    pub const MIN_POSITIVE: Self = Self::from_bits(1 << F::MANTISSA_BITS);
+
+    pub const MIN_POSITIVE_SUBNORMAL: Self = Self::from_bits(1);
+
+    /// Creates a value from its bit pattern, which is stored in the lowest [`BITS`](Self::BITS) bits of `bits`. The remaining bits are ignored.
+    #[must_use]
+    #[inline]
+    pub const fn from_bits(bits: u16) -> Self {
+        let () = Self::VALID;
+        Self {
+            bits: bits & (Self::SIGN_MASK | (Self::SIGN_MASK - 1)),
+            format: PhantomData,
+        }
+    }
+
+    /// Returns the bit pattern of `self`, stored in the lowest [`BITS`](Self::BITS) bits.
+    #[must_use]
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.bits
+    }
+
+    #[inline]
+    const fn magnitude(self) -> u16 {
+        self.bits & !Self::SIGN_MASK
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_negative(self) -> bool {
+        self.bits & Self::SIGN_MASK != 0
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_sign_positive(self) -> bool {
+        !self.is_sign_negative()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_nan(self) -> bool {
+        match F::NON_FINITE {
+            NonFinite::Ieee => self.magnitude() > Self::INFINITY_MAGNITUDE,
+            NonFinite::NanOnly => self.magnitude() == Self::NAN_MAGNITUDE,
+            NonFinite::None => false,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_infinite(self) -> bool {
+        matches!(F::NON_FINITE, NonFinite::Ieee) && self.magnitude() == Self::INFINITY_MAGNITUDE
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        !self.is_nan() && !self.is_infinite()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self::from_bits(self.magnitude())
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn neg(self) -> Self {
+        Self::from_bits(self.bits ^ Self::SIGN_MASK)
+    }
+
+    /// the result of a conversion whose magnitude is too large to represent, where `to_infinity` is whether the rounding mode rounds such values to infinity rather than to the largest finite value
+    #[inline]
+    const fn overflow(negative: bool, to_infinity: bool) -> Self {
+        let magnitude = if F::SATURATING || !to_infinity {
+            Self::MAX_MAGNITUDE
+        } else {
+            match F::NON_FINITE {
+                NonFinite::Ieee => Self::INFINITY_MAGNITUDE,
+                NonFinite::NanOnly => Self::NAN_MAGNITUDE,
+                NonFinite::None => Self::MAX_MAGNITUDE,
+            }
+        };
+        Self::from_sign_and_magnitude(negative, magnitude)
+    }
+
+    #[inline]
+    const fn from_sign_and_magnitude(negative: bool, magnitude: u16) -> Self {
+        if negative {
+            Self::from_bits(Self::SIGN_MASK | magnitude)
+        } else {
+            Self::from_bits(magnitude)
+        }
+    }
+
+    /// Converts `value` to a value of the format, rounding according to the given rounding mode if it cannot be represented exactly. Values too large in magnitude to represent are rounded to the largest finite value if the rounding mode requires it, and otherwise are handled as described by [`MiniFloatFormat::SATURATING`]. NaN is converted to NaN, or to zero if the format has no NaNs. Infinity is converted to infinity if the format has infinities, and otherwise is treated as a finite value that is too large to represent.
+    #[must_use]
+    pub const fn from_float_round<const W: usize, const MB: usize>(
+        value: Float<W, MB>,
+        rounding: RoundingMode,
+    ) -> Self {
+        let negative = value.is_sign_negative();
+        if value.is_nan() {
+            return Self::from_sign_and_magnitude(negative, Self::NAN_MAGNITUDE);
+        }
+        if value.is_infinite() {
+            return if matches!(F::NON_FINITE, NonFinite::Ieee) {
+                Self::from_sign_and_magnitude(negative, Self::INFINITY_MAGNITUDE)
+            } else {
+                Self::overflow(negative, true)
+            };
+        }
+        if value.is_zero() {
+            return Self::from_sign_and_magnitude(negative, 0);
+        }
+        // `value = mantissa * 2^exponent`
+        let (_, exponent, mantissa) = value.into_signed_parts();
+        let exponent = exponent - MB as FloatExponent;
+        let mb = F::MANTISSA_BITS as FloatExponent;
+        let msb_exponent = exponent + mantissa.bit_width() as FloatExponent - 1;
+        if msb_exponent > Self::MAX_BIASED_EXP as FloatExponent - Self::EXP_BIAS {
+            // larger than any finite value, even after rounding, which avoids shifting by more than the width of the mantissa below
+            return Self::overflow(negative, rounding.overflows_to_infinity(negative));
+        }
+        // exponent of the last place of the result, which is at least the exponent of the last place of the subnormals
+        let mut ulp_exponent = msb_exponent - mb;
+        if ulp_exponent < 1 - Self::EXP_BIAS - mb {
+            ulp_exponent = 1 - Self::EXP_BIAS - mb;
+        }
+        let shift = ulp_exponent - exponent;
+        let rounded = if shift <= 0 {
+            // no precision is lost
+            mantissa.shl((-shift) as Exponent)
+        } else {
+            let shift = shift as Exponent;
+            let truncated = mantissa.checked_shr(shift);
+            let truncated = match truncated {
+                Some(truncated) => truncated,
+                None => Uint::ZERO,
+            };
+            let round_bit = shift <= Uint::<W>::BITS && mantissa.bit(shift - 1);
+            let sticky_bit = mantissa.trailing_zeros() < shift - 1;
+            if rounding.rounds_away_from_zero(negative, truncated.bit(0), round_bit, sticky_bit) {
+                truncated.add(Uint::ONE)
+            } else {
+                truncated
+            }
+        };
+        // the rounded mantissa has at most `MANTISSA_BITS + 2 <= 16` bits
+        let bytes = rounded.to_le_bytes();
+        let mut rounded = bytes[0] as u16;
+        if W > 1 {
+            rounded |= (bytes[1] as u16) << 8;
+        }
+        if rounded >> (mb + 1) != 0 {
+            // rounding carried into the next power of two
+            rounded >>= 1;
+            ulp_exponent += 1;
+        }
+        if rounded >> mb == 0 {
+            // subnormal or zero
+            return Self::from_sign_and_magnitude(negative, rounded);
+        }
+        let biased_exponent = ulp_exponent + mb + Self::EXP_BIAS;
+        if biased_exponent > Self::MAX_BIASED_EXP as FloatExponent {
+            return Self::overflow(negative, rounding.overflows_to_infinity(negative));
+        }
+        let magnitude = ((biased_exponent as u16) << mb) | (rounded & Self::MANTISSA_MASK);
+        if magnitude > Self::MAX_MAGNITUDE {
+            return Self::overflow(negative, rounding.overflows_to_infinity(negative));
+        }
+        Self::from_sign_and_magnitude(negative, magnitude)
+    }
+
+    /// Converts `self` to a [`Float`], rounding according to the given rounding mode if it cannot be represented exactly. NaN is converted to NaN and infinity to infinity.
+    #[must_use]
+    pub const fn to_float_round<const W: usize, const MB: usize>(
+        self,
+        rounding: RoundingMode,
+    ) -> Float<W, MB> {
+        let negative = self.is_sign_negative();
+        if self.is_nan() {
+            return if negative { Float::NAN.neg() } else { Float::NAN };
+        }
+        if self.is_infinite() {
+            return if negative { Float::NEG_INFINITY } else { Float::INFINITY };
+        }
+        let biased_exponent = (self.magnitude() >> F::MANTISSA_BITS) as FloatExponent;
+        let fraction = self.magnitude() & Self::MANTISSA_MASK;
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            (fraction, 1 - Self::EXP_BIAS)
+        } else {
+            (fraction | (1 << F::MANTISSA_BITS), biased_exponent - Self::EXP_BIAS)
+        };
+        let mantissa = Uint::<2>::from_le_bytes(mantissa.to_le_bytes());
+        Float::from_uint_parts(
+            negative,
+            exponent - F::MANTISSA_BITS as FloatExponent,
+            mantissa,
+            rounding,
+        )
+        .0
+    }
+
+    /// Converts an `f32` to the nearest value of the format, with ties rounding to even. Values too large in magnitude to represent are handled as described by [`MiniFloatFormat::SATURATING`]. NaN is converted to NaN, or to zero if the format has no NaNs. Infinity is converted to infinity if the format has infinities, and otherwise is treated as a finite value that is too large to represent.
+    #[must_use]
+    #[inline]
+    pub const fn from_f32(value: f32) -> Self {
+        let bits = Uint::from_le_bytes(value.to_bits().to_le_bytes());
+        Self::from_float_round(Float::<4, 23>::from_bits(bits), RoundingMode::NearestTiesEven)
+    }
+
+    /// Converts an `f64` to the nearest value of the format, with ties rounding to even. Special values and values too large to represent are handled as in [`from_f32`](Self::from_f32).
+    #[must_use]
+    #[inline]
+    pub const fn from_f64(value: f64) -> Self {
+        let bits = Uint::from_le_bytes(value.to_bits().to_le_bytes());
+        Self::from_float_round(Float::<8, 52>::from_bits(bits), RoundingMode::NearestTiesEven)
+    }
+
+    /// Converts `self` to an `f64`. This conversion is exact.
+    #[must_use]
+    #[inline]
+    pub const fn to_f64(self) -> f64 {
+        self.to_float_round::<8, 52>(RoundingMode::NearestTiesEven).to_f64()
+    }
+
+    /// Converts `self` to an `f32`. This conversion is exact.
+    #[must_use]
+    #[inline]
+    pub const fn to_f32(self) -> f32 {
+        self.to_float_round::<4, 23>(RoundingMode::NearestTiesEven).to_f32()
+    }
+}
+
+impl<F: MiniFloatFormat> Default for MiniFloat<F> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<F: MiniFloatFormat> PartialEq for MiniFloat<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_f64() == other.to_f64()
+    }
+}
+
+impl<F: MiniFloatFormat> PartialOrd for MiniFloat<F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.to_f64().partial_cmp(&other.to_f64())
+    }
+}
+
+impl<F: MiniFloatFormat> Neg for MiniFloat<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl<F: MiniFloatFormat> Debug for MiniFloat<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl<F: MiniFloatFormat> Display for MiniFloat<F> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.to_f32(), f)
+    }
+}
+
+impl<F: MiniFloatFormat> From<MiniFloat<F>> for f32 {
+    #[inline]
+    fn from(value: MiniFloat<F>) -> Self {
+        value.to_f32()
+    }
+}
+
+impl<F: MiniFloatFormat> From<MiniFloat<F>> for f64 {
+    #[inline]
+    fn from(value: MiniFloat<F>) -> Self {
+        value.to_f64()
+    }
+}
+
+impl<F: MiniFloatFormat> CastFrom<f32> for MiniFloat<F> {
+    #[inline]
+    fn cast_from(value: f32) -> Self {
+        Self::from_f32(value)
+    }
+}
+
+impl<F: MiniFloatFormat> CastFrom<f64> for MiniFloat<F> {
+    #[inline]
+    fn cast_from(value: f64) -> Self {
+        Self::from_f64(value)
+    }
+}
+
+impl<F: MiniFloatFormat, const W: usize, const MB: usize> CastFrom<Float<W, MB>> for MiniFloat<F> {
+    #[inline]
+    fn cast_from(value: Float<W, MB>) -> Self {
+        Self::from_float_round(value, RoundingMode::NearestTiesEven)
+    }
+}
+
+impl<F: MiniFloatFormat, const W: usize, const MB: usize> CastFrom<MiniFloat<F>> for Float<W, MB> {
+    #[inline]
+    fn cast_from(value: MiniFloat<F>) -> Self {
+        value.to_float_round(RoundingMode::NearestTiesEven)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{BF16, F8E4M3, F8E5M2};
+
+    macro_rules! test_round_trip {
+        ($($ty: ty), *) => {
+            $(
+                for bits in 0..(1u32 << <$ty>::BITS) {
+                    let a = <$ty>::from_bits(bits as u16);
+                    let b = <$ty>::from_f32(a.to_f32());
+                    let c = <$ty>::from_f64(a.to_f64());
+                    if a.is_nan() {
+                        assert!(b.is_nan() && c.is_nan());
+                    } else {
+                        assert_eq!(a.to_bits(), b.to_bits());
+                        assert_eq!(a.to_bits(), c.to_bits());
+                    }
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn round_trip() {
+        test_round_trip!(BF16, F8E4M3, F8E5M2);
+    }
+
+    #[test]
+    fn special_values() {
+        assert_eq!(F8E4M3::MAX.to_f32(), 448.0);
+        assert_eq!(F8E4M3::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-9));
+        assert_eq!(F8E5M2::MAX.to_f32(), 57344.0);
+        assert_eq!(F8E5M2::MIN_POSITIVE_SUBNORMAL.to_f32(), 2f32.powi(-16));
+        assert_eq!(BF16::MAX.to_f32(), f32::from_bits(0x7f7f0000));
+        assert_eq!(BF16::ONE.to_f32(), 1.0);
+        assert_eq!(F8E4M3::ONE.to_f32(), 1.0);
+
+        assert!(F8E4M3::from_f32(f32::NAN).is_nan());
+        assert!(F8E4M3::from_f32(f32::INFINITY).is_nan());
+        assert!(F8E4M3::from_f32(480.0).is_nan());
+        assert_eq!(F8E4M3::from_f32(464.0), F8E4M3::MAX); // tie rounds to even
+        assert_eq!(F8E4M3::from_f32(-450.0), F8E4M3::MIN);
+
+        assert!(F8E5M2::from_f32(f32::INFINITY).is_infinite());
+        assert!(F8E5M2::from_f32(61440.0).is_infinite());
+        assert_eq!(F8E5M2::from_f32(61439.0), F8E5M2::MAX);
+
+        assert_eq!(BF16::from_f32(1.0 + 2f32.powi(-8)).to_f32(), 1.0);
+        assert_eq!(BF16::from_f32(1.0 + 2f32.powi(-8) + 2f32.powi(-20)).to_f32(), 1.0 + 2f32.powi(-7));
+    }
+
+    #[test]
+    fn custom_formats() {
+        #[derive(Clone, Copy)]
+        struct E2M1;
+
+        impl MiniFloatFormat for E2M1 {
+            const EXPONENT_BITS: u32 = 2;
+            const MANTISSA_BITS: u32 = 1;
+            const NON_FINITE: NonFinite = NonFinite::None;
+        }
+
+        #[derive(Clone, Copy)]
+        struct SaturatingE4M3;
+
+        impl MiniFloatFormat for SaturatingE4M3 {
+            const EXPONENT_BITS: u32 = 4;
+            const MANTISSA_BITS: u32 = 3;
+            const NON_FINITE: NonFinite = NonFinite::NanOnly;
+            const SATURATING: bool = true;
+        }
+
+        type FP4 = MiniFloat<E2M1>;
+        let values: [f32; 8] = [0.0, 0.5, 1.0, 1.5, 2.0, 3.0, 4.0, 6.0];
+        for (bits, value) in values.into_iter().enumerate() {
+            assert_eq!(FP4::from_bits(bits as u16).to_f32(), value);
+            assert_eq!(FP4::from_bits(bits as u16 | 0b1000).to_f32(), -value);
+        }
+        assert_eq!(FP4::from_f32(1e10), FP4::MAX);
+        assert_eq!(FP4::from_f32(f32::NEG_INFINITY), FP4::MIN);
+        assert_eq!(FP4::from_f32(2.5).to_f32(), 2.0);
+        assert_eq!(FP4::from_f32(0.25).to_bits(), 0);
+        assert!(FP4::from_f32(f32::NAN).is_finite());
+
+        type F8E4M3Sat = MiniFloat<SaturatingE4M3>;
+        assert_eq!(F8E4M3Sat::from_f32(1e10).to_f32(), 448.0);
+        assert_eq!(F8E4M3Sat::from_f32(f32::NEG_INFINITY).to_f32(), -448.0);
+        assert!(F8E4M3Sat::from_f32(f32::NAN).is_nan());
+    }
+
+    /// the nearest finite value to `a`, with ties rounding to even, found by searching every finite value of the format
+    fn nearest_by_search<F: MiniFloatFormat>(a: f64) -> MiniFloat<F> {
+        let mut best = MiniFloat::<F>::ZERO;
+        for bits in 0..(1u32 << MiniFloat::<F>::BITS) {
+            let candidate = MiniFloat::<F>::from_bits(bits as u16);
+            if !candidate.is_finite() {
+                continue;
+            }
+            let (d_candidate, d_best) = ((candidate.to_f64() - a).abs(), (best.to_f64() - a).abs());
+            if d_candidate < d_best || (d_candidate == d_best && bits & 1 == 0) {
+                best = candidate;
+            }
+        }
+        best
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_from_f32_fp8(a: f32) -> quickcheck::TestResult {
+            // restrict to values which round to a finite value, as overflow is tested separately
+            let a = a % 512.0;
+            if !a.is_finite() || a.abs() >= 464.0 {
+                return quickcheck::TestResult::discard();
+            }
+            let e4m3 = F8E4M3::from_f32(a);
+            let e5m2 = F8E5M2::from_f32(a);
+            let expected_e4m3 = nearest_by_search::<E4M3>(a as f64);
+            let expected_e5m2 = nearest_by_search::<E5M2>(a as f64);
+            quickcheck::TestResult::from_bool(e4m3 == expected_e4m3 && e4m3.is_sign_negative() == a.is_sign_negative() && e5m2 == expected_e5m2)
+        }
+
+        fn quickcheck_from_f32_bf16(a: f32) -> quickcheck::TestResult {
+            if a.is_nan() {
+                return quickcheck::TestResult::discard();
+            }
+            let expected = Float::<2, 7>::cast_from(a).to_bits();
+            quickcheck::TestResult::from_bool(BF16::from_f32(a).to_bits() == u16::from_le_bytes(expected.to_le_bytes()))
+        }
+
+        fn quickcheck_from_f64_bf16(a: f64) -> quickcheck::TestResult {
+            if a.is_nan() {
+                return quickcheck::TestResult::discard();
+            }
+            let expected = Float::<2, 7>::cast_from(a).to_bits();
+            quickcheck::TestResult::from_bool(BF16::from_f64(a).to_bits() == u16::from_le_bytes(expected.to_le_bytes()))
+        }
+
+        fn quickcheck_from_float_round_f32(a: f32) -> quickcheck::TestResult {
+            if a.is_nan() {
+                return quickcheck::TestResult::discard();
+            }
+            quickcheck::TestResult::from_bool(matches_float_round(Float::<4, 23>::cast_from(a)))
+        }
+
+        fn quickcheck_from_float_round_f128(a: Float<16, 112>) -> quickcheck::TestResult {
+            if a.is_nan() {
+                return quickcheck::TestResult::discard();
+            }
+            quickcheck::TestResult::from_bool(matches_float_round(a))
+        }
+    }
+
+    /// whether converting `a` to `BF16` and `F8E5M2` agrees with converting it to `Float`s of the same formats in every rounding mode, and whether converting back is exact
+    fn matches_float_round<const W: usize, const MB: usize>(a: Float<W, MB>) -> bool {
+        let rounding_modes = [
+            RoundingMode::NearestTiesEven,
+            RoundingMode::NearestTiesAway,
+            RoundingMode::TowardPositive,
+            RoundingMode::TowardNegative,
+            RoundingMode::TowardZero,
+        ];
+        rounding_modes.into_iter().all(|rounding| {
+            let bf16 = BF16::from_float_round(a, rounding);
+            let expected_bf16 = Float::<2, 7>::from_float_round(a, rounding);
+            let e5m2 = F8E5M2::from_float_round(a, rounding);
+            let expected_e5m2 = Float::<1, 2>::from_float_round(a, rounding);
+            bf16.to_bits() == u16::from_le_bytes(expected_bf16.to_bits().to_le_bytes())
+                && e5m2.to_bits() == expected_e5m2.to_bits().to_le_bytes()[0] as u16
+                && bf16.to_float_round::<16, 112>(rounding).to_bits() == Float::<16, 112>::cast_from(expected_bf16).to_bits()
+                && e5m2.to_float_round::<8, 52>(rounding).to_bits() == Float::<8, 52>::cast_from(expected_e5m2).to_bits()
+        })
+    }
+}
@@ -1,28 +1,7 @@
 use crate::Byte;
-#[cfg(test)]
-use crate::cast::As;
 use crate::doc;
 use crate::{Exponent, Int, Uint};
 
-#[cfg(test)]
-use crate::types::{F32, F64};
-
-#[cfg(test)]
-impl From<f64> for F64 {
-    #[inline]
-    fn from(f: f64) -> Self {
-        Self::from_bits(f.to_bits().as_())
-    }
-}
-
-#[cfg(test)]
-impl From<f32> for F32 {
-    #[inline]
-    fn from(f: f32) -> Self {
-        Self::from_bits(f.to_bits().as_())
-    }
-}
-
 macro_rules! handle_nan {
     ($ret: expr; $($n: expr), +) => {
         if $($n.is_nan()) || + {
@@ -31,6 +10,8 @@ macro_rules! handle_nan {
     };
 }
 
+#[cfg(feature = "alloc")]
+mod bignat;
 mod bytes;
 mod cast;
 mod classify;
@@ -39,19 +20,25 @@ mod const_trait_fillers;
 mod consts;
 mod convert;
 mod math;
+pub mod minifloat;
 #[cfg(feature = "numtraits")]
 mod numtraits;
 mod ops;
-// mod parse;
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "numtraits")]
+mod radix;
 #[cfg(feature = "rand")]
 mod random;
 mod rounding;
+#[cfg(feature = "alloc")]
 mod to_str;
+mod wide;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(transparent)]
 pub struct Float<const W: usize, const MB: usize> {
@@ -61,8 +48,6 @@ pub struct Float<const W: usize, const MB: usize> {
 pub(crate) type FloatExponent = i32; // TODO: decide whether this should be i128 or i32 (or i64). benefit of i128: more exponents possible. benefit of i32: this is what f32, f64 use, aligns better with u32 exponents used for ints
 pub(crate) type UnsignedFloatExponent = u32; // TODO: change these to just Exponent and SignedExponent
 
-// TODO: implement rand traits
-
 impl<const W: usize, const MB: usize> Float<W, MB> {
     const MB: Exponent = MB as _;
     const BITS: Exponent = Uint::<W>::BITS;
@@ -186,6 +171,15 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
     }
 }
 
+// implementation if we don't have alloc, as otherwise can't call assert_eq! (since this requires Debug)
+// here, not in the to_str module, since that is enabled by the `alloc` feature
+#[cfg(not(feature = "alloc"))]
+impl<const W: usize, const MB: usize> core::fmt::Debug for Float<W, MB> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(&self.bits, f)
+    }
+}
+
 impl<const W: usize, const MB: usize> Default for Float<W, MB> {
     #[doc = doc::default!()]
     #[inline]
@@ -1,7 +1,12 @@
 use super::Float;
 use crate::cast::CastFrom;
 use crate::Integer;
-use num_traits::{AsPrimitive, Bounded, ConstOne, ConstZero, One, Zero, float::TotalOrder};
+use core::num::FpCategory;
+use num_traits::float::{FloatConst, FloatCore, TotalOrder};
+use num_traits::{
+    AsPrimitive, Bounded, ConstOne, ConstZero, Euclid, FromPrimitive, Inv, MulAdd, MulAddAssign,
+    Num, NumCast, One, Pow, Signed, ToPrimitive, Zero,
+};
 
 impl<const W: usize, const MB: usize> Bounded for Float<W, MB> {
     #[inline]
@@ -31,7 +36,7 @@ impl<const W: usize, const MB: usize> Zero for Float<W, MB> {
 
     #[inline]
     fn is_zero(&self) -> bool {
-        Self::is_zero(&self)
+        Self::is_zero(self)
     }
 }
 
@@ -43,40 +48,375 @@ impl<const W: usize, const MB: usize> One for Float<W, MB> {
 
     #[inline]
     fn is_one(&self) -> bool {
-        Self::ONE.eq(&self)
+        Self::ONE.eq(self)
     }
 }
 
-// impl<const W: usize, const MB: usize> Signed for Float<W, MB> {
-//     #[inline]
-//     fn is_negative(&self) -> bool {
-//         Self::is_sign_negative(*self)
-//     }
+impl<const W: usize, const MB: usize> MulAdd for Float<W, MB> {
+    type Output = Self;
 
-//     #[inline]
-//     fn is_positive(&self) -> bool {
-//         Self::is_sign_positive(*self)
-//     }
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        Self::mul_add(self, a, b)
+    }
+}
+
+impl<const W: usize, const MB: usize> MulAddAssign for Float<W, MB> {
+    #[inline]
+    fn mul_add_assign(&mut self, a: Self, b: Self) {
+        *self = Self::mul_add(*self, a, b);
+    }
+}
+
+impl<const W: usize, const MB: usize> Num for Float<W, MB> {
+    type FromStrRadixErr = crate::errors::ParseFloatError;
+
+    #[inline]
+    fn from_str_radix(string: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Self::parse_radix(string, radix)
+    }
+}
+
+impl<const W: usize, const MB: usize> Signed for Float<W, MB> {
+    #[inline]
+    fn is_negative(&self) -> bool {
+        Self::is_sign_negative(*self)
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        Self::is_sign_positive(*self)
+    }
+
+    #[inline]
+    fn abs(&self) -> Self {
+        Self::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if self <= other {
+            Self::ZERO
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        Self::signum(*self)
+    }
+}
+
+impl<const W: usize, const MB: usize> Euclid for Float<W, MB> {
+    #[inline]
+    fn div_euclid(&self, v: &Self) -> Self {
+        Self::div_euclid(*self, *v)
+    }
+
+    #[inline]
+    fn rem_euclid(&self, v: &Self) -> Self {
+        Self::rem_euclid(*self, *v)
+    }
+}
+
+impl<const W: usize, const MB: usize> Inv for Float<W, MB> {
+    type Output = Self;
+
+    #[inline]
+    fn inv(self) -> Self {
+        Self::recip(self)
+    }
+}
+
+impl<const W: usize, const MB: usize> Inv for &Float<W, MB> {
+    type Output = Float<W, MB>;
+
+    #[inline]
+    fn inv(self) -> Float<W, MB> {
+        Float::recip(*self)
+    }
+}
+
+macro_rules! pow_int_impl {
+    ($($int: ty), *) => {
+        $(
+            impl<const W: usize, const MB: usize> Pow<$int> for Float<W, MB> {
+                type Output = Self;
+
+                #[inline]
+                fn pow(self, rhs: $int) -> Self {
+                    Self::powi(self, rhs as i32)
+                }
+            }
+        )*
+    };
+}
+
+pow_int_impl!(i8, u8, i16, u16, i32);
+
+impl<const W: usize, const MB: usize> Pow<Self> for Float<W, MB> {
+    type Output = Self;
+
+    #[inline]
+    fn pow(self, rhs: Self) -> Self {
+        Self::powf(self, rhs)
+    }
+}
+
+macro_rules! to_primitive_int {
+    ($primitive: ty, $method: ident) => {
+        #[inline]
+        fn $method(&self) -> Option<$primitive> {
+            let trunc = self.trunc();
+            // the bounds are powers of two, so are exact (or infinite, in which case every finite value is in range)
+            let bits = <$primitive>::BITS - (<$primitive>::MIN != 0) as u32;
+            let upper = Self::TWO.powi(bits as i32);
+            let lower = if <$primitive>::MIN == 0 {
+                Self::ZERO
+            } else {
+                upper.neg()
+            };
+            if trunc >= lower && trunc < upper {
+                Some(<$primitive>::cast_from(trunc))
+            } else {
+                None
+            }
+        }
+    };
+}
+
+impl<const W: usize, const MB: usize> ToPrimitive for Float<W, MB> {
+    to_primitive_int!(u8, to_u8);
+    to_primitive_int!(u16, to_u16);
+    to_primitive_int!(u32, to_u32);
+    to_primitive_int!(u64, to_u64);
+    to_primitive_int!(u128, to_u128);
+    to_primitive_int!(usize, to_usize);
+    to_primitive_int!(i8, to_i8);
+    to_primitive_int!(i16, to_i16);
+    to_primitive_int!(i32, to_i32);
+    to_primitive_int!(i64, to_i64);
+    to_primitive_int!(i128, to_i128);
+    to_primitive_int!(isize, to_isize);
+
+    #[inline]
+    fn to_f32(&self) -> Option<f32> {
+        Some(Self::to_f32(*self))
+    }
+
+    #[inline]
+    fn to_f64(&self) -> Option<f64> {
+        Some(Self::to_f64(*self))
+    }
+}
+
+macro_rules! from_primitive {
+    ($primitive: ty, $method: ident) => {
+        #[inline]
+        fn $method(n: $primitive) -> Option<Self> {
+            Some(Self::cast_from(n))
+        }
+    };
+}
+
+impl<const W: usize, const MB: usize> FromPrimitive for Float<W, MB> {
+    from_primitive!(u8, from_u8);
+    from_primitive!(u16, from_u16);
+    from_primitive!(u32, from_u32);
+    from_primitive!(u64, from_u64);
+    from_primitive!(u128, from_u128);
+    from_primitive!(usize, from_usize);
+    from_primitive!(i8, from_i8);
+    from_primitive!(i16, from_i16);
+    from_primitive!(i32, from_i32);
+    from_primitive!(i64, from_i64);
+    from_primitive!(i128, from_i128);
+    from_primitive!(isize, from_isize);
+    from_primitive!(f32, from_f32);
+    from_primitive!(f64, from_f64);
+}
+
+impl<const W: usize, const MB: usize> NumCast for Float<W, MB> {
+    /// Integer values are converted via `i128` or `u128` where possible, so that they are not first rounded to `f64`. All other values are converted via `f64`, so may lose precision.
+    #[inline]
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        match n.to_f64() {
+            Some(f) if f % 1.0 != 0.0 => Some(Self::cast_from(f)),
+            f => n
+                .to_i128()
+                .map(Self::cast_from)
+                .or_else(|| n.to_u128().map(Self::cast_from))
+                .or_else(|| f.map(Self::cast_from)),
+        }
+    }
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// `(mantissa, exponent, sign)` such that `self = sign * mantissa * 2^exponent`, following the conventions of the primitive floats. if the mantissa has more than 64 bits, it is truncated to its leading 64 bits, and if the exponent does not fit in an `i16`, it is saturated
+    fn integer_decode(self) -> (u64, i16, i8) {
+        let (negative, exponent, mantissa) = self.into_raw_parts();
+        let mantissa = if exponent == 0 {
+            mantissa.shl(1)
+        } else {
+            mantissa.bitor(Self::MANTISSA_IMPLICIT_LEADING_ONE_MASK)
+        };
+        let mut exponent = exponent as i64 - Self::EXP_BIAS as i64 - MB as i64;
+        let shift = (Self::MB + 1).saturating_sub(u64::BITS);
+        exponent += shift as i64;
+        let mantissa = u64::cast_from(mantissa.shr(shift));
+        let exponent = exponent.clamp(i16::MIN as i64, i16::MAX as i64) as i16;
+        (mantissa, exponent, if negative { -1 } else { 1 })
+    }
+}
+
+macro_rules! float_method {
+    { $(fn $name: ident ($($arg: ident $(: $ty: ty)?), *) -> $ret: ty;) * } => {
+        $(
+            #[inline]
+            fn $name($($arg $(: $ty)?), *) -> $ret {
+                Self::$name($($arg), *)
+            }
+        )*
+    };
+}
+
+macro_rules! float_core_methods {
+    () => {
+        #[inline]
+        fn nan() -> Self {
+            Self::NAN
+        }
+
+        #[inline]
+        fn infinity() -> Self {
+            Self::INFINITY
+        }
+
+        #[inline]
+        fn neg_infinity() -> Self {
+            Self::NEG_INFINITY
+        }
+
+        #[inline]
+        fn neg_zero() -> Self {
+            Self::NEG_ZERO
+        }
+
+        #[inline]
+        fn min_value() -> Self {
+            Self::MIN
+        }
+
+        #[inline]
+        fn min_positive_value() -> Self {
+            Self::MIN_POSITIVE
+        }
+
+        #[inline]
+        fn epsilon() -> Self {
+            Self::EPSILON
+        }
+
+        #[inline]
+        fn max_value() -> Self {
+            Self::MAX
+        }
+
+        float_method! {
+            fn is_nan(self) -> bool;
+            fn is_infinite(self) -> bool;
+            fn is_finite(self) -> bool;
+            fn is_normal(self) -> bool;
+            fn is_subnormal(self) -> bool;
+            fn classify(self) -> FpCategory;
+            fn floor(self) -> Self;
+            fn ceil(self) -> Self;
+            fn round(self) -> Self;
+            fn trunc(self) -> Self;
+            fn fract(self) -> Self;
+            fn abs(self) -> Self;
+            fn signum(self) -> Self;
+            fn is_sign_positive(self) -> bool;
+            fn is_sign_negative(self) -> bool;
+            fn min(self, other: Self) -> Self;
+            fn max(self, other: Self) -> Self;
+            fn recip(self) -> Self;
+            fn powi(self, n: i32) -> Self;
+            fn to_degrees(self) -> Self;
+            fn to_radians(self) -> Self;
+            fn integer_decode(self) -> (u64, i16, i8);
+        }
+    };
+}
+
+impl<const W: usize, const MB: usize> FloatCore for Float<W, MB> {
+    float_core_methods!();
+}
 
-//     #[inline]
-//     fn abs(&self) -> Self {
-//         Self::abs(*self)
-//     }
+impl<const W: usize, const MB: usize> num_traits::Float for Float<W, MB> {
+    float_core_methods!();
 
-//     #[inline]
-//     fn abs_sub(&self, other: &Self) -> Self {
-//         if self <= other {
-//             Self::ZERO
-//         } else {
-//             *self - *other
-//         }
-//     }
+    float_method! {
+        fn mul_add(self, a: Self, b: Self) -> Self;
+        fn sqrt(self) -> Self;
+        fn powf(self, n: Self) -> Self;
+        fn exp(self) -> Self;
+        fn exp2(self) -> Self;
+        fn exp_m1(self) -> Self;
+        fn ln(self) -> Self;
+        fn log(self, base: Self) -> Self;
+        fn log2(self) -> Self;
+        fn log10(self) -> Self;
+        fn ln_1p(self) -> Self;
+        fn cbrt(self) -> Self;
+        fn hypot(self, other: Self) -> Self;
+        fn sin(self) -> Self;
+        fn cos(self) -> Self;
+        fn tan(self) -> Self;
+        fn sin_cos(self) -> (Self, Self);
+        fn asin(self) -> Self;
+        fn acos(self) -> Self;
+        fn atan(self) -> Self;
+        fn atan2(self, other: Self) -> Self;
+        fn sinh(self) -> Self;
+        fn cosh(self) -> Self;
+        fn tanh(self) -> Self;
+        fn asinh(self) -> Self;
+        fn acosh(self) -> Self;
+        fn atanh(self) -> Self;
+        fn copysign(self, sign: Self) -> Self;
+    }
+
+    /// The positive difference of `self` and `other`: `self - other` if `self > other`, `+0` if `self <= other`, and NaN if either argument is NaN.
+    #[inline]
+    fn abs_sub(self, other: Self) -> Self {
+        handle_nan!(Self::NAN; self, other);
+        if self <= other {
+            Self::ZERO
+        } else {
+            self - other
+        }
+    }
+}
+
+macro_rules! float_const {
+    { $($name: ident), * } => {
+        impl<const W: usize, const MB: usize> FloatConst for Float<W, MB> {
+            $(
+                #[inline]
+                fn $name() -> Self {
+                    Self::$name
+                }
+            )*
+        }
+    };
+}
 
-//     #[inline]
-//     fn signum(&self) -> Self {
-//         Self::signum(*self)
-//     }
-// }
+float_const! {
+    E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4,
+    FRAC_PI_6, FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG2_E, PI, SQRT_2, TAU, LOG10_2, LOG2_10
+}
 
 macro_rules! impl_as_primitive {
     ($($primitive: ty), *) => {
@@ -111,15 +451,6 @@ impl<const W: usize, const MB: usize, const S: bool, const N: usize, const B: us
     }
 }
 
-// impl<const W: usize, const MB: usize, const S: bool, const N: usize, const B: usize, const OM: u8>
-//     AsPrimitive<Integer<S, N, B, OM>> for Float<W, MB>
-// {
-//     #[inline]
-//     fn as_(self) -> Integer<S, N, B, OM> {
-//         Integer::cast_from(self)
-//     }
-// }
-
 impl<const W1: usize, const MB1: usize, const W2: usize, const MB2: usize>
     AsPrimitive<Float<W2, MB2>> for Float<W1, MB1>
 {
@@ -132,15 +463,41 @@ impl<const W1: usize, const MB1: usize, const W2: usize, const MB2: usize>
 impl<const W: usize, const MB: usize> TotalOrder for Float<W, MB> {
     #[inline]
     fn total_cmp(&self, other: &Self) -> core::cmp::Ordering {
-        Self::total_cmp(&self, other)
+        Self::total_cmp(self, other)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::test::test_bignum;
+    use crate::types::F64;
+    use num_traits::Float;
     use super::*;
 
+    macro_rules! test_to_primitive {
+        ($($primitive: ty), *) => {
+            paste::paste! {
+                $(
+                    test_bignum! {
+                        function: <ftest as ToPrimitive>::[<to_ $primitive>](a: ref &ftest)
+                    }
+                )*
+            }
+        };
+    }
+
+    macro_rules! test_from_primitive {
+        ($($primitive: ty), *) => {
+            paste::paste! {
+                $(
+                    test_bignum! {
+                        function: <ftest as FromPrimitive>::[<from_ $primitive>](n: $primitive)
+                    }
+                )*
+            }
+        };
+    }
+
     crate::test::test_all! {
         testing floats;
 
@@ -153,5 +510,99 @@ mod tests {
         test_bignum! {
             function: <ftest as TotalOrder>::total_cmp(a: ref &ftest, b: ref &ftest)
         }
+        test_bignum! {
+            function: <ftest as MulAdd>::mul_add(a: ftest, b: ftest, c: ftest),
+            skip: a.mul_add(b, c).is_nan()
+        }
+
+        test_to_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+        test_from_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+        test_bignum! {
+            function: <ftest as Inv>::inv(a: ftest)
+        }
+        test_bignum! {
+            function: <ftest as FloatCore>::integer_decode(a: ftest)
+        }
+        test_bignum! {
+            function: <ftest as FloatCore>::classify(a: ftest)
+        }
+        test_bignum! {
+            function: <ftest as FloatCore>::signum(a: ftest)
+        }
+        test_bignum! {
+            function: <ftest as Signed>::abs_sub(a: ref &ftest, b: ref &ftest)
+        }
+        test_bignum! {
+            function: <ftest as Signed>::is_negative(a: ref &ftest)
+        }
+        test_bignum! {
+            function: <ftest as Float>::abs_sub(a: ftest, b: ftest)
+        }
+        test_bignum! {
+            function: <ftest as Float>::integer_decode(a: ftest)
+        }
+
+        #[test]
+        fn float_const() {
+            use crate::test::TestConvert;
+
+            macro_rules! assert_consts_eq {
+                ($($name: ident), *) => {
+                    $(
+                        assert_eq!(
+                            TestConvert::into(<FTEST as FloatConst>::$name()),
+                            TestConvert::into(<ftest as FloatConst>::$name()),
+                            stringify!($name)
+                        );
+                    )*
+                };
+            }
+
+            assert_consts_eq!(
+                E, FRAC_1_PI, FRAC_1_SQRT_2, FRAC_2_PI, FRAC_2_SQRT_PI, FRAC_PI_2, FRAC_PI_3,
+                FRAC_PI_4, FRAC_PI_6, FRAC_PI_8, LN_10, LN_2, LOG10_E, LOG2_E, PI, SQRT_2, TAU,
+                LOG10_2, LOG2_10
+            );
+        }
+    }
+
+    #[test]
+    fn from_str_radix() {
+        use crate::types::F32;
+
+        fn parse(s: &str, radix: u32) -> Option<f64> {
+            <F64 as Num>::from_str_radix(s, radix).ok().map(F64::to_f64)
+        }
+
+        assert_eq!(parse("1.8p-3", 16), Some(0.1875));
+        assert_eq!(parse("1P4", 16), Some(16.0));
+        assert_eq!(parse("1e5", 16), Some(485.0));
+        assert_eq!(parse("-101.01", 2), Some(-5.25));
+        assert_eq!(parse("zZ", 36), Some(1295.0));
+        assert_eq!(parse("0.1", 3), Some(1.0 / 3.0));
+        assert_eq!(parse("-0.000", 7), Some(-0.0));
+        assert_eq!(parse("1.5e3", 10), Some(1500.0));
+        assert_eq!(parse("-inf", 16), Some(f64::NEG_INFINITY));
+        assert!(parse("NaN", 2).unwrap().is_nan());
+        assert_eq!(parse("1p-100000", 16), Some(0.0));
+        assert_eq!(parse("1p100000", 16), Some(f64::INFINITY));
+
+        assert_eq!(parse("", 2), None);
+        assert_eq!(parse(".", 2), None);
+        assert_eq!(parse("12", 2), None);
+        assert_eq!(parse("1p3", 8), None);
+        assert_eq!(parse("1p", 16), None);
+
+        // halfway between `1` and the next float, so rounds to even
+        let halfway = <F32 as Num>::from_str_radix("1.000000000000000000000001", 2).unwrap();
+        assert_eq!(halfway.to_f32(), 1.0);
+        let above_halfway = <F32 as Num>::from_str_radix("1.0000000000000000000000011", 2).unwrap();
+        assert_eq!(above_halfway.to_f32(), 1.0 + f32::EPSILON);
+        // more digits than fit in the intermediate mantissa, the last of which breaks the tie
+        let halfway = <F32 as Num>::from_str_radix("1.000001000000000000000000000000", 16).unwrap();
+        assert_eq!(halfway.to_f32(), 1.0);
+        let above_halfway = <F32 as Num>::from_str_radix("1.000001000000000000000000000001", 16).unwrap();
+        assert_eq!(above_halfway.to_f32(), 1.0 + f32::EPSILON);
     }
 }
@@ -1,106 +1,117 @@
 use super::Float;
-use crate::{Exponent, Uint, float::UnsignedFloatExponent};
+use crate::float::FloatExponent;
+use crate::float::wide::Wide;
+use crate::{Exponent, FloatStatus, RoundingMode, doc};
+use core::cmp::Ordering;
 use core::num::FpCategory;
 
 impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// position of the most significant bit of both operands of an addition, after they have been aligned. this leaves one bit of headroom for the carry, and ensures the lowest set bit of any exact product of two mantissas is above bit zero
+    const ADD_ALIGN_POSITION: Exponent = Wide::<W>::BITS - 2;
+
+    /// shifts a non-zero mantissa so that its most significant bit is at `ADD_ALIGN_POSITION`, adjusting the exponent so that the value `mantissa * 2^exponent` is unchanged
     #[inline]
-    pub(super) fn add_internal(self, rhs: Self, negative: bool) -> Self {
-        let (a, b) = if rhs.abs().total_cmp(&self.abs()) == core::cmp::Ordering::Greater {
-            // If b has is larger than a, swap a and b so that a has the larger exponent
-            (rhs, self)
-        } else {
-            (self, rhs)
-        };
-        let (_, a_exp, a_mant) = a.into_normalised_signed_parts();
-        let (_, b_exp, b_mant) = b.into_normalised_signed_parts();
-        let exp_diff = (a_exp - b_exp) as UnsignedFloatExponent; // guaranteed to be non-negative since a >= b
+    pub(super) const fn align_wide_parts(
+        sign: bool,
+        exponent: FloatExponent,
+        mantissa: Wide<W>,
+    ) -> (bool, FloatExponent, Wide<W>) {
+        let shift = Self::ADD_ALIGN_POSITION + 1 - mantissa.bit_width();
+        (sign, exponent - shift as FloatExponent, mantissa.shl(shift))
+    }
 
-        // If the shift causes an overflow, the b_mant is too small so is set to 0
-        let (exp, mant) = if UnsignedFloatExponent::BITS - exp_diff.leading_zeros() <= Exponent::BITS
-        {
-            // number of bits needed to store exp_diff is less than bit width of Exponent, so can cast
-            let exp_diff = exp_diff as Exponent;
-            match b_mant.checked_shr(exp_diff) {
-                // shift b_mant so it is aligned (in terms of exponents) with a_mant, so we can add them
-                Some(shifted) => {
-                    let mut mant = a_mant + shifted;
-                    if mant.bit(Self::MB + 1) {
-                        // overflow occurred
-                        let mut shifted_mant: Uint<W> = mant >> 1;
-                        let gte_half = mant.is_odd(); // if discarded bits are at least a half
-                        let round_up = gte_half
-                            && !(b_mant.trailing_zeros() >= exp_diff && shifted_mant.is_even()); // round by ties-to-even
-                        if round_up {
-                            shifted_mant += Uint::ONE;
-                        }
-                        (a_exp + 1, shifted_mant)
-                    } else {
-                        // no overflow yet, but still need to check for overflow when performing ties-to-even rounding
-                        let round_up = b_mant.bit(exp_diff - 1)
-                            && !(b_mant.trailing_zeros() >= exp_diff - 1 && mant.is_even()); // round according to ties-to-even. exp_diff - 1 will be non-negative, since if exp_diff = 0, then we would have had the overflow condition earlier
-                        if round_up {
-                            mant += Uint::ONE;
-                        }
-                        if mant.bit(Self::MB + 1) {
-                            // overflow occurred
-                            debug_assert!(mant.is_even()); // since overflow occurred and we added one, the result must be even
-                            (a_exp + 1, mant >> 1) // don't need to worry about checking for round up here, as mantissa is even, so when right shifted by 1, the discarded bits will be less than half (i.e. no round up)
-                        } else {
-                            (a_exp, mant)
-                        }
-                    }
-                }
-                None => (a_exp, a_mant), // no round up since the type holding b_mant has bit-width <= exp_diff, and b_mant has bit-width strictly smaller than the type's bit-width (since we enforce the float exponent to be at least one bit wide)
-            }
-        } else {
-            // we can't even cast exp_diff to Exponent, so it would be right-shifted to 0, and no round up since b_mant can't ever be the full width of Exponent::MAX (since we enforce all bnum types to have bit width <= Exponent::MAX, and we enforce that the float exponent takes at least one bit)
-            (a_exp, a_mant)
+    #[inline]
+    pub(super) const fn into_aligned_wide_parts(self) -> (bool, FloatExponent, Wide<W>) {
+        let (sign, exponent, mantissa) = self.into_signed_parts();
+        Self::align_wide_parts(
+            sign,
+            exponent - Self::MB_AS_FLOAT_EXP,
+            Wide::from_uint(mantissa),
+        )
+    }
+
+    /// rounds the exact sum of two non-zero values which have been aligned with `align_wide_parts`
+    #[inline]
+    pub(super) fn add_aligned_wide_parts(
+        a: (bool, FloatExponent, Wide<W>),
+        b: (bool, FloatExponent, Wide<W>),
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        // both mantissas have their leading one in the same position, so we can compare the magnitudes by comparing the exponents first and then the mantissas
+        let a_larger = match a.1.cmp(&b.1) {
+            Ordering::Equal => a.2.cmp(&b.2),
+            ordering => ordering,
         };
-        if exp >= Self::MAX_EXP {
-            debug_assert!(exp.eq(&Self::MAX_EXP)); // shouldn't be possible for exponent to have exceeded maximum exponent
-            return if negative {
-                Self::NEG_INFINITY
+        let ((large_negative, large_exp, large_mant), (small_negative, small_exp, small_mant)) =
+            if let Ordering::Less = a_larger {
+                (b, a)
             } else {
-                Self::INFINITY
+                (a, b)
             };
+
+        let exp_diff = (large_exp - small_exp) as Exponent; // non-negative, since the large operand has the larger magnitude
+        let small_mant = small_mant.shr_jamming(exp_diff);
+        if large_negative == small_negative {
+            Self::from_wide_parts(large_negative, large_exp, large_mant.add(small_mant), rounding)
+        } else {
+            let diff = large_mant.sub(small_mant);
+            if diff.is_zero() {
+                return (Self::exact_zero_sum(rounding), FloatStatus::OK);
+            }
+            Self::from_wide_parts(large_negative, large_exp, diff, rounding)
         }
-        Self::from_normalised_signed_parts(negative, exp, mant)
     }
 
     #[inline]
-    pub(crate) fn add(self, rhs: Self) -> Self {
+    fn add_internal(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
+        Self::add_aligned_wide_parts(
+            self.into_aligned_wide_parts(),
+            rhs.into_aligned_wide_parts(),
+            rounding,
+        )
+    }
+
+    /// Computes `self + rhs`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn add_round(self, rhs: Self, rounding: RoundingMode) -> Self {
+        self.add_with_status(rhs, rounding).0
+    }
+
+    /// Computes `self + rhs`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn add_with_status(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
         let self_negative = self.is_sign_negative();
         let rhs_negative = rhs.is_sign_negative();
 
-        match (self.classify(), rhs.classify()) {
-            (FpCategory::Nan, _) => self,
-            (_, FpCategory::Nan) => rhs,
+        let exact = match (self.classify(), rhs.classify()) {
+            (FpCategory::Nan, _) => return (self, Self::nan_operands_status(&[self, rhs])),
+            (_, FpCategory::Nan) => return (rhs, Self::nan_operands_status(&[rhs])),
             (FpCategory::Infinite, FpCategory::Infinite) => {
                 if self_negative != rhs_negative {
-                    Self::NAN
-                } else {
-                    self
+                    return (Self::NAN, FloatStatus::INVALID);
                 }
+                self
             }
             (FpCategory::Infinite, _) => self,
             (_, FpCategory::Infinite) => rhs,
             (FpCategory::Zero, FpCategory::Zero) => {
-                if self_negative && rhs_negative {
-                    Self::NEG_ZERO
+                if self_negative == rhs_negative {
+                    self
                 } else {
-                    Self::ZERO
+                    Self::exact_zero_sum(rounding)
                 }
-            },
+            }
             (FpCategory::Zero, _) => rhs,
             (_, FpCategory::Zero) => self,
-            (_, _) => {
-                if self_negative != rhs_negative {
-                    self.sub_internal(rhs, self_negative)
-                } else {
-                    let r = self.add_internal(rhs, self_negative);
-                    r
-                }
-            }
-        }
+            (_, _) => return self.add_internal(rhs, rounding),
+        };
+        (exact, FloatStatus::OK)
+    }
+
+    #[inline]
+    pub(crate) fn add(self, rhs: Self) -> Self {
+        self.add_round(rhs, RoundingMode::DEFAULT)
     }
 }
@@ -1,101 +1,87 @@
 use super::Float;
-use crate::Exponent;
-use crate::Uint;
-use crate::cast::As;
-use crate::float::FloatExponent;
-use crate::float::UnsignedFloatExponent;
+use crate::float::wide::Wide;
+use crate::{FloatStatus, RoundingMode, Uint, doc};
 use core::num::FpCategory;
 
 impl<const W: usize, const MB: usize> Float<W, MB> {
     #[inline]
-    pub(crate) fn div_internal(self, rhs: Self, negative: bool) -> Self {
-        let (a, b) = (self, rhs);
-        let (_, e1, s1) = a.into_biased_parts();
-        let (_, e2, s2) = b.into_biased_parts();
+    fn div_internal(
+        self,
+        rhs: Self,
+        negative: bool,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let (_, self_exp, self_mant) = self.into_normalised_signed_parts();
+        let (_, rhs_exp, rhs_mant) = rhs.into_normalised_signed_parts();
 
-        let b1 = s1.bit_width();
-        let b2 = s2.bit_width();
-
-        let mut e =
-            (e1 as FloatExponent) - (e2 as FloatExponent) + Self::EXP_BIAS + (b1 as FloatExponent)
-                - (b2 as FloatExponent)
-                - 1;
-
-        let mut extra_shift = 0;
-        if !e.is_positive() {
-            extra_shift = (1 - e) as UnsignedFloatExponent;
-            e = 1;
+        // both mantissas have their leading one at bit MB. we ensure that the dividend is at least the divisor so that the quotient has its leading one at the first bit generated
+        let mut exp = self_exp - rhs_exp;
+        let mut rem = self_mant;
+        if let core::cmp::Ordering::Less = rem.cmp(&rhs_mant) {
+            rem = rem.shl(1);
+            exp -= 1;
         }
 
-        let total_shift = (MB as FloatExponent + 1 + b2 as FloatExponent - b1 as FloatExponent)
-            - (extra_shift as FloatExponent);
-
-        let large = if !total_shift.is_negative() {
-            (s1.as_::<Uint<{ W * 2 }>>()) << total_shift
-        } else {
-            (s1.as_::<Uint<{ W * 2 }>>()) >> (-total_shift)
-        };
-        let mut division = (large / (s2.as_::<Uint<{ W * 2 }>>())).as_::<Uint<W>>();
-
-        let rem = if division.bit_width() != Self::MB + 2 {
-            let rem = (large % (s2.as_::<Uint<{ W * 2 }>>())).as_::<Uint<W>>();
-            rem
-        } else {
-            e += 1;
-            division = ((large >> 1 as Exponent) / (s2.as_::<Uint<{ W * 2 }>>())).as_::<Uint<W>>();
-            let rem = ((large >> 1 as Exponent) % (s2.as_::<Uint<{ W * 2 }>>())).as_::<Uint<W>>();
-            rem
-        };
-        if rem * crate::n!(0b10) > s2 {
-            division += crate::n!(0b1);
-        } else if rem * crate::n!(0b10) == s2 {
-            if division.is_odd() {
-                division += crate::n!(0b1);
+        // long division, generating one bit more than the precision. the remainder is always less than twice the divisor, so fits in MB + 2 bits
+        let mut quotient = Uint::<W>::ZERO;
+        let mut i = 0;
+        while i < Self::MB + 2 {
+            quotient = quotient.shl(1);
+            if !matches!(rem.cmp(&rhs_mant), core::cmp::Ordering::Less) {
+                rem = rem.sub(rhs_mant);
+                quotient.set_bit(0, true);
             }
-        }
-        if division.bit_width() == Self::MB + 2 {
-            e += 1;
-            division >>= 1 as Exponent;
+            rem = rem.shl(1);
+            i += 1;
         }
 
-        if e > Self::MAX_EXP + Self::EXP_BIAS - 1 {
-            return Self::INFINITY;
-        }
-
-        if e == 1 && division.bit_width() < Self::MB + 1 {
-            return Self::from_raw_parts(negative, 0, division);
-        }
+        // a non-zero remainder is jammed into an extra sticky bit
+        let mut mantissa = Wide::from_uint(quotient).shl(1);
+        mantissa.set_bit(0, !rem.is_zero());
+        Self::from_wide_parts(
+            negative,
+            exp - Self::MB_AS_FLOAT_EXP - 2,
+            mantissa,
+            rounding,
+        )
+    }
 
-        if division >> Self::MB != Uint::ZERO {
-            division ^= Uint::ONE << Self::MB;
-        }
-        Self::from_raw_parts(negative, e as UnsignedFloatExponent, division)
+    /// Computes `self / rhs`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn div_round(self, rhs: Self, rounding: RoundingMode) -> Self {
+        self.div_with_status(rhs, rounding).0
     }
 
+    /// Computes `self / rhs`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    #[must_use = doc::must_use_op!(float)]
     #[inline]
-    pub(super) fn div(self, rhs: Self) -> Self {
+    pub fn div_with_status(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
         let negative = self.is_sign_negative() ^ rhs.is_sign_negative();
+        let infinity = if negative {
+            Self::NEG_INFINITY
+        } else {
+            Self::INFINITY
+        };
+        let zero = if negative { Self::NEG_ZERO } else { Self::ZERO };
         match (self.classify(), rhs.classify()) {
-            (FpCategory::Nan, _) | (_, FpCategory::Nan) => Self::NAN,
-            (FpCategory::Infinite, FpCategory::Infinite) => Self::NAN,
-            (FpCategory::Zero, FpCategory::Zero) => Self::NAN,
-            (FpCategory::Infinite, _) | (_, FpCategory::Zero) => {
-                if negative {
-                    Self::NEG_INFINITY
-                } else {
-                    Self::INFINITY
-                }
+            (FpCategory::Nan, _) | (_, FpCategory::Nan) => {
+                (Self::NAN, Self::nan_operands_status(&[self, rhs]))
             }
-            (FpCategory::Zero, _) | (_, FpCategory::Infinite) => {
-                if negative {
-                    Self::NEG_ZERO
-                } else {
-                    Self::ZERO
-                }
+            (FpCategory::Infinite, FpCategory::Infinite) | (FpCategory::Zero, FpCategory::Zero) => {
+                (Self::NAN, FloatStatus::INVALID)
             }
-            (_, _) => self.div_internal(rhs, negative),
+            (FpCategory::Infinite, _) => (infinity, FloatStatus::OK),
+            (_, FpCategory::Zero) => (infinity, FloatStatus::DIV_BY_ZERO),
+            (FpCategory::Zero, _) | (_, FpCategory::Infinite) => (zero, FloatStatus::OK),
+            (_, _) => self.div_internal(rhs, negative, rounding),
         }
     }
+
+    #[inline]
+    pub(super) fn div(self, rhs: Self) -> Self {
+        self.div_round(rhs, RoundingMode::DEFAULT)
+    }
 }
 
 /*/// Returns tuple of division and whether u is less than v
@@ -1,12 +1,12 @@
 use super::Float;
 use crate::helpers::full_op_impl;
 use core::iter::{Iterator, Product, Sum};
-use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
 
 mod add;
-#[cfg(nightly)]
 mod div;
 mod mul;
+mod mul_add;
 mod rem;
 mod sub;
 
@@ -80,7 +80,7 @@ impl<const W: usize, const MB: usize> Div for Float<W, MB> {
     }
 }
 
-// full_op_impl!(<const W: usize, const MB: usize> Div, DivAssign, Float<W, MB>, div, div_assign for Float<W, MB>);
+full_op_impl!(<const W: usize, const MB: usize> Div, DivAssign, Float<W, MB>, div, div_assign for Float<W, MB>);
 
 impl<const W: usize, const MB: usize> Rem for Float<W, MB> {
     type Output = Self;
@@ -111,9 +111,101 @@ impl<const W: usize, const MB: usize> Neg for &Float<W, MB> {
     }
 }
 
+/// checks that the results of an operation under each rounding mode are consistent: the directed roundings must bracket the exact result with adjacent floats, and each other mode must pick one of these two
+#[cfg(test)]
+fn rounding_modes_consistent<const W: usize, const MB: usize>(
+    results: [Float<W, MB>; 5],
+) -> bool {
+    let [nearest_even, nearest_away, up, down, zero] = results;
+    if down != up && down.next_up().to_bits() != up.to_bits() {
+        return false;
+    }
+    let toward_zero = if down.is_sign_negative() { up } else { down };
+    down <= nearest_even
+        && nearest_even <= up
+        && (nearest_away == down || nearest_away == up)
+        && zero.to_bits() == toward_zero.to_bits()
+}
+
+#[cfg(test)]
+macro_rules! test_rounding_modes {
+    ($method: ident ($($param: ident), *)) => {
+        paste::paste! {
+            quickcheck::quickcheck! {
+                fn [<quickcheck_ $method _rounding_modes>](a: FTEST $(, $param: FTEST)*) -> quickcheck::TestResult {
+                    use crate::RoundingMode;
+
+                    let results = [
+                        RoundingMode::NearestTiesEven,
+                        RoundingMode::NearestTiesAway,
+                        RoundingMode::TowardPositive,
+                        RoundingMode::TowardNegative,
+                        RoundingMode::TowardZero,
+                    ].map(|rounding| a.$method($($param, )* rounding));
+                    if results[0].is_nan() {
+                        return quickcheck::TestResult::discard();
+                    }
+                    quickcheck::TestResult::from_bool(super::rounding_modes_consistent(results))
+                }
+            }
+        }
+    };
+}
+
+/// checks the exception flags raised by an operation against its results: `nearest` and `toward_zero` are the results and flags of the operation when rounding to nearest and toward zero, and `down` and `up` are the results when rounding toward negative and positive infinity
+#[cfg(test)]
+fn status_consistent<const W: usize, const MB: usize>(
+    operands: &[Float<W, MB>],
+    nearest: (Float<W, MB>, crate::FloatStatus),
+    toward_zero: (Float<W, MB>, crate::FloatStatus),
+    [down, up]: [Float<W, MB>; 2],
+) -> bool {
+    use crate::FloatStatus;
+
+    let (result, status) = nearest;
+    if result.is_nan() {
+        let nan_operand = operands.iter().any(|f| f.is_nan());
+        let signaling_operand = operands.iter().any(|f| f.is_signaling_nan());
+        let invalid = !nan_operand || signaling_operand;
+        return status == if invalid { FloatStatus::INVALID } else { FloatStatus::OK };
+    }
+    if status.contains(FloatStatus::DIV_BY_ZERO) {
+        return status == FloatStatus::DIV_BY_ZERO && result.is_infinite();
+    }
+    let inexact = down != up;
+    let overflow = operands.iter().all(|f| f.is_finite()) && result.is_infinite();
+    let underflow = inexact && toward_zero.0.abs() < Float::MIN_POSITIVE;
+    [status, toward_zero.1].iter().all(|status| {
+        status.contains(FloatStatus::INEXACT) == inexact
+            && status.contains(FloatStatus::OVERFLOW) == overflow
+            && status.contains(FloatStatus::UNDERFLOW) == underflow
+            && !status.contains(FloatStatus::INVALID)
+    })
+}
+
+#[cfg(test)]
+macro_rules! test_status {
+    ($method: ident ($($param: ident), *)) => {
+        paste::paste! {
+            quickcheck::quickcheck! {
+                fn [<quickcheck_ $method _status>](a: FTEST $(, $param: FTEST)*) -> bool {
+                    use crate::RoundingMode;
+
+                    let nearest = a.[<$method _with_status>]($($param, )* RoundingMode::NearestTiesEven);
+                    let toward_zero = a.[<$method _with_status>]($($param, )* RoundingMode::TowardZero);
+                    let bounds = [RoundingMode::TowardNegative, RoundingMode::TowardPositive]
+                        .map(|rounding| a.[<$method _round>]($($param, )* rounding));
+                    super::status_consistent(&[a $(, $param)*], nearest, toward_zero, bounds)
+                }
+            }
+        }
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::test::test_bignum;
+    use crate::RoundingMode;
     use super::*;
 
     crate::test::test_all! {
@@ -137,14 +229,107 @@ mod tests {
                 (5.6143642e23f64 as ftest, 35279.223f64 as ftest)
             ]
         }
-        // test_bignum! {
-        //     function: <ftest as Div>::div(a: ftest, b: ftest)
-        // }
         test_bignum! {
-            function: <ftest as Rem>::rem(a: ftest, b: ftest)
+            function: <ftest as Div>::div(a: ftest, b: ftest),
+            skip: (a / b).is_nan(),
+            cases: [
+                (1.0, 3.0),
+                (ftest::MIN_POSITIVE, 3.0),
+                (ftest::MAX, ftest::MIN_POSITIVE),
+                (ftest::MIN_POSITIVE * ftest::EPSILON, 2.0)
+            ]
+        }
+        test_bignum! {
+            function: <ftest as Rem>::rem(a: ftest, b: ftest),
+            cases: [
+                (2.758e-198f64 as ftest, -6.329e-309f64 as ftest),
+                (1.0, ftest::MIN_POSITIVE / 3.0),
+                (ftest::MAX, ftest::MIN_POSITIVE * ftest::EPSILON),
+                (-ftest::MIN_POSITIVE * 12345.678, ftest::MIN_POSITIVE * 0.7),
+                (ftest::MIN_POSITIVE * 0.7, ftest::MIN_POSITIVE / 3.0),
+                (-ftest::MIN_POSITIVE / 3.0, ftest::MIN_POSITIVE * ftest::EPSILON * 7.0)
+            ]
         }
         test_bignum! {
             function: <ftest as Neg>::neg(f: ftest)
         }
+
+        test_rounding_modes!(add_round(b));
+        test_rounding_modes!(sub_round(b));
+        test_rounding_modes!(mul_round(b));
+        test_rounding_modes!(div_round(b));
+        test_rounding_modes!(mul_add_round(b, c));
+        test_rounding_modes!(sqrt_round());
+
+        test_status!(add(b));
+        test_status!(sub(b));
+        test_status!(mul(b));
+        test_status!(div(b));
+        test_status!(mul_add(b, c));
+        test_status!(sqrt());
+
+        #[test]
+        fn directed_rounding() {
+            let one = FTEST::ONE;
+            let tiny = FTEST::EPSILON.div(FTEST::from(4.0 as ftest));
+            assert_eq!(one.add_round(tiny, RoundingMode::TowardPositive), one.next_up());
+            assert_eq!(one.add_round(tiny, RoundingMode::NearestTiesEven), one);
+            assert_eq!(one.sub_round(tiny, RoundingMode::TowardNegative), one.next_down());
+            assert_eq!(one.sub_round(tiny, RoundingMode::TowardZero), one.next_down());
+            assert_eq!(one.neg().sub_round(tiny, RoundingMode::TowardZero), one.neg());
+            assert_eq!(one.neg().sub_round(tiny, RoundingMode::NearestTiesAway), one.neg());
+
+            let three = FTEST::from(3.0 as ftest);
+            let third_up = one.div_round(three, RoundingMode::TowardPositive);
+            let third_down = one.div_round(three, RoundingMode::TowardNegative);
+            assert_eq!(third_down.next_up(), third_up);
+            assert!(three.mul_round(third_down, RoundingMode::TowardNegative) < one);
+            assert!(three.mul_round(third_up, RoundingMode::TowardPositive) > one);
+
+            let two = FTEST::from(2.0 as ftest);
+            assert_eq!(two.sqrt_round(RoundingMode::TowardPositive), two.sqrt_round(RoundingMode::TowardNegative).next_up());
+
+            assert_eq!(FTEST::MAX.mul_round(two, RoundingMode::TowardZero), FTEST::MAX);
+            assert_eq!(FTEST::MAX.mul_round(two, RoundingMode::TowardPositive), FTEST::INFINITY);
+            assert_eq!(FTEST::MIN.mul_round(two, RoundingMode::TowardPositive), FTEST::MIN);
+            assert_eq!(FTEST::MIN.mul_round(two, RoundingMode::NearestTiesAway), FTEST::NEG_INFINITY);
+
+            // the exact zero sum of two values of opposite sign is negative only when rounding toward negative
+            assert!(one.sub_round(one, RoundingMode::TowardNegative).is_sign_negative());
+            assert!(one.sub_round(one, RoundingMode::TowardPositive).is_sign_positive());
+            assert!(FTEST::ZERO.add_round(FTEST::NEG_ZERO, RoundingMode::TowardNegative).is_sign_negative());
+            assert!(one.mul_add_round(one.neg(), one, RoundingMode::TowardNegative).is_sign_negative());
+        }
+
+        #[test]
+        fn exception_flags() {
+            use crate::FloatStatus;
+
+            let nearest = RoundingMode::NearestTiesEven;
+            let one = FTEST::ONE;
+            let two = FTEST::from(2.0 as ftest);
+            let three = FTEST::from(3.0 as ftest);
+            assert_eq!(one.add_with_status(two, nearest), (three, FloatStatus::OK));
+            assert_eq!(one.div_with_status(three, nearest).1, FloatStatus::INEXACT);
+            assert_eq!(one.div_with_status(FTEST::ZERO, nearest), (FTEST::INFINITY, FloatStatus::DIV_BY_ZERO));
+            assert_eq!(FTEST::ZERO.div_with_status(FTEST::ZERO, nearest).1, FloatStatus::INVALID);
+            assert_eq!(FTEST::INFINITY.sub_with_status(FTEST::INFINITY, nearest).1, FloatStatus::INVALID);
+            assert_eq!(FTEST::INFINITY.mul_with_status(FTEST::ZERO, nearest).1, FloatStatus::INVALID);
+            assert_eq!(one.neg().sqrt_with_status(nearest).1, FloatStatus::INVALID);
+            assert_eq!(FTEST::NAN.add_with_status(one, nearest).1, FloatStatus::OK);
+
+            let signaling_nan = FTEST::from_bits(FTEST::INFINITY.to_bits() | crate::Uint::ONE);
+            assert_eq!(signaling_nan.add_with_status(one, nearest).1, FloatStatus::INVALID);
+            assert_eq!(one.mul_add_with_status(one, signaling_nan, nearest).1, FloatStatus::INVALID);
+
+            assert_eq!(FTEST::MAX.mul_with_status(two, nearest), (FTEST::INFINITY, FloatStatus::OVERFLOW | FloatStatus::INEXACT));
+            assert_eq!(FTEST::MAX.mul_with_status(two, RoundingMode::TowardZero), (FTEST::MAX, FloatStatus::OVERFLOW | FloatStatus::INEXACT));
+
+            // an exact subnormal result does not underflow
+            assert_eq!(FTEST::MIN_POSITIVE.div_with_status(two, nearest).1, FloatStatus::OK);
+            assert_eq!(FTEST::MIN_POSITIVE.div_with_status(three, nearest).1, FloatStatus::UNDERFLOW | FloatStatus::INEXACT);
+            assert_eq!(FTEST::MIN_POSITIVE_SUBNORMAL.mul_with_status(FTEST::from(0.5 as ftest), nearest), (FTEST::ZERO, FloatStatus::UNDERFLOW | FloatStatus::INEXACT));
+        }
     }
 }
+
@@ -1,151 +1,57 @@
 use super::Float;
-use crate::Exponent;
-use crate::Int;
-use crate::Uint;
-use crate::float::FloatExponent;
-use crate::float::UnsignedFloatExponent;
+use crate::float::wide::Wide;
+use crate::{FloatStatus, RoundingMode, doc};
 use core::num::FpCategory;
 
 impl<const W: usize, const MB: usize> Float<W, MB> {
-    // TODO: use algorithm on Uint with twice as many bits, compare performance with this one
     #[inline]
-    pub(crate) fn mul_internal(self, rhs: Self, negative: bool) -> Self {
-        let (a, b) = (self, rhs);
-        let (_, exp_a, mant_a) = a.into_biased_parts();
-        let (_, exp_b, mant_b) = b.into_biased_parts();
-
-        // TODO: make so as_ can infer type so can switch trait definition if needed
-        let mut mant_prod = mant_a.widening_mul(mant_b);
-
-        let prod_bits = if mant_prod.1.bit_width() == 0 {
-            mant_prod.0.bit_width()
-        } else {
-            mant_prod.1.bit_width() + Self::BITS
-        };
-
-        if prod_bits == 0 {
-            return if negative { Self::NEG_ZERO } else { Self::ZERO };
-        }
-
-        let extra_bits = if prod_bits > (Self::MB + 1) {
-            prod_bits - (Self::MB + 1)
-        } else {
-            0
-        };
-
-        let mut exp = (exp_a as FloatExponent) - Self::EXP_BIAS
-            + (exp_b as FloatExponent)
-            + (extra_bits as FloatExponent)
-            - (MB as FloatExponent);
-
-        if exp > Self::MAX_EXP + Self::EXP_BIAS - 1 {
-            return if negative {
-                Self::NEG_INFINITY
-            } else {
-                Self::INFINITY
-            };
-        }
-
-        let mut extra_shift = 0;
-        if !exp.is_positive() {
-            extra_shift = (1 - exp) as UnsignedFloatExponent;
-            exp = 1;
-        }
-        let total_shift = (extra_bits as UnsignedFloatExponent) + extra_shift;
-
-        let mp0tz = mant_prod.0.trailing_zeros();
-        let tz = if mp0tz == Self::BITS {
-            mant_prod.1.trailing_zeros() + Self::BITS
-        } else {
-            mp0tz
-        };
-
-        let sticky_bit = (tz as UnsignedFloatExponent + 1) < total_shift;
-        let mut mant = match Exponent::try_from(total_shift - 1) {
-            Ok(sub) => {
-                if sub > Self::BITS * 2 {
-                    (Uint::ZERO, Uint::ZERO)
-                } else if sub >= Self::BITS {
-                    (mant_prod.1 >> (sub - Self::BITS), Uint::ZERO)
-                } else {
-                    let mask = Uint::MAX >> (Self::BITS - sub);
-                    let carry = mant_prod.1 & mask;
-                    mant_prod.1 >>= sub;
-                    mant_prod.0 = (mant_prod.0 >> sub) | (carry << (Self::BITS - sub));
-                    mant_prod
-                }
-            }
-            Err(_) => (Uint::ZERO, Uint::ZERO),
-        };
-        if mant.0.bit(0) {
-            if sticky_bit || mant.0.bit(1) {
-                // Round up
-                let (sum, carry) = mant.0.overflowing_add(Uint::ONE);
-                mant.0 = sum;
-                if carry {
-                    mant.1 += Uint::ONE;
-                }
-            }
-        }
-        {
-            let carry = mant.1.bit(0);
-            //mant.1 >>= 1 as Exponent;
-            mant.0 >>= 1 as Exponent;
-            if carry {
-                mant.0 |= Int::MIN.cast_unsigned();
-            }
-        }
-
-        let mut m1b = mant.1.bit_width();
-        if m1b != 0 {
-            m1b -= 1;
-        }
-        /*let bits = if m1b == 0 {
-            mant.0.bit_width()
-        } else {
-            m1b + Self::BITS
-        };*/
-        let m0b = mant.0.bit_width();
-        if m0b > Self::MB + 1 {
-            // it's possible that the mantissa has too many bits, so shift it right and increase the exponent until it has the correct number of bits
-            let inc = m0b - (Self::MB + 1);
-            mant.0 = mant.0 >> inc;
-            exp += inc as FloatExponent;
-        }
-
-        if exp > Self::MAX_EXP + Self::EXP_BIAS - 1 {
-            return if negative {
-                Self::NEG_INFINITY
-            } else {
-                Self::INFINITY
-            };
-        }
+    fn mul_internal(
+        self,
+        rhs: Self,
+        negative: bool,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let (_, self_exp, self_mant) = self.into_signed_parts();
+        let (_, rhs_exp, rhs_mant) = rhs.into_signed_parts();
+        let (lo, hi) = self_mant.widening_mul(rhs_mant); // exact product of the mantissas
+
+        let exp = self_exp + rhs_exp - 2 * Self::MB_AS_FLOAT_EXP;
+        Self::from_wide_parts(negative, exp, Wide { lo, hi }, rounding)
+    }
 
-        if exp == 1 && m1b < Self::MB + 1 {
-            return Self::from_raw_parts(negative, 0, mant.0);
-        }
-        //if mant >> Self::MB != Uint::ZERO {
-        mant.0 ^= Uint::ONE << Self::MB;
-        //}
-        Self::from_raw_parts(negative, exp as UnsignedFloatExponent, mant.0)
+    /// Computes `self * rhs`, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn mul_round(self, rhs: Self, rounding: RoundingMode) -> Self {
+        self.mul_with_status(rhs, rounding).0
     }
 
+    /// Computes `self * rhs`, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    #[must_use = doc::must_use_op!(float)]
     #[inline]
-    pub(super) fn mul(self, rhs: Self) -> Self {
+    pub fn mul_with_status(self, rhs: Self, rounding: RoundingMode) -> (Self, FloatStatus) {
         let negative = self.is_sign_negative() ^ rhs.is_sign_negative();
         match (self.classify(), rhs.classify()) {
-            (FpCategory::Nan, _) | (_, FpCategory::Nan) => return Self::NAN,
+            (FpCategory::Nan, _) | (_, FpCategory::Nan) => {
+                (Self::NAN, Self::nan_operands_status(&[self, rhs]))
+            }
             (FpCategory::Infinite, FpCategory::Zero) | (FpCategory::Zero, FpCategory::Infinite) => {
-                Self::NAN
+                (Self::NAN, FloatStatus::INVALID)
             }
             (FpCategory::Infinite, _) | (_, FpCategory::Infinite) => {
-                if negative {
+                let infinity = if negative {
                     Self::NEG_INFINITY
                 } else {
                     Self::INFINITY
-                }
+                };
+                (infinity, FloatStatus::OK)
             }
-            (_, _) => self.mul_internal(rhs, negative),
+            (_, _) => self.mul_internal(rhs, negative, rounding), // zero operands give a zero product of the correct sign
         }
     }
+
+    #[inline]
+    pub(super) fn mul(self, rhs: Self) -> Self {
+        self.mul_round(rhs, RoundingMode::DEFAULT)
+    }
 }
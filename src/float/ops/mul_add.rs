@@ -0,0 +1,107 @@
+use super::Float;
+use crate::float::wide::Wide;
+use crate::{FloatStatus, RoundingMode, doc};
+use core::num::FpCategory;
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    #[inline]
+    fn mul_add_internal(
+        self,
+        a: Self,
+        b: Self,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        let product_negative = self.is_sign_negative() ^ a.is_sign_negative();
+        let (_, self_exp, self_mant) = self.into_signed_parts();
+        let (_, a_exp, a_mant) = a.into_signed_parts();
+        let (lo, hi) = self_mant.widening_mul(a_mant);
+
+        // value of product is product * 2^product_exp
+        let product_exp = self_exp + a_exp - 2 * Self::MB_AS_FLOAT_EXP;
+        let product = Self::align_wide_parts(product_negative, product_exp, Wide { lo, hi });
+
+        if b.is_zero() {
+            return Self::from_wide_parts(product.0, product.1, product.2, rounding);
+        }
+        Self::add_aligned_wide_parts(product, b.into_aligned_wide_parts(), rounding)
+    }
+
+    /// Fused multiply-add: computes `(self * a) + b` with only one rounding error, yielding a more accurate result than an unfused multiply-add.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn mul_add(self, a: Self, b: Self) -> Self {
+        self.mul_add_round(a, b, RoundingMode::DEFAULT)
+    }
+
+    /// Computes `(self * a) + b` with only one rounding error, rounding the result according to the given rounding mode.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn mul_add_round(self, a: Self, b: Self, rounding: RoundingMode) -> Self {
+        self.mul_add_with_status(a, b, rounding).0
+    }
+
+    /// Computes `(self * a) + b` with only one rounding error, rounding the result according to the given rounding mode. Returns the result together with the exception flags raised by the operation.
+    #[must_use = doc::must_use_op!(float)]
+    #[inline]
+    pub fn mul_add_with_status(
+        self,
+        a: Self,
+        b: Self,
+        rounding: RoundingMode,
+    ) -> (Self, FloatStatus) {
+        if self.is_nan() || a.is_nan() || b.is_nan() {
+            return (Self::NAN, Self::nan_operands_status(&[self, a, b]));
+        }
+        let product_negative = self.is_sign_negative() ^ a.is_sign_negative();
+        let exact = match (self.classify(), a.classify()) {
+            (FpCategory::Infinite, FpCategory::Zero) | (FpCategory::Zero, FpCategory::Infinite) => {
+                return (Self::NAN, FloatStatus::INVALID);
+            }
+            (FpCategory::Infinite, _) | (_, FpCategory::Infinite) => {
+                if b.is_infinite() && b.is_sign_negative() != product_negative {
+                    return (Self::NAN, FloatStatus::INVALID);
+                } else if product_negative {
+                    Self::NEG_INFINITY
+                } else {
+                    Self::INFINITY
+                }
+            }
+            _ if b.is_infinite() => b,
+            (FpCategory::Zero, _) | (_, FpCategory::Zero) => {
+                if b.is_zero() && product_negative != b.is_sign_negative() {
+                    Self::exact_zero_sum(rounding)
+                } else {
+                    b
+                }
+            }
+            (_, _) => return self.mul_add_internal(a, b, rounding),
+        };
+        (exact, FloatStatus::OK)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test::test_bignum;
+
+    crate::test::test_all! {
+        testing floats;
+
+        test_bignum! {
+            function: <ftest>::mul_add(a: ftest, b: ftest, c: ftest),
+            skip: a.mul_add(b, c).is_nan(),
+            cases: [
+                (1.0, 1.0, -1.0),
+                (-0.0, 1.0, 0.0),
+                (1.0 + ftest::EPSILON, 1.0 - ftest::EPSILON, -1.0),
+                (ftest::MAX, 2.0, -ftest::MAX),
+                (ftest::MIN_POSITIVE, 0.5, 0.0),
+                (ftest::MIN_POSITIVE, ftest::EPSILON, -ftest::MIN_POSITIVE * ftest::EPSILON),
+                (3.0, ftest::MIN_POSITIVE / 4.0, ftest::MIN_POSITIVE / 8.0),
+                (ftest::MAX, ftest::MAX, ftest::NEG_INFINITY),
+                (1.0, 1.0, ftest::EPSILON / 2.0),
+                (1.0, -1.0, ftest::EPSILON / 4.0)
+            ]
+        }
+    }
+}
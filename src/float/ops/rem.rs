@@ -1,7 +1,7 @@
 use super::Float;
 use crate::Exponent;
 use crate::Uint;
-use crate::float::UnsignedFloatExponent;
+use crate::float::{FloatExponent, UnsignedFloatExponent};
 
 impl<const W: usize, const MB: usize> Float<W, MB> {
     #[inline]
@@ -24,8 +24,9 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
 
         let mut uxi = self.to_bits();
         let mut uyi = y.to_bits();
-        let mut ex = self.signed_biased_exponent();
-        let mut ey = y.signed_biased_exponent();
+        // the raw exponents are zero for subnormals, which are normalised below
+        let mut ex = self.into_raw_parts().1 as FloatExponent;
+        let mut ey = y.into_raw_parts().1 as FloatExponent;
         let mut i;
 
         if uxi << 1 as Exponent <= uyi << 1 as Exponent {
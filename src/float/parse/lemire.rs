@@ -0,0 +1,139 @@
+use super::table::{LARGEST_POWER_OF_FIVE, POWER_OF_FIVE_128, SMALLEST_POWER_OF_FIVE};
+use crate::Uint;
+use crate::cast::CastFrom;
+use crate::float::{Float, FloatExponent, UnsignedFloatExponent};
+
+// Implementation of the Eisel-Lemire algorithm for converting a decimal `w * 10^q` to the nearest float, where `w` fits in a `u64`. See Daniel Lemire, "Number Parsing at a Gigabyte per Second" (https://arxiv.org/abs/2101.11408) and Noble Mushtak, Daniel Lemire, "Fast Number Parsing Without Fallback" (https://arxiv.org/abs/2212.06644). This follows the implementation in the fast_float C++ library.
+
+/// largest integer `k` such that `5^k < 2^bits`
+const fn max_power_of_five_below(bits: u32) -> i64 {
+    let mut power: u128 = 1;
+    let mut k = 0;
+    while power * 5 < 1 << bits {
+        power *= 5;
+        k += 1;
+    }
+    k
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// the algorithm needs the precision of the float plus three extra bits to fit in the high 64 bits of the product. the proof that the 128-bit truncated powers of five are always precise enough only covers precisions up to that of binary64
+    pub(super) const EISEL_LEMIRE_SUPPORTED: bool = MB <= 52;
+
+    /// the range of exponents `q` for which `w * 5^q` can be exactly halfway between two floats
+    const MIN_EXPONENT_ROUND_TO_EVEN: i64 = if Self::EISEL_LEMIRE_SUPPORTED {
+        -max_power_of_five_below(64 - (MB as u32 + 1))
+    } else {
+        0
+    };
+    const MAX_EXPONENT_ROUND_TO_EVEN: i64 = if Self::EISEL_LEMIRE_SUPPORTED {
+        max_power_of_five_below(MB as u32 + 2)
+    } else {
+        0
+    };
+
+    /// the biased exponent of infinity
+    const INFINITE_POWER: FloatExponent = Self::EXP_BIAS * 2 + 1;
+
+    /// returns the float nearest to `w * 10^q`, or `None` if this can't be determined without more precision
+    pub(super) fn eisel_lemire(negative: bool, q: i64, mut w: u64) -> Option<Self> {
+        debug_assert!(Self::EISEL_LEMIRE_SUPPORTED);
+        if w == 0 {
+            return Some(Self::ZERO.copysign_bool(negative));
+        }
+        if q < SMALLEST_POWER_OF_FIVE as i64 || q > LARGEST_POWER_OF_FIVE as i64 {
+            return None;
+        }
+        let lz = w.leading_zeros();
+        w <<= lz;
+        let (lo, hi) = compute_product_approx(q, w, MB + 3);
+        if lo == u64::MAX && !(-27..=55).contains(&q) {
+            // the product may be inaccurate in its low bits, which could affect the rounding
+            return None;
+        }
+        let upper_bit = (hi >> 63) as i32;
+        let shift = upper_bit + 64 - MB as i32 - 3;
+        let mut mantissa = hi >> shift;
+        let mut power2 = power(q as i32) + upper_bit - lz as i32 + Self::EXP_BIAS;
+        if power2 <= 0 {
+            // the result is subnormal or zero
+            if -power2 + 1 >= 64 {
+                return Some(Self::ZERO.copysign_bool(negative));
+            }
+            mantissa >>= -power2 + 1;
+            mantissa += mantissa & 1;
+            mantissa >>= 1;
+            power2 = (mantissa >= 1 << MB) as i32; // rounding up may give the smallest normal number
+            return Some(Self::from_biased_u64_parts(negative, power2, mantissa));
+        }
+        if lo <= 1
+            && q >= Self::MIN_EXPONENT_ROUND_TO_EVEN
+            && q <= Self::MAX_EXPONENT_ROUND_TO_EVEN
+            && mantissa & 3 == 1
+            && mantissa << shift == hi
+        {
+            // exactly halfway between two floats, with an even lower neighbour, so we round down
+            mantissa &= !1;
+        }
+        mantissa += mantissa & 1;
+        mantissa >>= 1;
+        if mantissa >= 2 << MB {
+            mantissa = 1 << MB;
+            power2 += 1;
+        }
+        mantissa &= !(1 << MB);
+        if power2 >= Self::INFINITE_POWER {
+            return Some(Self::INFINITY.copysign_bool(negative));
+        }
+        Some(Self::from_biased_u64_parts(negative, power2, mantissa))
+    }
+
+    #[inline]
+    fn from_biased_u64_parts(negative: bool, exponent: FloatExponent, mantissa: u64) -> Self {
+        Self::from_raw_parts(
+            negative,
+            exponent as UnsignedFloatExponent,
+            Uint::cast_from(mantissa),
+        )
+    }
+
+    #[inline]
+    const fn copysign_bool(self, negative: bool) -> Self {
+        if negative { self.neg() } else { self }
+    }
+}
+
+/// approximation of `floor(log2(10^q)) + 63`
+#[inline]
+const fn power(q: i32) -> i32 {
+    (q.wrapping_mul(152_170 + 65536) >> 16) + 63
+}
+
+#[inline]
+const fn full_multiplication(a: u64, b: u64) -> (u64, u64) {
+    let r = (a as u128) * (b as u128);
+    (r as u64, (r >> 64) as u64)
+}
+
+/// computes the high 128 bits of `w * 5^q`, returned as a (low, high) pair. only the top `precision` bits are guaranteed to be correct
+#[inline]
+fn compute_product_approx(q: i64, w: u64, precision: usize) -> (u64, u64) {
+    debug_assert!(precision <= 64);
+    let mask = if precision < 64 {
+        u64::MAX >> precision
+    } else {
+        u64::MAX
+    };
+    let index = (q - SMALLEST_POWER_OF_FIVE as i64) as usize;
+    let (hi5, lo5) = POWER_OF_FIVE_128[index];
+    let (mut first_lo, mut first_hi) = full_multiplication(w, hi5);
+    if first_hi & mask == mask {
+        // the low bits of the product may carry into the bits we need, so we need a second multiplication
+        let (_, second_hi) = full_multiplication(w, lo5);
+        first_lo = first_lo.wrapping_add(second_hi);
+        if second_hi > first_lo {
+            first_hi += 1;
+        }
+    }
+    (first_lo, first_hi)
+}
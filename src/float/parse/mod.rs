@@ -0,0 +1,350 @@
+use super::bignat::BigNat;
+use super::wide::Wide;
+use super::{Float, FloatExponent};
+use crate::errors::{FloatErrorKind, ParseFloatError};
+use crate::{Exponent, FloatStatus, RoundingMode};
+use core::str::FromStr;
+
+mod lemire;
+mod table;
+
+/// `floor(log10(2) * 2^64)`
+const LOG10_2_SCALED: i128 = 0x4D104D427DE7FBCC;
+
+/// `floor(x * log10(2))`, accurate for all `x` of magnitude less than `2^60`
+#[inline]
+pub(super) const fn floor_log10_pow2(x: i64) -> i64 {
+    ((x as i128 * LOG10_2_SCALED) >> 64) as i64
+}
+
+/// the significant digits of a decimal number, which are the digits of `integer` followed by the digits of `fraction`, with `leading_zeros` leading zeros removed and `len` digits kept
+struct Digits<'a> {
+    integer: &'a [u8],
+    fraction: &'a [u8],
+    leading_zeros: usize,
+    len: usize,
+}
+
+impl Digits<'_> {
+    #[inline]
+    fn iter(&self) -> impl Iterator<Item = u8> + '_ {
+        self.integer
+            .iter()
+            .chain(self.fraction.iter())
+            .skip(self.leading_zeros)
+            .take(self.len)
+            .map(|digit| digit - b'0')
+    }
+}
+
+/// the largest number of decimal digits whose value always fits in a `u64`
+const U64_DIGITS: usize = 19;
+
+/// largest power of ten which fits in a `u32`, and its exponent
+const LARGEST_POWER_OF_TEN: (u32, usize) = (1_000_000_000, 9);
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// returns the float nearest to `digits * 10^exponent`
+    fn from_decimal(negative: bool, digits: Digits, exponent: i64) -> Self {
+        let zero = if negative { Self::NEG_ZERO } else { Self::ZERO };
+        if digits.len == 0 {
+            return zero;
+        }
+        if Self::EISEL_LEMIRE_SUPPORTED && digits.len <= U64_DIGITS {
+            let w = digits.iter().fold(0u64, |w, digit| w * 10 + digit as u64);
+            if let Some(out) = Self::eisel_lemire(negative, exponent, w) {
+                return out;
+            }
+        }
+
+        // the value lies in [10^(magnitude - 1), 10^magnitude). we first deal with values which are so large or small that they round to infinity or zero, so that the exact computation below doesn't need huge integers
+        let magnitude = exponent.saturating_add(digits.len as i64);
+        if magnitude - 1 > floor_log10_pow2(Self::MAX_EXP as i64) + 1 {
+            return if negative {
+                Self::NEG_INFINITY
+            } else {
+                Self::INFINITY
+            };
+        }
+        if magnitude < floor_log10_pow2(Self::MIN_SUBNORMAL_EXP as i64 - 1) - 1 {
+            return zero;
+        }
+
+        let mut numerator = BigNat::from_u64(0);
+        let mut iter = digits.iter().peekable();
+        while iter.peek().is_some() {
+            let (mut chunk, mut power) = (0, 1);
+            for digit in iter.by_ref().take(LARGEST_POWER_OF_TEN.1) {
+                chunk = chunk * 10 + digit as u32;
+                power *= 10;
+            }
+            numerator.mul_add_small(power, chunk);
+        }
+        let (out, _) = if exponent >= 0 {
+            numerator.mul_pow10(exponent as Exponent);
+            Self::from_big_nat_parts(negative, 0, numerator)
+        } else {
+            Self::from_big_nat_quotient(negative, (-exponent) as Exponent, numerator)
+        };
+        out
+    }
+
+    /// rounds `mantissa * 2^exponent` to the nearest float
+    #[inline]
+    fn from_big_nat_parts(
+        negative: bool,
+        mut exponent: FloatExponent,
+        mut mantissa: BigNat,
+    ) -> (Self, FloatStatus) {
+        let width = mantissa.bit_width();
+        if width > Self::MB + 3 {
+            // keep two more bits than the precision of the float, the lowest of which is a sticky bit
+            let shift = width - (Self::MB + 3);
+            mantissa.shr_jamming(shift);
+            exponent += shift as FloatExponent;
+        }
+        Self::from_wide_parts(
+            negative,
+            exponent,
+            mantissa.to_wide(),
+            RoundingMode::NearestTiesEven,
+        )
+    }
+
+    /// rounds `numerator / 10^exponent` to the nearest float
+    #[inline]
+    fn from_big_nat_quotient(
+        negative: bool,
+        exponent: Exponent,
+        numerator: BigNat,
+    ) -> (Self, FloatStatus) {
+        // 10^exponent = 5^exponent * 2^exponent, and the power of two is folded into the exponent of the result
+        let mut denominator = BigNat::from_u64(1);
+        denominator.mul_pow5(exponent);
+        Self::from_big_nat_ratio(negative, -(exponent as i64), numerator, denominator)
+    }
+
+    /// rounds `numerator / denominator * 2^exponent` to the nearest float
+    pub(super) fn from_big_nat_ratio(
+        negative: bool,
+        exponent: i64,
+        mut numerator: BigNat,
+        mut denominator: BigNat,
+    ) -> (Self, FloatStatus) {
+        // scale the numerator or denominator by a power of two, so that the quotient has `MB + 4` or `MB + 5` bits: two more than the precision of the float, plus a sticky bit
+        let quotient_bits = Self::MB + 4;
+        let shift =
+            quotient_bits as i64 + denominator.bit_width() as i64 - numerator.bit_width() as i64;
+        if shift > 0 {
+            numerator.shl(shift as Exponent);
+        } else {
+            denominator.shl((-shift) as Exponent);
+        }
+        let mut quotient: Wide<W> = numerator.div_rem_wide(&denominator, quotient_bits + 1);
+        if !numerator.is_zero() {
+            quotient.set_bit(0, true);
+        }
+        // clamp the exponent so that it can't overflow, but is still far enough out of range to round to zero or infinity
+        let bound = (FloatExponent::MAX / 4) as i64;
+        let exponent = (exponent - shift).clamp(-bound, bound);
+        Self::from_wide_parts(
+            negative,
+            exponent as FloatExponent,
+            quotient,
+            RoundingMode::NearestTiesEven,
+        )
+    }
+}
+
+/// splits off the leading ASCII digits of `s`
+#[inline]
+fn split_digits(s: &[u8]) -> (&[u8], &[u8]) {
+    let len = s.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    s.split_at(len)
+}
+
+impl<const W: usize, const MB: usize> FromStr for Float<W, MB> {
+    type Err = ParseFloatError;
+
+    /// Parses a decimal float literal, rounding to the nearest representable value (with ties rounding to even). The accepted grammar is the same as for the primitive float types: an optional sign, followed by either `inf`, `infinity` or `nan` (case-insensitive), or by a decimal number with an optional fractional part and an optional exponent, such as `12`, `-1.5`, `.5e-3` or `6.02E23`.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        const INVALID: ParseFloatError = ParseFloatError {
+            kind: FloatErrorKind::Invalid,
+        };
+
+        let s = src.as_bytes();
+        let (negative, s) = match s.first() {
+            None => {
+                return Err(ParseFloatError {
+                    kind: FloatErrorKind::Empty,
+                });
+            }
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            Some(_) => (false, s),
+        };
+        let special = if s.eq_ignore_ascii_case(b"inf") || s.eq_ignore_ascii_case(b"infinity") {
+            Some(Self::INFINITY)
+        } else if s.eq_ignore_ascii_case(b"nan") {
+            Some(Self::NAN)
+        } else {
+            None
+        };
+        if let Some(special) = special {
+            return Ok(if negative { special.neg() } else { special });
+        }
+
+        let (integer, s) = split_digits(s);
+        let (fraction, s) = match s.split_first() {
+            Some((b'.', s)) => split_digits(s),
+            _ => (&s[..0], s),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(INVALID);
+        }
+        let exponent = match s.split_first() {
+            None => 0,
+            Some((b'e' | b'E', s)) => {
+                let (exponent_negative, s) = match s.split_first() {
+                    Some((b'-', s)) => (true, s),
+                    Some((b'+', s)) => (false, s),
+                    _ => (false, s),
+                };
+                let (exponent_digits, s) = split_digits(s);
+                if exponent_digits.is_empty() || !s.is_empty() {
+                    return Err(INVALID);
+                }
+                // saturate large exponents, which are then handled by the check for values which round to zero or infinity
+                let exponent = exponent_digits.iter().fold(0i64, |exponent, digit| {
+                    (exponent * 10 + (digit - b'0') as i64).min(1 << 48)
+                });
+                if exponent_negative {
+                    -exponent
+                } else {
+                    exponent
+                }
+            }
+            Some(_) => return Err(INVALID),
+        };
+
+        let total_len = integer.len() + fraction.len();
+        let digits = integer.iter().chain(fraction.iter());
+        let leading_zeros = digits.clone().take_while(|digit| **digit == b'0').count();
+        let trailing_zeros = digits
+            .rev()
+            .take(total_len - leading_zeros)
+            .take_while(|digit| **digit == b'0')
+            .count();
+        let digits = Digits {
+            integer,
+            fraction,
+            leading_zeros,
+            len: total_len - leading_zeros - trailing_zeros,
+        };
+        let exponent = exponent - fraction.len() as i64 + trailing_zeros as i64;
+        Ok(Self::from_decimal(negative, digits, exponent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{F32, F64};
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    fn parses_correctly(s: &str) -> bool {
+        let f64_correct = match (F64::from_str(s), f64::from_str(s)) {
+            (Ok(a), Ok(b)) => a.to_bits() == F64::from(b).to_bits() || (a.is_nan() && b.is_nan()),
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+        let f32_correct = match (F32::from_str(s), f32::from_str(s)) {
+            (Ok(a), Ok(b)) => a.to_bits() == F32::from(b).to_bits() || (a.is_nan() && b.is_nan()),
+            (Err(_), Err(_)) => true,
+            _ => false,
+        };
+        f64_correct && f32_correct
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_from_str_round_trip(a: f64, b: f32) -> bool {
+            parses_correctly(&a.to_string()) && parses_correctly(&b.to_string())
+                && parses_correctly(&alloc::format!("{:e}", a)) && parses_correctly(&alloc::format!("{:.30e}", b))
+        }
+
+        fn quickcheck_from_str_digits(digits: u128, exponent: i16, fraction_len: u8) -> bool {
+            let digits = digits.to_string();
+            let split = (fraction_len as usize).min(digits.len());
+            let (integer, fraction) = digits.split_at(digits.len() - split);
+            parses_correctly(&alloc::format!("{integer}.{fraction}e{exponent}"))
+        }
+    }
+
+    #[test]
+    fn from_str() {
+        let cases = [
+            "",
+            "+",
+            "-",
+            ".",
+            "e5",
+            "1e",
+            "1e+",
+            "1.2.3",
+            "1,5",
+            " 1",
+            "1 ",
+            "--1",
+            "+-1",
+            "0x10",
+            "1_000",
+            "0",
+            "-0",
+            "+0",
+            ".0",
+            "0.",
+            "00000.00000e-99999",
+            "1",
+            "-1",
+            "1.",
+            ".5",
+            "12.5e-3",
+            "6.02E23",
+            "inf",
+            "-Infinity",
+            "INF",
+            "infinit",
+            "nan",
+            "-NaN",
+            "NAN",
+            "nana",
+            "1e308",
+            "1.7976931348623157e308",
+            "1.7976931348623158e308",
+            "1.797693134862315807937289714053e308",
+            "1e309",
+            "1e99999999999999999999",
+            "1e-99999999999999999999",
+            "4.9e-324",
+            "2.4703282292062327e-324",
+            "2.4703282292062328e-324",
+            "2.2250738585072011e-308",
+            "2.2250738585072014e-308",
+            "9007199254740993",
+            "9007199254740992.999999999999999999999999999",
+            "0.1",
+            "0.3",
+            "3.4028235e38",
+            "3.4028236e38",
+            "1.4e-45",
+            "7.0064923216240854e-46",
+            "7.006492321624086e-46",
+            "123456789012345678901234567890",
+            "0.000000000000000000000000000000000000000000001",
+            "2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324",
+        ];
+        for case in cases {
+            assert!(parses_correctly(case), "{case}");
+        }
+    }
+}
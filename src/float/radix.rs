@@ -0,0 +1,345 @@
+#[cfg(feature = "alloc")]
+use super::bignat::BigNat;
+#[cfg(any(test, not(feature = "alloc")))]
+use super::math::TwoFloat;
+use super::wide::Wide;
+use super::{Float, FloatExponent};
+use crate::cast::CastFrom;
+use crate::errors::{FloatErrorKind, ParseFloatError};
+use crate::{Exponent, RoundingMode, Uint};
+
+/// the leading digits of a number in some radix, accumulated exactly into `mantissa`, together with the number of following digits which didn't fit and were dropped, and whether any of those were non-zero
+struct RadixDigits<const W: usize> {
+    mantissa: Wide<W>,
+    dropped: i64,
+    sticky: bool,
+}
+
+impl<const W: usize> RadixDigits<W> {
+    #[inline]
+    fn new(digits: impl Iterator<Item = u32>, radix: u32) -> Self {
+        let mut out = Self {
+            mantissa: Wide::ZERO,
+            dropped: 0,
+            sticky: false,
+        };
+        // the radix is at most 36, so multiplying by it and adding a digit adds at most 6 bits
+        let limit = Wide::<W>::BITS - 6;
+        for digit in digits {
+            if out.mantissa.bit_width() <= limit {
+                out.mantissa = mul_add_small(out.mantissa, radix, digit);
+            } else {
+                out.dropped += 1;
+                out.sticky |= digit != 0;
+            }
+        }
+        out
+    }
+}
+
+/// `mantissa * radix + digit`, which must fit in a `Wide`
+#[inline]
+fn mul_add_small<const W: usize>(mantissa: Wide<W>, radix: u32, digit: u32) -> Wide<W> {
+    let mut out = Wide::from_uint(Uint::cast_from(digit));
+    for i in 0..u32::BITS - radix.leading_zeros() {
+        if radix & (1 << i) != 0 {
+            out = out.add(mantissa.shl(i as Exponent));
+        }
+    }
+    out
+}
+
+/// clamps `exponent` so that it can't overflow when the width of a mantissa is added to it, but is still far enough out of range to round to zero or infinity
+#[inline]
+fn clamp_exponent(exponent: i64) -> FloatExponent {
+    let bound = (FloatExponent::MAX / 4) as i64;
+    exponent.clamp(-bound, bound) as FloatExponent
+}
+
+/// parses a decimal exponent with an optional sign, saturating large exponents, which then round to zero or infinity
+#[inline]
+fn parse_exponent(s: &[u8]) -> Option<i64> {
+    let (negative, s) = match s.split_first() {
+        Some((b'-', s)) => (true, s),
+        Some((b'+', s)) => (false, s),
+        _ => (false, s),
+    };
+    let (digits, s) = split_radix_digits(s, 10);
+    if digits.is_empty() || !s.is_empty() {
+        return None;
+    }
+    let exponent = digits.iter().fold(0i64, |exponent, digit| {
+        (exponent * 10 + (digit - b'0') as i64).min(1 << 48)
+    });
+    Some(if negative { -exponent } else { exponent })
+}
+
+/// splits off the leading digits of `s` in the given radix
+#[inline]
+fn split_radix_digits(s: &[u8], radix: u32) -> (&[u8], &[u8]) {
+    let len = s
+        .iter()
+        .take_while(|byte| (**byte as char).is_digit(radix))
+        .count();
+    s.split_at(len)
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// Parses a float literal in the given radix, rounding to the nearest representable value (with ties rounding to even). For radix `10`, the accepted grammar is the same as for [`FromStr::from_str`](core::str::FromStr::from_str). Otherwise, the accepted grammar is an optional sign, followed by either `inf`, `infinity` or `nan` (case-insensitive), or by a number with an optional fractional part, whose digits are the ASCII digits and letters (case-insensitive) valid in the radix. For radix `16`, the number may be followed by a `p` or `P` and a decimal binary exponent, such as `1.8p-3`.
+    ///
+    /// Without the `alloc` feature, radices which are not powers of two are evaluated with roughly twice the precision of the float rather than exactly, so the result is almost always, but not always, correctly rounded.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `radix` is not in the range from 2 to 36 inclusive.
+    pub(super) fn parse_radix(src: &str, radix: u32) -> Result<Self, ParseFloatError> {
+        const INVALID: ParseFloatError = ParseFloatError {
+            kind: FloatErrorKind::Invalid,
+        };
+
+        assert!(
+            (2..=36).contains(&radix),
+            crate::errors::err_msg!("Radix must be in range [2, 36]")
+        );
+        #[cfg(feature = "alloc")]
+        if radix == 10 {
+            return src.parse();
+        }
+        let s = src.as_bytes();
+        let (negative, s) = match s.first() {
+            None => {
+                return Err(ParseFloatError {
+                    kind: FloatErrorKind::Empty,
+                });
+            }
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            Some(_) => (false, s),
+        };
+        let special = if s.eq_ignore_ascii_case(b"inf") || s.eq_ignore_ascii_case(b"infinity") {
+            Some(Self::INFINITY)
+        } else if s.eq_ignore_ascii_case(b"nan") {
+            Some(Self::NAN)
+        } else {
+            None
+        };
+        if let Some(special) = special {
+            return Ok(if negative { special.neg() } else { special });
+        }
+
+        let (integer, s) = split_radix_digits(s, radix);
+        let (fraction, s) = match s.split_first() {
+            Some((b'.', s)) => split_radix_digits(s, radix),
+            _ => (&s[..0], s),
+        };
+        if integer.is_empty() && fraction.is_empty() {
+            return Err(INVALID);
+        }
+        // `e` is a digit in radices above 14, so a power of the radix can only be given in radix 10, which is parsed by `FromStr` when the `alloc` feature is enabled
+        let (radix_exponent, binary_exponent) = match s.split_first() {
+            None => (0, 0),
+            Some((b'p' | b'P', s)) if radix == 16 => (0, parse_exponent(s).ok_or(INVALID)?),
+            Some((b'e' | b'E', s)) if radix == 10 => (parse_exponent(s).ok_or(INVALID)?, 0),
+            Some(_) => return Err(INVALID),
+        };
+
+        let digits = integer
+            .iter()
+            .chain(fraction.iter())
+            .map(|digit| (*digit as char).to_digit(radix).unwrap());
+        let radix_exponent = radix_exponent - fraction.len() as i64;
+        if radix.is_power_of_two() {
+            return Ok(Self::from_power_of_two_radix_digits(
+                negative,
+                RadixDigits::new(digits, radix),
+                radix.trailing_zeros(),
+                radix_exponent,
+                binary_exponent,
+            ));
+        }
+        #[cfg(feature = "alloc")]
+        let out = Self::from_radix_digits_exact(negative, digits, radix, radix_exponent);
+        #[cfg(not(feature = "alloc"))]
+        let out = Self::from_radix_digits_approx(
+            negative,
+            RadixDigits::new(digits, radix),
+            radix,
+            radix_exponent,
+        );
+        Ok(out)
+    }
+
+    /// rounds `digits * 2^(bits * radix_exponent + binary_exponent)` to the nearest float, where `digits` are in radix `2^bits`. this is exact, since the dropped digits only affect the sticky bit
+    fn from_power_of_two_radix_digits(
+        negative: bool,
+        digits: RadixDigits<W>,
+        bits: u32,
+        radix_exponent: i64,
+        binary_exponent: i64,
+    ) -> Self {
+        let RadixDigits {
+            mut mantissa,
+            dropped,
+            sticky,
+        } = digits;
+        if sticky {
+            // digits are only dropped once the mantissa is nearly as wide as a `Wide`, so the lowest bit is well below the rounding bit
+            mantissa.set_bit(0, true);
+        }
+        let exponent = (radix_exponent + dropped) * bits as i64 + binary_exponent;
+        let (out, _) = Self::from_wide_parts(
+            negative,
+            clamp_exponent(exponent),
+            mantissa,
+            RoundingMode::NearestTiesEven,
+        );
+        out
+    }
+
+    /// rounds `digits * radix^radix_exponent` to the nearest float
+    #[cfg(feature = "alloc")]
+    fn from_radix_digits_exact(
+        negative: bool,
+        digits: impl Iterator<Item = u32>,
+        radix: u32,
+        radix_exponent: i64,
+    ) -> Self {
+        let mut numerator = BigNat::from_u64(0);
+        for digit in digits {
+            numerator.mul_add_small(radix, digit);
+        }
+        if numerator.is_zero() {
+            return if negative { Self::NEG_ZERO } else { Self::ZERO };
+        }
+        let mut denominator = BigNat::from_u64(1);
+        let power = if radix_exponent < 0 {
+            &mut denominator
+        } else {
+            &mut numerator
+        };
+        for _ in 0..radix_exponent.unsigned_abs() {
+            power.mul_small(radix);
+        }
+        let (out, _) = Self::from_big_nat_ratio(negative, 0, numerator, denominator);
+        out
+    }
+
+    /// rounds `digits * radix^radix_exponent` to the nearest float, computing the product in a `TwoFloat`, so that the result is almost always correctly rounded
+    #[cfg(any(test, not(feature = "alloc")))]
+    fn from_radix_digits_approx(
+        negative: bool,
+        digits: RadixDigits<W>,
+        radix: u32,
+        radix_exponent: i64,
+    ) -> Self {
+        let RadixDigits {
+            mantissa, dropped, ..
+        } = digits;
+        if mantissa.is_zero() {
+            return if negative { Self::NEG_ZERO } else { Self::ZERO };
+        }
+        // split the mantissa into its leading `MB + 1` bits, which are exact as a float, and the remaining bits, so that their sum is `mantissa / 2^shift`
+        let shift = mantissa.bit_width().saturating_sub(Self::MB + 1);
+        let upper = mantissa.shr(shift);
+        let lower = mantissa.sub(upper.shl(shift));
+        let (upper, _) = Self::from_wide_parts(false, 0, upper, RoundingMode::NearestTiesEven);
+        let (lower, _) = Self::from_wide_parts(
+            false,
+            -(shift as FloatExponent),
+            lower,
+            RoundingMode::NearestTiesEven,
+        );
+        let mantissa = TwoFloat::two_sum(upper, lower);
+
+        let radix_exponent = radix_exponent + dropped;
+        let (power, power_exponent) = Self::radix_power(radix, radix_exponent.unsigned_abs());
+        let (value, exponent) = if radix_exponent < 0 {
+            (mantissa.div(power), shift as i64 - power_exponent)
+        } else {
+            (mantissa.mul(power), shift as i64 + power_exponent)
+        };
+        let out = value.round_scaled(clamp_exponent(exponent));
+        if negative { out.neg() } else { out }
+    }
+
+    /// `(p, e)` such that `radix^n = p * 2^e`, with `1 <= p < 2`. `p` is computed by square-and-multiply, scaling each intermediate result so that it can't overflow
+    #[cfg(any(test, not(feature = "alloc")))]
+    fn radix_power(radix: u32, mut n: u64) -> (TwoFloat<W, MB>, i64) {
+        let normalise = |x: TwoFloat<W, MB>| {
+            let exponent = x.hi.exponent();
+            (x.mul_pow2(-exponent), exponent as i64)
+        };
+        let (mut base, mut base_exponent) = normalise(TwoFloat::from_float(Self::cast_from(radix)));
+        let (mut out, mut out_exponent) = (TwoFloat::ONE, 0);
+        while n != 0 {
+            if n & 1 == 1 {
+                let (product, exponent) = normalise(out.mul(base));
+                out = product;
+                out_exponent += base_exponent + exponent;
+            }
+            n >>= 1;
+            if n != 0 {
+                let (square, exponent) = normalise(base.sqr());
+                base = square;
+                base_exponent = 2 * base_exponent + exponent;
+            }
+        }
+        (out, out_exponent)
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::RadixDigits;
+    use crate::types::{F32, F64};
+    use alloc::vec::Vec;
+    use quickcheck::TestResult;
+
+    quickcheck::quickcheck! {
+        fn quickcheck_from_radix_digits_approx(digits: Vec<u8>, radix: u8, exponent: i16) -> TestResult {
+            let radix = radix as u32 % 35 + 2;
+            if radix.is_power_of_two() {
+                return TestResult::discard();
+            }
+            let exponent = exponent as i64 / 32;
+            let digits = digits.iter().map(|digit| *digit as u32 % radix);
+            let f64_correct = F64::from_radix_digits_exact(false, digits.clone(), radix, exponent)
+                == F64::from_radix_digits_approx(false, RadixDigits::new(digits.clone(), radix), radix, exponent);
+            let f32_correct = F32::from_radix_digits_exact(true, digits.clone(), radix, exponent)
+                == F32::from_radix_digits_approx(true, RadixDigits::new(digits, radix), radix, exponent);
+            TestResult::from_bool(f64_correct && f32_correct)
+        }
+    }
+
+    #[test]
+    fn from_radix_digits_approx() {
+        let cases: [(&[u32], u32, i64); 7] = [
+            (&[1], 10, 308),
+            (
+                &[1, 7, 9, 7, 6, 9, 3, 1, 3, 4, 8, 6, 2, 3, 1, 5, 8],
+                10,
+                292,
+            ),
+            (&[4, 9], 10, -325),
+            (
+                &[2, 2, 2, 5, 0, 7, 3, 8, 5, 8, 5, 0, 7, 2, 0, 1, 4],
+                10,
+                -324,
+            ),
+            (&[1], 3, -700),
+            (&[35; 100], 36, 100),
+            (&[1], 10, 400),
+        ];
+        for (digits, radix, exponent) in cases {
+            let exact =
+                F64::from_radix_digits_exact(false, digits.iter().copied(), radix, exponent);
+            let approx = F64::from_radix_digits_approx(
+                false,
+                RadixDigits::new(digits.iter().copied(), radix),
+                radix,
+                exponent,
+            );
+            assert_eq!(exact, approx, "{digits:?} * {radix}^{exponent}");
+        }
+    }
+}
@@ -1,74 +1,186 @@
-use rand::distr::uniform::{SampleBorrow, SampleUniform, UniformSampler};
-use rand::distr::{Distribution, Open01, OpenClosed01, StandardUniform};
-use rand::{Fill, Rng, SeedableRng};
+/*
+Most of the code in this file is adapted from code from the Rust `rand` library, https://docs.rs/rand/latest/rand/, modified under the MIT license. The changes are released under either the MIT license or the Apache License 2.0, as described in the README. See LICENSE-MIT or LICENSE-APACHE at the project root.
+The original license file for `rand` can be found in this project's root at licenses/LICENSE-rand.
+
+The appropriate copyright notices for this are given below:
+Copyright 2018 Developers of the Rand project.
+Copyright 2013-2017 The Rust Project Developers.
+Copyright 2018-2020 Developers of the Rand project.
+Copyright 2017 The Rust Project Developers.
+*/
 
 use super::{Float, FloatExponent};
-use crate::Uint;
+use crate::random::UniformFloat;
+use crate::{RoundingMode, Uint};
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler};
+use rand::distr::{Distribution, Open01, OpenClosed01, StandardUniform};
+use rand::{Rng, RngExt};
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// `mantissa * 2^exponent`, which must be exactly representable
+    #[inline]
+    fn from_exact_parts(exponent: FloatExponent, mantissa: Uint<W>) -> Self {
+        Self::from_uint_parts(false, exponent, mantissa, RoundingMode::NearestTiesEven).0
+    }
+
+    /// `MB + 1` random bits, which is the precision of `Self`
+    #[inline]
+    fn random_precision_bits<R: Rng + ?Sized>(rng: &mut R) -> Uint<W> {
+        rng.random::<Uint<W>>().shr(Self::BITS - (Self::MB + 1))
+    }
+
+    /// a random float in `[1, 2)`, whose whole mantissa is filled with random bits
+    #[inline]
+    fn random_one_two<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        let fraction = rng.random::<Uint<W>>().shr(Self::BITS - Self::MB);
+        Self::from_raw_parts(false, Self::EXP_BIAS as _, fraction)
+    }
+}
 
 impl<const W: usize, const MB: usize> Distribution<Float<W, MB>> for StandardUniform {
+    /// Samples uniformly from the half-open interval `[0, 1)`, as a random multiple of `2^-(MB + 1)`.
+    #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float<W, MB> {
-        let random_bits: Uint<W> = rng.random();
-        let mantissa = random_bits.shr(Float::<W, MB>::BITS - Float::<W, MB>::MB - 1);
-        if mantissa.is_zero() {
-            return Float::ZERO;
-        }
-        if mantissa.is_one() {
-            return Float::HALF_EPSILON;
-        }
-        let mantissa_bits = mantissa.bit_width();
-        let abs_exponent = Float::<W, MB>::MB + 2 - mantissa_bits; // has to be in this order to prevent overflow
-        Float::from_signed_parts(
-            false,
-            -(abs_exponent as FloatExponent),
-            mantissa.shl(abs_exponent - 1),
-        )
+        let value = Float::<W, MB>::random_precision_bits(rng);
+        Float::from_exact_parts(-(MB as FloatExponent + 1), value)
     }
 }
 
 impl<const W: usize, const MB: usize> Distribution<Float<W, MB>> for OpenClosed01 {
+    /// Samples uniformly from the half-open interval `(0, 1]`, as a random multiple of `2^-(MB + 1)`.
+    #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float<W, MB> {
-        let random_bits: Uint<W> = rng.random();
-        let mantissa = random_bits.shr(Float::<W, MB>::BITS - Float::<W, MB>::MB - 1);
-        let mantissa = mantissa.add(Uint::ONE); // add one so mantissa is never zero
-        if mantissa.is_zero() {
-            return Float::HALF_EPSILON;
-        }
-        let mantissa_bits = mantissa.bit_width();
-        let abs_exponent = Float::<W, MB>::MB + 2 - mantissa_bits; // has to be in this order to prevent overflow
-        Float::from_signed_parts(
-            false,
-            -(abs_exponent as FloatExponent),
-            mantissa.shl(abs_exponent - 1),
-        )
+        let value = Float::<W, MB>::random_precision_bits(rng).add(Uint::ONE);
+        Float::from_exact_parts(-(MB as FloatExponent + 1), value)
     }
 }
 
 impl<const W: usize, const MB: usize> Distribution<Float<W, MB>> for Open01 {
+    /// Samples uniformly from the open interval `(0, 1)`, as a random odd multiple of `2^-(MB + 1)`.
+    #[inline]
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Float<W, MB> {
-        let random_bits: Uint<W> = rng.random();
-        let mantissa = random_bits.shr(Float::<W, MB>::BITS - Float::<W, MB>::MB);
-        if mantissa.is_zero() {
-            return Float::HALF_EPSILON;
+        let fraction = rng.random::<Uint<W>>().shr(Float::<W, MB>::BITS - Float::<W, MB>::MB);
+        let value = fraction.shl(1).bitor(Uint::ONE);
+        Float::from_exact_parts(-(MB as FloatExponent + 1), value)
+    }
+}
+
+impl<const W: usize, const MB: usize> SampleUniform for Float<W, MB> {
+    type Sampler = UniformFloat<Self>;
+}
+
+impl<const W: usize, const MB: usize> UniformFloat<Float<W, MB>> {
+    /// the largest value of the random float in `[0, 1)` which is multiplied by the scale
+    #[inline]
+    fn max_rand() -> Float<W, MB> {
+        Float::ONE - Float::EPSILON
+    }
+
+    /// decreases `scale` until the largest sample, `scale * MAX_RAND + low`, is at most `high`, to account for rounding
+    fn new_bounded(low: Float<W, MB>, high: Float<W, MB>, mut scale: Float<W, MB>) -> Self {
+        while scale * Self::max_rand() + low > high {
+            scale = scale.next_down();
+        }
+        debug_assert!(Float::ZERO <= scale);
+        Self { low, scale }
+    }
+
+    /// `value * scale + low`, where `value` is a random float in `[0, 1)`
+    #[inline]
+    fn sample_scaled<R: Rng + ?Sized>(
+        low: Float<W, MB>,
+        scale: Float<W, MB>,
+        rng: &mut R,
+    ) -> Float<W, MB> {
+        let value = Float::random_one_two(rng) - Float::ONE;
+        value * scale + low
+    }
+
+    /// checks that `low <= high` (or `low < high` if `!inclusive`), and that both `low`, `high` and their difference are finite
+    fn check_range(low: Float<W, MB>, high: Float<W, MB>, inclusive: bool) -> Result<Float<W, MB>, Error> {
+        #[cfg(debug_assertions)]
+        if !low.is_finite() || !high.is_finite() {
+            return Err(Error::NonFinite);
         }
-        let mantissa_bits = mantissa.bit_width();
-        let abs_exponent = Float::<W, MB>::MB + 1 - mantissa_bits; // has to be in this order to prevent overflow
-        let exponent = -(abs_exponent as FloatExponent);
-        let mut mantissa = mantissa.shl(1).bitor(Uint::ONE); // = 2*mantissa + 1
-        mantissa.set_bit(0, true);
-        mantissa = mantissa.shl(Float::<W, MB>::MB - mantissa_bits);
-        let a = Float::from_signed_parts(false, exponent, mantissa);
-        a
+        let non_empty = if inclusive { low <= high } else { low < high };
+        if !non_empty {
+            return Err(Error::EmptyRange);
+        }
+        let range = high - low;
+        if !range.is_finite() {
+            return Err(Error::NonFinite);
+        }
+        Ok(range)
+    }
+}
+
+impl<const W: usize, const MB: usize> UniformSampler for UniformFloat<Float<W, MB>> {
+    type X = Float<W, MB>;
+
+    #[inline]
+    fn new<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low_b.borrow(), *high_b.borrow());
+        let scale = Self::check_range(low, high, false)?;
+        Ok(Self::new_bounded(low, high, scale))
+    }
+
+    #[inline]
+    fn new_inclusive<B1, B2>(low_b: B1, high_b: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low_b.borrow(), *high_b.borrow());
+        let range = Self::check_range(low, high, true)?;
+        // scale so that the largest possible value is `high`
+        Ok(Self::new_bounded(low, high, range / Self::max_rand()))
+    }
+
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Self::sample_scaled(self.low, self.scale, rng)
+    }
+
+    #[inline]
+    fn sample_single<R: Rng + ?Sized, B1, B2>(
+        low_b: B1,
+        high_b: B2,
+        rng: &mut R,
+    ) -> Result<Self::X, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self::sample_single_inclusive(low_b, high_b, rng)
+    }
+
+    #[inline]
+    fn sample_single_inclusive<R: Rng + ?Sized, B1, B2>(
+        low_b: B1,
+        high_b: B2,
+        rng: &mut R,
+    ) -> Result<Self::X, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let (low, high) = (*low_b.borrow(), *high_b.borrow());
+        let scale = Self::check_range(low, high, true)?;
+        Ok(Self::sample_scaled(low, scale, rng))
     }
 }
 
 #[cfg(test)]
-mod tests {
-    use crate::test::convert;
-    use rand::distr::OpenClosed01;
-    use rand::rngs::StdRng;
-    use rand::{Rng, SeedableRng};
-    use rand::{distr::Open01, rngs::SmallRng};
-    use super::*;
+crate::test::test_all! {
+    testing floats;
+
+    use rand::distr::{Open01, OpenClosed01, Uniform};
+    use rand::rngs::SmallRng; // use SmallRng as doesn't require an extra crate feature
+    use rand::{RngExt, SeedableRng};
 
     fn seeded_rngs<R: SeedableRng + Clone>(seed: u64) -> (R, R) {
         let rng = R::seed_from_u64(seed);
@@ -76,23 +188,73 @@ mod tests {
         (rng, rng2)
     }
 
-    crate::test::test_all! {
-        testing floats;
+    quickcheck::quickcheck! {
+        #[allow(non_snake_case)]
+        fn quickcheck_SmallRng_gen_ftest(seed: u64) -> bool {
+            use crate::test::convert;
+
+            let (mut rng, mut rng2) = seeded_rngs::<SmallRng>(seed);
+
+            let mut result = convert::test_eq(rng.random::<FTEST>(), rng2.random::<ftest>());
+            result &= convert::test_eq(rng.sample::<FTEST, _>(OpenClosed01), rng2.sample::<ftest, _>(OpenClosed01));
+            result &= convert::test_eq(rng.sample::<FTEST, _>(Open01), rng2.sample::<ftest, _>(Open01));
+            result
+        }
+
+        #[allow(non_snake_case)]
+        fn quickcheck_SmallRng_gen_range_ftest(seed: u64, min: ftest, max: ftest) -> quickcheck::TestResult {
+            if min.partial_cmp(&max) != Some(core::cmp::Ordering::Less) || !(max - min).is_finite() {
+                return quickcheck::TestResult::discard();
+            }
+            use crate::test::convert;
+
+            let mut result = true;
+
+            let (mut rng, mut rng2) = seeded_rngs::<SmallRng>(seed);
+
+            let min_big = FTEST::from(min);
+            let max_big = FTEST::from(max);
+
+            let big = rng.random_range(min_big..max_big);
+            let primitive = rng2.random_range(min..max); // calls sample_single from UniformSampler
+
+            result &= convert::test_eq(big, primitive);
 
-        #[test]
-        fn test_random() {
-            let mut r = StdRng::seed_from_u64(0);
-            let seed = r.random();
-            let (mut r1, mut r2) = seeded_rngs::<SmallRng>(seed);
-            let big: FTEST = r1.random();
-            let prim: ftest = r2.random();
+            let big = rng.random_range(min_big..=max_big);
+            let primitive = rng2.random_range(min..=max); // calls sample_single_inclusive from UniformSampler
 
-            assert!(convert::test_eq(big, prim));
+            result &= convert::test_eq(big, primitive);
 
-            let big: FTEST = r1.sample(OpenClosed01);
-            let prim: ftest = r2.sample(OpenClosed01);
+            let big_uniform = Uniform::new(min_big, max_big).unwrap();
+            let primitive_uniform = Uniform::new(min, max).unwrap();
+            let big_inclusive_uniform = Uniform::new_inclusive(min_big, max_big).unwrap();
+            let primitive_inclusive_uniform = Uniform::new_inclusive(min, max).unwrap();
 
-            assert!(convert::test_eq(big, prim));
+            for _ in 0..10 {
+                let big = rng.sample(big_uniform); // calls sample from UniformSampler
+                let primitive = rng2.sample(primitive_uniform);
+
+                result &= min_big <= big && big < max_big;
+                result &= convert::test_eq(big, primitive);
+
+                let big = rng.sample(big_inclusive_uniform);
+                let primitive = rng2.sample(primitive_inclusive_uniform);
+
+                result &= min_big <= big && big <= max_big;
+                result &= convert::test_eq(big, primitive);
+            }
+
+            quickcheck::TestResult::from_bool(result)
         }
     }
+
+    #[test]
+    fn uniform_errors() {
+        use rand::distr::uniform::Error;
+
+        assert_eq!(Uniform::new(FTEST::ONE, FTEST::ONE), Err(Error::EmptyRange));
+        assert_eq!(Uniform::new_inclusive(FTEST::ONE, FTEST::ZERO), Err(Error::EmptyRange));
+        assert_eq!(Uniform::new(FTEST::MIN, FTEST::MAX), Err(Error::NonFinite));
+        assert_eq!(Uniform::new_inclusive(FTEST::ONE, FTEST::ONE).map(|u| u.sample(&mut SmallRng::seed_from_u64(0))), Ok(FTEST::ONE));
+    }
 }
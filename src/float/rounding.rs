@@ -116,6 +116,9 @@ impl<const W: usize, const MB: usize> Float<W, MB> {
                 }
             }
             Ordering::Equal => {
+                if trunc.is_zero() {
+                    return trunc; // zero is even
+                }
                 let (_, exponent, mantissa) = trunc.into_signed_parts();
                 let mantissa_length = (Self::MB - mantissa.trailing_zeros()) as FloatExponent;
                 debug_assert!(exponent >= mantissa_length);
@@ -1,6 +1,525 @@
 use super::Float;
+use super::bignat::BigNat;
+use super::parse::floor_log10_pow2;
+use crate::Exponent;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Alignment, Debug, Display, Formatter, LowerExp, UpperExp, Write};
 
-impl<const W: usize, const MB: usize> Float<W, MB> {}
+// Conversion of floats to decimal strings, using exact big integer arithmetic so that it works for floats of any precision. The shortest representation is computed with the free-format algorithm of Robert G. Burger and R. Kent Dybvig, "Printing Floating-Point Numbers Quickly and Accurately" (1996), which refines Dragon4 from Guy L. Steele Jr. and Jon L. White, "How to Print Floating-Point Numbers Accurately" (1990). Representations with a given precision are computed by generating the exact digits and rounding half to even.
 
-// TODO: include copyright notice as described in dragon4 paper
-// TODO: look at the f256 Rust crate (useful to look at for a lot of methods, you could even get in contact with the author to see if they would be interested in contributing to the float part of this crate)
+/// decimal digits `d_1, d_2, ..., d_n` (with `d_1` non-zero) and an exponent `k`, representing the value `0.d_1 d_2 ... d_n * 10^k`. zero is represented by no digits
+struct Decimal {
+    digits: Vec<u8>,
+    exponent: i64,
+}
+
+/// the exact value of a positive float divided by `10^exponent`, stored as the fraction `numerator / denominator`
+struct ScaledValue {
+    numerator: BigNat,
+    denominator: BigNat,
+    exponent: i64,
+}
+
+impl ScaledValue {
+    /// `numerator / (denominator * 10^exponent)`, where `exponent` is an estimate of the decimal exponent of the value. `margins` are multiplied by the same power of ten as the numerator
+    fn new(
+        mut numerator: BigNat,
+        mut denominator: BigNat,
+        exponent: i64,
+        margins: &mut [&mut BigNat],
+    ) -> Self {
+        if exponent >= 0 {
+            denominator.mul_pow10(exponent as Exponent);
+        } else {
+            let power = (-exponent) as Exponent;
+            numerator.mul_pow10(power);
+            for margin in margins.iter_mut() {
+                margin.mul_pow10(power);
+            }
+        }
+        Self {
+            numerator,
+            denominator,
+            exponent,
+        }
+    }
+
+    /// divides the value by ten, increasing the exponent
+    #[inline]
+    fn div_ten(&mut self) {
+        self.denominator.mul_small(10);
+        self.exponent += 1;
+    }
+
+    /// the integer part of ten times the value, which must be less than one, replacing the value with the fractional part
+    #[inline]
+    fn next_digit(&mut self) -> u8 {
+        self.numerator.mul_small(10);
+        self.numerator.div_rem_digit(&self.denominator)
+    }
+
+    /// compares `numerator + addend` with `denominator`
+    #[inline]
+    fn cmp_sum_with_one(&self, addend: &BigNat) -> Ordering {
+        self.numerator.add(addend).cmp(&self.denominator)
+    }
+
+    /// compares the value with one half
+    #[inline]
+    fn cmp_with_half(&self) -> Ordering {
+        let mut doubled = self.numerator.clone();
+        doubled.shl(1);
+        doubled.cmp(&self.denominator)
+    }
+}
+
+impl Decimal {
+    const ZERO: Self = Self {
+        digits: Vec::new(),
+        exponent: 0,
+    };
+
+    /// adds one to the last digit, removing any trailing zeros produced by the carry
+    fn increment(&mut self) {
+        while let Some(last) = self.digits.last_mut() {
+            if *last < 9 {
+                *last += 1;
+                return;
+            }
+            self.digits.pop();
+        }
+        self.digits.push(1);
+        self.exponent += 1;
+    }
+
+    /// writes the number in positional notation, with `fraction_len` digits after the decimal point
+    fn write_fixed(&self, s: &mut String, fraction_len: usize) {
+        let digit = |i: i64| {
+            if i >= 0 && (i as usize) < self.digits.len() {
+                (b'0' + self.digits[i as usize]) as char
+            } else {
+                '0'
+            }
+        };
+        if self.exponent <= 0 {
+            s.push('0');
+        } else {
+            s.extend((0..self.exponent).map(digit));
+        }
+        if fraction_len > 0 {
+            s.push('.');
+            s.extend((self.exponent..self.exponent + fraction_len as i64).map(digit));
+        }
+    }
+
+    /// writes the number in scientific notation, with `fraction_len` digits after the decimal point
+    fn write_exp(&self, s: &mut String, fraction_len: usize, e: char) {
+        let digit = |i: usize| (b'0' + self.digits.get(i).copied().unwrap_or(0)) as char;
+        s.push(digit(0));
+        if fraction_len > 0 {
+            s.push('.');
+            s.extend((1..=fraction_len).map(digit));
+        }
+        let exponent = if self.digits.is_empty() {
+            0
+        } else {
+            self.exponent - 1
+        };
+        write!(s, "{}{}", e, exponent).unwrap();
+    }
+}
+
+impl<const W: usize, const MB: usize> Float<W, MB> {
+    /// returns `(f, e)` such that `|self| = f * 2^e`. `self` must be finite
+    #[inline]
+    fn into_big_nat_parts(self) -> (BigNat, i64) {
+        let (_, exponent, mantissa) = self.into_signed_parts();
+        (
+            BigNat::from_uint(mantissa),
+            exponent as i64 - Self::MB as i64,
+        )
+    }
+
+    /// returns `(numerator, denominator, unit)` such that `numerator / denominator = f * 2^e` and `unit / denominator = 2^e`, where the numerator and denominator have both been scaled by `2^scale`
+    #[inline]
+    fn scaled_fraction(
+        mantissa: BigNat,
+        exponent: i64,
+        scale: Exponent,
+    ) -> (BigNat, BigNat, BigNat) {
+        let mut numerator = mantissa;
+        let mut denominator = BigNat::from_u64(1);
+        let mut unit = BigNat::from_u64(1);
+        numerator.shl(scale);
+        denominator.shl(scale);
+        if exponent >= 0 {
+            numerator.shl(exponent as Exponent);
+            unit.shl(exponent as Exponent);
+        } else {
+            denominator.shl((-exponent) as Exponent);
+        }
+        (numerator, denominator, unit)
+    }
+
+    /// estimate of the smallest `k` such that `f * 2^e < 10^k`. the estimate is at most one less than the correct value
+    #[inline]
+    fn estimate_decimal_exponent(mantissa: &BigNat, exponent: i64) -> i64 {
+        floor_log10_pow2(exponent + mantissa.bit_width() as i64 - 1) + 1
+    }
+
+    /// the shortest decimal which rounds to `|self|` when parsed, choosing the closest such decimal to `|self|` if there are several. `self` must be finite
+    fn to_shortest_decimal(self) -> Decimal {
+        if self.is_zero() {
+            return Decimal::ZERO;
+        }
+        let (mantissa, exponent) = self.into_big_nat_parts();
+        let decimal_exponent = Self::estimate_decimal_exponent(&mantissa, exponent);
+
+        // if the mantissa is even, then the midpoints between `self` and its neighbours round to `self` when parsed
+        let inclusive = !mantissa.bit(0);
+        // the gap below `self` is half the gap above it if `self` is a power of two whose predecessor is normal
+        let (_, unbiased_exponent, _) = self.into_signed_parts();
+        let asymmetric = mantissa.trailing_zeros() == Self::MB
+            && mantissa.bit_width() == Self::MB + 1
+            && unbiased_exponent > Self::MIN_EXP - 1;
+
+        // `margin_low` and `margin_high` are the distances to the midpoints between `self` and its neighbours. everything is scaled by four, so that these are integers
+        let (numerator, denominator, margin_scale) = Self::scaled_fraction(mantissa, exponent, 2);
+        let (mut margin_low, mut margin_high) = (margin_scale.clone(), margin_scale);
+        margin_high.shl(1);
+        if !asymmetric {
+            margin_low.shl(1);
+        }
+        let mut value = ScaledValue::new(
+            numerator,
+            denominator,
+            decimal_exponent,
+            &mut [&mut margin_low, &mut margin_high],
+        );
+
+        let high_reached =
+            |value: &ScaledValue, margin_high: &BigNat| match value.cmp_sum_with_one(margin_high) {
+                Ordering::Greater => true,
+                Ordering::Equal => inclusive,
+                Ordering::Less => false,
+            };
+        // fix the estimate of the exponent, so that the upper end of the rounding interval is less than `10^exponent`, or equal to it if the interval is inclusive
+        while high_reached(&value, &margin_high) {
+            value.div_ten();
+        }
+
+        let mut digits = Vec::new();
+        loop {
+            let digit = value.next_digit();
+            margin_low.mul_small(10);
+            margin_high.mul_small(10);
+            let low_reached = match value.numerator.cmp(&margin_low) {
+                Ordering::Less => true,
+                Ordering::Equal => inclusive,
+                Ordering::Greater => false,
+            };
+            let high_reached = high_reached(&value, &margin_high);
+            let round_up = match (low_reached, high_reached) {
+                (false, false) => {
+                    digits.push(digit);
+                    continue;
+                }
+                (true, false) => false,
+                (false, true) => true,
+                (true, true) => !matches!(value.cmp_with_half(), Ordering::Less),
+            };
+            digits.push(digit + round_up as u8);
+            break;
+        }
+        Decimal {
+            digits,
+            exponent: value.exponent,
+        }
+    }
+
+    /// `|self|` rounded half to even to a decimal with digits up to and including the place value `10^(k - digit_count(k))`, where `k` is the exponent of `|self|`
+    fn to_exact_decimal(self, digit_count: impl Fn(i64) -> i64) -> Decimal {
+        if self.is_zero() {
+            return Decimal::ZERO;
+        }
+        let (mantissa, exponent) = self.into_big_nat_parts();
+        let decimal_exponent = Self::estimate_decimal_exponent(&mantissa, exponent);
+        let (numerator, denominator, _) = Self::scaled_fraction(mantissa, exponent, 0);
+        let mut value = ScaledValue::new(numerator, denominator, decimal_exponent, &mut []);
+        if !matches!(value.numerator.cmp(&value.denominator), Ordering::Less) {
+            value.div_ten();
+        }
+        let count = digit_count(value.exponent);
+        if count < 0 {
+            // the value is less than a tenth of the last place value, so rounds to zero
+            return Decimal::ZERO;
+        }
+        let mut decimal = Decimal {
+            digits: (0..count).map(|_| value.next_digit()).collect(),
+            exponent: value.exponent,
+        };
+        let odd = decimal.digits.last().is_some_and(|digit| digit % 2 == 1);
+        let round_up = match value.cmp_with_half() {
+            Ordering::Greater => true,
+            Ordering::Equal => odd,
+            Ordering::Less => false,
+        };
+        if round_up {
+            decimal.increment();
+        }
+        while let Some(0) = decimal.digits.last() {
+            decimal.digits.pop();
+        }
+        if decimal.digits.is_empty() {
+            return Decimal::ZERO;
+        }
+        decimal
+    }
+
+    fn fmt_decimal(&self, f: &mut Formatter, notation: Notation) -> fmt::Result {
+        if self.is_nan() {
+            return pad(f, "", "NaN");
+        }
+        let sign = if self.is_sign_negative() {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        if self.is_infinite() {
+            return pad(f, sign, "inf");
+        }
+        let mut s = String::new();
+        match (notation, f.precision()) {
+            (Notation::Fixed | Notation::Debug, Some(precision)) => {
+                let decimal = self.to_exact_decimal(|exponent| exponent + precision as i64);
+                decimal.write_fixed(&mut s, precision);
+            }
+            (Notation::Exp(e), Some(precision)) => {
+                let decimal = self.to_exact_decimal(|_| precision as i64 + 1);
+                decimal.write_exp(&mut s, precision, e);
+            }
+            (notation, None) => {
+                let decimal = self.to_shortest_decimal();
+                let fraction_len = (decimal.digits.len() as i64 - decimal.exponent).max(0) as usize;
+                match notation {
+                    Notation::Fixed => decimal.write_fixed(&mut s, fraction_len),
+                    Notation::Exp(e) => {
+                        decimal.write_exp(&mut s, decimal.digits.len().saturating_sub(1), e)
+                    }
+                    // like the primitive float types, use scientific notation for very large and very small numbers, and always include a decimal point otherwise
+                    Notation::Debug => {
+                        if !decimal.digits.is_empty()
+                            && (decimal.exponent > 16 || decimal.exponent < -3)
+                        {
+                            decimal.write_exp(&mut s, decimal.digits.len() - 1, 'e');
+                        } else {
+                            decimal.write_fixed(&mut s, fraction_len.max(1));
+                        }
+                    }
+                }
+            }
+        }
+        pad(f, sign, &s)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Notation {
+    Fixed,
+    Exp(char),
+    Debug,
+}
+
+/// writes `sign` followed by `body` to `f`, respecting the width, fill, alignment and sign-aware zero padding options of `f`. as with the primitive float types, numbers are right-aligned by default
+fn pad(f: &mut Formatter, sign: &str, body: &str) -> fmt::Result {
+    let len = sign.len() + body.len();
+    let width = match f.width() {
+        Some(width) if width > len => width,
+        _ => {
+            f.write_str(sign)?;
+            return f.write_str(body);
+        }
+    };
+    let padding = width - len;
+    if f.sign_aware_zero_pad() {
+        f.write_str(sign)?;
+        for _ in 0..padding {
+            f.write_char('0')?;
+        }
+        return f.write_str(body);
+    }
+    let (before, after) = match f.align() {
+        Some(Alignment::Left) => (0, padding),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    f.write_str(sign)?;
+    f.write_str(body)?;
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
+impl<const W: usize, const MB: usize> Debug for Float<W, MB> {
+    /// Formats the float in the same way as [`Display`], except that numbers with a magnitude of at least `1e16` or less than `1e-4` are formatted in scientific notation, and other numbers always include a decimal point.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_decimal(f, Notation::Debug)
+    }
+}
+
+impl<const W: usize, const MB: usize> Display for Float<W, MB> {
+    /// Formats the float in positional notation. If no precision is specified, the shortest string which parses back to the same float is used. Otherwise, the exact value of the float is rounded half to even to the given number of decimal places.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_decimal(f, Notation::Fixed)
+    }
+}
+
+impl<const W: usize, const MB: usize> LowerExp for Float<W, MB> {
+    /// Formats the float in scientific notation, with a lowercase `e`. If no precision is specified, the shortest string which parses back to the same float is used. Otherwise, the exact value of the float is rounded half to even to the given number of decimal places.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_decimal(f, Notation::Exp('e'))
+    }
+}
+
+impl<const W: usize, const MB: usize> UpperExp for Float<W, MB> {
+    /// Formats the float in scientific notation, with an uppercase `E`. If no precision is specified, the shortest string which parses back to the same float is used. Otherwise, the exact value of the float is rounded half to even to the given number of decimal places.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_decimal(f, Notation::Exp('E'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Float;
+    use crate::types::{F32, F64};
+
+    macro_rules! formats_correctly {
+        ($a: expr, $b: expr; $($format: literal), *) => {
+            true $(&& format!($format, $a) == format!($format, $b))*
+        };
+    }
+
+    macro_rules! test_formats {
+        ($($format: literal), *) => {
+            fn formats_correctly(a: f64, b: f32) -> bool {
+                formats_correctly!(F64::from(a), a; $($format), *) && formats_correctly!(F32::from(b), b; $($format), *)
+            }
+        };
+    }
+
+    test_formats!(
+        "{}",
+        "{:e}",
+        "{:E}",
+        "{:+}",
+        "{:.0}",
+        "{:.3}",
+        "{:.20}",
+        "{:.0e}",
+        "{:.5e}",
+        "{:.30E}",
+        "{:12}",
+        "{:<12}",
+        "{:^+12}",
+        "{:*>12}",
+        "{:012}",
+        "{:+012.3}",
+        "{:012e}",
+        "{:?}",
+        "{:+?}",
+        "{:.2?}",
+        "{:12?}"
+    );
+
+    fn round_trips<const W: usize, const MB: usize>(a: Float<W, MB>) -> bool {
+        [format!("{}", a), format!("{:e}", a)]
+            .iter()
+            .all(|s| match s.parse::<Float<W, MB>>() {
+                Ok(b) => a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()),
+                Err(_) => false,
+            })
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_fmt(a: f64, b: f32) -> bool {
+            formats_correctly(a, b)
+        }
+
+        fn quickcheck_fmt_from_bits(a: u64, b: u32) -> bool {
+            formats_correctly(f64::from_bits(a), f32::from_bits(b))
+        }
+
+        fn quickcheck_round_trip(a: Float<2, 10>, b: Float<10, 64>, c: Float<16, 112>) -> bool {
+            round_trips(a) && round_trips(b) && round_trips(c)
+        }
+    }
+
+    #[test]
+    fn fmt() {
+        let cases = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            0.1,
+            0.3,
+            0.5,
+            1.5,
+            2.5,
+            0.125,
+            100.0,
+            1e21,
+            1e22,
+            1e23,
+            1e-7,
+            123456.789,
+            9.5,
+            0.05,
+            0.005,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            5e-324,
+            1.7976931348623157e308,
+            f64::from_bits(0x000f_ffff_ffff_ffff),
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+            9007199254740993.0,
+            0.3f32 as f64,
+        ];
+        for case in cases {
+            assert!(formats_correctly(case, case as f32), "{case}");
+        }
+        for case in [
+            f32::MAX,
+            f32::MIN_POSITIVE,
+            1e-45,
+            16777217.0,
+            3.4028235e38,
+            f32::EPSILON,
+        ] {
+            assert!(formats_correctly(case as f64, case), "{case}");
+        }
+
+        type F256 = Float<32, 236>;
+        let tenth: F256 = "0.1".parse().unwrap();
+        for case in [
+            F256::MAX,
+            F256::MIN_POSITIVE,
+            F256::MIN_POSITIVE_SUBNORMAL,
+            F256::EPSILON,
+            tenth,
+        ] {
+            assert!(round_trips(case), "{case:e}");
+        }
+    }
+}
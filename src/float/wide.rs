@@ -0,0 +1,157 @@
+use crate::Exponent;
+use crate::Uint;
+use core::cmp::Ordering;
+
+/// Unsigned integer with twice as many bits as `Uint<W>`, stored as a (low, high) pair in the same form returned by `widening_mul`. This is wide enough to hold the exact product of two mantissas, with room to spare for alignment and a carry bit.
+#[derive(Clone, Copy)]
+pub(super) struct Wide<const W: usize> {
+    pub lo: Uint<W>,
+    pub hi: Uint<W>,
+}
+
+impl<const W: usize> Wide<W> {
+    pub const BITS: Exponent = Uint::<W>::BITS * 2;
+
+    pub const ZERO: Self = Self::from_uint(Uint::ZERO);
+
+    #[inline]
+    pub const fn from_uint(lo: Uint<W>) -> Self {
+        Self { lo, hi: Uint::ZERO }
+    }
+
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.lo.is_zero() && self.hi.is_zero()
+    }
+
+    #[inline]
+    pub const fn bit_width(self) -> Exponent {
+        if self.hi.is_zero() {
+            self.lo.bit_width()
+        } else {
+            self.hi.bit_width() + Uint::<W>::BITS
+        }
+    }
+
+    #[inline]
+    pub const fn trailing_zeros(self) -> Exponent {
+        if self.lo.is_zero() {
+            self.hi.trailing_zeros() + Uint::<W>::BITS
+        } else {
+            self.lo.trailing_zeros()
+        }
+    }
+
+    #[inline]
+    pub const fn bit(&self, index: Exponent) -> bool {
+        if index < Uint::<W>::BITS {
+            self.lo.bit(index)
+        } else {
+            self.hi.bit(index - Uint::<W>::BITS)
+        }
+    }
+
+    #[inline]
+    pub const fn set_bit(&mut self, index: Exponent, value: bool) {
+        if index < Uint::<W>::BITS {
+            self.lo.set_bit(index, value)
+        } else {
+            self.hi.set_bit(index - Uint::<W>::BITS, value)
+        }
+    }
+
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        match self.hi.cmp(&other.hi) {
+            Ordering::Equal => self.lo.cmp(&other.lo),
+            ordering => ordering,
+        }
+    }
+
+    #[inline]
+    pub const fn shl(self, rhs: Exponent) -> Self {
+        debug_assert!(rhs < Self::BITS);
+        if rhs >= Uint::<W>::BITS {
+            return Self {
+                lo: Uint::ZERO,
+                hi: self.lo.unbounded_shl(rhs - Uint::<W>::BITS),
+            };
+        }
+        if rhs == 0 {
+            return self;
+        }
+        Self {
+            lo: self.lo.unbounded_shl(rhs),
+            hi: self
+                .hi
+                .unbounded_shl(rhs)
+                .bitor(self.lo.unbounded_shr(Uint::<W>::BITS - rhs)),
+        }
+    }
+
+    #[inline]
+    pub const fn shr(self, rhs: Exponent) -> Self {
+        if rhs >= Self::BITS {
+            return Self::ZERO;
+        }
+        if rhs >= Uint::<W>::BITS {
+            return Self::from_uint(self.hi.unbounded_shr(rhs - Uint::<W>::BITS));
+        }
+        if rhs == 0 {
+            return self;
+        }
+        Self {
+            lo: self
+                .lo
+                .unbounded_shr(rhs)
+                .bitor(self.hi.unbounded_shl(Uint::<W>::BITS - rhs)),
+            hi: self.hi.unbounded_shr(rhs),
+        }
+    }
+
+    /// right shift, setting the least significant bit of the result if any of the discarded bits were set. the result then lies strictly between the same two multiples of two as the exact (unrounded) shifted value, so rounding it to any precision coarser than two bits gives the same result as rounding the exact value
+    #[inline]
+    pub const fn shr_jamming(self, rhs: Exponent) -> Self {
+        let mut out = self.shr(rhs);
+        if rhs != 0 && self.trailing_zeros() < rhs && !self.is_zero() {
+            out.lo.set_bit(0, true);
+        }
+        out
+    }
+
+    #[inline]
+    pub const fn add(self, rhs: Self) -> Self {
+        let (lo, carry) = self.lo.overflowing_add(rhs.lo);
+        let (hi, _) = self.hi.carrying_add(rhs.hi, carry);
+        Self { lo, hi }
+    }
+
+    #[inline]
+    pub const fn sub(self, rhs: Self) -> Self {
+        let (lo, borrow) = self.lo.overflowing_sub(rhs.lo);
+        let (hi, _) = self.hi.borrowing_sub(rhs.hi, borrow);
+        Self { lo, hi }
+    }
+
+    /// returns the integer square root of `self`, and whether the square root is inexact (i.e. whether `self` is not a perfect square). computed digit by digit, two bits of `self` at a time
+    #[inline]
+    pub const fn isqrt_rem(self) -> (Self, bool) {
+        let mut root = Self::ZERO;
+        let mut rem = Self::ZERO;
+        let mut i = self.bit_width().div_ceil(2);
+        while i > 0 {
+            i -= 1;
+            rem = rem.shl(2);
+            rem.set_bit(1, self.bit(2 * i + 1));
+            rem.set_bit(0, self.bit(2 * i));
+            let mut trial = root.shl(2);
+            trial.set_bit(0, true);
+            root = root.shl(1);
+            if !matches!(rem.cmp(&trial), Ordering::Less) {
+                rem = rem.sub(trial);
+                root.set_bit(0, true);
+            }
+        }
+        (root, !rem.is_zero())
+    }
+}
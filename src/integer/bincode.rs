@@ -0,0 +1,145 @@
+use super::Integer;
+use ::bincode::config::{Config, Endianness, IntEncoding};
+use ::bincode::de::read::Reader;
+use ::bincode::de::{BorrowDecoder, Decoder};
+use ::bincode::enc::write::Writer;
+use ::bincode::enc::Encoder;
+use ::bincode::error::{DecodeError, EncodeError};
+use ::bincode::{BorrowDecode, Decode, Encode};
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Encode for Integer<S, N, B, OM> {
+    /// With fixed integer encoding, writes the `N` bytes of `self` in the configured byte order, so with the default little-endian configuration, the bytes returned by [`to_bytes`](Integer::to_bytes) are written.
+    ///
+    /// With variable integer encoding, writes the number of bytes as a `usize`, followed by the bytes of `self` in the configured byte order with redundant leading bytes removed, as returned by [`to_le_bytes_trimmed`](Integer::to_le_bytes_trimmed) or [`to_be_bytes_trimmed`](Integer::to_be_bytes_trimmed). Zero is encoded as a single byte, and small values in only a few bytes.
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        let big_endian = matches!(encoder.config().endianness(), Endianness::Big);
+        if matches!(encoder.config().int_encoding(), IntEncoding::Fixed) {
+            let mut bytes = self.to_bytes();
+            if big_endian {
+                bytes.reverse();
+            }
+            return encoder.writer().write(&bytes);
+        }
+        let (mut bytes, start) = self.to_minimal_be_bytes();
+        let bytes = &mut bytes[start..];
+        if !big_endian {
+            bytes.reverse();
+        }
+        bytes.len().encode(encoder)?;
+        encoder.writer().write(bytes)
+    }
+}
+
+impl<Context, const S: bool, const N: usize, const B: usize, const OM: u8> Decode<Context> for Integer<S, N, B, OM> {
+    /// Decodes an integer written as described in the [`Encode`] implementation. Variable-length encodings with redundant leading bytes are accepted. An error is returned if the value is out of range for the type.
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        let len = if matches!(decoder.config().int_encoding(), IntEncoding::Fixed) {
+            N
+        } else {
+            usize::decode(decoder)?
+        };
+        if len > N {
+            return Err(DecodeError::Other("(bnum) integer out of range for its bit width"));
+        }
+        decoder.claim_bytes_read(len)?;
+        let mut buf = [0; N];
+        let bytes = &mut buf[..len];
+        decoder.reader().read(bytes)?;
+        let value = if matches!(decoder.config().endianness(), Endianness::Big) {
+            Self::from_be_slice(bytes)
+        } else {
+            Self::from_le_slice(bytes)
+        };
+        value.ok_or(DecodeError::Other("(bnum) integer out of range for its bit width"))
+    }
+}
+
+impl<'de, Context, const S: bool, const N: usize, const B: usize, const OM: u8> BorrowDecode<'de, Context> for Integer<S, N, B, OM> {
+    #[inline]
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        Self::decode(decoder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I256, U256};
+    use crate::{Int, Uint};
+    use bincode::config;
+    use bincode::{Decode, Encode};
+
+    fn encode<T: Encode, C: config::Config>(value: T, config: C) -> ([u8; 48], usize) {
+        let mut buf = [0; 48];
+        let len = bincode::encode_into_slice(value, &mut buf, config).unwrap();
+        (buf, len)
+    }
+
+    fn decode<T: Decode<()>, C: config::Config>(bytes: &[u8], config: C) -> Option<T> {
+        match bincode::decode_from_slice(bytes, config) {
+            Ok((value, len)) if len == bytes.len() => Some(value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn fixed() {
+        let config = config::standard().with_fixed_int_encoding();
+        let a = 0x0102.as_::<U256>();
+        let (buf, len) = encode(a, config);
+        assert_eq!(buf[..len], a.to_bytes());
+        assert_eq!(decode(&buf[..len], config), Some(a));
+
+        let (buf, len) = encode(a, config.with_big_endian());
+        assert_eq!(buf[30..len], [0x01, 0x02]);
+        assert_eq!(decode(&buf[..len], config.with_big_endian()), Some(a));
+
+        assert_eq!(decode::<U256, _>(&[0; 31], config), None);
+        assert_eq!(decode::<Uint<3, 20>, _>(&[0x00, 0x00, 0x10], config), None);
+        assert_eq!(decode(&[0x00, 0x00, 0xF8], config), Some(Int::<3, 20>::MIN));
+    }
+
+    #[test]
+    fn variable() {
+        let config = config::standard();
+        let cases: [(i128, &[u8]); 6] = [
+            (0, &[0]),
+            (1, &[1, 0x01]),
+            (0x1234, &[2, 0x34, 0x12]),
+            (-1, &[1, 0xFF]),
+            (128, &[2, 0x80, 0x00]),
+            (-129, &[2, 0x7F, 0xFF]),
+        ];
+        for (value, expected) in cases {
+            let (buf, len) = encode(value.as_::<I256>(), config);
+            assert_eq!(&buf[..len], expected);
+            assert_eq!(decode(expected, config), Some(value.as_::<I256>()));
+        }
+        let (buf, len) = encode(128.as_::<U256>(), config);
+        assert_eq!(buf[..len], [1, 0x80]);
+        let (buf, len) = encode(0x1234.as_::<U256>(), config.with_big_endian());
+        assert_eq!(buf[..len], [2, 0x12, 0x34]);
+        let (buf, len) = encode(U256::MAX, config);
+        assert_eq!(buf[0], 32);
+        assert_eq!(buf[1..len], [0xFF; 32]);
+
+        // redundant leading bytes are accepted, but not more than `N` bytes
+        assert_eq!(decode(&[3, 0x01, 0x00, 0x00], config), Some(1.as_::<U256>()));
+        assert_eq!(decode::<Uint<2>, _>(&[3, 0x01, 0x00, 0x00], config), None);
+        assert_eq!(decode::<Uint<3, 20>, _>(&[3, 0x00, 0x00, 0x10], config), None);
+        assert_eq!(decode::<U256, _>(&[2, 0x01], config), None);
+    }
+
+    fn roundtrip<C: config::Config>(a: U256, b: I256, config: C) -> bool {
+        let mut buf = [0; 80];
+        let len = bincode::encode_into_slice((a, b), &mut buf, config).unwrap();
+        decode(&buf[..len], config) == Some((a, b))
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_roundtrip(a: U256, b: I256) -> bool {
+            let config = config::standard();
+            roundtrip(a, b, config) && roundtrip(a, b, config.with_big_endian()) && roundtrip(a, b, config.with_fixed_int_encoding()) && roundtrip(a, b, config.with_big_endian().with_fixed_int_encoding())
+        }
+    }
+}
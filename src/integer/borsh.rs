@@ -0,0 +1,157 @@
+use super::Integer;
+use ::borsh::io::{Error, ErrorKind, Read, Result, Write};
+use ::borsh::{BorshDeserialize, BorshSerialize};
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> BorshSerialize for Integer<S, N, B, OM> {
+    /// Writes the `N` little-endian bytes of `self`. If the bit width is not a multiple of 8, the unused high bits of the last byte are zero for unsigned integers, and copies of the sign bit for signed integers.
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        writer.write_all(&self.bytes)
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> BorshDeserialize for Integer<S, N, B, OM> {
+    /// Reads `N` little-endian bytes, returning an error if the unused high bits of the last byte are not set as described in the [`BorshSerialize`] implementation, since then the bytes do not represent a value of this type.
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        let mut bytes = [0; N];
+        reader.read_exact(&mut bytes)?;
+        match Self::from_le_slice(&bytes) {
+            Some(out) => Ok(out),
+            None => Err(Error::new(ErrorKind::InvalidData, "integer out of range for its bit width")),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+type Definitions = alloc::collections::BTreeMap<::borsh::schema::Declaration, ::borsh::schema::Definition>;
+
+/// the declaration of a type's schema together with the function adding its definitions
+#[cfg(feature = "alloc")]
+type Schema = (::borsh::schema::Declaration, fn(&mut Definitions));
+
+#[cfg(feature = "alloc")]
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N, B, OM> {
+    /// the declaration and the function adding the definitions of the primitive integer type with the same signedness and bit width as `Self`, if there is one whose borsh serialization is the same as that of `Self`
+    fn borsh_primitive_schema() -> Option<Schema> {
+        use ::borsh::BorshSchema;
+
+        if Self::BITS as usize != N * 8 {
+            return None;
+        }
+        let schema: Schema = match (S, N) {
+            (false, 1) => (u8::declaration(), u8::add_definitions_recursively),
+            (false, 2) => (u16::declaration(), u16::add_definitions_recursively),
+            (false, 4) => (u32::declaration(), u32::add_definitions_recursively),
+            (false, 8) => (u64::declaration(), u64::add_definitions_recursively),
+            (false, 16) => (u128::declaration(), u128::add_definitions_recursively),
+            (true, 1) => (i8::declaration(), i8::add_definitions_recursively),
+            (true, 2) => (i16::declaration(), i16::add_definitions_recursively),
+            (true, 4) => (i32::declaration(), i32::add_definitions_recursively),
+            (true, 8) => (i64::declaration(), i64::add_definitions_recursively),
+            (true, 16) => (i128::declaration(), i128::add_definitions_recursively),
+            _ => return None,
+        };
+        Some(schema)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> ::borsh::BorshSchema for Integer<S, N, B, OM> {
+    /// If the bit width is that of a primitive integer type (`u8` to `u128` or `i8` to `i128`), adds exactly the definition of that type, since the serializations are the same. Otherwise, adds a definition as a sequence of `N` bytes.
+    fn add_definitions_recursively(definitions: &mut Definitions) {
+        use ::borsh::schema::{add_definition, Definition};
+
+        if let Some((_, add_definitions)) = Self::borsh_primitive_schema() {
+            add_definitions(definitions);
+            return;
+        }
+        let definition = Definition::Sequence {
+            length_width: Definition::ARRAY_LENGTH_WIDTH,
+            length_range: N as u64..=N as u64,
+            elements: u8::declaration(),
+        };
+        add_definition(Self::declaration(), definition, definitions);
+        u8::add_definitions_recursively(definitions);
+    }
+
+    /// If the bit width is that of a primitive integer type, the name of that type, for example `u128`. Otherwise, a name of the form `bnum::Integer<S, N, BITS>`, for example `bnum::Integer<false, 32, 256>` or `bnum::Integer<true, 3, 20>`, which cannot clash with the names of borsh's primitive types.
+    fn declaration() -> ::borsh::schema::Declaration {
+        match Self::borsh_primitive_schema() {
+            Some((declaration, _)) => declaration,
+            None => format!("bnum::Integer<{}, {}, {}>", S, N, Self::BITS),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I128, U256};
+    use crate::{Int, Uint};
+    use borsh::BorshDeserialize;
+
+    /// 20-bit signed integer
+    type I20 = Int<3, 20>;
+
+    /// 20-bit unsigned integer
+    type U20 = Uint<3, 20>;
+
+    #[test]
+    fn serialize() {
+        assert_eq!(borsh::to_vec(&0x1234.as_::<U256>()).unwrap()[..3], [0x34, 0x12, 0x00]);
+        assert_eq!(borsh::to_vec(&(-2).as_::<I128>()).unwrap(), [0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(borsh::to_vec(&U20::MAX).unwrap(), [0xFF, 0xFF, 0x0F]);
+        assert_eq!(borsh::to_vec(&I20::MIN).unwrap(), [0x00, 0x00, 0xF8]);
+    }
+
+    #[test]
+    fn deserialize_padding() {
+        assert_eq!(U20::try_from_slice(&[0xFF, 0xFF, 0x0F]).ok(), Some(U20::MAX));
+        assert!(U20::try_from_slice(&[0xFF, 0xFF, 0x1F]).is_err());
+        assert_eq!(I20::try_from_slice(&[0x00, 0x00, 0xF8]).ok(), Some(I20::MIN));
+        assert_eq!(I20::try_from_slice(&[0xFF, 0xFF, 0x07]).ok(), Some(I20::MAX));
+        // the padding bits must be copies of the sign bit
+        assert!(I20::try_from_slice(&[0x00, 0x00, 0x08]).is_err());
+        assert!(I20::try_from_slice(&[0xFF, 0xFF, 0xF7]).is_err());
+        assert!(U20::try_from_slice(&[0xFF, 0xFF]).is_err());
+        assert!(U20::try_from_slice(&[0xFF, 0xFF, 0x0F, 0x00]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn schema() {
+        use borsh::BorshSchema;
+
+        assert_eq!(U256::declaration(), "bnum::Integer<false, 32, 256>");
+        assert_eq!(I20::declaration(), "bnum::Integer<true, 3, 20>");
+        assert_eq!(I128::declaration(), "i128");
+        assert_eq!(borsh::max_serialized_size::<I20>(), Ok(3));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn schema_with_primitives() {
+        use borsh::schema::{BorshSchemaContainer, Definition};
+
+        // primitive and bnum integers of the same width side by side, as well as a bnum integer whose sequence definition refers to `u8`
+        type Fields = (u8, Uint<1>, u64, Uint<8>, i128, I128, U20, U256);
+        let container = BorshSchemaContainer::for_type::<Fields>();
+        assert_eq!(container.validate(), Ok(()));
+        assert_eq!(container.get_definition("u64"), Some(&Definition::Primitive(8)));
+        assert_eq!(container.max_serialized_size(), Ok(1 + 1 + 8 + 8 + 16 + 16 + 3 + 32));
+        let value: Fields = (1, Uint::ONE, 2, 2.as_::<Uint<8>>(), -3, I128::MIN, U20::MAX, U256::MAX);
+        let bytes = borsh::to_vec(&value).unwrap();
+        assert_eq!(bytes.len(), 1 + 1 + 8 + 8 + 16 + 16 + 3 + 32);
+        assert_eq!(bytes[..1], bytes[1..2]);
+        assert_eq!(bytes[2..10], 2u64.to_le_bytes());
+        assert_eq!(bytes[10..18], 2u64.to_le_bytes());
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: U256, b: I20) -> bool {
+            U256::try_from_slice(&borsh::to_vec(&a).unwrap()).ok() == Some(a)
+                && I20::try_from_slice(&borsh::to_vec(&b).unwrap()).ok() == Some(b)
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use super::Integer;
+use ::bytemuck::{Pod, Zeroable};
+
+// SAFETY: `Integer` is `#[repr(transparent)]` over `[u8; N]`, and the all-zero bit pattern is the value zero for every bit width and signedness
+unsafe impl<const S: bool, const N: usize, const B: usize, const OM: u8> Zeroable for Integer<S, N, B, OM> {}
+
+/// Only implemented for integers which use all the bits of their bytes (i.e. `B` is `0`), since for other bit widths, the unused high bits of the last byte must be zero for unsigned integers and copies of the sign bit for signed integers, so not every bit pattern is a valid value.
+// SAFETY: `Integer` is `#[repr(transparent)]` over `[u8; N]`, so it has no padding bytes, and when `B` is `0` every bit pattern is a valid value
+unsafe impl<const S: bool, const N: usize, const OM: u8> Pod for Integer<S, N, 0, OM> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I256, U256};
+    use crate::Uint;
+
+    #[test]
+    fn cast_slice() {
+        let mut bytes = [0u8; 64];
+        bytes[0] = 0x01;
+        bytes[32..].fill(0xFF);
+        let ints: &[I256] = bytemuck::cast_slice(&bytes);
+        assert_eq!(ints, [1.as_::<I256>(), (-1).as_()]);
+        let ints: &[U256] = bytemuck::cast_slice(&bytes);
+        assert_eq!(ints, [1.as_::<U256>(), U256::MAX]);
+        assert_eq!(bytemuck::bytes_of(&ints[1]), [0xFF; 32]);
+
+        assert_eq!(<Uint<3, 20> as bytemuck::Zeroable>::zeroed(), Uint::<3, 20>::ZERO);
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_matches_to_bytes(a: U256, b: I256) -> bool {
+            bytemuck::bytes_of(&a) == a.to_bytes() && bytemuck::pod_read_unaligned::<I256>(&b.to_le_bytes()) == b
+        }
+    }
+}
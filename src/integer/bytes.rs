@@ -1,7 +1,11 @@
 use super::Integer;
 use crate::doc;
+use crate::errors::BufferTooSmallError;
 use crate::Byte;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// Methods that convert integers to and from byte arrays and slices.
 impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N, B, OM> {
     /// Returns the underlying bytes of `self` as an array.
@@ -195,7 +199,7 @@ impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N,
         if S && !negative && bytes[N - 1].leading_zeros() <= Self::LAST_BYTE_PAD_BITS {
             return None;
         }
-        if negative && bytes[N - 1].leading_ones() < Self::LAST_BYTE_PAD_BITS {
+        if negative && bytes[N - 1].leading_ones() <= Self::LAST_BYTE_PAD_BITS {
             return None;
         }
         Some(Self::from_bytes(bytes))
@@ -273,11 +277,261 @@ impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N,
         if S && !negative && bytes[N - 1].leading_zeros() <= Self::LAST_BYTE_PAD_BITS {
             return None;
         }
-        if negative && bytes[N - 1].leading_ones() < Self::LAST_BYTE_PAD_BITS {
+        if negative && bytes[N - 1].leading_ones() <= Self::LAST_BYTE_PAD_BITS {
             return None;
         }
         Some(Self::from_bytes(bytes))
     }
+
+    /// Returns the big-endian bytes of `self` with redundant leading bytes removed. This is the shortest slice of bytes that [`from_be_slice`](Self::from_be_slice) converts back to `self`.
+    ///
+    /// For unsigned integers, all leading zero bytes are removed. For signed integers, the bytes are in two's complement, and leading `0x00` or `0xFF` bytes are removed as long as the sign of the remaining bytes is unchanged. Zero is converted to an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::{U256, I256};
+    ///
+    /// let a: U256 = n!(0x1F00);
+    /// assert_eq!(a.to_be_bytes_trimmed(), vec![0x1F, 0x00]);
+    /// assert_eq!(U256::MIN.to_be_bytes_trimmed(), vec![]);
+    ///
+    /// let b: I256 = n!(128);
+    /// assert_eq!(b.to_be_bytes_trimmed(), vec![0x00, 0x80]);
+    ///
+    /// let c: I256 = n!(-129);
+    /// assert_eq!(c.to_be_bytes_trimmed(), vec![0xFF, 0x7F]);
+    /// assert_eq!(I256::from_be_slice(&c.to_be_bytes_trimmed()), Some(c));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use = doc::must_use_op!()]
+    pub fn to_be_bytes_trimmed(self) -> Vec<u8> {
+        let (bytes, start) = self.to_minimal_be_bytes();
+        bytes[start..].to_vec()
+    }
+
+    /// Returns the little-endian bytes of `self` with redundant trailing bytes removed. This is the shortest slice of bytes that [`from_le_slice`](Self::from_le_slice) converts back to `self`.
+    ///
+    /// The bytes are the same as those returned by [`to_be_bytes_trimmed`](Self::to_be_bytes_trimmed), in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::{U256, I256};
+    ///
+    /// let a: U256 = n!(0x1F00);
+    /// assert_eq!(a.to_le_bytes_trimmed(), vec![0x00, 0x1F]);
+    ///
+    /// let b: I256 = n!(-129);
+    /// assert_eq!(b.to_le_bytes_trimmed(), vec![0x7F, 0xFF]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use = doc::must_use_op!()]
+    pub fn to_le_bytes_trimmed(self) -> Vec<u8> {
+        let mut out = self.to_be_bytes_trimmed();
+        out.reverse();
+        out
+    }
+
+    /// Returns the shortest big-endian two's complement bytes of `self`, keeping exactly one sign bit. The most significant bit of the first byte is set if and only if `self` is negative, so the bytes can be read back by [`Int::from_be_slice`](crate::Int::from_be_slice) regardless of the signedness of `self`.
+    ///
+    /// For signed integers, this is the same as [`to_be_bytes_trimmed`](Self::to_be_bytes_trimmed), except that zero is converted to `[0x00]`. For unsigned integers, a leading `0x00` byte is added if the most significant bit of the value is set. This is the encoding used by, for example, ASN.1 DER `INTEGER`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::{U256, I256};
+    ///
+    /// let a: U256 = n!(0x80);
+    /// assert_eq!(a.to_be_bytes_signed_trimmed(), vec![0x00, 0x80]);
+    /// assert_eq!(U256::MIN.to_be_bytes_signed_trimmed(), vec![0x00]);
+    ///
+    /// let b: I256 = n!(-128);
+    /// assert_eq!(b.to_be_bytes_signed_trimmed(), vec![0x80]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use = doc::must_use_op!()]
+    pub fn to_be_bytes_signed_trimmed(self) -> Vec<u8> {
+        let (leading_zero, bytes, start) = self.to_minimal_signed_be_bytes();
+        let mut out = Vec::with_capacity(N - start + leading_zero as usize);
+        if leading_zero {
+            out.push(0);
+        }
+        out.extend_from_slice(&bytes[start..]);
+        out
+    }
+
+    /// Returns the shortest little-endian two's complement bytes of `self`, keeping exactly one sign bit.
+    ///
+    /// The bytes are the same as those returned by [`to_be_bytes_signed_trimmed`](Self::to_be_bytes_signed_trimmed), in reverse order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::U256;
+    ///
+    /// let a: U256 = n!(0xFF01);
+    /// assert_eq!(a.to_le_bytes_signed_trimmed(), vec![0x01, 0xFF, 0x00]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use = doc::must_use_op!()]
+    pub fn to_le_bytes_signed_trimmed(self) -> Vec<u8> {
+        let mut out = self.to_be_bytes_signed_trimmed();
+        out.reverse();
+        out
+    }
+
+    /// Writes the bytes returned by [`to_be_bytes_trimmed`](Self::to_be_bytes_trimmed) to the start of `buf`, and returns the number of bytes written. The rest of `buf` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is shorter than the number of bytes to be written. The error contains the required length, and `buf` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::{U256, I256};
+    ///
+    /// let mut buf = [0; 4];
+    ///
+    /// let a: U256 = n!(0x1F00);
+    /// assert_eq!(a.write_be_bytes_into(&mut buf), Ok(2));
+    /// assert_eq!(buf[..2], [0x1F, 0x00]);
+    ///
+    /// let b: I256 = n!(-129);
+    /// assert_eq!(b.write_be_bytes_into(&mut buf), Ok(2));
+    /// assert_eq!(buf[..2], [0xFF, 0x7F]);
+    ///
+    /// let c = U256::MAX;
+    /// assert_eq!(c.write_be_bytes_into(&mut buf).unwrap_err().required(), 32);
+    /// ```
+    pub const fn write_be_bytes_into(self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        let (bytes, start) = self.to_minimal_be_bytes();
+        write_trimmed(false, &bytes, start, buf, false)
+    }
+
+    /// Writes the bytes returned by [`to_le_bytes_trimmed`](Self::to_le_bytes_trimmed) to the start of `buf`, and returns the number of bytes written. The rest of `buf` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is shorter than the number of bytes to be written. The error contains the required length, and `buf` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::U256;
+    ///
+    /// let mut buf = [0; 4];
+    /// let a: U256 = n!(0x1F00);
+    /// assert_eq!(a.write_le_bytes_into(&mut buf), Ok(2));
+    /// assert_eq!(buf[..2], [0x00, 0x1F]);
+    /// ```
+    pub const fn write_le_bytes_into(self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        let (bytes, start) = self.to_minimal_be_bytes();
+        write_trimmed(false, &bytes, start, buf, true)
+    }
+
+    /// Writes the bytes returned by [`to_be_bytes_signed_trimmed`](Self::to_be_bytes_signed_trimmed) to the start of `buf`, and returns the number of bytes written. The rest of `buf` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is shorter than the number of bytes to be written. The error contains the required length, and `buf` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::U256;
+    ///
+    /// let mut buf = [0; 32];
+    /// let a: U256 = n!(0x80);
+    /// assert_eq!(a.write_be_bytes_signed_into(&mut buf), Ok(2));
+    /// assert_eq!(buf[..2], [0x00, 0x80]);
+    ///
+    /// assert_eq!(U256::MAX.write_be_bytes_signed_into(&mut buf).unwrap_err().required(), 33);
+    /// ```
+    pub const fn write_be_bytes_signed_into(self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        let (leading_zero, bytes, start) = self.to_minimal_signed_be_bytes();
+        write_trimmed(leading_zero, &bytes, start, buf, false)
+    }
+
+    /// Writes the bytes returned by [`to_le_bytes_signed_trimmed`](Self::to_le_bytes_signed_trimmed) to the start of `buf`, and returns the number of bytes written. The rest of `buf` is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `buf` is shorter than the number of bytes to be written. The error contains the required length, and `buf` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::I256;
+    ///
+    /// let mut buf = [0; 4];
+    /// let a: I256 = n!(-129);
+    /// assert_eq!(a.write_le_bytes_signed_into(&mut buf), Ok(2));
+    /// assert_eq!(buf[..2], [0x7F, 0xFF]);
+    /// ```
+    pub const fn write_le_bytes_signed_into(self, buf: &mut [u8]) -> Result<usize, BufferTooSmallError> {
+        let (leading_zero, bytes, start) = self.to_minimal_signed_be_bytes();
+        write_trimmed(leading_zero, &bytes, start, buf, true)
+    }
+
+    /// the big-endian bytes of `self` with redundant leading bytes removed, so that zero has no bytes. for signed integers, the bytes are in two's complement, and a leading byte is only removed if doing so does not change the sign
+    pub(crate) const fn to_minimal_be_bytes(self) -> ([u8; N], usize) {
+        let mut bytes = self.to_bytes();
+        let mut i = 0;
+        while i < N / 2 {
+            let tmp = bytes[i];
+            bytes[i] = bytes[N - 1 - i];
+            bytes[N - 1 - i] = tmp;
+            i += 1;
+        }
+        let negative = self.is_negative_internal();
+        let redundant = if negative { u8::MAX } else { 0 };
+        let mut start = 0;
+        while start < N && bytes[start] == redundant {
+            // the sign of the integer represented by the remaining bytes
+            let next_negative = S && start + 1 < N && bytes[start + 1] >= 0x80;
+            if next_negative != negative {
+                break;
+            }
+            start += 1;
+        }
+        (bytes, start)
+    }
+
+    /// the shortest big-endian two's complement bytes of `self` with exactly one sign bit: whether a leading zero byte is needed, followed by the minimal big-endian bytes. the bytes are never empty, and unsigned integers with a leading one bit need a leading zero byte so that they are not interpreted as negative
+    pub(crate) const fn to_minimal_signed_be_bytes(self) -> (bool, [u8; N], usize) {
+        let (bytes, start) = self.to_minimal_be_bytes();
+        let leading_zero = start == N || (!S && bytes[start] >= 0x80);
+        (leading_zero, bytes, start)
+    }
+}
+
+/// writes an optional leading zero byte followed by `bytes[start..]` to the start of `buf`, in reverse order if `le` is true
+const fn write_trimmed<const N: usize>(leading_zero: bool, bytes: &[u8; N], start: usize, buf: &mut [u8], le: bool) -> Result<usize, BufferTooSmallError> {
+    let len = N - start + leading_zero as usize;
+    if buf.len() < len {
+        return Err(BufferTooSmallError { required: len });
+    }
+    if leading_zero {
+        buf[if le { len - 1 } else { 0 }] = 0;
+    }
+    let offset = leading_zero as usize;
+    let mut i = start;
+    while i < N {
+        let j = offset + i - start;
+        buf[if le { len - 1 - j } else { j }] = bytes[i];
+        i += 1;
+    }
+    Ok(len)
 }
 
 impl<const S: bool, const N: usize, const OM: u8> Integer<S, N, 0, OM> {    
@@ -528,4 +782,67 @@ mod tests {
         #[cfg(feature = "alloc")]
         test_from_endian_slice!(stest, le);
     }
+    #[test]
+    fn from_slice_padding_bits() {
+        type I20 = crate::Int<3, 20>;
+
+        // the padding bits are ones but the sign bit is zero
+        assert_eq!(I20::from_be_slice(&[0xF7, 0xFF, 0xFF]), None);
+        assert_eq!(I20::from_le_slice(&[0xFF, 0xFF, 0xF7]), None);
+        assert_eq!(I20::from_be_slice(&[0xF8, 0x00, 0x00]), Some(I20::MIN));
+        assert_eq!(I20::from_le_slice(&[0xFF, 0xFF, 0x07]), Some(I20::MAX));
+    }
+
+    #[test]
+    fn write_trimmed() {
+        use crate::errors::BufferTooSmallError;
+        use crate::types::{I256, U256};
+
+        let mut buf = [0xAA; 4];
+        assert_eq!(U256::ZERO.write_be_bytes_into(&mut buf), Ok(0));
+        assert_eq!(U256::ZERO.write_be_bytes_signed_into(&mut buf), Ok(1));
+        assert_eq!(buf, [0x00, 0xAA, 0xAA, 0xAA]);
+        assert_eq!(I256::MIN.write_le_bytes_into(&mut buf), Err(BufferTooSmallError { required: 32 }));
+        assert_eq!(buf, [0x00, 0xAA, 0xAA, 0xAA]);
+        assert_eq!(crate::Uint::<3, 20>::MAX.write_le_bytes_signed_into(&mut buf), Ok(3));
+        assert_eq!(buf, [0xFF, 0xFF, 0x0F, 0xAA]);
+        assert_eq!(crate::Int::<3, 20>::MIN.write_be_bytes_into(&mut buf), Ok(3));
+        assert_eq!(buf, [0xF8, 0x00, 0x00, 0xAA]);
+        assert_eq!(crate::Uint::<1>::MAX.write_be_bytes_signed_into(&mut buf[..1]), Err(BufferTooSmallError { required: 2 }));
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_write_trimmed(a: u128, b: i128) -> bool {
+            use crate::cast::As;
+            use crate::types::{I256, U256};
+
+            let mut buf = [0; 33];
+            let len = a.as_::<U256>().write_be_bytes_into(&mut buf).unwrap();
+            // minimal: no leading zero byte
+            let mut passed = len == (128 - a.leading_zeros() as usize).div_ceil(8) && U256::from_be_slice(&buf[..len]) == Some(a.as_());
+            let len = a.as_::<U256>().write_le_bytes_signed_into(&mut buf).unwrap();
+            passed &= len > 0 && buf[len - 1] < 0x80 && I256::from_le_slice(&buf[..len]) == Some(a.as_());
+            passed &= len == 1 || I256::from_le_slice(&buf[..len - 1]) != Some(a.as_());
+
+            let len = b.as_::<I256>().write_le_bytes_into(&mut buf).unwrap();
+            passed &= I256::from_le_slice(&buf[..len]) == Some(b.as_()) && (len == 0 || I256::from_le_slice(&buf[..len - 1]) != Some(b.as_()));
+            let len2 = b.as_::<I256>().write_be_bytes_signed_into(&mut buf).unwrap();
+            passed && len2 == len.max(1) && I256::from_be_slice(&buf[..len2]) == Some(b.as_())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    quickcheck::quickcheck! {
+        fn quickcheck_to_bytes_trimmed(a: crate::types::U256, b: crate::types::I256) -> bool {
+            let mut buf = [0; 33];
+            let len = a.write_be_bytes_into(&mut buf).unwrap();
+            let mut passed = a.to_be_bytes_trimmed() == buf[..len];
+            let len = a.write_le_bytes_into(&mut buf).unwrap();
+            passed &= a.to_le_bytes_trimmed() == buf[..len];
+            let len = b.write_be_bytes_signed_into(&mut buf).unwrap();
+            passed &= b.to_be_bytes_signed_trimmed() == buf[..len];
+            let len = a.write_le_bytes_signed_into(&mut buf).unwrap();
+            passed && a.to_le_bytes_signed_trimmed() == buf[..len]
+        }
+    }
 }
\ No newline at end of file
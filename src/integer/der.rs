@@ -0,0 +1,159 @@
+use super::Integer;
+use ::der::{DecodeValue, EncodeValue, Error, FixedTag, Header, Length, Reader, Tag, ValueOrd, Writer};
+use core::cmp::Ordering;
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> FixedTag for Integer<S, N, B, OM> {
+    const TAG: Tag = Tag::Integer;
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> EncodeValue for Integer<S, N, B, OM> {
+    #[inline]
+    fn value_len(&self) -> Result<Length, Error> {
+        let (leading_zero, _, start) = self.to_minimal_signed_be_bytes();
+        Length::try_from(N - start + leading_zero as usize)
+    }
+
+    /// Writes the big-endian two's complement bytes of `self`, with redundant leading `0x00` and `0xFF` bytes removed. For unsigned integers, a leading `0x00` byte is written if the most significant bit of the value is set, so that the value is not interpreted as negative.
+    fn encode_value(&self, writer: &mut impl Writer) -> Result<(), Error> {
+        let (leading_zero, bytes, start) = self.to_minimal_signed_be_bytes();
+        if leading_zero {
+            writer.write_byte(0)?;
+        }
+        writer.write(&bytes[start..])
+    }
+}
+
+impl<'a, const S: bool, const N: usize, const B: usize, const OM: u8> DecodeValue<'a> for Integer<S, N, B, OM> {
+    type Error = Error;
+
+    /// Decodes the contents of a DER `INTEGER`. Only the canonical encoding is accepted: an error is returned if the contents are empty or begin with a redundant `0x00` or `0xFF` byte. An error is also returned if the value is out of range for the type, which includes negative values for unsigned integers.
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> Result<Self, Error> {
+        let len = u32::from(header.length()) as usize;
+        if len == 0 {
+            return Err(reader.error(Tag::Integer.length_error()));
+        }
+        let mut buf = [0; N];
+        // the first byte, if there are too many bytes to fit in `buf`
+        let lead = if len > N { Some(reader.read_byte()?) } else { None };
+        let bytes = reader.read_into(&mut buf[..Ord::min(len, N)])?;
+
+        let (first, second) = match lead {
+            Some(lead) => (lead, Some(bytes[0])),
+            None => (bytes[0], bytes.get(1).copied()),
+        };
+        if let Some(second) = second {
+            if (first == 0x00 && second < 0x80) || (first == 0xFF && second >= 0x80) {
+                return Err(reader.error(Tag::Integer.non_canonical_error()));
+            }
+        }
+        // since the encoding is canonical, a leading byte which does not fit in `buf` is only valid if it is a zero byte that prevents an unsigned integer from being interpreted as negative
+        let value = match lead {
+            None if S || first < 0x80 => Self::from_be_slice(bytes),
+            Some(0x00) if !S && len == N + 1 => Self::from_be_slice(bytes),
+            _ => None,
+        };
+        value.ok_or_else(|| reader.error(Tag::Integer.value_error()))
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> ValueOrd for Integer<S, N, B, OM> {
+    /// Compares the contents of the DER encodings of `self` and `other` lexicographically, as required for sorting the elements of a DER `SET OF`.
+    fn value_cmp(&self, other: &Self) -> Result<Ordering, Error> {
+        let contents = |int: &Self| {
+            let (leading_zero, bytes, start) = int.to_minimal_signed_be_bytes();
+            leading_zero.then_some(0).into_iter().chain(bytes.into_iter().skip(start))
+        };
+        Ok(contents(self).cmp(contents(other)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I128, I256, U128, U256};
+    use crate::{Int, Uint};
+    use der::{Decode, Encode, ErrorKind, Tag, ValueOrd};
+
+    fn encode<T: Encode>(value: T) -> ([u8; 40], usize) {
+        let mut buf = [0; 40];
+        let len = value.encode_to_slice(&mut buf).unwrap().len();
+        (buf, len)
+    }
+
+    #[test]
+    fn encode_vectors() {
+        let cases: [(i128, &[u8]); 8] = [
+            (0, &[0x02, 0x01, 0x00]),
+            (127, &[0x02, 0x01, 0x7F]),
+            (128, &[0x02, 0x02, 0x00, 0x80]),
+            (256, &[0x02, 0x02, 0x01, 0x00]),
+            (-1, &[0x02, 0x01, 0xFF]),
+            (-128, &[0x02, 0x01, 0x80]),
+            (-129, &[0x02, 0x02, 0xFF, 0x7F]),
+            (65535, &[0x02, 0x03, 0x00, 0xFF, 0xFF]),
+        ];
+        for (value, expected) in cases {
+            let (buf, len) = encode(value.as_::<I256>());
+            assert_eq!(&buf[..len], expected);
+            assert_eq!(I256::from_der(expected), Ok(value.as_::<I256>()));
+            if value >= 0 {
+                let (buf, len) = encode(value.as_::<U256>());
+                assert_eq!(&buf[..len], expected);
+                assert_eq!(U256::from_der(expected), Ok(value.as_::<U256>()));
+            }
+        }
+        let (buf, len) = encode(U256::MAX);
+        assert_eq!(buf[..3], [0x02, 33, 0x00]);
+        assert_eq!(buf[3..len], [0xFF; 32]);
+        assert_eq!(U256::from_der(&buf[..len]), Ok(U256::MAX));
+    }
+
+    #[test]
+    fn decode_non_canonical() {
+        let non_canonical = ErrorKind::Noncanonical { tag: Tag::Integer };
+        assert_eq!(U256::from_der(&[0x02, 0x02, 0x00, 0x7F]).unwrap_err().kind(), non_canonical);
+        assert_eq!(I256::from_der(&[0x02, 0x02, 0xFF, 0x80]).unwrap_err().kind(), non_canonical);
+        assert_eq!(I256::from_der(&[0x02, 0x02, 0x00, 0x00]).unwrap_err().kind(), non_canonical);
+        assert_eq!(Uint::<1>::from_der(&[0x02, 0x03, 0x00, 0x00, 0x80]).unwrap_err().kind(), non_canonical);
+        assert_eq!(U256::from_der(&[0x02, 0x00]).unwrap_err().kind(), ErrorKind::Length { tag: Tag::Integer });
+    }
+
+    #[test]
+    fn decode_out_of_range() {
+        let out_of_range = ErrorKind::Value { tag: Tag::Integer };
+        assert_eq!(U256::from_der(&[0x02, 0x01, 0x80]).unwrap_err().kind(), out_of_range);
+        assert_eq!(Int::<1>::from_der(&[0x02, 0x02, 0x00, 0x80]).unwrap_err().kind(), out_of_range);
+        assert_eq!(Int::<1>::from_der(&[0x02, 0x02, 0xFF, 0x7F]).unwrap_err().kind(), out_of_range);
+        assert_eq!(Uint::<1>::from_der(&[0x02, 0x02, 0x00, 0x80]), Ok(Uint::<1>::from_byte(0x80)));
+        assert_eq!(Uint::<1>::from_der(&[0x02, 0x02, 0x01, 0x00]).unwrap_err().kind(), out_of_range);
+        assert_eq!(Uint::<1>::from_der(&[0x02, 0x03, 0x00, 0x80, 0x00]).unwrap_err().kind(), out_of_range);
+        assert_eq!(Uint::<3, 20>::from_der(&[0x02, 0x03, 0x10, 0x00, 0x00]).unwrap_err().kind(), out_of_range);
+        assert_eq!(Int::<3, 20>::from_der(&[0x02, 0x03, 0xF8, 0x00, 0x00]), Ok(Int::<3, 20>::MIN));
+        assert_eq!(Int::<3, 20>::from_der(&[0x02, 0x03, 0xF7, 0xFF, 0xFF]).unwrap_err().kind(), out_of_range);
+        assert_eq!(U256::from_der(&[0x04, 0x01, 0x00]).unwrap_err().kind(), ErrorKind::TagUnexpected { expected: Some(Tag::Integer), actual: Tag::OctetString });
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_matches_primitive(a: i128, b: u128) -> bool {
+            let (buf, len) = encode(a.as_::<I128>());
+            let (expected, expected_len) = encode(a);
+            if buf[..len] != expected[..expected_len] || I128::from_der(&buf[..len]) != Ok(a.as_()) {
+                return false;
+            }
+            let (buf, len) = encode(b.as_::<U128>());
+            let (expected, expected_len) = encode(b);
+            buf[..len] == expected[..expected_len] && U128::from_der(&buf[..len]) == Ok(b.as_())
+        }
+
+        fn quickcheck_value_cmp(a: i64, b: i64) -> bool {
+            let (c, d) = (a as u64, b as u64);
+            a.value_cmp(&b) == a.as_::<I128>().value_cmp(&b.as_()) && c.value_cmp(&d) == c.as_::<U128>().value_cmp(&d.as_())
+        }
+
+        fn quickcheck_roundtrip(a: U256, b: I256) -> bool {
+            let (buf, len) = encode(a);
+            let (buf2, len2) = encode(b);
+            U256::from_der(&buf[..len]) == Ok(a) && I256::from_der(&buf2[..len2]) == Ok(b)
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use super::{Int, Integer, Uint};
+use crate::errors::{Leb128ErrorKind, ReadLeb128Error};
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N, B, OM> {
+    /// the number of bytes in the shortest LEB128 encoding of `self`. for signed integers, the encoding must also contain the sign bit
+    #[inline]
+    const fn leb128_len(&self) -> usize {
+        let significant_bits = if self.is_negative_internal() {
+            Self::BITS - self.leading_ones() + 1
+        } else if S {
+            Self::BITS - self.leading_zeros() + 1
+        } else {
+            Self::BITS - self.leading_zeros()
+        };
+        if significant_bits == 0 {
+            1
+        } else {
+            significant_bits.div_ceil(7) as usize
+        }
+    }
+
+    /// the 7 bits of `self` starting at bit `7 * index`, where the bits past the end of `self` are copies of the sign bit
+    #[inline]
+    const fn leb128_group(&self, index: usize) -> u8 {
+        let extension = if self.is_negative_internal() { u8::MAX } else { 0 };
+        let i = index * 7 / 8;
+        let lo = if i < N { self.bytes[i] } else { extension };
+        let hi = if i + 1 < N { self.bytes[i + 1] } else { extension };
+        let both = u16::from_le_bytes([lo, hi]);
+        (both >> (index * 7 % 8)) as u8 & 0x7F
+    }
+
+    const fn write_leb128(self, buf: &mut [u8]) -> Option<usize> {
+        let len = self.leb128_len();
+        if buf.len() < len {
+            return None;
+        }
+        let mut i = 0;
+        while i < len {
+            buf[i] = self.leb128_group(i);
+            if i + 1 < len {
+                buf[i] |= 0x80; // continuation bit
+            }
+            i += 1;
+        }
+        Some(len)
+    }
+
+    const fn read_leb128(bytes: &[u8]) -> Result<(Self, usize), ReadLeb128Error> {
+        const OVERFLOW: ReadLeb128Error = ReadLeb128Error {
+            kind: Leb128ErrorKind::Overflow,
+        };
+        let total_bits = N * 8;
+        let mut out = [0; N];
+        // whether any of the bits which lie past the end of `out` are ones, or are zeros
+        let mut high_ones = false;
+        let mut high_zeros = false;
+        let mut i = 0;
+        loop {
+            if i == bytes.len() {
+                return Err(ReadLeb128Error {
+                    kind: Leb128ErrorKind::UnexpectedEnd,
+                });
+            }
+            let group = bytes[i] & 0x7F;
+            let position = i * 7;
+            let high_mask = if position >= total_bits {
+                0x7F
+            } else if position + 7 > total_bits {
+                0x7F & (0x7F << (total_bits - position))
+            } else {
+                0
+            };
+            high_ones |= group & high_mask != 0;
+            high_zeros |= !group & high_mask != 0;
+
+            let (index, shift) = (position / 8, position % 8);
+            if index < N {
+                out[index] |= group << shift;
+            }
+            if index + 1 < N && shift > 1 {
+                out[index + 1] |= group >> (8 - shift);
+            }
+            i += 1;
+            if bytes[i - 1] & 0x80 == 0 {
+                break;
+            }
+        }
+        let bits = i * 7;
+        if S && bytes[i - 1] & 0x40 != 0 {
+            // sign extend
+            if bits < total_bits {
+                let mut index = bits / 8;
+                out[index] |= u8::MAX << (bits % 8);
+                index += 1;
+                while index < N {
+                    out[index] = u8::MAX;
+                    index += 1;
+                }
+            } else if high_zeros || out[N - 1] < 0x80 {
+                return Err(OVERFLOW);
+            }
+        } else if high_ones || (S && bits > total_bits && out[N - 1] >= 0x80) {
+            return Err(OVERFLOW);
+        }
+        match Self::from_le_slice(&out) {
+            Some(value) => Ok((value, i)),
+            None => Err(OVERFLOW),
+        }
+    }
+}
+
+/// Methods for LEB128 variable-length encoding, as used by formats such as WebAssembly, DWARF and Protocol Buffers.
+impl<const N: usize, const B: usize, const OM: u8> Uint<N, B, OM> {
+    /// Writes the unsigned LEB128 encoding of `self` to the start of `buf`, and returns the number of bytes written. If `buf` is too short to hold the encoding, then `None` is returned and `buf` is left unchanged.
+    ///
+    /// The shortest encoding is always written, which has length at most <code>Self::BITS.div_ceil(7)</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::U256;
+    ///
+    /// let mut buf = [0; 8];
+    /// assert_eq!(n!(624485U256).write_uleb128(&mut buf), Some(3));
+    /// assert_eq!(buf[..3], [0xE5, 0x8E, 0x26]);
+    ///
+    /// assert_eq!(U256::MAX.write_uleb128(&mut buf), None);
+    /// ```
+    #[inline]
+    pub const fn write_uleb128(self, buf: &mut [u8]) -> Option<usize> {
+        self.write_leb128(buf)
+    }
+
+    /// Reads an unsigned LEB128 encoded integer from the start of `bytes`, returning the value together with the number of bytes that were read.
+    ///
+    /// Encodings that are longer than necessary (those padded with `0x80` bytes) are accepted, as long as the value is in range.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `bytes` ends before the end of the encoding (which is the first byte whose most significant bit is clear), or if the encoded value is too large to be represented by `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::errors::Leb128ErrorKind;
+    /// use bnum::types::U256;
+    ///
+    /// let bytes = [0xE5, 0x8E, 0x26, 0xFF];
+    /// assert_eq!(U256::read_uleb128(&bytes), Ok((n!(624485), 3)));
+    ///
+    /// let err = U256::read_uleb128(&[0xE5, 0x8E]).unwrap_err();
+    /// assert_eq!(err.kind(), &Leb128ErrorKind::UnexpectedEnd);
+    ///
+    /// let err = <t!(U8)>::read_uleb128(&[0x80, 0x02]).unwrap_err();
+    /// assert_eq!(err.kind(), &Leb128ErrorKind::Overflow);
+    /// ```
+    #[inline]
+    pub const fn read_uleb128(bytes: &[u8]) -> Result<(Self, usize), ReadLeb128Error> {
+        Self::read_leb128(bytes)
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> Int<N, B, OM> {
+    /// Writes the signed LEB128 encoding of `self` to the start of `buf`, and returns the number of bytes written. If `buf` is too short to hold the encoding, then `None` is returned and `buf` is left unchanged.
+    ///
+    /// The shortest encoding is always written, which has length at most <code>Self::BITS.div_ceil(7)</code>.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    ///
+    /// let mut buf = [0; 8];
+    /// assert_eq!(n!(-123456I256).write_sleb128(&mut buf), Some(3));
+    /// assert_eq!(buf[..3], [0xC0, 0xBB, 0x78]);
+    ///
+    /// assert_eq!(n!(64I256).write_sleb128(&mut buf), Some(2));
+    /// assert_eq!(buf[..2], [0xC0, 0x00]);
+    /// ```
+    #[inline]
+    pub const fn write_sleb128(self, buf: &mut [u8]) -> Option<usize> {
+        self.write_leb128(buf)
+    }
+
+    /// Reads a signed LEB128 encoded integer from the start of `bytes`, returning the value together with the number of bytes that were read.
+    ///
+    /// Encodings that are longer than necessary (those padded with `0x80` or `0xFF` bytes) are accepted, as long as the value is in range.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `bytes` ends before the end of the encoding (which is the first byte whose most significant bit is clear), or if the encoded value is out of range for `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::errors::Leb128ErrorKind;
+    /// use bnum::types::I256;
+    ///
+    /// assert_eq!(I256::read_sleb128(&[0xC0, 0xBB, 0x78]), Ok((n!(-123456), 3)));
+    /// assert_eq!(I256::read_sleb128(&[0xFF, 0xFF, 0x7F]), Ok((n!(-1), 3)));
+    ///
+    /// let err = <t!(I8)>::read_sleb128(&[0x80, 0x01]).unwrap_err();
+    /// assert_eq!(err.kind(), &Leb128ErrorKind::Overflow);
+    /// ```
+    #[inline]
+    pub const fn read_sleb128(bytes: &[u8]) -> Result<(Self, usize), ReadLeb128Error> {
+        Self::read_leb128(bytes)
+    }
+
+    /// Maps `self` to an unsigned integer using zigzag encoding, so that integers with small absolute values are mapped to small unsigned integers: `0, -1, 1, -2, 2, ...` are mapped to `0, 1, 2, 3, 4, ...`.
+    ///
+    /// This is typically used before writing a signed integer as an unsigned LEB128 varint, as in Protocol Buffers. The inverse of this method is [`from_zigzag`](Self::from_zigzag).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::{I256, U256};
+    ///
+    /// assert_eq!(n!(-1I256).to_zigzag(), n!(1));
+    /// assert_eq!(n!(2I256).to_zigzag(), n!(4));
+    /// assert_eq!(I256::MIN.to_zigzag(), U256::MAX);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_zigzag(self) -> Uint<N, B, OM> {
+        self.wrapping_shl(1)
+            .bitxor(self.wrapping_shr(Self::BITS - 1))
+            .cast_unsigned()
+    }
+
+    /// Maps an unsigned integer produced by [`to_zigzag`](Self::to_zigzag) back to the signed integer it was produced from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bnum::prelude::*;
+    /// use bnum::types::{I256, U256};
+    ///
+    /// assert_eq!(I256::from_zigzag(n!(3)), n!(-2));
+    /// assert_eq!(I256::from_zigzag(n!(4)), n!(2));
+    /// assert_eq!(I256::from_zigzag(U256::MAX), I256::MIN);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_zigzag(value: Uint<N, B, OM>) -> Self {
+        let sign = if value.bit(0) { Self::NEG_ONE } else { Self::ZERO };
+        value.wrapping_shr(1).cast_signed().bitxor(sign)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::errors::Leb128ErrorKind;
+    use crate::types::{I128, U128};
+    use crate::{Int, Uint};
+
+    #[test]
+    fn read_overflow() {
+        let overflow = Leb128ErrorKind::Overflow;
+        let read_u = |bytes: &[u8]| Uint::<1>::read_uleb128(bytes).map_err(|e| *e.kind());
+        let read_i = |bytes: &[u8]| Int::<1>::read_sleb128(bytes).map_err(|e| *e.kind());
+        assert_eq!(read_u(&[0xFF, 0x01]), Ok((Uint::MAX, 2)));
+        assert_eq!(read_u(&[0xFF, 0x03]), Err(overflow));
+        assert_eq!(read_u(&[0xFF, 0x81, 0x80, 0x00]), Ok((Uint::MAX, 4)));
+        assert_eq!(read_u(&[0xFF, 0x81, 0x80, 0x01]), Err(overflow));
+        assert_eq!(read_i(&[0xFF, 0x00]), Ok((Int::MAX, 2)));
+        assert_eq!(read_i(&[0x80, 0x01]), Err(overflow));
+        assert_eq!(read_i(&[0x80, 0x7F]), Ok((Int::MIN, 2)));
+        assert_eq!(read_i(&[0xFF, 0x7E]), Err(overflow));
+        assert_eq!(read_i(&[0x80, 0xFF, 0x7F]), Ok((Int::MIN, 3)));
+        assert_eq!(read_i(&[0x80, 0xFF, 0x77]), Err(overflow));
+        assert_eq!(read_i(&[0xFF, 0x80, 0x00]), Ok((Int::MAX, 3)));
+        assert_eq!(Uint::<3, 20>::read_uleb128(&[0xFF, 0xFF, 0x3F]).map(|(v, _)| v), Ok(Uint::MAX));
+        assert!(Uint::<3, 20>::read_uleb128(&[0x80, 0x80, 0x40]).is_err());
+        assert_eq!(Int::<3, 20>::read_sleb128(&[0x80, 0x80, 0x60]).map(|(v, _)| v), Ok(Int::MIN));
+        assert!(Int::<3, 20>::read_sleb128(&[0xFF, 0xFF, 0x5F]).is_err());
+        assert_eq!(read_u(&[]), Err(Leb128ErrorKind::UnexpectedEnd));
+        assert_eq!(read_i(&[0x80]), Err(Leb128ErrorKind::UnexpectedEnd));
+    }
+
+    #[test]
+    fn write_short_buffer() {
+        let mut buf = [0xAA; 2];
+        assert_eq!(300.as_::<U128>().write_uleb128(&mut buf[..1]), None);
+        assert_eq!(buf, [0xAA; 2]);
+        assert_eq!(300.as_::<U128>().write_uleb128(&mut buf), Some(2));
+        assert_eq!(buf, [0xAC, 0x02]);
+    }
+
+    fn uleb128(mut a: u128) -> ([u8; 19], usize) {
+        let mut buf = [0; 19];
+        let mut i = 0;
+        loop {
+            buf[i] = a as u8 & 0x7F;
+            a >>= 7;
+            if a == 0 {
+                return (buf, i + 1);
+            }
+            buf[i] |= 0x80;
+            i += 1;
+        }
+    }
+
+    fn sleb128(mut a: i128) -> ([u8; 19], usize) {
+        let mut buf = [0; 19];
+        let mut i = 0;
+        loop {
+            buf[i] = a as u8 & 0x7F;
+            a >>= 7;
+            if (a == 0 && buf[i] & 0x40 == 0) || (a == -1 && buf[i] & 0x40 != 0) {
+                return (buf, i + 1);
+            }
+            buf[i] |= 0x80;
+            i += 1;
+        }
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_uleb128(a: u128) -> bool {
+            let (expected, len) = uleb128(a);
+            let mut buf = [0; 19];
+            a.as_::<U128>().write_uleb128(&mut buf) == Some(len)
+                && buf == expected
+                && U128::read_uleb128(&buf) == Ok((a.as_(), len))
+        }
+
+        fn quickcheck_sleb128(a: i128) -> bool {
+            let (expected, len) = sleb128(a);
+            let mut buf = [0; 19];
+            a.as_::<I128>().write_sleb128(&mut buf) == Some(len)
+                && buf == expected
+                && I128::read_sleb128(&buf) == Ok((a.as_(), len))
+        }
+
+        fn quickcheck_leb128_roundtrip(a: Uint<3, 20>, b: Int<3, 20>) -> bool {
+            let mut buf = [0; 6];
+            let len = a.write_uleb128(&mut buf).unwrap();
+            let len2 = b.write_sleb128(&mut buf[len..]).unwrap();
+            Uint::read_uleb128(&buf) == Ok((a, len)) && Int::read_sleb128(&buf[len..]) == Ok((b, len2))
+        }
+
+        fn quickcheck_zigzag(a: i128) -> bool {
+            let zigzag = ((a << 1) ^ (a >> 127)) as u128;
+            a.as_::<I128>().to_zigzag() == zigzag.as_::<U128>() && I128::from_zigzag(zigzag.as_()) == a.as_::<I128>()
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use super::{Integer, Uint};
+use ::minicbor::data::{Int, Tag, Type};
+use ::minicbor::decode::{self, Decoder};
+use ::minicbor::encode::{self, Encoder, Write};
+use ::minicbor::{CborLen, Decode, Encode};
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N, B, OM> {
+    /// `self` as a native CBOR integer, if it is in the range `-2^64..2^64`
+    #[inline]
+    fn to_cbor_int(self) -> Option<Int> {
+        i128::try_from(self).ok().and_then(|int| Int::try_from(int).ok())
+    }
+
+    /// the bignum tag and the value stored in the byte string: `self` for non-negative integers and `-1 - self` for negative integers
+    #[inline]
+    fn to_cbor_bignum(self) -> (Tag, Uint<N, B, OM>) {
+        if self.is_negative_internal() {
+            (Tag::NegBignum, (!self).force_sign())
+        } else {
+            (Tag::PosBignum, self.force_sign())
+        }
+    }
+}
+
+impl<C, const S: bool, const N: usize, const B: usize, const OM: u8> Encode<C> for Integer<S, N, B, OM> {
+    /// Encodes `self` as a native CBOR integer if it is in the range `-2^64..2^64`, and otherwise as a bignum: a byte string containing the big-endian bytes of `self` (or of `-1 - self` for negative integers) without leading zeros, tagged with tag 2 (or tag 3 for negative integers).
+    fn encode<W: Write>(&self, e: &mut Encoder<W>, _ctx: &mut C) -> Result<(), encode::Error<W::Error>> {
+        if let Some(int) = self.to_cbor_int() {
+            return e.int(int)?.ok();
+        }
+        let (tag, value) = self.to_cbor_bignum();
+        let (bytes, start) = value.to_minimal_be_bytes();
+        e.tag(tag)?.bytes(&bytes[start..])?.ok()
+    }
+}
+
+impl<C, const S: bool, const N: usize, const B: usize, const OM: u8> CborLen<C> for Integer<S, N, B, OM> {
+    fn cbor_len(&self, ctx: &mut C) -> usize {
+        if let Some(int) = self.to_cbor_int() {
+            return int.cbor_len(ctx);
+        }
+        let (_, value) = self.to_cbor_bignum();
+        let (_, start) = value.to_minimal_be_bytes();
+        let len = N - start;
+        // one byte for the tag, followed by the header and contents of the byte string
+        1 + len.cbor_len(ctx) + len
+    }
+}
+
+impl<'b, C, const S: bool, const N: usize, const B: usize, const OM: u8> Decode<'b, C> for Integer<S, N, B, OM> {
+    /// Decodes a native CBOR integer, or a bignum with tag 2 or tag 3. Bignums with leading zero bytes and bignums stored in indefinite-length byte strings are accepted. An error is returned if the value is out of range for the type.
+    fn decode(d: &mut Decoder<'b>, _ctx: &mut C) -> Result<Self, decode::Error> {
+        let p = d.position();
+        let out_of_range = || decode::Error::message("(bnum) integer out of range for its bit width").at(p);
+        match d.datatype()? {
+            Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::Int => {
+                Self::try_from(i128::from(d.int()?)).map_err(|_| out_of_range())
+            }
+            Type::Tag => {
+                let negative = match d.tag()? {
+                    Tag::PosBignum => false,
+                    Tag::NegBignum => true,
+                    _ => return Err(decode::Error::message("(bnum) expected bignum tag 2 or 3").at(p)),
+                };
+                let mut value = Uint::<N, B, OM>::ZERO;
+                for bytes in d.bytes_iter()? {
+                    for &byte in bytes? {
+                        // checking for zero first means that leading zero bytes are accepted for integers with fewer than 8 bits
+                        value = if value.is_zero() {
+                            Uint::try_from(byte).map_err(|_| out_of_range())?
+                        } else if value.leading_zeros() < u8::BITS {
+                            return Err(out_of_range());
+                        } else {
+                            (value << u8::BITS) | Uint::from_byte(byte)
+                        };
+                    }
+                }
+                let value = Self::try_from(&value).map_err(|_| out_of_range())?;
+                match (negative, S) {
+                    (false, _) => Ok(value),
+                    (true, true) => Ok(!value),
+                    (true, false) => Err(out_of_range()),
+                }
+            }
+            ty => Err(decode::Error::type_mismatch(ty).with_message("expected integer or bignum").at(p)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I128, I256, U256};
+    use crate::{Int, Uint};
+    use minicbor::encode::write::Cursor;
+    use minicbor::{CborLen, Encode};
+
+    fn encode<T: Encode<()> + CborLen<()>>(value: T) -> ([u8; 48], usize) {
+        let mut cursor = Cursor::new([0; 48]);
+        minicbor::encode(&value, &mut cursor).unwrap();
+        assert_eq!(minicbor::len(&value), cursor.position());
+        let len = cursor.position();
+        (cursor.into_inner(), len)
+    }
+
+    #[test]
+    fn encode_native() {
+        let cases: [(i128, &[u8]); 6] = [
+            (0, &[0x00]),
+            (23, &[0x17]),
+            (1000, &[0x19, 0x03, 0xE8]),
+            (-1, &[0x20]),
+            (u64::MAX as i128, &[0x1B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+            (-(1 << 64), &[0x3B, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]),
+        ];
+        for (value, expected) in cases {
+            let (buf, len) = encode(value.as_::<I256>());
+            assert_eq!(&buf[..len], expected);
+            assert_eq!(minicbor::decode(expected).ok(), Some(value.as_::<I256>()));
+        }
+    }
+
+    #[test]
+    fn encode_bignum() {
+        // examples from RFC 8949, appendix A
+        let (buf, len) = encode((1u128 << 64).as_::<U256>());
+        assert_eq!(buf[..len], [0xC2, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        let (buf, len) = encode((-(1i128 << 64) - 1).as_::<I256>());
+        assert_eq!(buf[..len], [0xC3, 0x49, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+
+        let (buf, len) = encode(U256::MAX);
+        assert_eq!(buf[..3], [0xC2, 0x58, 32]);
+        assert_eq!(buf[3..len], [0xFF; 32]);
+        let (buf, len) = encode(I256::MIN);
+        assert_eq!(buf[..4], [0xC3, 0x58, 32, 0x7F]);
+        assert_eq!(buf[4..len], [0xFF; 31]);
+    }
+
+    #[test]
+    fn decode() {
+        // leading zeros and indefinite-length byte strings
+        assert_eq!(minicbor::decode(&[0xC2, 0x43, 0x00, 0x00, 0x05]).ok(), Some(5.as_::<U256>()));
+        assert_eq!(minicbor::decode(&[0xC3, 0x5F, 0x41, 0x01, 0x40, 0x41, 0x00, 0xFF]).ok(), Some((-257).as_::<I256>()));
+        assert_eq!(minicbor::decode(&[0xC2, 0x40]).ok(), Some(U256::ZERO));
+
+        assert!(minicbor::decode::<U256>(&[0x20]).is_err());
+        assert!(minicbor::decode::<U256>(&[0xC3, 0x40]).is_err());
+        assert!(minicbor::decode::<Uint<1>>(&[0x19, 0x01, 0x00]).is_err());
+        assert!(minicbor::decode::<Int<1>>(&[0xC2, 0x41, 0x80]).is_err());
+        assert_eq!(minicbor::decode(&[0xC3, 0x41, 0x7F]).ok(), Some(Int::<1>::MIN));
+        assert_eq!(minicbor::decode(&[0xC2, 0x42, 0x00, 0x3F]).ok(), Some(Uint::<1, 6>::MAX));
+        assert!(minicbor::decode::<Uint<1, 6>>(&[0xC2, 0x41, 0x40]).is_err());
+        assert!(minicbor::decode::<Uint<3, 20>>(&[0xC2, 0x43, 0x10, 0x00, 0x00]).is_err());
+        assert!(minicbor::decode::<U256>(&[0xC4, 0x41, 0x01]).is_err());
+        assert!(minicbor::decode::<U256>(&[0x41, 0x01]).is_err());
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_roundtrip(a: U256, b: I256, c: i128) -> bool {
+            let (buf, len) = encode(a);
+            let (buf2, len2) = encode(b);
+            let (buf3, len3) = encode(c.as_::<I128>());
+            minicbor::decode(&buf[..len]).ok() == Some(a) && minicbor::decode(&buf2[..len2]).ok() == Some(b) && minicbor::decode(&buf3[..len3]).ok() == Some(c.as_::<I128>())
+        }
+    }
+}
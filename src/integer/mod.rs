@@ -9,6 +9,7 @@ mod convert;
 mod div;
 #[cfg(feature = "alloc")]
 mod fmt;
+mod leb128;
 mod math;
 mod mul;
 #[cfg(feature = "numtraits")]
@@ -20,6 +21,36 @@ mod radix;
 #[cfg(feature = "rand")]
 mod random;
 
+#[cfg(feature = "serde")]
+pub(crate) mod serde;
+
+#[cfg(feature = "borsh")]
+mod borsh;
+
+#[cfg(feature = "rlp")]
+mod rlp;
+
+#[cfg(feature = "scale")]
+mod scale;
+
+#[cfg(feature = "der")]
+mod der;
+
+#[cfg(feature = "minicbor")]
+mod minicbor;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
+
+#[cfg(feature = "rkyv")]
+mod rkyv;
+
+#[cfg(feature = "bincode")]
+mod bincode;
+
 mod saturating;
 mod strict;
 mod unchecked;
@@ -32,18 +63,6 @@ use crate::digits::Digits;
 use crate::OverflowMode;
 use crate::doc;
 
-#[cfg(feature = "serde")]
-use ::{
-    serde::{Deserialize, Serialize},
-    serde_big_array::BigArray,
-};
-
-#[cfg(feature = "borsh")]
-use ::{
-    alloc::string::ToString,
-    borsh::{BorshDeserialize, BorshSchema, BorshSerialize},
-};
-
 use core::default::Default;
 
 /// A fixed-size integer type, generic over signedness, bit width, and overflow behaviour.
@@ -60,7 +79,7 @@ use core::default::Default;
 /// 
 /// `Integer` closely follows the API and behaviour of Rust's primitive integer types: `u8`, `i8`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`, `u128`, `i128`, `usize` and `isize`. The only differences are:
 /// - The primitive integers are stored in native-endian byte order. `Integer`s are always stored in little-endian byte order.
-/// - Primitive integers are serialised in [`serde`](https://docs.rs/serde/latest/serde/) as decimal strings. `Integer`s are serialised using [`derive(Serialize)`](https://docs.rs/serde/latest/serde/derive.Serialize.html), i.e. as a struct.
+/// - Primitive integers are serialised in [`serde`](https://docs.rs/serde/latest/serde/) as numbers. Since most formats cannot represent numbers wider than 128 bits, `Integer`s are serialised as decimal strings in human-readable formats, and as their minimal big-endian bytes in binary formats (see the [`serde`](crate::serde) module for how to choose a different representation).
 /// - In `no-alloc` environments, primitive integers are formatted as decimal strings by the [`Debug`] trait, whereas `Integer`s are formatted as padded hexadecimal strings.
 /// - The primitive integers panic on arithmetic overflow if [`overflow-checks`](https://doc.rust-lang.org/cargo/reference/profiles.html#overflow-checks) is enabled, and wrap around on overflow if `overflow-checks` is disabled. The overflow behaviour of `Integer` is determined by [`Self::OVERFLOW_MODE`]:
 ///    - [`Wrap`](OverflowMode::Wrap): arithmetic operations wrap around on overflow, so the behaviour is the same as the [`Wrapping(T)`](core::num::Wrapping) type in the standard library (i.e. the same as the primitive integer type behaviour when `overflow-checks` is disabled).
@@ -69,16 +88,11 @@ use core::default::Default;
 ///    - [`OverflowMode::DEFAULT`]: the overflow behaviour is the same as the primitive integer type overflow behaviour.
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 // in benchmarks, derive(PartialEq) impl is no slower than manual impl using the const eq method
-#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-#[cfg_attr(
-    feature = "borsh",
-    derive(BorshSerialize, BorshDeserialize, BorshSchema)
-)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[cfg_attr(feature = "valuable", derive(valuable::Valuable))]
+#[cfg_attr(feature = "zerocopy", derive(::zerocopy::KnownLayout, ::zerocopy::Immutable, ::zerocopy::IntoBytes, ::zerocopy::Unaligned))]
 #[repr(transparent)]
 pub struct Integer<const S: bool, const N: usize, const B: usize = 0, const OM: u8 = {OverflowMode::DEFAULT as u8}> {
-    #[cfg_attr(feature = "serde", serde(with = "BigArray"))]
     pub(crate) bytes: [Byte; N],
 }
 
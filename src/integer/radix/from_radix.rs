@@ -375,6 +375,11 @@ impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N,
         } else {
             (src, false)
         };
+        Self::from_sign_and_ascii_radix(negative, src, radix)
+    }
+
+    /// parses the ASCII digits `src` in the given radix, negating the result if `negative`, which must be `false` if `S` is `false`
+    pub(crate) const fn from_sign_and_ascii_radix(negative: bool, src: &[u8], radix: u32) -> Result<Self, ParseIntError> {
         match Uint::from_buf_radix::<false, true, true>(src, radix) {
             Ok(uint) => {
                 let out = uint.force_sign::<S>();
@@ -0,0 +1,138 @@
+use super::Integer;
+use ::rkyv::bytecheck::CheckBytes;
+use ::rkyv::rancor::{fail, Fallible, Source};
+use ::rkyv::traits::{CopyOptimization, NoUndef};
+use ::rkyv::{Archive, Deserialize, Place, Portable, Serialize};
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+// SAFETY: `Integer` is `#[repr(transparent)]` over `[u8; N]`, so it has the same layout on every target, and has no padding or uninitialised bytes
+unsafe impl<const S: bool, const N: usize, const B: usize, const OM: u8> Portable for Integer<S, N, B, OM> {}
+
+// SAFETY: `Integer` is `#[repr(transparent)]` over `[u8; N]`, which has no padding or uninitialised bytes
+unsafe impl<const S: bool, const N: usize, const B: usize, const OM: u8> NoUndef for Integer<S, N, B, OM> {}
+
+/// The archived form of an integer is the integer itself: its `N` little-endian bytes, with an alignment of 1. This means that archived integers can be used directly, without deserialising them, and compared with other integers.
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Archive for Integer<S, N, B, OM> {
+    // SAFETY: `Integer` has no padding or uninitialised bytes
+    const COPY_OPTIMIZATION: CopyOptimization<Self> = unsafe { CopyOptimization::enable() };
+
+    type Archived = Self;
+    type Resolver = ();
+
+    #[inline]
+    fn resolve(&self, _: Self::Resolver, out: Place<Self::Archived>) {
+        out.write(*self);
+    }
+}
+
+impl<Ser: Fallible + ?Sized, const S: bool, const N: usize, const B: usize, const OM: u8> Serialize<Ser> for Integer<S, N, B, OM> {
+    #[inline]
+    fn serialize(&self, _: &mut Ser) -> Result<Self::Resolver, Ser::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized, const S: bool, const N: usize, const B: usize, const OM: u8> Deserialize<Self, D> for Integer<S, N, B, OM> {
+    #[inline]
+    fn deserialize(&self, _: &mut D) -> Result<Self, D::Error> {
+        Ok(*self)
+    }
+}
+
+#[derive(Debug)]
+struct OutOfRangeError;
+
+impl Display for OutOfRangeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("(bnum) integer out of range for its bit width")
+    }
+}
+
+impl Error for OutOfRangeError {}
+
+// SAFETY: `check_bytes` only returns `Ok` if the bytes are a valid value of `Integer`
+unsafe impl<C: Fallible + ?Sized, const S: bool, const N: usize, const B: usize, const OM: u8> CheckBytes<C> for Integer<S, N, B, OM>
+where
+    C::Error: Source,
+{
+    /// Checks that the unused high bits of the last byte are zero for unsigned integers, and copies of the sign bit for signed integers. Every bit pattern is valid if `B` is `0`.
+    #[inline]
+    unsafe fn check_bytes(value: *const Self, _: &mut C) -> Result<(), C::Error> {
+        // SAFETY: the caller guarantees that `value` is aligned and points to enough initialised bytes to represent `Self`, which has the same layout as `[u8; N]`
+        let bytes = unsafe { &*value.cast::<[u8; N]>() };
+        if Self::from_le_slice(bytes).is_none() {
+            fail!(OutOfRangeError);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I256, U256};
+    use crate::{Int, Uint};
+    use core::mem::MaybeUninit;
+    use rkyv::api::low::{access, from_bytes, to_bytes_in_with_alloc};
+    use rkyv::rancor::Failure;
+    use rkyv::ser::allocator::SubAllocator;
+    use rkyv::ser::writer::Buffer;
+    use rkyv::util::Align;
+    use rkyv::{Archive, Deserialize, Serialize};
+
+    #[derive(Archive, Serialize, Deserialize, Debug, PartialEq)]
+    struct Entry {
+        key: U256,
+        value: I256,
+    }
+
+    /// serializes `entry` into `output`, returning the number of bytes written
+    fn serialize(entry: &Entry, output: &mut Align<[MaybeUninit<u8>; 64]>) -> usize {
+        let mut alloc = [MaybeUninit::uninit(); 64];
+        let bytes = to_bytes_in_with_alloc::<_, _, Failure>(entry, Buffer::from(&mut **output), SubAllocator::new(&mut alloc)).unwrap();
+        bytes.len()
+    }
+
+    /// the first `len` bytes of `output`, which must have been initialised by `serialize`
+    fn written(output: &Align<[MaybeUninit<u8>; 64]>, len: usize) -> &[u8] {
+        // SAFETY: the first `len` bytes were written by `serialize`
+        unsafe { core::slice::from_raw_parts(output.as_ptr().cast(), len) }
+    }
+
+    #[test]
+    fn archive_is_le_bytes() {
+        let entry = Entry {
+            key: 0x0102.as_(),
+            value: (-1).as_(),
+        };
+        let mut output = Align([MaybeUninit::uninit(); 64]);
+        let len = serialize(&entry, &mut output);
+        let bytes = written(&output, len);
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(bytes[..32], entry.key.to_bytes());
+        assert_eq!(bytes[32..], [0xFF; 32]);
+
+        let archived = access::<ArchivedEntry, Failure>(bytes).unwrap();
+        assert_eq!(archived.key, entry.key);
+        assert!(archived.value < I256::ZERO);
+        assert_eq!(from_bytes::<Entry, Failure>(bytes).unwrap(), entry);
+    }
+
+    #[test]
+    fn check_bytes() {
+        assert_eq!(access::<Uint<3, 20>, Failure>(&[0xFF, 0xFF, 0x0F]).ok(), Some(&Uint::<3, 20>::MAX));
+        assert!(access::<Uint<3, 20>, Failure>(&[0x00, 0x00, 0x10]).is_err());
+        assert_eq!(access::<Int<3, 20>, Failure>(&[0x00, 0x00, 0xF8]).ok(), Some(&Int::<3, 20>::MIN));
+        assert!(access::<Int<3, 20>, Failure>(&[0xFF, 0xFF, 0xF7]).is_err());
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_roundtrip(a: U256, b: I256) -> bool {
+            let entry = Entry { key: a, value: b };
+            let mut output = Align([MaybeUninit::uninit(); 64]);
+            let len = serialize(&entry, &mut output);
+            from_bytes::<Entry, Failure>(written(&output, len)).ok() == Some(entry)
+        }
+    }
+}
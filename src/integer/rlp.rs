@@ -0,0 +1,67 @@
+use super::Integer;
+use ::rlp::{Decodable, DecoderError, Encodable, Rlp, RlpStream};
+
+impl<const N: usize, const B: usize, const OM: u8> Encodable for Integer<false, N, B, OM> {
+    /// Encodes `self` as an RLP string containing its big-endian bytes without leading zeros, so that zero is encoded as the empty string.
+    fn rlp_append(&self, s: &mut RlpStream) {
+        let (bytes, start) = self.to_minimal_be_bytes();
+        s.encoder().encode_value(&bytes[start..]);
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> Decodable for Integer<false, N, B, OM> {
+    /// Decodes an RLP string of big-endian bytes. Only the canonical encoding is accepted: an error is returned if there are leading zeros, or if the value is out of range for the type.
+    fn decode(rlp: &Rlp) -> Result<Self, DecoderError> {
+        rlp.decoder().decode_value(|bytes| {
+            if bytes.first() == Some(&0) {
+                return Err(DecoderError::RlpInvalidIndirection);
+            }
+            Self::from_be_slice(bytes).ok_or(DecoderError::RlpIsTooBig)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{U128, U256};
+    use crate::Uint;
+    use rlp::DecoderError;
+
+    #[test]
+    fn encode() {
+        assert_eq!(rlp::encode(&U256::ZERO)[..], [0x80]);
+        assert_eq!(rlp::encode(&15.as_::<U256>())[..], [0x0F]);
+        assert_eq!(rlp::encode(&0x80.as_::<U256>())[..], [0x81, 0x80]);
+        assert_eq!(rlp::encode(&1024.as_::<U256>())[..], [0x82, 0x04, 0x00]);
+        let max = rlp::encode(&U256::MAX);
+        assert_eq!(max[0], 0xA0);
+        assert_eq!(max[1..], [0xFF; 32]);
+    }
+
+    #[test]
+    fn decode() {
+        assert_eq!(rlp::decode(&[0x80]), Ok(U256::ZERO));
+        assert_eq!(rlp::decode(&[0x0F]), Ok(15.as_::<U256>()));
+        assert_eq!(rlp::decode(&[0x82, 0x04, 0x00]), Ok(1024.as_::<U256>()));
+        // non-canonical encodings
+        assert_eq!(rlp::decode::<U256>(&[0x00]), Err(DecoderError::RlpInvalidIndirection));
+        assert_eq!(rlp::decode::<U256>(&[0x81, 0x05]), Err(DecoderError::RlpInvalidIndirection));
+        assert_eq!(rlp::decode::<U256>(&[0x82, 0x00, 0x05]), Err(DecoderError::RlpInvalidIndirection));
+        // out of range
+        let mut too_big = [0x01; 34];
+        too_big[0] = 0xA1;
+        assert_eq!(rlp::decode::<U256>(&too_big), Err(DecoderError::RlpIsTooBig));
+        assert_eq!(rlp::decode::<Uint<3, 20>>(&[0x83, 0x10, 0x00, 0x00]), Err(DecoderError::RlpIsTooBig));
+        assert_eq!(rlp::decode::<Uint<3, 20>>(&[0x83, 0x0F, 0xFF, 0xFF]), Ok(Uint::<3, 20>::MAX));
+        assert_eq!(rlp::decode::<U256>(&[0xC0]), Err(DecoderError::RlpExpectedToBeData));
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_matches_primitive(a: u128) -> bool {
+            let int = a.as_::<U128>();
+            let encoded = rlp::encode(&int);
+            encoded == rlp::encode(&a) && rlp::decode(&encoded) == Ok(int)
+        }
+    }
+}
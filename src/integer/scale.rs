@@ -0,0 +1,121 @@
+use super::Integer;
+use ::parity_scale_codec::{ConstEncodedLen, Decode, DecodeWithMemTracking, Encode, EncodeLike, Error, Input, MaxEncodedLen, Output};
+use ::scale_info::{MetaType, Type, TypeDefArray, TypeDefPrimitive, TypeInfo};
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Encode for Integer<S, N, B, OM> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        N
+    }
+
+    /// Writes the `N` little-endian bytes of `self`, as returned by [`to_bytes`](Integer::to_bytes). If the bit width is not a multiple of 8, the unused high bits of the last byte are zero for unsigned integers, and copies of the sign bit for signed integers.
+    #[inline]
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(&self.bytes)
+    }
+
+    #[inline]
+    fn using_encoded<R, F: FnOnce(&[u8]) -> R>(&self, f: F) -> R {
+        f(&self.bytes)
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        N
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> EncodeLike for Integer<S, N, B, OM> {}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Decode for Integer<S, N, B, OM> {
+    /// Reads `N` little-endian bytes, returning an error if the unused high bits of the last byte are not set as described in the [`Encode`] implementation, since then the bytes do not represent a value of this type.
+    #[inline]
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let mut bytes = [0; N];
+        input.read(&mut bytes)?;
+        Self::from_le_slice(&bytes).ok_or_else(|| "(bnum) integer out of range for its bit width".into())
+    }
+
+    #[inline]
+    fn encoded_fixed_size() -> Option<usize> {
+        Some(N)
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> DecodeWithMemTracking for Integer<S, N, B, OM> {}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> MaxEncodedLen for Integer<S, N, B, OM> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        N
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> ConstEncodedLen for Integer<S, N, B, OM> {}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> TypeInfo for Integer<S, N, B, OM> {
+    type Identity = Self;
+
+    /// If there is a primitive type with the same signedness and bit width (for example `u256` or `i64`), then the type info of that primitive is returned. Otherwise, the type info of `[u8; N]` is returned, which has the same encoding.
+    fn type_info() -> Type {
+        let primitive = match (S, Self::BITS) {
+            (false, 8) => TypeDefPrimitive::U8,
+            (false, 16) => TypeDefPrimitive::U16,
+            (false, 32) => TypeDefPrimitive::U32,
+            (false, 64) => TypeDefPrimitive::U64,
+            (false, 128) => TypeDefPrimitive::U128,
+            (false, 256) => TypeDefPrimitive::U256,
+            (true, 8) => TypeDefPrimitive::I8,
+            (true, 16) => TypeDefPrimitive::I16,
+            (true, 32) => TypeDefPrimitive::I32,
+            (true, 64) => TypeDefPrimitive::I64,
+            (true, 128) => TypeDefPrimitive::I128,
+            (true, 256) => TypeDefPrimitive::I256,
+            _ => return TypeDefArray::new(N as u32, MetaType::new::<u8>()).into(),
+        };
+        primitive.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I256, U256};
+    use crate::{Int, Uint};
+    use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+    use scale_info::{MetaType, TypeDef, TypeDefPrimitive, TypeInfo};
+
+    #[test]
+    fn encode_decode() {
+        let a = 0x0102.as_::<U256>();
+        let mut expected = [0; 32];
+        expected[0] = 0x02;
+        expected[1] = 0x01;
+        a.using_encoded(|bytes| assert_eq!(bytes, expected));
+        assert_eq!(U256::decode(&mut &expected[..]), Ok(a));
+        assert!((-1).as_::<I256>().using_encoded(|bytes| bytes == [0xFF; 32]));
+        assert_eq!(U256::max_encoded_len(), 32);
+        assert_eq!(Uint::<3, 20>::encoded_fixed_size(), Some(3));
+
+        assert!(U256::decode(&mut &[0; 31][..]).is_err());
+        assert!(Uint::<3, 20>::decode(&mut &[0x00, 0x00, 0x10][..]).is_err());
+        assert_eq!(Int::<3, 20>::decode(&mut &[0x00, 0x00, 0xF8][..]), Ok(Int::<3, 20>::MIN));
+        assert!(Int::<3, 20>::decode(&mut &[0x00, 0x00, 0x08][..]).is_err());
+    }
+
+    #[test]
+    fn type_info() {
+        assert_eq!(U256::type_info().type_def, TypeDef::Primitive(TypeDefPrimitive::U256));
+        assert_eq!(Int::<8>::type_info().type_def, TypeDef::Primitive(TypeDefPrimitive::I64));
+        assert_eq!(Uint::<3, 20>::type_info(), <[u8; 3]>::type_info());
+        assert_eq!(Int::<5>::type_info(), <[u8; 5]>::type_info());
+        assert_ne!(MetaType::new::<U256>(), MetaType::new::<I256>());
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_roundtrip(a: U256, b: I256) -> bool {
+            let encoded = (a, b).encode();
+            encoded.len() == 64 && <(U256, I256)>::decode(&mut &encoded[..]) == Ok((a, b))
+        }
+    }
+}
@@ -0,0 +1,270 @@
+use super::Integer;
+use crate::cast::CastFrom;
+use crate::errors::ParseIntError;
+use crate::Uint;
+use ::serde::de::{self, Deserialize, Deserializer, SeqAccess, Unexpected, Visitor};
+use ::serde::{Serialize, Serializer};
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use core::num::IntErrorKind;
+
+/// writes the decimal digits of `magnitude`, most significant first, without needing to allocate
+fn write_decimal<const N: usize>(magnitude: Uint<N, 0, 0>, f: &mut Formatter) -> fmt::Result {
+    // the largest power of ten that fits in a `u64`
+    const CHUNK: u64 = 10u64.pow(19);
+
+    if magnitude.bit_width() <= u64::BITS {
+        return write!(f, "{}", u64::cast_from(magnitude));
+    }
+    // `magnitude` has more than 64 bits, so `N > 8` and `CHUNK` fits in `Uint<N>`
+    let (quotient, remainder) = magnitude.div_rem_unchecked(Uint::cast_from(CHUNK));
+    write_decimal(quotient, f)?;
+    write!(f, "{:019}", u64::cast_from(remainder))
+}
+
+/// formats an integer as a decimal string
+pub(crate) struct DecimalStr<'a, const S: bool, const N: usize, const B: usize, const OM: u8>(pub &'a Integer<S, N, B, OM>);
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Display for DecimalStr<'_, S, N, B, OM> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.0.is_negative_internal() {
+            f.write_str("-")?;
+        }
+        write_decimal(self.0.unsigned_abs_internal().force::<false, 0, 0>(), f)
+    }
+}
+
+/// formats an integer as a lowercase hexadecimal string prefixed with `0x`, and with a leading `-` if the integer is negative
+pub(crate) struct HexStr<'a, const S: bool, const N: usize, const B: usize, const OM: u8>(pub &'a Integer<S, N, B, OM>);
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Display for HexStr<'_, S, N, B, OM> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.0.is_negative_internal() {
+            f.write_str("-")?;
+        }
+        f.write_str("0x")?;
+        let magnitude = self.0.unsigned_abs_internal();
+        let digits = magnitude.bit_width().div_ceil(4).max(1);
+        let bytes = magnitude.to_bytes();
+        for i in (0..digits as usize).rev() {
+            write!(f, "{:x}", (bytes[i / 2] >> (4 * (i % 2))) & 0xF)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Integer<S, N, B, OM> {
+    /// parses a decimal string, or a hexadecimal string prefixed with `0x`, either of which may have a leading sign
+    pub(crate) const fn from_serde_str(src: &str) -> Result<Self, ParseIntError> {
+        let src = src.as_bytes();
+        let (negative, digits) = match src {
+            [b'-', digits @ ..] => (true, digits),
+            [b'+', digits @ ..] => (false, digits),
+            digits => (false, digits),
+        };
+        match digits {
+            [b'0', b'x' | b'X', hex @ ..] => {
+                if hex.is_empty() || (negative && !S) {
+                    return Err(ParseIntError {
+                        kind: IntErrorKind::InvalidDigit,
+                    });
+                }
+                Self::from_sign_and_ascii_radix(negative, hex, 16)
+            }
+            _ => Self::from_ascii_radix(src, 10),
+        }
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> Serialize for Integer<S, N, B, OM> {
+    /// Serializes `self` as a decimal string if the format is human-readable, and as its minimal big-endian bytes otherwise.
+    fn serialize<Ser: Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        if serializer.is_human_readable() {
+            crate::serde::decimal::serialize(self, serializer)
+        } else {
+            crate::serde::bytes::serialize(self, serializer)
+        }
+    }
+}
+
+impl<'de, const S: bool, const N: usize, const B: usize, const OM: u8> Deserialize<'de> for Integer<S, N, B, OM> {
+    /// Deserializes an integer from a number, a decimal string, a hexadecimal string prefixed with `0x`, or big-endian bytes. For binary formats, which are not self-describing, big-endian bytes are expected.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(IntegerVisitor(PhantomData))
+        } else {
+            deserializer.deserialize_bytes(IntegerVisitor(PhantomData))
+        }
+    }
+}
+
+/// accepts every representation that `Integer`s can be serialized as
+pub(crate) struct IntegerVisitor<const S: bool, const N: usize, const B: usize, const OM: u8>(pub PhantomData<Integer<S, N, B, OM>>);
+
+macro_rules! visit_primitive {
+    ($($method: ident($int: ty) => $unexpected: expr),*) => {
+        $(
+            fn $method<E: de::Error>(self, v: $int) -> Result<Self::Value, E> {
+                Integer::try_from(v).map_err(|_| E::invalid_value($unexpected(v), &self))
+            }
+        )*
+    };
+}
+
+impl<'de, const S: bool, const N: usize, const B: usize, const OM: u8> Visitor<'de> for IntegerVisitor<S, N, B, OM> {
+    type Value = Integer<S, N, B, OM>;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "an integer in range for a {}-bit {} integer, as a number, a decimal or `0x`-prefixed hexadecimal string, or big-endian bytes", Integer::<S, N, B, OM>::BITS, if S { "signed" } else { "unsigned" })
+    }
+
+    visit_primitive!(
+        visit_u64(u64) => Unexpected::Unsigned,
+        visit_i64(i64) => Unexpected::Signed,
+        visit_u128(u128) => |_| Unexpected::Other("128-bit unsigned integer"),
+        visit_i128(i128) => |_| Unexpected::Other("128-bit signed integer")
+    );
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Integer::from_serde_str(v).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Integer::from_be_slice(v).ok_or_else(|| E::invalid_value(Unexpected::Bytes(v), &self))
+    }
+
+    /// some formats (such as JSON) serialize bytes as a sequence
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut bytes = [0; N];
+        let mut len = 0;
+        while let Some(byte) = seq.next_element()? {
+            if len == N {
+                return Err(de::Error::invalid_length(len + 1, &self));
+            }
+            bytes[len] = byte;
+            len += 1;
+        }
+        self.visit_bytes(&bytes[..len])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I128, U128};
+    use crate::{Int, Uint};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    /// 20-bit signed integer
+    type I20 = Int<3, 20>;
+
+    #[test]
+    fn human_readable() {
+        assert_tokens(&U128::MAX.readable(), &[Token::Str("340282366920938463463374607431768211455")]);
+        assert_tokens(&I128::MIN.readable(), &[Token::Str("-170141183460469231731687303715884105728")]);
+        assert_tokens(&U128::ZERO.readable(), &[Token::Str("0")]);
+        assert_tokens(&(-1234).as_::<I128>().readable(), &[Token::Str("-1234")]);
+        // 10^19 is the boundary between the chunks of decimal digits
+        assert_tokens(&10u128.pow(19).as_::<U128>().readable(), &[Token::Str("10000000000000000000")]);
+        assert_tokens(&(10u128.pow(38) + 7).as_::<U128>().readable(), &[Token::Str("100000000000000000000000000000000000007")]);
+
+        assert_de_tokens(&1234.as_::<I128>().readable(), &[Token::U64(1234)]);
+        assert_de_tokens(&(-1234).as_::<I128>().readable(), &[Token::I64(-1234)]);
+        assert_de_tokens(&0x4D2.as_::<I128>().readable(), &[Token::Str("0x4d2")]);
+        assert_de_tokens(&(-0x4D2).as_::<I128>().readable(), &[Token::Str("-0X4D2")]);
+        assert_de_tokens(&0x4D2.as_::<U128>().readable(), &[Token::Str("+1234")]);
+        assert_de_tokens(&0x4D2.as_::<U128>().readable(), &[Token::Bytes(&[0x04, 0xD2])]);
+        assert_de_tokens(&0x4D2.as_::<U128>().readable(), &[Token::Seq { len: Some(2) }, Token::U8(0x04), Token::U8(0xD2), Token::SeqEnd]);
+    }
+
+    #[test]
+    fn human_readable_errors() {
+        assert_de_tokens_error::<serde_test::Readable<I20>>(&[Token::I64(1 << 19)], "invalid value: integer `524288`, expected an integer in range for a 20-bit signed integer, as a number, a decimal or `0x`-prefixed hexadecimal string, or big-endian bytes");
+        assert_de_tokens_error::<serde_test::Readable<U128>>(&[Token::Str("-0x1")], "(bnum) attempt to parse integer from string containing invalid digit");
+        assert_de_tokens_error::<serde_test::Readable<U128>>(&[Token::Str("0x")], "(bnum) attempt to parse integer from string containing invalid digit");
+        assert_de_tokens_error::<serde_test::Readable<I20>>(&[Token::Str("-0x80001")], "(bnum) attempt to parse integer too small to be represented by the target type");
+        assert_de_tokens_error::<serde_test::Readable<I20>>(&[Token::Bytes(&[0x08, 0x00, 0x00])], "invalid value: byte array, expected an integer in range for a 20-bit signed integer, as a number, a decimal or `0x`-prefixed hexadecimal string, or big-endian bytes");
+    }
+
+    #[test]
+    fn compact() {
+        assert_tokens(&U128::ZERO.compact(), &[Token::Bytes(&[])]);
+        assert_tokens(&I128::ZERO.compact(), &[Token::Bytes(&[])]);
+        assert_tokens(&0x4D2.as_::<U128>().compact(), &[Token::Bytes(&[0x04, 0xD2])]);
+        assert_tokens(&128.as_::<I128>().compact(), &[Token::Bytes(&[0x00, 0x80])]);
+        assert_tokens(&127.as_::<I128>().compact(), &[Token::Bytes(&[0x7F])]);
+        assert_tokens(&(-1).as_::<I128>().compact(), &[Token::Bytes(&[0xFF])]);
+        assert_tokens(&(-128).as_::<I128>().compact(), &[Token::Bytes(&[0x80])]);
+        assert_tokens(&(-129).as_::<I128>().compact(), &[Token::Bytes(&[0xFF, 0x7F])]);
+        assert_tokens(&I20::MIN.compact(), &[Token::Bytes(&[0xF8, 0x00, 0x00])]);
+        assert_tokens(&Uint::<3, 20>::MAX.compact(), &[Token::Bytes(&[0x0F, 0xFF, 0xFF])]);
+    }
+
+    #[test]
+    fn with_modules() {
+        #[derive(serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Debug)]
+        struct Fields {
+            #[serde(with = "crate::serde::decimal")]
+            decimal: I128,
+            #[serde(with = "crate::serde::hex")]
+            hex: I128,
+            #[serde(with = "crate::serde::bytes")]
+            bytes: U128,
+        }
+
+        let fields = Fields {
+            decimal: (-1234).as_(),
+            hex: (-1234).as_(),
+            bytes: 1234.as_(),
+        };
+        let tokens = [
+            Token::Struct { name: "Fields", len: 3 },
+            Token::Str("decimal"),
+            Token::Str("-1234"),
+            Token::Str("hex"),
+            Token::Str("-0x4d2"),
+            Token::Str("bytes"),
+            Token::Bytes(&[0x04, 0xD2]),
+            Token::StructEnd,
+        ];
+        assert_tokens(&fields.readable(), &tokens);
+        assert_tokens(&fields.compact(), &tokens);
+        assert_tokens(&I128::ZERO.readable(), &[Token::Str("0")]);
+    }
+
+    #[cfg(feature = "alloc")]
+    quickcheck::quickcheck! {
+        fn quickcheck_strings(a: u128, b: i128) -> bool {
+            use super::{DecimalStr, HexStr};
+            use alloc::format;
+            use alloc::string::ToString;
+
+            let (a_int, b_int) = (a.as_::<U128>(), b.as_::<I128>());
+            let b_hex = if b < 0 {
+                format!("-0x{:x}", b.unsigned_abs())
+            } else {
+                format!("0x{:x}", b)
+            };
+            DecimalStr(&a_int).to_string() == a.to_string()
+                && DecimalStr(&b_int).to_string() == b.to_string()
+                && HexStr(&a_int).to_string() == format!("0x{:x}", a)
+                && HexStr(&b_int).to_string() == b_hex
+                && I128::from_serde_str(&b_hex) == Ok(b_int)
+                && U128::from_serde_str(&a.to_string()) == Ok(a_int)
+        }
+
+        fn quickcheck_minimal_bytes(a: u128, b: i128) -> bool {
+            let (a_int, b_int) = (a.as_::<U128>(), b.as_::<I128>());
+            let (a_bytes, a_start) = a_int.to_minimal_be_bytes();
+            let (b_bytes, b_start) = b_int.to_minimal_be_bytes();
+            let a_len = (u128::BITS - a.leading_zeros()).div_ceil(8) as usize;
+            // the number of bits needed to represent `b` in two's complement, excluding the sign bit
+            let b_bits = if b < 0 { i128::BITS - b.leading_ones() } else { i128::BITS - b.leading_zeros() };
+            let b_len = if b == 0 { 0 } else { (b_bits + 1).div_ceil(8) as usize };
+            a_bytes[a_start..] == a.to_be_bytes()[16 - a_len..]
+                && b_bytes[b_start..] == b.to_be_bytes()[16 - b_len..]
+                && U128::from_be_slice(&a_bytes[a_start..]) == Some(a_int)
+                && I128::from_be_slice(&b_bytes[b_start..]) == Some(b_int)
+        }
+    }
+}
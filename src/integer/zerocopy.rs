@@ -0,0 +1,30 @@
+// `KnownLayout`, `Immutable`, `IntoBytes` and `Unaligned` are derived on `Integer` itself, since zerocopy only supports implementing its traits via its derive macros.
+//
+// `TryFromBytes`, `FromZeros` and `FromBytes` are not implemented: the derives would implement them for every bit width, but when `B` is not `0`, the unused high bits of the last byte must be zero for unsigned integers and copies of the sign bit for signed integers, so not every bit pattern is a valid value. The `crate::zerocopy::IntegerBytes` wrapper implements them instead, and can be viewed as an `Integer` without copying.
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::types::{I256, U256};
+    use crate::Uint;
+    use zerocopy::{IntoBytes, KnownLayout, Unaligned};
+
+    fn assert_unaligned<T: Unaligned + KnownLayout + ?Sized>() {}
+
+    #[test]
+    fn as_bytes() {
+        let ints = [1.as_::<U256>(), U256::MAX];
+        let bytes = ints.as_bytes();
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(bytes[..32], 1.as_::<U256>().to_bytes());
+        assert_eq!(bytes[32..], [0xFF; 32]);
+        assert_eq!(IntoBytes::as_bytes(&Uint::<3, 20>::MAX), [0xFF, 0xFF, 0x0F]);
+        assert_unaligned::<[I256]>();
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_matches_to_bytes(a: U256, b: I256) -> bool {
+            IntoBytes::as_bytes(&a) == a.to_bytes() && IntoBytes::as_bytes(&b) == b.to_le_bytes()
+        }
+    }
+}
@@ -13,6 +13,7 @@
         int_from_ascii
     )
 )]
+#![cfg_attr(all(feature = "float", nightly, not(test)), feature(f16, f128))]
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(any(feature = "arbitrary", feature = "quickcheck")), no_std)]
 // TODO: MAKE SURE NO_STD IS ENABLED WHEN PUBLISHING NEW VERSION
@@ -34,13 +35,37 @@ pub mod literal_parse;
 pub mod prelude;
 mod digits;
 mod overflow;
+#[cfg(any(feature = "float", feature = "decimal", feature = "fixed", feature = "scaled"))]
+mod rounding;
+#[cfg(any(feature = "float", feature = "decimal"))]
+mod status;
 
-// #[cfg(feature = "float")]
-// mod float;
+#[cfg(feature = "float")]
+mod float;
+
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "fixed")]
+mod fixed;
+#[cfg(any(feature = "fixed", feature = "scaled"))]
+mod fixed_point;
+#[cfg(feature = "scaled")]
+mod scaled;
+#[cfg(feature = "ratio")]
+mod ratio;
 
 #[cfg(feature = "rand")]
 pub mod random;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
+#[cfg(feature = "scale")]
+pub mod scale;
+
+#[cfg(feature = "zerocopy")]
+pub mod zerocopy;
+
 pub mod types;
 
 #[cfg(test)]
@@ -51,6 +76,22 @@ type Byte = u8;
 
 pub use integer::{Int, Integer, Uint};
 pub use overflow::OverflowMode;
+#[cfg(any(feature = "float", feature = "decimal", feature = "fixed", feature = "scaled"))]
+pub use rounding::RoundingMode;
+#[cfg(any(feature = "float", feature = "decimal"))]
+pub use status::FloatStatus;
+
+#[cfg(feature = "float")]
+pub use float::Float;
+
+#[cfg(feature = "float")]
+pub use float::minifloat;
 
-// #[cfg(feature = "float")]
-// pub use float::Float;
\ No newline at end of file
+#[cfg(feature = "decimal")]
+pub use decimal::Decimal;
+#[cfg(feature = "fixed")]
+pub use fixed::{Fixed, IFixed, UFixed};
+#[cfg(feature = "scaled")]
+pub use scaled::ScaledDecimal;
+#[cfg(feature = "ratio")]
+pub use ratio::Ratio;
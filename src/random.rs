@@ -1,6 +1,6 @@
-//! Items relating to the generation of random [`Integer`](crate::Integer) values.
+//! Items relating to the generation of random [`Integer`](crate::Integer) and `Float` values.
 //!
-//! The `rand` feature must be enabled to use items from this module.
+//! The `rand` feature must be enabled to use items from this module, and the `float` feature must also be enabled to use `UniformFloat`.
 
 
 /// Used for generating uniformly random [`Integer`](crate::Integer) values in a given range.
@@ -12,4 +12,15 @@ pub struct UniformInt<X> {
     pub(crate) low: X,
     pub(crate) range: X,
     pub(crate) thresh: X,
-}
\ No newline at end of file
+}
+
+/// Used for generating uniformly random [`Float`](crate::Float) values in a given range.
+///
+/// Implements the [`UniformSampler`](rand::distr::uniform::UniformSampler) trait from the [`rand`] crate. This struct should not be used directly; instead use the [`Uniform`](rand::distr::Uniform) struct from the [`rand`] crate.
+#[cfg(feature = "float")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UniformFloat<X> {
+    pub(crate) low: X,
+    pub(crate) scale: X,
+}
@@ -0,0 +1,37 @@
+use super::Ratio;
+use core::fmt::{self, Debug, Display, Formatter};
+
+impl<const N: usize> Display for Ratio<N> {
+    /// Formats `self` as `numer/denom` in lowest terms, or as just `numer` if `self` is an integer.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut s = self.numer_magnitude().to_str_radix(10);
+        if !self.is_integer() {
+            s.push('/');
+            s.push_str(&self.denom.to_str_radix(10));
+        }
+        f.pad_integral(!self.is_negative(), "", &s)
+    }
+}
+
+impl<const N: usize> Debug for Ratio<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::q;
+    use alloc::format;
+
+    #[test]
+    fn display() {
+        assert_eq!(format!("{}", q(-3, 4)), "-3/4");
+        assert_eq!(format!("{}", q(10, 5)), "2");
+        assert_eq!(format!("{}", q(0, 5)), "0");
+        assert_eq!(format!("{:+}", q(1, 3)), "+1/3");
+        assert_eq!(format!("{:>6}", q(1, 3)), "   1/3");
+        assert_eq!(format!("{:?}", q(i32::MIN, 3)), "-2147483648/3");
+    }
+}
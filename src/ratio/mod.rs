@@ -0,0 +1,420 @@
+use crate::{doc, Int, Uint};
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+mod fmt;
+mod ops;
+mod parse;
+
+/// Rational number type with const-generic bit width, stored as a signed numerator and an unsigned denominator in lowest terms.
+///
+/// A `Ratio<N>` represents the value `numer / denom`, where `numer` is an [`Int<N>`](crate::Int) and `denom` is a non-zero [`Uint<N>`](crate::Uint). Values are always normalised so that `numer` and `denom` have no common factors, so that in particular, zero is always stored as `0/1`. This means that two ratios are equal if and only if their numerators and denominators are equal.
+///
+/// Arithmetic is exact. The `checked_*` methods return `None` if the reduced result (or an intermediate product) is not representable, and the arithmetic operators panic in this case, regardless of the overflow mode.
+///
+/// # Examples
+///
+/// ```
+/// use bnum::Ratio;
+/// use bnum::prelude::*;
+///
+/// type Q = Ratio<32>;
+///
+/// let a: Q = "1/6".parse().unwrap();
+/// let b: Q = "-3/4".parse().unwrap();
+/// assert_eq!((a + b).to_string(), "-7/12");
+/// assert_eq!((a * b).to_string(), "-1/8");
+/// assert_eq!((a / b).to_string(), "-2/9");
+/// assert_eq!(Q::new(n!(10), n!(4)).to_string(), "5/2");
+/// assert_eq!(Q::new(n!(10), n!(4)).floor(), Q::from_int(n!(2)));
+/// assert!(a > b);
+/// ```
+#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Ratio<const N: usize> {
+    numer: Int<N>,
+    denom: Uint<N>,
+}
+
+/// greatest common divisor, with `gcd(0, 0) = 0`
+const fn gcd<const N: usize>(mut a: Uint<N>, mut b: Uint<N>) -> Uint<N> {
+    // binary GCD algorithm: https://en.wikipedia.org/wiki/Binary_GCD_algorithm
+    if a.is_zero() {
+        return b;
+    }
+    if b.is_zero() {
+        return a;
+    }
+    let (a_tz, b_tz) = (a.trailing_zeros(), b.trailing_zeros());
+    let shift = if a_tz < b_tz { a_tz } else { b_tz };
+    a = a.unbounded_shr(a_tz);
+    loop {
+        b = b.unbounded_shr(b.trailing_zeros());
+        if a.gt(&b) {
+            let t = a;
+            a = b;
+            b = t;
+        }
+        b = b.wrapping_sub(a);
+        if b.is_zero() {
+            return a.unbounded_shl(shift);
+        }
+    }
+}
+
+/// Associated constants.
+impl<const N: usize> Ratio<N> {
+    pub const ZERO: Self = Self::from_int(Int::ZERO);
+
+    pub const ONE: Self = Self::from_int(Int::ONE);
+
+    /// The smallest value, [`Int::MIN`](crate::Integer::MIN) divided by one.
+    pub const MIN: Self = Self::from_int(Int::MIN);
+
+    /// The largest value, [`Int::MAX`](crate::Integer::MAX) divided by one.
+    pub const MAX: Self = Self::from_int(Int::MAX);
+}
+
+impl<const N: usize> Ratio<N> {
+    /// Creates a ratio with the value `numer / denom`, reduced to lowest terms. Returns `None` if `denom` is zero.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_new(numer: Int<N>, denom: Uint<N>) -> Option<Self> {
+        if denom.is_zero() {
+            return None;
+        }
+        // reducing never increases the magnitude of the numerator, so the result is always representable
+        Self::from_magnitudes(numer.is_negative(), numer.unsigned_abs(), denom)
+    }
+
+    /// Creates a ratio with the value `numer / denom`, reduced to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `denom` is zero.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn new(numer: Int<N>, denom: Uint<N>) -> Self {
+        match Self::checked_new(numer, denom) {
+            Some(ratio) => ratio,
+            None => panic!(crate::errors::err_msg!("denominator of ratio must be non-zero")),
+        }
+    }
+
+    /// Creates a ratio with the value `int`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn from_int(int: Int<N>) -> Self {
+        Self {
+            numer: int,
+            denom: Uint::ONE,
+        }
+    }
+
+    /// Returns the numerator of `self` in lowest terms, which carries the sign of `self`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn numer(self) -> Int<N> {
+        self.numer
+    }
+
+    /// Returns the denominator of `self` in lowest terms, which is always non-zero.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn denom(self) -> Uint<N> {
+        self.denom
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        self.numer.is_zero()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.numer.is_negative()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        self.numer.is_positive()
+    }
+
+    /// Returns `true` if `self` is an integer, i.e. if its denominator is one.
+    #[must_use]
+    #[inline]
+    pub const fn is_integer(self) -> bool {
+        self.denom.eq(&Uint::ONE)
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        let negative = self.is_negative();
+        if negative != other.is_negative() {
+            return if negative {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        }
+        // compare `|a| * d` with `|c| * b`, which cannot overflow when computed with double the width
+        let (lo, hi) = self.numer_magnitude().widening_mul(other.denom);
+        let (other_lo, other_hi) = other.numer_magnitude().widening_mul(self.denom);
+        let ordering = match hi.cmp(&other_hi) {
+            Ordering::Equal => lo.cmp(&other_lo),
+            ordering => ordering,
+        };
+        if negative {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+
+    /// Returns the largest integer less than or equal to `self`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn floor(self) -> Self {
+        let (quotient, remainder) = self.numer_magnitude().div_rem_unchecked(self.denom);
+        let negative = self.is_negative();
+        Self::from_int_magnitude(negative, Self::round_up_if(quotient, negative && !remainder.is_zero()))
+    }
+
+    /// Returns the smallest integer greater than or equal to `self`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn ceil(self) -> Self {
+        let (quotient, remainder) = self.numer_magnitude().div_rem_unchecked(self.denom);
+        let negative = self.is_negative();
+        Self::from_int_magnitude(negative, Self::round_up_if(quotient, !negative && !remainder.is_zero()))
+    }
+
+    /// Returns the nearest integer to `self`, rounding half-way cases away from zero.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn round(self) -> Self {
+        let (quotient, remainder) = self.numer_magnitude().div_rem_unchecked(self.denom);
+        let half_or_more = remainder.ge(&self.denom.wrapping_sub(remainder));
+        Self::from_int_magnitude(self.is_negative(), Self::round_up_if(quotient, half_or_more))
+    }
+
+    /// Returns the integer part of `self`, rounding towards zero.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn trunc(self) -> Self {
+        let (quotient, _) = self.numer_magnitude().div_rem_unchecked(self.denom);
+        Self::from_int_magnitude(self.is_negative(), quotient)
+    }
+
+    /// Returns the fractional part of `self`, i.e. `self - self.trunc()`, which has the same sign as `self`.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn fract(self) -> Self {
+        let (_, remainder) = self.numer_magnitude().div_rem_unchecked(self.denom);
+        // `remainder` and `denom` have the same common factors as `numer` and `denom`, so the result is already in lowest terms
+        if remainder.is_zero() {
+            return Self::ZERO;
+        }
+        Self {
+            numer: Self::from_int_magnitude(self.is_negative(), remainder).numer,
+            denom: self.denom,
+        }
+    }
+
+    /// `|numer|`
+    #[inline]
+    const fn numer_magnitude(self) -> Uint<N> {
+        self.numer.unsigned_abs()
+    }
+
+    /// `quotient + 1` if `round_up`, otherwise `quotient`
+    #[inline]
+    const fn round_up_if(quotient: Uint<N>, round_up: bool) -> Uint<N> {
+        if round_up {
+            quotient.wrapping_add(Uint::ONE)
+        } else {
+            quotient
+        }
+    }
+
+    /// the integer with the given sign and magnitude, which must be representable
+    #[inline]
+    const fn from_int_magnitude(negative: bool, magnitude: Uint<N>) -> Self {
+        let numer = if negative {
+            magnitude.wrapping_neg()
+        } else {
+            magnitude
+        };
+        Self::from_int(numer.cast_signed())
+    }
+
+    /// the ratio `(-1)^negative * numer / denom` reduced to lowest terms, or `None` if the reduced numerator is not representable. `denom` must be non-zero
+    const fn from_magnitudes(negative: bool, numer: Uint<N>, denom: Uint<N>) -> Option<Self> {
+        let divisor = gcd(numer, denom);
+        let (numer, denom) = (numer.wrapping_div(divisor), denom.wrapping_div(divisor));
+        Self::from_reduced_magnitudes(negative, numer, denom)
+    }
+
+    /// the ratio `(-1)^negative * numer / denom`, where `numer / denom` is in lowest terms, or `None` if the numerator is not representable. `denom` must be non-zero
+    const fn from_reduced_magnitudes(negative: bool, numer: Uint<N>, denom: Uint<N>) -> Option<Self> {
+        let fits = match numer.cmp(&Int::<N>::MIN.unsigned_abs()) {
+            Ordering::Less => true,
+            Ordering::Equal => negative,
+            Ordering::Greater => false,
+        };
+        if !fits {
+            return None;
+        }
+        Some(Self {
+            numer: Self::from_int_magnitude(negative, numer).numer,
+            denom,
+        })
+    }
+}
+
+impl<const N: usize> PartialOrd for Ratio<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Ord::cmp(self, other))
+    }
+}
+
+impl<const N: usize> Ord for Ratio<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        Self::cmp(self, other)
+    }
+}
+
+impl<const N: usize> Default for Ratio<N> {
+    #[doc = doc::default!()]
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> From<Int<N>> for Ratio<N> {
+    #[inline]
+    fn from(int: Int<N>) -> Self {
+        Self::from_int(int)
+    }
+}
+
+#[cfg(any(test, feature = "quickcheck"))]
+impl<const N: usize> quickcheck::Arbitrary for Ratio<N> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let denom = Uint::arbitrary(g);
+        let denom = if denom.is_zero() { Uint::ONE } else { denom };
+        Self::new(Int::arbitrary(g), denom)
+    }
+}
+
+// implementation if we don't have alloc, as otherwise can't call assert_eq! (since this requires Debug)
+#[cfg(not(feature = "alloc"))]
+impl<const N: usize> core::fmt::Debug for Ratio<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Ratio")
+            .field("numer", &self.numer)
+            .field("denom", &self.denom)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::gcd;
+    use crate::cast::As;
+    use crate::Ratio;
+
+    type Q = Ratio<4>;
+
+    pub(super) fn q(numer: i32, denom: u32) -> Q {
+        Q::new(numer.as_(), denom.as_())
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(q(10, 4).numer(), 5.as_());
+        assert_eq!(q(10, 4).denom(), 2u32.as_());
+        assert_eq!(q(-10, 4).numer(), (-5).as_());
+        assert_eq!(q(0, 7), Q::ZERO);
+        assert_eq!(q(i32::MIN, 2).numer(), (i32::MIN / 2).as_());
+        assert_eq!(q(i32::MIN, u32::MAX).denom(), u32::MAX.as_());
+        assert_eq!(Q::checked_new(1.as_(), 0u32.as_()), None);
+        assert!(q(6, 3).is_integer());
+        assert!(!q(6, 4).is_integer());
+    }
+
+    #[test]
+    #[should_panic(expected = "denominator of ratio must be non-zero")]
+    fn new_zero_denominator() {
+        let _ = q(1, 0);
+    }
+
+    #[test]
+    fn cmp() {
+        assert!(q(1, 3) < q(1, 2));
+        assert!(q(-1, 3) > q(-1, 2));
+        assert!(q(-1, 3) < Q::ZERO);
+        assert!(Q::MIN < q(i32::MIN, 3));
+        assert!(q(i32::MAX, u32::MAX) > q(i32::MAX - 1, u32::MAX - 1));
+        assert_eq!(q(2, 4).cmp(&q(1, 2)), core::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn rounding() {
+        let cases = [
+            // (value, floor, ceil, round, trunc)
+            (q(7, 2), 3, 4, 4, 3),
+            (q(-7, 2), -4, -3, -4, -3),
+            (q(5, 3), 1, 2, 2, 1),
+            (q(-5, 3), -2, -1, -2, -1),
+            (q(4, 3), 1, 2, 1, 1),
+            (q(-6, 1), -6, -6, -6, -6),
+            (q(i32::MIN, 1), i32::MIN, i32::MIN, i32::MIN, i32::MIN),
+            (q(i32::MAX, u32::MAX), 0, 1, 0, 0),
+            (q(1, u32::MAX), 0, 1, 0, 0),
+        ];
+        for (value, floor, ceil, round, trunc) in cases {
+            assert_eq!(value.floor(), q(floor, 1));
+            assert_eq!(value.ceil(), q(ceil, 1));
+            assert_eq!(value.round(), q(round, 1));
+            assert_eq!(value.trunc(), q(trunc, 1));
+        }
+        assert_eq!(q(-7, 2).fract(), q(-1, 2));
+        assert_eq!(q(8, 2).fract(), Q::ZERO);
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_gcd(a: u32, b: u32) -> bool {
+            fn reference(a: u32, b: u32) -> u32 {
+                if b == 0 { a } else { reference(b, a % b) }
+            }
+            gcd::<4>(a.as_(), b.as_()) == reference(a, b).as_()
+        }
+
+        fn quickcheck_cmp(a: i16, b: u16, c: i16, d: u16) -> quickcheck::TestResult {
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let expected = (a as i64 * d as i64).cmp(&(c as i64 * b as i64));
+            quickcheck::TestResult::from_bool(q(a as i32, b as u32).cmp(&q(c as i32, d as u32)) == expected)
+        }
+
+        fn quickcheck_floor_ceil(a: i32, b: u32) -> quickcheck::TestResult {
+            if b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let (a, b) = (a as i64, b as i64);
+            let value = q(a as i32, b as u32);
+            quickcheck::TestResult::from_bool(
+                value.floor() == q(a.div_euclid(b) as i32, 1)
+                    && value.ceil() == q(-((-a).div_euclid(b)) as i32, 1)
+            )
+        }
+    }
+}
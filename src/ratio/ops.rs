@@ -0,0 +1,324 @@
+use super::{gcd, Ratio};
+use crate::doc;
+use crate::helpers::full_op_impl;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// `Some(value)`, or returns `None` from the enclosing function
+macro_rules! tri {
+    ($value: expr) => {
+        match $value {
+            Some(value) => value,
+            None => return None,
+        }
+    };
+}
+
+impl<const N: usize> Ratio<N> {
+    /// `self + (-1)^negate * rhs`
+    const fn checked_add_signed(self, rhs: Self, negate: bool) -> Option<Self> {
+        // the intermediate products are kept as small as possible using the algorithm in section 4.5.1 of The Art of Computer Programming, volume 2, by Donald Knuth, which also ensures the result is in lowest terms
+        let (a, b) = (self.numer_magnitude(), self.denom);
+        let (c, d) = (rhs.numer_magnitude(), rhs.denom);
+        let (a_negative, c_negative) = (self.is_negative(), rhs.is_negative() != negate);
+        let divisor = gcd(b, d);
+        let (b, d_reduced) = (b.wrapping_div(divisor), d.wrapping_div(divisor));
+        let (x, y) = (tri!(a.checked_mul(d_reduced)), tri!(c.checked_mul(b)));
+        let (negative, numer) = if a_negative == c_negative {
+            (a_negative, tri!(x.checked_add(y)))
+        } else if x.ge(&y) {
+            (a_negative, x.wrapping_sub(y))
+        } else {
+            (c_negative, y.wrapping_sub(x))
+        };
+        if numer.is_zero() {
+            return Some(Self::ZERO);
+        }
+        let divisor = gcd(numer, divisor);
+        let denom = tri!(b.checked_mul(d.wrapping_div(divisor)));
+        Self::from_reduced_magnitudes(negative, numer.wrapping_div(divisor), denom)
+    }
+
+    /// Computes `self + rhs`, returning `None` if the result, or an intermediate product used to compute it, is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add_signed(rhs, false)
+    }
+
+    /// Computes `self - rhs`, returning `None` if the result, or an intermediate product used to compute it, is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add_signed(rhs, true)
+    }
+
+    /// Computes `self * rhs`, returning `None` if the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        if self.is_zero() || rhs.is_zero() {
+            return Some(Self::ZERO);
+        }
+        let (a, b) = (self.numer_magnitude(), self.denom);
+        let (c, d) = (rhs.numer_magnitude(), rhs.denom);
+        // cancelling common factors before multiplying means the result is in lowest terms
+        let (g1, g2) = (gcd(a, d), gcd(c, b));
+        let numer = tri!(a.wrapping_div(g1).checked_mul(c.wrapping_div(g2)));
+        let denom = tri!(b.wrapping_div(g2).checked_mul(d.wrapping_div(g1)));
+        Self::from_reduced_magnitudes(self.is_negative() != rhs.is_negative(), numer, denom)
+    }
+
+    /// Computes `self / rhs`, returning `None` if `rhs` is zero or the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+        let (a, b) = (self.numer_magnitude(), self.denom);
+        let (c, d) = (rhs.numer_magnitude(), rhs.denom);
+        let (g1, g2) = (gcd(a, c), gcd(b, d));
+        let numer = tri!(a.wrapping_div(g1).checked_mul(d.wrapping_div(g2)));
+        let denom = tri!(b.wrapping_div(g2).checked_mul(c.wrapping_div(g1)));
+        Self::from_reduced_magnitudes(self.is_negative() != rhs.is_negative(), numer, denom)
+    }
+
+    /// Computes `-self`, returning `None` if the numerator of `self` is [`Int::MIN`](crate::Integer::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.numer.checked_neg() {
+            Some(numer) => Some(Self {
+                numer,
+                denom: self.denom,
+            }),
+            None => None,
+        }
+    }
+
+    /// Computes the absolute value of `self`, returning `None` if the numerator of `self` is [`Int::MIN`](crate::Integer::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        if self.is_negative() {
+            self.checked_neg()
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Computes `1 / self`, returning `None` if `self` is zero or the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_recip(self) -> Option<Self> {
+        if self.is_zero() {
+            return None;
+        }
+        Self::from_reduced_magnitudes(self.is_negative(), self.denom, self.numer_magnitude())
+    }
+
+    /// Computes `-self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the numerator of `self` is [`Int::MIN`](crate::Integer::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn neg(self) -> Self {
+        match self.checked_neg() {
+            Some(out) => out,
+            None => panic!(crate::errors::err_msg!("attempt to negate with overflow")),
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the numerator of `self` is [`Int::MIN`](crate::Integer::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        match self.checked_abs() {
+            Some(out) => out,
+            None => panic!(crate::errors::err_msg!("attempt to negate with overflow")),
+        }
+    }
+
+    /// Computes `1 / self`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `self` is zero, or if the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn recip(self) -> Self {
+        if self.is_zero() {
+            panic!(crate::errors::err_msg!(crate::errors::div_by_zero_message!()));
+        }
+        match self.checked_recip() {
+            Some(out) => out,
+            None => panic!(crate::errors::err_msg!("attempt to calculate reciprocal with overflow")),
+        }
+    }
+}
+
+macro_rules! op_trait {
+    ($Trait: ident, $AssignTrait: ident, $method: ident, $assign_method: ident, $checked: ident, $message: expr) => {
+        impl<const N: usize> $Trait for Ratio<N> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                match self.$checked(rhs) {
+                    Some(out) => out,
+                    None => panic!($message),
+                }
+            }
+        }
+
+        full_op_impl!(<const N: usize> $Trait, $AssignTrait, Ratio<N>, $method, $assign_method for Ratio<N>);
+    };
+}
+
+op_trait!(Add, AddAssign, add, add_assign, checked_add, crate::errors::err_msg!("attempt to add with overflow"));
+op_trait!(Sub, SubAssign, sub, sub_assign, checked_sub, crate::errors::err_msg!("attempt to subtract with overflow"));
+op_trait!(Mul, MulAssign, mul, mul_assign, checked_mul, crate::errors::err_msg!("attempt to multiply with overflow"));
+
+impl<const N: usize> Div for Ratio<N> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.is_zero() {
+            panic!(crate::errors::err_msg!(crate::errors::div_by_zero_message!()));
+        }
+        match self.checked_div(rhs) {
+            Some(out) => out,
+            None => panic!(crate::errors::err_msg!("attempt to divide with overflow")),
+        }
+    }
+}
+
+full_op_impl!(<const N: usize> Div, DivAssign, Ratio<N>, div, div_assign for Ratio<N>);
+
+impl<const N: usize> Neg for Ratio<N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl<const N: usize> Neg for &Ratio<N> {
+    type Output = Ratio<N>;
+
+    #[inline]
+    fn neg(self) -> Ratio<N> {
+        (*self).neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::q;
+    use crate::Ratio;
+
+    type Q = Ratio<4>;
+
+    /// the reduced form of `numer / denom`, computed with primitive arithmetic
+    fn reference(numer: i128, denom: i128) -> Option<Q> {
+        fn gcd(a: u128, b: u128) -> u128 {
+            if b == 0 { a } else { gcd(b, a % b) }
+        }
+        let divisor = gcd(numer.unsigned_abs(), denom.unsigned_abs()) as i128;
+        let (mut numer, mut denom) = (numer / divisor, denom / divisor);
+        if denom < 0 {
+            (numer, denom) = (-numer, -denom);
+        }
+        Some(q(i32::try_from(numer).ok()?, u32::try_from(denom).ok()?))
+    }
+
+    fn parts(a: Q) -> (i128, i128) {
+        use crate::cast::As;
+
+        (a.numer().as_::<i32>() as i128, a.denom().as_::<u32>() as i128)
+    }
+
+    #[test]
+    fn arithmetic() {
+        assert_eq!(q(1, 6) + q(1, 3), q(1, 2));
+        assert_eq!(q(1, 6) - q(1, 3), q(-1, 6));
+        assert_eq!(q(1, 2) - q(1, 2), Q::ZERO);
+        assert_eq!(q(-2, 3) * q(9, 4), q(-3, 2));
+        assert_eq!(q(-2, 3) / q(-4, 9), q(3, 2));
+        assert_eq!(q(-2, 3).recip(), q(-3, 2));
+        assert_eq!(q(i32::MIN, 1).checked_neg(), None);
+        assert_eq!(q(i32::MIN, 3).checked_abs(), None);
+        assert_eq!(q(1, u32::MAX).checked_recip(), None);
+        assert_eq!(q(-1, 1 << 31).checked_recip(), Some(q(i32::MIN, 1)));
+        assert_eq!(Q::MAX.checked_add(Q::ONE), None);
+        assert_eq!(Q::MIN.checked_sub(Q::ONE), None);
+        assert_eq!(Q::MIN.checked_add(Q::ONE), Some(q(i32::MIN + 1, 1)));
+        // the sum fits, although the intermediate products do not
+        assert_eq!(q(1, u32::MAX).checked_add(q(1, u32::MAX)), Some(q(2, u32::MAX)));
+        assert_eq!(q(1, 3).checked_div(Q::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_overflow() {
+        let _ = Q::MAX + Q::ONE;
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = Q::ONE / Q::ZERO;
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_add(a: Q, b: Q) -> bool {
+            let ((n1, d1), (n2, d2)) = (parts(a), parts(b));
+            // an intermediate product may overflow even if the result is representable
+            match a.checked_add(b) {
+                Some(sum) => Some(sum) == reference(n1 * d2 + n2 * d1, d1 * d2),
+                None => true,
+            }
+        }
+
+        fn quickcheck_add_small(a: i16, b: u16, c: i16, d: u16) -> quickcheck::TestResult {
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            // for these inputs, the intermediate products are always representable
+            let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+            let expected = reference(a * d + c * b, b * d);
+            quickcheck::TestResult::from_bool(q(a as i32, b as u32).checked_add(q(c as i32, d as u32)) == expected)
+        }
+
+        fn quickcheck_sub(a: i16, b: u16, c: i16, d: u16) -> quickcheck::TestResult {
+            if b == 0 || d == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+            let expected = reference(a * d - c * b, b * d);
+            quickcheck::TestResult::from_bool(q(a as i32, b as u32).checked_sub(q(c as i32, d as u32)) == expected)
+        }
+
+        fn quickcheck_mul(a: Q, b: Q) -> bool {
+            let ((n1, d1), (n2, d2)) = (parts(a), parts(b));
+            a.checked_mul(b) == reference(n1 * n2, d1 * d2)
+        }
+
+        fn quickcheck_div(a: Q, b: Q) -> quickcheck::TestResult {
+            if b.is_zero() {
+                return quickcheck::TestResult::discard();
+            }
+            let ((n1, d1), (n2, d2)) = (parts(a), parts(b));
+            quickcheck::TestResult::from_bool(a.checked_div(b) == reference(n1 * d2, d1 * n2))
+        }
+    }
+}
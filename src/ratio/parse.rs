@@ -0,0 +1,70 @@
+use super::Ratio;
+use crate::errors::{ParseRatioError, RatioErrorKind};
+use crate::{Int, Uint};
+use core::str::FromStr;
+
+impl<const N: usize> FromStr for Ratio<N> {
+    type Err = ParseRatioError;
+
+    /// Parses a ratio of the form `a/b` or `a`, where `a` is a decimal integer with an optional sign and `b` is a non-zero unsigned decimal integer, such as `-3/4` or `7`. The result is reduced to lowest terms.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let int_error = |err| ParseRatioError {
+            kind: RatioErrorKind::Int(err),
+        };
+        let (numer, denom) = match src.split_once('/') {
+            Some((numer, denom)) => (numer, Some(denom)),
+            None => (src, None),
+        };
+        let numer = Int::<N>::from_str_radix(numer, 10).map_err(int_error)?;
+        let denom = match denom {
+            Some(denom) => Uint::<N>::from_str_radix(denom, 10).map_err(int_error)?,
+            None => Uint::ONE,
+        };
+        Self::checked_new(numer, denom).ok_or(ParseRatioError {
+            kind: RatioErrorKind::ZeroDenominator,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::q;
+    use crate::errors::{ParseIntError, ParseRatioError, RatioErrorKind};
+    use crate::Ratio;
+    use core::num::IntErrorKind;
+
+    type Q = Ratio<4>;
+
+    fn int_error(kind: IntErrorKind) -> Result<Q, ParseRatioError> {
+        Err(ParseRatioError {
+            kind: RatioErrorKind::Int(ParseIntError { kind }),
+        })
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!("3/4".parse(), Ok(q(3, 4)));
+        assert_eq!("-6/8".parse(), Ok(q(-3, 4)));
+        assert_eq!("+12".parse(), Ok(q(12, 1)));
+        assert_eq!("0/5".parse(), Ok(Q::ZERO));
+        assert_eq!("-2147483648/4294967295".parse(), Ok(q(i32::MIN, u32::MAX)));
+        assert_eq!("1/0".parse::<Q>(), Err(ParseRatioError {
+            kind: RatioErrorKind::ZeroDenominator,
+        }));
+        assert_eq!("".parse(), int_error(IntErrorKind::Empty));
+        assert_eq!("1/".parse(), int_error(IntErrorKind::Empty));
+        assert_eq!("1/-2".parse(), int_error(IntErrorKind::InvalidDigit));
+        assert_eq!("1/2/3".parse(), int_error(IntErrorKind::InvalidDigit));
+        assert_eq!("1.5".parse(), int_error(IntErrorKind::InvalidDigit));
+        assert_eq!("2147483648".parse(), int_error(IntErrorKind::PosOverflow));
+    }
+
+    #[cfg(feature = "alloc")]
+    quickcheck::quickcheck! {
+        fn quickcheck_from_str_round_trip(a: Q) -> bool {
+            use alloc::string::ToString;
+
+            a.to_string().parse() == Ok(a)
+        }
+    }
+}
@@ -0,0 +1,47 @@
+#[cfg(any(feature = "decimal", feature = "fixed", feature = "scaled"))]
+use core::cmp::Ordering;
+
+/// An enum that represents the different possible rounding behaviour for operations whose exact result cannot be represented by the output type, such as floating point arithmetic.
+///
+/// The variants correspond to the rounding-direction attributes specified by IEEE 754.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[repr(u8)]
+pub enum RoundingMode {
+    /// Round to the nearest representable value. If the exact value lies exactly halfway between two representable values, round to the one whose least significant digit is even. This corresponds to `roundTiesToEven` in IEEE 754.
+    NearestTiesEven = 0,
+    /// Round to the nearest representable value. If the exact value lies exactly halfway between two representable values, round to the one with the larger magnitude. This corresponds to `roundTiesToAway` in IEEE 754.
+    NearestTiesAway = 1,
+    /// Round to the closest representable value which is greater than or equal to the exact value. This corresponds to `roundTowardPositive` in IEEE 754.
+    TowardPositive = 2,
+    /// Round to the closest representable value which is less than or equal to the exact value. This corresponds to `roundTowardNegative` in IEEE 754.
+    TowardNegative = 3,
+    /// Round to the closest representable value whose magnitude is less than or equal to the magnitude of the exact value. This corresponds to `roundTowardZero` in IEEE 754.
+    TowardZero = 4,
+}
+
+impl RoundingMode {
+    /// The default rounding mode, [`NearestTiesEven`](Self::NearestTiesEven). This is the rounding mode used by the arithmetic operators and by methods which don't take a rounding mode as a parameter.
+    pub const DEFAULT: Self = Self::NearestTiesEven;
+
+    /// whether a value which has been truncated towards zero should have its magnitude incremented, given how the discarded part compares to half a unit in the last place, whether the discarded part is non-zero, and whether the truncated value is odd
+    #[cfg(any(feature = "decimal", feature = "fixed", feature = "scaled"))]
+    #[inline]
+    pub(crate) const fn round_up(self, negative: bool, half_cmp: Ordering, inexact: bool, odd: bool) -> bool {
+        match self {
+            Self::NearestTiesEven => {
+                matches!(half_cmp, Ordering::Greater) || (matches!(half_cmp, Ordering::Equal) && odd)
+            }
+            Self::NearestTiesAway => !matches!(half_cmp, Ordering::Less),
+            Self::TowardPositive => inexact && !negative,
+            Self::TowardNegative => inexact && negative,
+            Self::TowardZero => false,
+        }
+    }
+}
+
+impl Default for RoundingMode {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,285 @@
+//! Support for the compact encoding of unsigned [`Integer`](crate::Integer)s in the [SCALE codec](https://docs.rs/parity-scale-codec/latest/parity_scale_codec/).
+//!
+//! `Integer`s implement [`Encode`] and [`Decode`] directly, using their fixed-width little-endian bytes. The [`Compact`] wrapper in this module instead uses the variable-length compact encoding, in the same way as [`parity_scale_codec::Compact`] does for the primitive unsigned integers. Since `parity_scale_codec::Compact` can only be implemented for types wider than 128 bits by the `parity_scale_codec` crate itself, the wrapper is provided here instead: to compact-encode a field of a struct deriving `Encode` and `Decode`, use `#[codec(encoded_as = "bnum::scale::Compact<...>")]` rather than `#[codec(compact)]`.
+//!
+//! The `scale` feature must be enabled to use items from this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use bnum::prelude::*;
+//! use bnum::scale::Compact;
+//! use bnum::types::U256;
+//! use parity_scale_codec::{Decode, Encode};
+//!
+//! #[derive(Encode, Decode, PartialEq, Debug)]
+//! struct Balance {
+//!     #[codec(encoded_as = "Compact<U256>")]
+//!     free: U256,
+//!     reserved: U256,
+//! }
+//!
+//! let balance = Balance {
+//!     free: n!(42),
+//!     reserved: n!(0),
+//! };
+//! let encoded = balance.encode();
+//! assert_eq!(encoded.len(), 1 + 32);
+//! assert_eq!(encoded[0], 42 << 2);
+//! assert_eq!(Balance::decode(&mut &encoded[..]), Ok(balance));
+//! ```
+
+use crate::Uint;
+use ::parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, EncodeAsRef, EncodeLike, Error, Input, MaxEncodedLen, Output};
+use ::scale_info::{MetaType, Type, TypeDefCompact, TypeInfo};
+
+/// The largest number of bytes that can follow the prefix byte in the compact encoding.
+const MAX_BYTES: usize = 0b111111 + 4;
+
+/// A wrapper around an unsigned [`Integer`](crate::Integer) which is encoded with the SCALE compact encoding.
+///
+/// Values less than 2<sup>30</sup> are encoded in one, two or four bytes, with the two lowest bits of the first byte indicating the length. Larger values are encoded as a prefix byte holding the number of bytes that follow, followed by the minimal little-endian bytes of the value. Only the canonical (shortest) encoding is accepted when decoding.
+///
+/// The compact encoding can represent values with at most 536 bits, so using `Compact` with an integer type whose bit width is greater than 536 causes a compile-time error.
+///
+/// [`TypeInfo`] is only available when the bit width of the integer is that of one of the primitive unsigned integer types of `scale-info` (8, 16, 32, 64, 128 or 256 bits), since the compact type information must refer to a primitive type. Calling [`TypeInfo::type_info`] for any other width causes a compile-time error.
+///
+/// ```compile_fail
+/// use bnum::scale::Compact;
+/// use bnum::Uint;
+/// use scale_info::TypeInfo;
+///
+/// dbg!(Compact::<Uint<3>>::type_info()); // there is no 24-bit primitive
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Compact<T>(pub T);
+
+impl<const N: usize, const B: usize, const OM: u8> Compact<Uint<N, B, OM>> {
+    const ASSERT_FITS: () = assert!(
+        Uint::<N, B, OM>::BITS <= MAX_BYTES as u32 * 8,
+        "(bnum) the SCALE compact encoding only supports integers with at most 536 bits"
+    );
+
+    const ASSERT_PRIMITIVE: () = assert!(
+        matches!(Uint::<N, B, OM>::BITS, 8 | 16 | 32 | 64 | 128 | 256),
+        "(bnum) type information for the SCALE compact encoding is only available for integers with the bit width of a primitive unsigned integer"
+    );
+
+    #[inline]
+    const fn encoded_len(width: u32) -> usize {
+        match width {
+            0..=6 => 1,
+            7..=14 => 2,
+            15..=30 => 4,
+            _ => 1 + width.div_ceil(8) as usize,
+        }
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> From<Uint<N, B, OM>> for Compact<Uint<N, B, OM>> {
+    #[inline]
+    fn from(value: Uint<N, B, OM>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> From<&Uint<N, B, OM>> for Compact<Uint<N, B, OM>> {
+    #[inline]
+    fn from(value: &Uint<N, B, OM>) -> Self {
+        Self(*value)
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> From<Compact<Uint<N, B, OM>>> for Uint<N, B, OM> {
+    #[inline]
+    fn from(value: Compact<Self>) -> Self {
+        value.0
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> Encode for Compact<Uint<N, B, OM>> {
+    #[inline]
+    fn size_hint(&self) -> usize {
+        const { Self::ASSERT_FITS };
+        Self::encoded_len(self.0.bit_width())
+    }
+
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        const { Self::ASSERT_FITS };
+        let bytes = self.0.as_bytes();
+        let width = self.0.bit_width();
+        let mut low = 0u32;
+        for &byte in bytes.iter().take(4).rev() {
+            low = (low << 8) | byte as u32;
+        }
+        match width {
+            0..=6 => dest.push_byte((low as u8) << 2),
+            7..=14 => dest.write(&(((low as u16) << 2) | 0b01).to_le_bytes()),
+            15..=30 => dest.write(&((low << 2) | 0b10).to_le_bytes()),
+            _ => {
+                let len = width.div_ceil(8) as usize;
+                dest.push_byte((((len - 4) as u8) << 2) | 0b11);
+                dest.write(&bytes[..len]);
+            }
+        }
+    }
+
+    #[inline]
+    fn encoded_size(&self) -> usize {
+        self.size_hint()
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> EncodeLike for Compact<Uint<N, B, OM>> {}
+
+impl<'a, const N: usize, const B: usize, const OM: u8> EncodeAsRef<'a, Uint<N, B, OM>> for Compact<Uint<N, B, OM>> {
+    type RefType = Self;
+}
+
+impl<const N: usize, const B: usize, const OM: u8> Decode for Compact<Uint<N, B, OM>> {
+    /// Decodes a compact-encoded integer, returning an error if the encoding is not the shortest one for its value, or if the value is out of range for the type.
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        const { Self::ASSERT_FITS };
+        let prefix = input.read_byte()?;
+        let mut buf = [0; MAX_BYTES];
+        // the minimum bit width for the encoding to be canonical
+        let (len, min_width) = match prefix & 0b11 {
+            0b00 => (1, 0),
+            0b01 => (2, 7),
+            0b10 => (4, 15),
+            _ => {
+                let len = (prefix >> 2) as usize + 4;
+                (len, Ord::max(31, len as u32 * 8 - 7))
+            }
+        };
+        if prefix & 0b11 == 0b11 {
+            input.read(&mut buf[..len])?;
+        } else {
+            buf[0] = prefix;
+            input.read(&mut buf[1..len])?;
+            let low = u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) >> 2;
+            buf[..4].copy_from_slice(&low.to_le_bytes());
+        }
+        let bytes = &buf[..len];
+        let width = match bytes.iter().rposition(|&byte| byte != 0) {
+            Some(i) => i as u32 * 8 + u8::BITS - bytes[i].leading_zeros(),
+            None => 0,
+        };
+        if width < min_width {
+            return Err("(bnum) compact encoding is not canonical".into());
+        }
+        match Uint::from_le_slice(bytes) {
+            Some(value) => Ok(Self(value)),
+            None => Err("(bnum) integer out of range for its bit width".into()),
+        }
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> DecodeWithMemTracking for Compact<Uint<N, B, OM>> {}
+
+impl<const N: usize, const B: usize, const OM: u8> MaxEncodedLen for Compact<Uint<N, B, OM>> {
+    #[inline]
+    fn max_encoded_len() -> usize {
+        const { Self::ASSERT_FITS };
+        Self::encoded_len(Uint::<N, B, OM>::BITS)
+    }
+}
+
+impl<const N: usize, const B: usize, const OM: u8> TypeInfo for Compact<Uint<N, B, OM>> {
+    type Identity = Self;
+
+    /// The compact type information of the primitive unsigned integer with the same bit width. Causes a compile-time error if there is no such primitive.
+    fn type_info() -> Type {
+        const { Self::ASSERT_PRIMITIVE };
+        TypeDefCompact::new(MetaType::new::<Uint<N, B, OM>>()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Compact;
+    use crate::cast::As;
+    use crate::types::{U128, U256, U512};
+    use crate::Uint;
+    use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+    fn encode<T: Encode>(value: T) -> ([u8; 70], usize) {
+        let mut out = [0; 70];
+        let len = value.using_encoded(|bytes| {
+            out[..bytes.len()].copy_from_slice(bytes);
+            bytes.len()
+        });
+        (out, len)
+    }
+
+    #[test]
+    fn encode_matches_primitives() {
+        for value in [0u128, 1, 63, 64, 16383, 16384, (1 << 30) - 1, 1 << 30, u32::MAX as u128, 1 << 32, u64::MAX as u128, u128::MAX] {
+            let (bytes, len) = encode(Compact(value.as_::<U256>()));
+            let expected = parity_scale_codec::Compact(value).encode();
+            assert_eq!(bytes[..len], expected[..]);
+            assert_eq!(Compact(value.as_::<U256>()).size_hint(), len);
+        }
+    }
+
+    #[test]
+    fn encode_wide() {
+        let (bytes, len) = encode(Compact(U256::MAX));
+        assert_eq!(len, 33);
+        assert_eq!(bytes[0], ((32 - 4) << 2) | 0b11);
+        assert_eq!(bytes[1..33], [0xFF; 32]);
+
+        let (bytes, len) = encode(Compact(Uint::<67>::MAX));
+        assert_eq!(len, 68);
+        assert_eq!(bytes[0], 0xFF);
+        assert_eq!(Compact::<Uint<67>>::max_encoded_len(), 68);
+        assert_eq!(Compact::<U256>::max_encoded_len(), 33);
+        assert_eq!(Compact::<Uint<1>>::max_encoded_len(), 2);
+        assert_eq!(Compact::<Uint<1, 6>>::max_encoded_len(), 1);
+    }
+
+    #[test]
+    fn decode_non_canonical() {
+        assert!(Compact::<U256>::decode(&mut &[0b01, 0x00][..]).is_err());
+        assert!(Compact::<U256>::decode(&mut &[0b10, 0x01, 0x00, 0x00][..]).is_err());
+        assert!(Compact::<U256>::decode(&mut &[0b11, 0xFF, 0xFF, 0xFF, 0x3F][..]).is_err());
+        assert!(Compact::<U256>::decode(&mut &[0b111, 0x00, 0x00, 0x00, 0x40, 0x00][..]).is_err());
+        assert_eq!(Compact::<U256>::decode(&mut &[0b11, 0x00, 0x00, 0x00, 0x40][..]), Ok(Compact((1u32 << 30).as_())));
+    }
+
+    #[test]
+    fn decode_out_of_range() {
+        let mut bytes = [0xFF; 34];
+        bytes[0] = ((33 - 4) << 2) | 0b11;
+        assert!(Compact::<U256>::decode(&mut &bytes[..]).is_err());
+        assert_eq!(Compact::<U512>::decode(&mut &bytes[..]).map(|c| c.0.bit_width()), Ok(264));
+        assert_eq!(Compact::<Uint<1, 6>>::decode(&mut &[0xFC][..]), Ok(Compact(Uint::<1, 6>::MAX)));
+        assert!(Compact::<Uint<1, 6>>::decode(&mut &[0x01, 0x01][..]).is_err());
+    }
+
+    #[test]
+    fn type_info() {
+        use scale_info::{TypeDef, TypeDefPrimitive, TypeInfo};
+
+        let TypeDef::Compact(compact) = Compact::<U256>::type_info().type_def else {
+            panic!("expected compact type info");
+        };
+        assert_eq!(compact.type_param.type_info().type_def, TypeDef::Primitive(TypeDefPrimitive::U256));
+        let TypeDef::Compact(compact) = Compact::<Uint<2>>::type_info().type_def else {
+            panic!("expected compact type info");
+        };
+        assert_eq!(compact.type_param.type_info().type_def, TypeDef::Primitive(TypeDefPrimitive::U16));
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_roundtrip(a: U256) -> bool {
+            let (bytes, len) = encode(Compact(a));
+            Compact::<U256>::decode(&mut &bytes[..len]) == Ok(Compact(a))
+        }
+
+        fn quickcheck_matches_primitive(a: u128) -> bool {
+            let (bytes, len) = encode(Compact(a.as_::<U128>()));
+            parity_scale_codec::Compact(a).encode()[..] == bytes[..len]
+        }
+    }
+}
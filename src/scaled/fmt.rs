@@ -0,0 +1,84 @@
+use super::{pow10, ScaledDecimal};
+use crate::fixed_point::wide::Wide;
+use crate::RoundingMode;
+use core::fmt::{self, Debug, Display, Formatter};
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Display for ScaledDecimal<N, SCALE, OM> {
+    /// Formats `self` in decimal. By default, all `SCALE` decimal places are written, so that e.g. `1.5` with a `SCALE` of `2` is written as `1.50`. If a precision is specified, exactly that many digits are written after the decimal point, with the value rounded to nearest with ties to even if the precision is less than `SCALE`.
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let scale = SCALE as usize;
+        let places = f.precision().unwrap_or(scale);
+        let mut magnitude = self.magnitude();
+        if places < scale {
+            magnitude = Wide::from_lo(magnitude)
+                .div_round(pow10((scale - places) as u32), false, RoundingMode::NearestTiesEven)
+                .lo;
+        }
+        let digits = magnitude.to_str_radix(10);
+        let (integer, fraction) = digits.split_at(digits.len().saturating_sub(places.min(scale)));
+        let mut s = alloc::string::String::with_capacity(places + integer.len() + 2);
+        s.push_str(if integer.is_empty() { "0" } else { integer });
+        if places > 0 {
+            s.push('.');
+            s.extend(core::iter::repeat_n('0', places.min(scale) - fraction.len()));
+            s.push_str(fraction);
+            s.extend(core::iter::repeat_n('0', places.saturating_sub(scale)));
+        }
+        f.pad_integral(!self.is_negative(), "", &s)
+    }
+}
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Debug for ScaledDecimal<N, SCALE, OM> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::ScaledDecimal;
+    use alloc::string::ToString;
+
+    type D2 = ScaledDecimal<4, 2>;
+    type D0 = ScaledDecimal<1, 0>;
+
+    fn d2(unscaled: i32) -> D2 {
+        D2::from_unscaled(unscaled.as_())
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(d2(0).to_string(), "0.00");
+        assert_eq!(d2(150).to_string(), "1.50");
+        assert_eq!(d2(-5).to_string(), "-0.05");
+        assert_eq!(D2::MIN.to_string(), "-21474836.48");
+        assert_eq!(D2::MAX.to_string(), "21474836.47");
+        assert_eq!(D0::MIN.to_string(), "-128");
+        assert_eq!(ScaledDecimal::<4, 9>::ONE.to_string(), "1.000000000");
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(format!("{:.4}", d2(150)), "1.5000");
+        assert_eq!(format!("{:.1}", d2(125)), "1.2");
+        assert_eq!(format!("{:.1}", d2(135)), "1.4");
+        assert_eq!(format!("{:.0}", d2(-250)), "-2");
+        assert_eq!(format!("{:.0}", d2(-251)), "-3");
+        assert_eq!(format!("{:.1}", d2(-4)), "-0.0");
+        assert_eq!(format!("{:.0}", d2(99)), "1");
+        assert_eq!(format!("{:.2}", D0::MAX), "127.00");
+        assert_eq!(format!("{:>8}|{:<+6}|{:08}|{:?}", d2(150), d2(100), d2(-150), d2(50)), "    1.50|+1.00 |-0001.50|0.50");
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: D2) -> bool {
+            a.to_string().parse::<D2>() == Ok(a)
+        }
+
+        fn quickcheck_round_trip_wide(a: ScaledDecimal<32, 30>) -> bool {
+            a.to_string().parse::<ScaledDecimal<32, 30>>() == Ok(a)
+        }
+    }
+}
@@ -0,0 +1,378 @@
+use crate::fixed_point::wide::{small, Mag, Wide};
+use crate::{doc, Exponent, Int, OverflowMode, RoundingMode};
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+mod fmt;
+mod ops;
+mod parse;
+
+/// Signed decimal number type with a fixed number of decimal places, const-generic bit width and overflow behaviour.
+///
+/// A `ScaledDecimal<N, SCALE, OM>` is stored as an [`Int<N, 0, OM>`](crate::Int) `unscaled`, and represents the value `unscaled * 10^(-SCALE)`. So for example, a `ScaledDecimal<32, 18>` is a 256-bit number with 18 decimal places. Since values are stored in decimal, decimal fractions such as `0.1` are represented exactly, and parsing and formatting never introduce binary rounding errors. `10^SCALE` must be representable by a [`Uint<N>`](crate::Uint).
+///
+/// Addition and subtraction are exact (up to overflow). Multiplication and division are computed exactly on twice as many bits as the type has, and then correctly rounded to `SCALE` decimal places, by default to the nearest representable value with ties rounding to even. The `*_round` methods allow other rounding modes to be used.
+///
+/// As with [`Integer`](crate::Integer), the behaviour of arithmetic operations on overflow is determined by [`Self::OVERFLOW_MODE`]: results either wrap around, cause a panic, or saturate to [`MIN`](Self::MIN) or [`MAX`](Self::MAX). The `checked_*`, `wrapping_*`, `saturating_*` and `overflowing_*` methods are available regardless of the overflow mode.
+///
+/// # Examples
+///
+/// ```
+/// use bnum::ScaledDecimal;
+/// use bnum::RoundingMode;
+/// use bnum::prelude::*;
+///
+/// // 256 bits, 18 decimal places
+/// type D = ScaledDecimal<32, 18>;
+///
+/// let price: D = "19.99".parse().unwrap();
+/// let rate: D = "0.075".parse().unwrap();
+/// let tax = price * rate;
+/// assert_eq!(tax.to_string(), "1.499250000000000000");
+///
+/// // round to cents
+/// let tax = tax.rescale::<2>();
+/// assert_eq!(tax.to_string(), "1.50");
+/// assert_eq!(tax.rescale_round::<1>(RoundingMode::TowardZero).to_string(), "1.5");
+///
+/// let third = D::ONE / D::from_int(n!(3));
+/// assert_eq!(third.to_string(), "0.333333333333333333");
+/// ```
+#[derive(Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ScaledDecimal<const N: usize, const SCALE: u32, const OM: u8 = { OverflowMode::DEFAULT as u8 }> {
+    unscaled: Int<N, 0, OM>,
+}
+
+/// `10^exp`, which is assumed not to overflow
+#[inline]
+const fn pow10<const N: usize>(exp: Exponent) -> Mag<N> {
+    small::<N>(10).wrapping_pow(exp)
+}
+
+/// Associated constants.
+impl<const N: usize, const SCALE: u32, const OM: u8> ScaledDecimal<N, SCALE, OM> {
+    /// `10^SCALE`
+    const POW: Mag<N> = match small::<N>(10).checked_pow(SCALE) {
+        Some(pow) => pow,
+        None => panic!("`10^SCALE` must be representable by the underlying unsigned integer type of a `ScaledDecimal`"),
+    };
+
+    /// The overflow mode used for this type, which is the overflow mode of the underlying [`Int`](crate::Int) type.
+    pub const OVERFLOW_MODE: OverflowMode = Int::<N, 0, OM>::OVERFLOW_MODE;
+
+    /// The total number of bits of the type.
+    pub const BITS: Exponent = Int::<N, 0, OM>::BITS;
+
+    pub const ZERO: Self = Self::from_unscaled(Int::ZERO);
+
+    /// The smallest positive value, `10^(-SCALE)`.
+    pub const DELTA: Self = Self::from_unscaled(Int::ONE);
+
+    /// The value one.
+    ///
+    /// Evaluating this constant causes a compile-time error if one is not representable by the type, i.e. if `10^SCALE` is larger than [`Int::MAX`](crate::Integer::MAX).
+    pub const ONE: Self = {
+        assert!(
+            Self::POW.bit_width() < Self::BITS,
+            "one is not representable by this `ScaledDecimal` type"
+        );
+        Self::from_unscaled(Self::POW.force())
+    };
+
+    pub const MIN: Self = Self::from_unscaled(Int::MIN);
+
+    pub const MAX: Self = Self::from_unscaled(Int::MAX);
+}
+
+impl<const N: usize, const SCALE: u32, const OM: u8> ScaledDecimal<N, SCALE, OM> {
+    /// Creates a scaled decimal from its unscaled value, so that the value is `unscaled * 10^(-SCALE)`.
+    #[must_use]
+    #[inline(always)]
+    pub const fn from_unscaled(unscaled: Int<N, 0, OM>) -> Self {
+        let _ = Self::POW;
+        Self { unscaled }
+    }
+
+    /// Returns the unscaled value of `self`, so that the value of `self` is `unscaled * 10^(-SCALE)`.
+    #[must_use = doc::must_use_op!()]
+    #[inline(always)]
+    pub const fn to_unscaled(self) -> Int<N, 0, OM> {
+        self.unscaled
+    }
+
+    /// Converts an integer to a scaled decimal, returning `None` if the integer is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_from_int(int: Int<N, 0, OM>) -> Option<Self> {
+        match Self::overflowing_from_int(int) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+
+    /// Converts an integer to a scaled decimal, returning the result wrapped around if the integer is not representable, together with whether this occured.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn overflowing_from_int(int: Int<N, 0, OM>) -> (Self, bool) {
+        let magnitude = int.unsigned_abs_internal().force();
+        Self::from_magnitude(int.is_negative_internal(), Wide::mul(magnitude, Self::POW))
+    }
+
+    /// Converts an integer to a scaled decimal.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// If the integer is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn from_int(int: Int<N, 0, OM>) -> Self {
+        Self::resolve_overflow(
+            Self::overflowing_from_int(int),
+            int.is_negative_internal(),
+            crate::errors::err_msg!("attempt to convert integer to scaled decimal with overflow"),
+        )
+    }
+
+    /// Rounds `self` to an integer according to the given rounding mode.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_int_round(self, rounding: RoundingMode) -> Int<N, 0, OM> {
+        let negative = self.is_negative();
+        // the magnitude of the result is at most the magnitude of `self`, so always fits
+        let magnitude = Wide::from_lo(self.magnitude())
+            .div_round(Self::POW, negative, rounding)
+            .lo;
+        if negative {
+            magnitude.wrapping_neg().force()
+        } else {
+            magnitude.force()
+        }
+    }
+
+    /// Returns the integer part of `self`, rounding towards negative infinity.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn to_int(self) -> Int<N, 0, OM> {
+        self.to_int_round(RoundingMode::TowardNegative)
+    }
+
+    /// Converts `self` to a scaled decimal with `NEW_SCALE` decimal places, rounding according to the given rounding mode, and returning the wrapped result together with whether the result is not representable. If `NEW_SCALE` is at least `SCALE`, the conversion is exact and can only overflow.
+    #[must_use = doc::must_use_op!()]
+    pub const fn overflowing_rescale_round<const NEW_SCALE: u32>(
+        self,
+        rounding: RoundingMode,
+    ) -> (ScaledDecimal<N, NEW_SCALE, OM>, bool) {
+        let negative = self.is_negative();
+        let magnitude = if NEW_SCALE >= SCALE {
+            Wide::mul(self.magnitude(), pow10(NEW_SCALE - SCALE))
+        } else {
+            Wide::from_lo(self.magnitude()).div_round(pow10(SCALE - NEW_SCALE), negative, rounding)
+        };
+        ScaledDecimal::from_magnitude(negative, magnitude)
+    }
+
+    /// Converts `self` to a scaled decimal with `NEW_SCALE` decimal places, rounding according to the given rounding mode.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// If the result is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn rescale_round<const NEW_SCALE: u32>(self, rounding: RoundingMode) -> ScaledDecimal<N, NEW_SCALE, OM> {
+        ScaledDecimal::resolve_overflow(
+            self.overflowing_rescale_round(rounding),
+            self.is_negative(),
+            crate::errors::err_msg!("attempt to rescale with overflow"),
+        )
+    }
+
+    /// Converts `self` to a scaled decimal with `NEW_SCALE` decimal places, rounding to the nearest representable value, with ties rounding to even.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// If the result is not representable, the result depends on [`Self::OVERFLOW_MODE`]: it either wraps around, causes a panic, or saturates.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn rescale<const NEW_SCALE: u32>(self) -> ScaledDecimal<N, NEW_SCALE, OM> {
+        self.rescale_round(RoundingMode::DEFAULT)
+    }
+
+    /// Converts `self` to a scaled decimal with `NEW_SCALE` decimal places, rounding to the nearest representable value with ties rounding to even, and returning `None` if the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_rescale<const NEW_SCALE: u32>(self) -> Option<ScaledDecimal<N, NEW_SCALE, OM>> {
+        match self.overflowing_rescale_round(RoundingMode::DEFAULT) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(self) -> bool {
+        self.unscaled.is_zero()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_negative(self) -> bool {
+        self.unscaled.is_negative_internal()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn is_positive(self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    #[must_use]
+    #[inline]
+    pub const fn cmp(&self, other: &Self) -> Ordering {
+        self.unscaled.cmp(&other.unscaled)
+    }
+
+    /// the magnitude of `self`
+    #[inline]
+    const fn magnitude(self) -> Mag<N> {
+        self.unscaled.unsigned_abs_internal().force()
+    }
+
+    /// the value with the given sign and magnitude, wrapped to the width of the type, together with whether it is out of range
+    const fn from_magnitude(negative: bool, magnitude: Wide<N>) -> (Self, bool) {
+        let lo = if negative {
+            magnitude.lo.wrapping_neg()
+        } else {
+            magnitude.lo
+        };
+        let out = Self::from_unscaled(lo.force());
+        let overflow = if !magnitude.hi.is_zero() {
+            true
+        } else {
+            let sign_bit = Mag::<N>::ONE.unbounded_shl(Self::BITS - 1);
+            match magnitude.lo.cmp(&sign_bit) {
+                Ordering::Less => false,
+                Ordering::Equal => !negative,
+                Ordering::Greater => true,
+            }
+        };
+        (out, overflow)
+    }
+
+    /// the result of an operation according to the overflow mode, given the wrapped result, whether overflow occurred, and the sign of the exact result
+    #[inline]
+    const fn resolve_overflow((out, overflow): (Self, bool), negative: bool, message: &'static str) -> Self {
+        if !overflow {
+            return out;
+        }
+        match Self::OVERFLOW_MODE {
+            OverflowMode::Wrap => out,
+            OverflowMode::Panic => panic!("{}", message),
+            OverflowMode::Saturate => Self::saturate((out, overflow), negative),
+        }
+    }
+
+    /// the saturated result of an operation, given the wrapped result, whether overflow occurred, and the sign of the exact result
+    #[inline]
+    const fn saturate((out, overflow): (Self, bool), negative: bool) -> Self {
+        if !overflow {
+            out
+        } else if negative {
+            Self::MIN
+        } else {
+            Self::MAX
+        }
+    }
+}
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Default for ScaledDecimal<N, SCALE, OM> {
+    #[doc = doc::default!()]
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+#[cfg(any(test, feature = "quickcheck"))]
+impl<const N: usize, const SCALE: u32, const OM: u8> quickcheck::Arbitrary for ScaledDecimal<N, SCALE, OM> {
+    #[inline]
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::from_unscaled(Int::arbitrary(g))
+    }
+}
+
+// implementation if we don't have alloc, as otherwise can't call assert_eq! (since this requires Debug)
+#[cfg(not(feature = "alloc"))]
+impl<const N: usize, const SCALE: u32, const OM: u8> core::fmt::Debug for ScaledDecimal<N, SCALE, OM> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("ScaledDecimal")
+            .field("unscaled", &self.unscaled)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::{RoundingMode, ScaledDecimal};
+
+    type D2 = ScaledDecimal<4, 2>;
+    type D2s = ScaledDecimal<4, 2, 2>;
+    type D9 = ScaledDecimal<4, 9>;
+
+    fn d2(unscaled: i32) -> D2 {
+        D2::from_unscaled(unscaled.as_())
+    }
+
+    #[test]
+    fn from_int() {
+        assert_eq!(D2::from_int(3.as_()), d2(300));
+        assert_eq!(D2::from_int((-3).as_()), d2(-300));
+        assert_eq!(D2::checked_from_int(21474836.as_()), Some(d2(2147483600)));
+        assert_eq!(D2::checked_from_int(21474837.as_()), None);
+        assert_eq!(D2::checked_from_int((-21474836).as_()), Some(d2(-2147483600)));
+        assert_eq!(D2s::from_int(100000000.as_()), D2s::MAX);
+        assert_eq!(D2s::from_int((-100000000).as_()), D2s::MIN);
+        assert_eq!(D9::checked_from_int(3.as_()), None);
+        assert_eq!(D9::ONE.to_unscaled(), 1000000000.as_());
+    }
+
+    #[test]
+    fn to_int() {
+        assert_eq!(d2(250).to_int(), 2.as_());
+        assert_eq!(d2(-250).to_int(), (-3).as_());
+        assert_eq!(d2(250).to_int_round(RoundingMode::NearestTiesEven), 2.as_());
+        assert_eq!(d2(350).to_int_round(RoundingMode::NearestTiesEven), 4.as_());
+        assert_eq!(d2(-250).to_int_round(RoundingMode::NearestTiesAway), (-3).as_());
+        assert_eq!(d2(-299).to_int_round(RoundingMode::TowardZero), (-2).as_());
+        assert_eq!(D2::MIN.to_int_round(RoundingMode::TowardNegative), (-21474837).as_());
+    }
+
+    #[test]
+    fn rescale() {
+        assert_eq!(d2(125).rescale::<1>(), ScaledDecimal::<4, 1>::from_unscaled(12.as_()));
+        assert_eq!(d2(135).rescale::<1>(), ScaledDecimal::<4, 1>::from_unscaled(14.as_()));
+        assert_eq!(d2(-125).rescale_round::<1>(RoundingMode::TowardNegative), ScaledDecimal::<4, 1>::from_unscaled((-13).as_()));
+        assert_eq!(d2(-125).rescale::<4>(), ScaledDecimal::<4, 4>::from_unscaled((-12500).as_()));
+        assert_eq!(d2(-125).rescale::<2>(), d2(-125));
+        assert_eq!(D2::MAX.checked_rescale::<3>(), None);
+        assert_eq!(D2::MAX.rescale::<0>(), ScaledDecimal::<4, 0>::from_unscaled(21474836.as_()));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to rescale with overflow")]
+    fn rescale_panics() {
+        let _ = ScaledDecimal::<4, 2, 1>::MAX.rescale::<3>();
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_rescale_round_trip(a: i16) -> bool {
+            let a = D2::from_unscaled(a.as_());
+            a.rescale::<5>().rescale::<2>() == a
+        }
+
+        fn quickcheck_int_round_trip(a: i16) -> bool {
+            D2::from_int(a.as_()).to_int() == a.as_()
+        }
+    }
+}
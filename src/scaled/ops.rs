@@ -0,0 +1,266 @@
+use super::ScaledDecimal;
+use crate::doc;
+use crate::fixed_point::wide::Wide;
+use crate::fixed_point::{exact_op, rounded_op};
+use crate::helpers::full_op_impl;
+use crate::RoundingMode;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+impl<const N: usize, const SCALE: u32, const OM: u8> ScaledDecimal<N, SCALE, OM> {
+    exact_op!(unscaled, from_unscaled; add, checked_add, wrapping_add, saturating_add, overflowing_add, "+");
+    exact_op!(unscaled, from_unscaled; sub, checked_sub, wrapping_sub, saturating_sub, overflowing_sub, "-");
+    rounded_op!(mul, mul_round, wrapping_mul, saturating_mul, overflowing_mul, overflowing_mul_round, "*", "attempt to multiply with overflow");
+    rounded_op!(div, div_round, wrapping_div, saturating_div, overflowing_div, overflowing_div_round, "/", "attempt to divide with overflow");
+
+    /// Computes `self * rhs`, rounding to nearest with ties to even, and returning `None` if the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        match self.overflowing_mul(rhs) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+
+    /// Computes `self / rhs`, rounding to nearest with ties to even, and returning `None` if `rhs` is zero or the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.is_zero() {
+            return None;
+        }
+        match self.overflowing_div(rhs) {
+            (out, false) => Some(out),
+            (_, true) => None,
+        }
+    }
+
+    /// Computes `self * rhs`, rounding the result to `SCALE` decimal places according to the given rounding mode, and returning the wrapped result together with whether the result is not representable.
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn overflowing_mul_round(self, rhs: Self, rounding: RoundingMode) -> (Self, bool) {
+        let negative = self.is_negative() != rhs.is_negative();
+        let product = Wide::mul(self.magnitude(), rhs.magnitude()).div_round(Self::POW, negative, rounding);
+        Self::from_magnitude(negative, product)
+    }
+
+    /// Computes `self / rhs`, rounding the result to `SCALE` decimal places according to the given rounding mode, and returning the wrapped result together with whether the result is not representable.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `rhs` is zero.
+    #[must_use = doc::must_use_op!()]
+    pub const fn overflowing_div_round(self, rhs: Self, rounding: RoundingMode) -> (Self, bool) {
+        if rhs.is_zero() {
+            panic!(crate::errors::err_msg!(crate::errors::div_by_zero_message!()));
+        }
+        let negative = self.is_negative() != rhs.is_negative();
+        let quotient = Wide::mul(self.magnitude(), Self::POW).div_round(rhs.magnitude(), negative, rounding);
+        Self::from_magnitude(negative, quotient)
+    }
+
+    /// Computes `-self`.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// Overflow occurs only if `self` is [`MIN`](Self::MIN), and the result is the same as for the underlying [`Int`](crate::Int) type, so depends on [`Self::OVERFLOW_MODE`].
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn neg(self) -> Self {
+        Self::from_unscaled(self.unscaled.neg())
+    }
+
+    /// Computes `-self`, returning `None` if `self` is [`MIN`](Self::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.unscaled.checked_neg() {
+            Some(unscaled) => Some(Self::from_unscaled(unscaled)),
+            None => None,
+        }
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Overflow behaviour
+    ///
+    /// Overflow occurs only if `self` is [`MIN`](Self::MIN), and the result is the same as for the underlying [`Int`](crate::Int) type, so depends on [`Self::OVERFLOW_MODE`].
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self::from_unscaled(self.unscaled.abs())
+    }
+
+    /// Computes the absolute value of `self`, returning `None` if `self` is [`MIN`](Self::MIN).
+    #[must_use = doc::must_use_op!()]
+    #[inline]
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.unscaled.checked_abs() {
+            Some(unscaled) => Some(Self::from_unscaled(unscaled)),
+            None => None,
+        }
+    }
+}
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Add for ScaledDecimal<N, SCALE, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::add(self, rhs)
+    }
+}
+
+full_op_impl!(<const N: usize, const SCALE: u32, const OM: u8> Add, AddAssign, ScaledDecimal<N, SCALE, OM>, add, add_assign for ScaledDecimal<N, SCALE, OM>);
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Sub for ScaledDecimal<N, SCALE, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::sub(self, rhs)
+    }
+}
+
+full_op_impl!(<const N: usize, const SCALE: u32, const OM: u8> Sub, SubAssign, ScaledDecimal<N, SCALE, OM>, sub, sub_assign for ScaledDecimal<N, SCALE, OM>);
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Mul for ScaledDecimal<N, SCALE, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Self::mul(self, rhs)
+    }
+}
+
+full_op_impl!(<const N: usize, const SCALE: u32, const OM: u8> Mul, MulAssign, ScaledDecimal<N, SCALE, OM>, mul, mul_assign for ScaledDecimal<N, SCALE, OM>);
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Div for ScaledDecimal<N, SCALE, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        Self::div(self, rhs)
+    }
+}
+
+full_op_impl!(<const N: usize, const SCALE: u32, const OM: u8> Div, DivAssign, ScaledDecimal<N, SCALE, OM>, div, div_assign for ScaledDecimal<N, SCALE, OM>);
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Neg for ScaledDecimal<N, SCALE, OM> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::neg(self)
+    }
+}
+
+impl<const N: usize, const SCALE: u32, const OM: u8> Neg for &ScaledDecimal<N, SCALE, OM> {
+    type Output = ScaledDecimal<N, SCALE, OM>;
+
+    #[inline]
+    fn neg(self) -> ScaledDecimal<N, SCALE, OM> {
+        (*self).neg()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::{RoundingMode, ScaledDecimal};
+
+    type D2 = ScaledDecimal<8, 2>;
+    type D2w = ScaledDecimal<8, 2, 0>;
+    type D2s = ScaledDecimal<8, 2, 2>;
+
+    fn d2(unscaled: i64) -> D2 {
+        D2::from_unscaled(unscaled.as_())
+    }
+
+    /// `a * b / 100`, rounded to nearest with ties to even, computed with primitive arithmetic
+    fn mul_reference(a: i64, b: i64) -> i128 {
+        div_reference(a as i128 * b as i128, 100)
+    }
+
+    /// `a / b`, rounded to nearest with ties to even, computed with primitive arithmetic
+    fn div_reference(a: i128, b: i128) -> i128 {
+        let (dividend, divisor) = (a.unsigned_abs(), b.unsigned_abs());
+        let (quotient, remainder) = (dividend / divisor, dividend % divisor);
+        let quotient = if 2 * remainder > divisor || (2 * remainder == divisor && quotient & 1 == 1) {
+            quotient + 1
+        } else {
+            quotient
+        };
+        if (a < 0) != (b < 0) {
+            -(quotient as i128)
+        } else {
+            quotient as i128
+        }
+    }
+
+    #[test]
+    fn add_sub() {
+        assert_eq!(d2(10) + d2(20), d2(30));
+        assert_eq!(d2(10) - d2(-20), d2(30));
+        assert_eq!(D2::MAX.checked_add(D2::DELTA), None);
+        assert_eq!(D2s::MAX + D2s::DELTA, D2s::MAX);
+        assert_eq!(D2w::MIN.wrapping_sub(D2w::DELTA), D2w::MAX);
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(d2(150) * d2(250), d2(375));
+        // 0.15 * 0.15 = 0.0225
+        assert_eq!(d2(15) * d2(15), d2(2));
+        // 0.25 * 0.1 = 0.025
+        assert_eq!(d2(25) * d2(10), d2(2));
+        assert_eq!(d2(-25) * d2(10), d2(-2));
+        assert_eq!(d2(35) * d2(10), d2(4));
+        assert_eq!(d2(25).mul_round(d2(10), RoundingMode::NearestTiesAway), d2(3));
+        assert_eq!(d2(-25).mul_round(d2(10), RoundingMode::TowardPositive), d2(-2));
+        assert_eq!(d2(-25).mul_round(d2(10), RoundingMode::TowardNegative), d2(-3));
+        assert_eq!(D2::MAX.checked_mul(d2(200)), None);
+        assert_eq!(D2s::MIN * D2s::from_unscaled(200.as_()), D2s::MIN);
+    }
+
+    #[test]
+    fn div() {
+        assert_eq!(d2(100) / d2(300), d2(33));
+        assert_eq!(d2(200) / d2(300), d2(67));
+        assert_eq!(d2(-100) / d2(800), d2(-12));
+        assert_eq!(d2(100).div_round(d2(300), RoundingMode::TowardPositive), d2(34));
+        assert_eq!(D2::MAX.checked_div(d2(50)), None);
+        assert_eq!(d2(1).checked_div(D2::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to divide by zero")]
+    fn div_by_zero() {
+        let _ = d2(1) / D2::ZERO;
+    }
+
+    #[test]
+    fn neg_abs() {
+        assert_eq!(-d2(150), d2(-150));
+        assert_eq!(d2(-150).abs(), d2(150));
+        assert_eq!(D2::MIN.checked_neg(), None);
+        assert_eq!(D2::MIN.checked_abs(), None);
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_mul(a: i64, b: i64) -> bool {
+            let expected = mul_reference(a, b);
+            let (out, overflow) = d2(a).overflowing_mul(d2(b));
+            overflow == i64::try_from(expected).is_err() && out == d2(expected as i64)
+        }
+
+        fn quickcheck_div(a: i64, b: i64) -> quickcheck::TestResult {
+            if b == 0 {
+                return quickcheck::TestResult::discard();
+            }
+            let expected = div_reference(a as i128 * 100, b as i128);
+            let (out, overflow) = d2(a).overflowing_div(d2(b));
+            quickcheck::TestResult::from_bool(overflow == i64::try_from(expected).is_err() && out == d2(expected as i64))
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use super::ScaledDecimal;
+use crate::errors::{FixedErrorKind, ParseFixedError};
+use crate::fixed_point::wide::{small, Mag, Wide};
+use crate::RoundingMode;
+use core::cmp::Ordering;
+use core::str::FromStr;
+
+/// splits off the leading ASCII digits of `s`
+#[inline]
+fn split_digits(s: &[u8]) -> (&[u8], &[u8]) {
+    let len = s.iter().take_while(|byte| byte.is_ascii_digit()).count();
+    s.split_at(len)
+}
+
+impl<const N: usize, const SCALE: u32, const OM: u8> FromStr for ScaledDecimal<N, SCALE, OM> {
+    type Err = ParseFixedError;
+
+    /// Parses a decimal number, rounding to `SCALE` decimal places (with ties rounding to even).
+    ///
+    /// The accepted grammar is an optional sign, followed by decimal digits with an optional fractional part, such as `12`, `-1.50` or `.5`. An error is returned if the rounded value is out of range for the type.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        let s = src.as_bytes();
+        let (negative, s) = match s.first() {
+            None => {
+                return Err(ParseFixedError {
+                    kind: FixedErrorKind::Empty,
+                });
+            }
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            Some(_) => (false, s),
+        };
+        let (integer, s) = split_digits(s);
+        let (fraction, s) = match s.split_first() {
+            Some((b'.', s)) => split_digits(s),
+            _ => (&s[..0], s),
+        };
+        if (integer.is_empty() && fraction.is_empty()) || !s.is_empty() {
+            return Err(ParseFixedError {
+                kind: FixedErrorKind::Invalid,
+            });
+        }
+        const OVERFLOW: ParseFixedError = ParseFixedError {
+            kind: FixedErrorKind::Overflow,
+        };
+
+        let scale = SCALE as usize;
+        let (kept, rest) = fraction.split_at(fraction.len().min(scale));
+        let padding = core::iter::repeat_n(&b'0', scale - kept.len());
+        let mut unscaled = Mag::<N>::ZERO;
+        for digit in integer.iter().chain(kept).chain(padding) {
+            let (lo, carry) = unscaled.carrying_mul(small(10), small(digit - b'0'));
+            if !carry.is_zero() {
+                return Err(OVERFLOW);
+            }
+            unscaled = lo;
+        }
+
+        // the discarded digits are compared with half a unit in the last place by looking at the first discarded digit, and then whether any of the remaining discarded digits are non-zero
+        let sticky = rest.len() > 1 && rest[1..].iter().any(|digit| *digit != b'0');
+        let half_cmp = match rest.first() {
+            None => Ordering::Less,
+            Some(digit) => match digit.cmp(&b'5') {
+                Ordering::Equal if sticky => Ordering::Greater,
+                ordering => ordering,
+            },
+        };
+        let inexact = rest.iter().any(|digit| *digit != b'0');
+        let mut magnitude = Wide::from_lo(unscaled);
+        if RoundingMode::NearestTiesEven.round_up(negative, half_cmp, inexact, unscaled.bit(0)) {
+            magnitude = magnitude.add(Wide::from_lo(Mag::ONE));
+        }
+        match Self::from_magnitude(negative, magnitude) {
+            (out, false) => Ok(out),
+            (_, true) => Err(OVERFLOW),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cast::As;
+    use crate::errors::{FixedErrorKind, ParseFixedError};
+    use crate::ScaledDecimal;
+    use core::str::FromStr;
+
+    type D2 = ScaledDecimal<4, 2>;
+    type D0 = ScaledDecimal<1, 0>;
+
+    fn d2(unscaled: i32) -> D2 {
+        D2::from_unscaled(unscaled.as_())
+    }
+
+    fn err<T>(kind: FixedErrorKind) -> Result<T, ParseFixedError> {
+        Err(ParseFixedError { kind })
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(D2::from_str(""), err(FixedErrorKind::Empty));
+        for s in ["-", ".", "+.", "1.2.3", "--1", "1 ", "1e5", "0x10", "1_000"] {
+            assert_eq!(D2::from_str(s), err(FixedErrorKind::Invalid), "{s}");
+        }
+        for s in ["21474837", "21474836.48", "-21474836.4851", "1000000000000"] {
+            assert_eq!(D2::from_str(s), err(FixedErrorKind::Overflow), "{s}");
+        }
+        assert_eq!(D0::from_str("127.5"), err(FixedErrorKind::Overflow));
+    }
+
+    #[test]
+    fn exact() {
+        assert_eq!(D2::from_str("1.5").unwrap(), d2(150));
+        assert_eq!(D2::from_str("-.25").unwrap(), d2(-25));
+        assert_eq!(D2::from_str("+0003.").unwrap(), d2(300));
+        assert_eq!(D2::from_str("-21474836.48").unwrap(), D2::MIN);
+        assert_eq!(D2::from_str("21474836.47").unwrap(), D2::MAX);
+        assert_eq!(D2::from_str("0.010000").unwrap(), D2::DELTA);
+        assert_eq!(D2::from_str("-0").unwrap(), D2::ZERO);
+        assert_eq!(D0::from_str("-128").unwrap(), D0::MIN);
+    }
+
+    #[test]
+    fn rounding() {
+        assert_eq!(D2::from_str("0.005").unwrap(), d2(0));
+        assert_eq!(D2::from_str("0.015").unwrap(), d2(2));
+        assert_eq!(D2::from_str("0.0050000000000000000000001").unwrap(), d2(1));
+        assert_eq!(D2::from_str("-0.0250").unwrap(), d2(-2));
+        assert_eq!(D2::from_str("-0.0251").unwrap(), d2(-3));
+        assert_eq!(D2::from_str("0.00499999999999").unwrap(), d2(0));
+        assert_eq!(D2::from_str("21474836.474").unwrap(), D2::MAX);
+        assert_eq!(D0::from_str("-128.5").unwrap(), D0::MIN);
+        assert_eq!(D0::from_str("126.5").unwrap(), D0::from_unscaled(126.as_()));
+    }
+}
@@ -0,0 +1,110 @@
+//! Modules for choosing how [`Integer`](crate::Integer)s are represented in [`serde`](https://docs.rs/serde/latest/serde/), for use with the `#[serde(with = "...")]` field attribute.
+//!
+//! By default, `Integer`s are serialized as decimal strings in human-readable formats (such as JSON), and as their minimal big-endian bytes in binary formats. A string is used rather than a number since most formats cannot represent integers wider than 128 bits. The modules here can be used to choose one of these representations regardless of the format.
+//!
+//! Whichever representation is chosen, self-describing formats can deserialize an `Integer` from a number, a decimal string, a hexadecimal string prefixed with `0x`, or big-endian bytes.
+//!
+//! The `serde` feature must be enabled to use items from this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use bnum::prelude::*;
+//! use bnum::types::{I256, U256};
+//! use serde::{Deserialize, Serialize};
+//! use serde_test::{assert_tokens, Configure, Token};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Balance {
+//!     owner: U256,
+//!     #[serde(with = "bnum::serde::hex")]
+//!     amount: I256,
+//! }
+//!
+//! let balance = Balance {
+//!     owner: n!(1234567890123456789012345678901234567890),
+//!     amount: n!(-0x1F),
+//! };
+//! assert_tokens(&balance.readable(), &[
+//!     Token::Struct { name: "Balance", len: 2 },
+//!     Token::Str("owner"),
+//!     Token::Str("1234567890123456789012345678901234567890"),
+//!     Token::Str("amount"),
+//!     Token::Str("-0x1f"),
+//!     Token::StructEnd,
+//! ]);
+//! ```
+
+use crate::Integer;
+use crate::integer::serde::{DecimalStr, HexStr, IntegerVisitor};
+use ::serde::{Deserializer, Serializer};
+use core::marker::PhantomData;
+
+/// Serialize an [`Integer`] as a decimal string, such as `"-1234"`.
+///
+/// Deserialization accepts a number, a decimal string, a hexadecimal string prefixed with `0x`, or big-endian bytes.
+pub mod decimal {
+    use super::*;
+
+    /// Serializes an [`Integer`] as a decimal string.
+    pub fn serialize<Ser: Serializer, const S: bool, const N: usize, const B: usize, const OM: u8>(
+        value: &Integer<S, N, B, OM>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_str(&DecimalStr(value))
+    }
+
+    /// Deserializes an [`Integer`], hinting to the format that a string is expected.
+    pub fn deserialize<'de, D: Deserializer<'de>, const S: bool, const N: usize, const B: usize, const OM: u8>(
+        deserializer: D,
+    ) -> Result<Integer<S, N, B, OM>, D::Error> {
+        deserializer.deserialize_str(IntegerVisitor(PhantomData))
+    }
+}
+
+/// Serialize an [`Integer`] as a lowercase hexadecimal string prefixed with `0x`, such as `"-0x4d2"`.
+///
+/// Deserialization accepts a number, a decimal string, a hexadecimal string prefixed with `0x`, or big-endian bytes.
+pub mod hex {
+    use super::*;
+
+    /// Serializes an [`Integer`] as a hexadecimal string prefixed with `0x`.
+    pub fn serialize<Ser: Serializer, const S: bool, const N: usize, const B: usize, const OM: u8>(
+        value: &Integer<S, N, B, OM>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error> {
+        serializer.collect_str(&HexStr(value))
+    }
+
+    /// Deserializes an [`Integer`], hinting to the format that a string is expected.
+    pub fn deserialize<'de, D: Deserializer<'de>, const S: bool, const N: usize, const B: usize, const OM: u8>(
+        deserializer: D,
+    ) -> Result<Integer<S, N, B, OM>, D::Error> {
+        deserializer.deserialize_str(IntegerVisitor(PhantomData))
+    }
+}
+
+/// Serialize an [`Integer`] as its big-endian bytes, with redundant leading bytes removed.
+///
+/// For unsigned integers, leading zero bytes are removed, so zero is serialized as no bytes. For signed integers, the bytes are in two's complement, and leading `0x00` or `0xFF` bytes are removed as long as this does not change the sign, so for example, `-1` is serialized as `[0xFF]` and `128` as `[0x00, 0x80]`.
+///
+/// Deserialization accepts big-endian bytes (or a sequence of bytes), as well as a number, a decimal string, or a hexadecimal string prefixed with `0x` in self-describing formats.
+pub mod bytes {
+    use super::*;
+
+    /// Serializes an [`Integer`] as its minimal big-endian bytes.
+    pub fn serialize<Ser: Serializer, const S: bool, const N: usize, const B: usize, const OM: u8>(
+        value: &Integer<S, N, B, OM>,
+        serializer: Ser,
+    ) -> Result<Ser::Ok, Ser::Error> {
+        let (bytes, start) = value.to_minimal_be_bytes();
+        serializer.serialize_bytes(&bytes[start..])
+    }
+
+    /// Deserializes an [`Integer`], hinting to the format that bytes are expected.
+    pub fn deserialize<'de, D: Deserializer<'de>, const S: bool, const N: usize, const B: usize, const OM: u8>(
+        deserializer: D,
+    ) -> Result<Integer<S, N, B, OM>, D::Error> {
+        deserializer.deserialize_bytes(IntegerVisitor(PhantomData))
+    }
+}
@@ -0,0 +1,79 @@
+use core::ops::{BitOr, BitOrAssign};
+
+/// A set of the exception flags specified by IEEE 754, which are raised by floating point operations to signal that the result of the operation is exceptional in some way.
+///
+/// Flags can be combined with the `|` operator, so that the flags raised by a sequence of operations can be accumulated.
+///
+/// # Examples
+///
+/// ```
+/// use bnum::FloatStatus;
+///
+/// let mut status = FloatStatus::OK;
+/// status |= FloatStatus::INEXACT;
+/// status |= FloatStatus::UNDERFLOW;
+///
+/// assert!(status.contains(FloatStatus::INEXACT));
+/// assert!(status.contains(FloatStatus::INEXACT | FloatStatus::UNDERFLOW));
+/// assert!(!status.contains(FloatStatus::OVERFLOW));
+/// assert!(!status.is_ok());
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub struct FloatStatus(u8);
+
+impl FloatStatus {
+    /// No flags are raised: the result of the operation is exact.
+    pub const OK: Self = Self(0);
+
+    /// The operation has no usefully definable result, for example `0 / 0`, `inf - inf` or the square root of a negative number, or one of the operands is a signaling NaN. The result is a quiet NaN.
+    pub const INVALID: Self = Self(1 << 0);
+
+    /// A finite non-zero number was divided by zero, so the result is an exact infinity.
+    pub const DIV_BY_ZERO: Self = Self(1 << 1);
+
+    /// The rounded result is too large in magnitude to be represented as a finite number. This flag is always raised together with [`INEXACT`](Self::INEXACT).
+    pub const OVERFLOW: Self = Self(1 << 2);
+
+    /// The exact result is non-zero and smaller in magnitude than the smallest normal number, and the rounded result is inexact. Tininess is detected before rounding. This flag is always raised together with [`INEXACT`](Self::INEXACT).
+    pub const UNDERFLOW: Self = Self(1 << 3);
+
+    /// The rounded result differs from the exact result.
+    pub const INEXACT: Self = Self(1 << 4);
+
+    /// Returns `true` if no flags are raised.
+    #[must_use]
+    #[inline]
+    pub const fn is_ok(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all of the flags raised in `flags` are also raised in `self`.
+    #[must_use]
+    #[inline]
+    pub const fn contains(self, flags: Self) -> bool {
+        self.0 & flags.0 == flags.0
+    }
+
+    /// Returns the flags raised in either `self` or `flags`.
+    #[must_use]
+    #[inline]
+    pub const fn union(self, flags: Self) -> Self {
+        Self(self.0 | flags.0)
+    }
+}
+
+impl BitOr for FloatStatus {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for FloatStatus {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
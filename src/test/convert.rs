@@ -124,7 +124,12 @@ impl TestConvert for f64 {
 
     #[inline]
     fn into(self) -> Self::Output {
-        self.to_bits()
+        // the sign and payload of a NaN result are not specified, so all NaNs are treated as equal
+        if self.is_nan() {
+            f64::NAN.to_bits()
+        } else {
+            self.to_bits()
+        }
     }
 }
 
@@ -133,33 +138,46 @@ impl TestConvert for f32 {
 
     #[inline]
     fn into(self) -> Self::Output {
-        self.to_bits()
+        // the sign and payload of a NaN result are not specified, so all NaNs are treated as equal
+        if self.is_nan() {
+            f32::NAN.to_bits()
+        } else {
+            self.to_bits()
+        }
     }
 }
 
-// #[cfg(feature = "float")]
-// impl TestConvert for crate::types::F64 {
-//     type Output = u64;
+#[cfg(feature = "float")]
+impl TestConvert for crate::types::F64 {
+    type Output = u64;
 
-//     #[inline]
-//     fn into(self) -> Self::Output {
-//         use crate::cast::As;
+    #[inline]
+    fn into(self) -> Self::Output {
+        use crate::cast::As;
 
-//         self.to_bits().as_()
-//     }
-// }
+        if self.is_nan() {
+            f64::NAN.to_bits()
+        } else {
+            self.to_bits().as_()
+        }
+    }
+}
 
-// #[cfg(feature = "float")]
-// impl TestConvert for crate::types::F32 {
-//     type Output = u32;
+#[cfg(feature = "float")]
+impl TestConvert for crate::types::F32 {
+    type Output = u32;
 
-//     #[inline]
-//     fn into(self) -> Self::Output {
-//         use crate::cast::As;
+    #[inline]
+    fn into(self) -> Self::Output {
+        use crate::cast::As;
 
-//         self.to_bits().as_()
-//     }
-// }
+        if self.is_nan() {
+            f32::NAN.to_bits()
+        } else {
+            self.to_bits().as_()
+        }
+    }
+}
 
 impl<T: TestConvert, U: TestConvert> TestConvert for (T, U) {
     type Output = (<T as TestConvert>::Output, <U as TestConvert>::Output);
@@ -170,6 +188,23 @@ impl<T: TestConvert, U: TestConvert> TestConvert for (T, U) {
     }
 }
 
+impl<T: TestConvert, U: TestConvert, V: TestConvert> TestConvert for (T, U, V) {
+    type Output = (
+        <T as TestConvert>::Output,
+        <U as TestConvert>::Output,
+        <V as TestConvert>::Output,
+    );
+
+    #[inline]
+    fn into(self) -> Self::Output {
+        (
+            TestConvert::into(self.0),
+            TestConvert::into(self.1),
+            TestConvert::into(self.2),
+        )
+    }
+}
+
 impl<T, const N: usize> TestConvert for [T; N] {
     type Output = Self;
 
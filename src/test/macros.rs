@@ -12,7 +12,10 @@ macro_rules! test_bignum {
                     })?
 
                     let (big, primitive) = $($unsafe)? {
-                        crate::test::results!(<$primitive $(as $Trait $(<$($gen), *>)?)?>::$function ($($($re)? TryInto::try_into($param).expect("test argument conversion failed")), *))
+                        crate::test::results!(
+                            <$primitive $(as $Trait $(<$($gen), *>)?)?>::$function ($($($re)? TryInto::try_into($param).expect("test argument conversion failed")), *),
+                            primitive: ($(crate::test::primitive_arg!($($re)? $param)), *)
+                        )
                     };
 
                     quickcheck::TestResult::from_bool(big == primitive)
@@ -75,15 +78,30 @@ macro_rules! test_bignum {
 
 pub(crate) use test_bignum;
 
+// arguments passed by reference are already of the primitive type, so are passed to the primitive function unconverted, since the primitive function's parameter type may be ambiguous (e.g. the `Rhs` of `PartialEq::eq`)
+macro_rules! primitive_arg {
+    (& $param: ident) => {
+        &$param
+    };
+    ($param: ident) => {
+        TryInto::try_into($param).expect("test argument conversion failed")
+    };
+}
+
+pub(crate) use primitive_arg;
+
 macro_rules! results {
     (<$primitive: ty $(as $Trait: ty)?> :: $function: ident ($($arg: expr), *)) => {
+        crate::test::results!(<$primitive $(as $Trait)?>::$function ($($arg), *), primitive: ($($arg), *))
+    };
+    (<$primitive: ty $(as $Trait: ty)?> :: $function: ident ($($arg: expr), *), primitive: ($($prim_arg: expr), *)) => {
         paste::paste! {
             {
                 let big_result = <[<$primitive:upper>] $(as $Trait)?>::$function(
                     $($arg), *
                 );
                 let prim_result = <$primitive $(as $Trait)?>::$function(
-                    $($arg), *
+                    $($prim_arg), *
                 );
 
                 use crate::test::TestConvert;
@@ -285,13 +303,13 @@ pub(crate) use primitive_with_overflow_behaviour;
 
 macro_rules! overflow_mode_int {
     (wrapping) => {
-        crate::integer::OverflowMode::Wrap as u8
+        crate::OverflowMode::Wrap as u8
     };
     (saturating) => {
-        crate::integer::OverflowMode::Saturate as u8
+        crate::OverflowMode::Saturate as u8
     };
     () => {
-        crate::integer::OverflowMode::DEFAULT as u8
+        crate::OverflowMode::DEFAULT as u8
     };
 }
 
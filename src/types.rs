@@ -42,29 +42,53 @@ macro_rules! call_types_macro {
 
 call_types_macro!(int_types);
 
-// #[cfg(feature = "float")]
-// /// 16-bit floating point type with 10 bits of precision, stored as the binary16 (half precision) format defined in IEEE 754-2019.
-// pub type F16 = crate::Float<2, 10>;
+#[cfg(feature = "float")]
+/// 16-bit floating point type with 10 bits of precision, stored as the binary16 (half precision) format defined in IEEE 754-2019.
+pub type F16 = crate::Float<2, 10>;
 
-// #[cfg(feature = "float")]
-// /// 32-bit floating point type with 23 bits of precision, stored as the binary32 (single precision) format defined in IEEE 754-2019.
-// pub type F32 = crate::Float<4, 23>;
+#[cfg(feature = "float")]
+/// 32-bit floating point type with 23 bits of precision, stored as the binary32 (single precision) format defined in IEEE 754-2019.
+pub type F32 = crate::Float<4, 23>;
 
-// #[cfg(feature = "float")]
-// /// 64-bit floating point type with 52 bits of precision, stored as the binary64 (double precision) format defined in IEEE 754-2019.
-// pub type F64 = crate::Float<8, 52>;
+#[cfg(feature = "float")]
+/// 64-bit floating point type with 52 bits of precision, stored as the binary64 (double precision) format defined in IEEE 754-2019.
+pub type F64 = crate::Float<8, 52>;
 
-// #[cfg(feature = "float")]
-// /// 80-bit floating point type with 64 bits of precision.
-// pub type F80 = crate::Float<10, 64>;
+#[cfg(feature = "float")]
+/// 80-bit floating point type with 64 bits of precision.
+pub type F80 = crate::Float<10, 64>;
 
-// #[cfg(feature = "float")]
-// /// 128-bit floating point type with 112 bits of precision, stored as the binary128 (quadruple precision) format defined in IEEE 754-2019.
-// pub type F128 = crate::Float<16, 112>;
+#[cfg(feature = "float")]
+/// 128-bit floating point type with 112 bits of precision, stored as the binary128 (quadruple precision) format defined in IEEE 754-2019.
+pub type F128 = crate::Float<16, 112>;
 
-// #[cfg(feature = "float")]
-// /// 256-bit floating point type with 236 bits of precision, stored as the binary256 (octuple precision) format defined in IEEE 754-2019.
-// pub type F256 = crate::Float<32, 236>;
+#[cfg(feature = "float")]
+/// 256-bit floating point type with 236 bits of precision, stored as the binary256 (octuple precision) format defined in IEEE 754-2019.
+pub type F256 = crate::Float<32, 236>;
+
+#[cfg(feature = "float")]
+/// 16-bit brain floating point type with 7 bits of precision, stored as the bfloat16 format (the upper 16 bits of an IEEE 754 binary32).
+pub type BF16 = crate::minifloat::MiniFloat<crate::minifloat::BFloat16>;
+
+#[cfg(feature = "float")]
+/// 8-bit floating point type with 3 bits of precision and no infinities, stored as the E4M3 format defined in the OCP 8-bit floating point specification.
+pub type F8E4M3 = crate::minifloat::MiniFloat<crate::minifloat::E4M3>;
+
+#[cfg(feature = "float")]
+/// 8-bit floating point type with 2 bits of precision, stored as the E5M2 format defined in the OCP 8-bit floating point specification.
+pub type F8E5M2 = crate::minifloat::MiniFloat<crate::minifloat::E5M2>;
+
+#[cfg(feature = "decimal")]
+/// 32-bit decimal floating point type with 7 digits of precision, stored as the decimal32 format defined in IEEE 754-2019.
+pub type D32 = crate::Decimal<4>;
+
+#[cfg(feature = "decimal")]
+/// 64-bit decimal floating point type with 16 digits of precision, stored as the decimal64 format defined in IEEE 754-2019.
+pub type D64 = crate::Decimal<8>;
+
+#[cfg(feature = "decimal")]
+/// 128-bit decimal floating point type with 34 digits of precision, stored as the decimal128 format defined in IEEE 754-2019.
+pub type D128 = crate::Decimal<16>;
 
 
 #[cfg(test)]
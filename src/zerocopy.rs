@@ -0,0 +1,185 @@
+//! Reading [`Integer`]s from bytes without copying via the [`zerocopy`](https://docs.rs/zerocopy/latest/zerocopy/) crate.
+//!
+//! `Integer`s implement zerocopy's `KnownLayout`, `Immutable`, `IntoBytes` and `Unaligned` traits directly. zerocopy only supports implementing its `TryFromBytes`, `FromZeros` and `FromBytes` traits via its derive macros, which would implement them for every bit width, and would not check the unused high bits of the last byte when `B` is not `0` (these must be zero for unsigned integers and copies of the sign bit for signed integers). The [`IntegerBytes`] wrapper in this module is used instead: it implements all of zerocopy's conversion traits, including `FromBytes`, and can be viewed as an `Integer` without copying. When `B` is `0`, every bit pattern is a valid `Integer`, so this view is infallible; for other bit widths, the unused bits are checked first.
+//!
+//! The `zerocopy` feature must be enabled to use items from this module.
+//!
+//! # Examples
+//!
+//! ```
+//! use bnum::prelude::*;
+//! use bnum::types::U256;
+//! use bnum::zerocopy::IntegerBytes;
+//! use bnum::Uint;
+//! use zerocopy::FromBytes;
+//!
+//! let mut buffer = [0u8; 64];
+//! buffer[0] = 42;
+//! buffer[32..].fill(0xFF);
+//!
+//! let bytes = <[IntegerBytes<false, 32>]>::ref_from_bytes(&buffer).unwrap();
+//! let ints: &[U256] = IntegerBytes::as_integers(bytes);
+//! assert_eq!(ints, [n!(42), U256::MAX]);
+//!
+//! // 20-bit integers, whose unused high bits must be zero
+//! let bytes = <[IntegerBytes<false, 3, 20>]>::ref_from_bytes(&buffer[..6]).unwrap();
+//! assert_eq!(IntegerBytes::try_as_integers(bytes), Some(&[n!(42), Uint::MIN][..]));
+//! let bytes = IntegerBytes::<false, 3, 20>::ref_from_bytes(&buffer[31..34]).unwrap();
+//! assert_eq!(bytes.try_as_integer(), None);
+//! ```
+
+use crate::{Integer, OverflowMode};
+use ::zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned};
+
+/// The `N` little-endian bytes of an [`Integer<S, N, B, OM>`], which are not necessarily a valid value of that type.
+///
+/// `IntegerBytes` implements all of zerocopy's conversion traits, so can be read from any bytes without copying, and then viewed as an `Integer` (see the [module-level documentation](self)).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, FromBytes, IntoBytes, KnownLayout, Immutable, Unaligned)]
+#[repr(transparent)]
+pub struct IntegerBytes<const S: bool, const N: usize, const B: usize = 0, const OM: u8 = { OverflowMode::DEFAULT as u8 }>(pub [u8; N]);
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> IntegerBytes<S, N, B, OM> {
+    /// Returns the integer represented by `self`, or `None` if the unused high bits of the last byte are not zero for unsigned integers, or not copies of the sign bit for signed integers. This always returns `Some` if `B` is `0`.
+    #[must_use]
+    #[inline]
+    pub const fn try_get(self) -> Option<Integer<S, N, B, OM>> {
+        Integer::from_le_slice(&self.0)
+    }
+
+    /// Returns a reference to `self` viewed as an integer, or `None` if `self` does not represent a valid integer, as described in [`try_get`](Self::try_get).
+    #[must_use]
+    #[inline]
+    pub const fn try_as_integer(&self) -> Option<&Integer<S, N, B, OM>> {
+        if self.try_get().is_none() {
+            return None;
+        }
+        // SAFETY: `Integer` and `IntegerBytes` are both `#[repr(transparent)]` over `[u8; N]`, and the bytes have been checked to be a valid value of `Integer`
+        Some(unsafe { &*(self as *const Self).cast() })
+    }
+
+    /// Returns a reference to `slice` viewed as a slice of integers, or `None` if any of its elements do not represent a valid integer, as described in [`try_get`](Self::try_get).
+    #[must_use]
+    #[inline]
+    pub const fn try_as_integers(slice: &[Self]) -> Option<&[Integer<S, N, B, OM>]> {
+        let mut i = 0;
+        while i < slice.len() {
+            if slice[i].try_get().is_none() {
+                return None;
+            }
+            i += 1;
+        }
+        // SAFETY: `Integer` and `IntegerBytes` are both `#[repr(transparent)]` over `[u8; N]`, so slices of them have the same layout, and every element has been checked to be a valid value of `Integer`
+        Some(unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) })
+    }
+}
+
+impl<const S: bool, const N: usize, const OM: u8> IntegerBytes<S, N, 0, OM> {
+    /// Returns the integer represented by `self`. Since `B` is `0`, every bit pattern is a valid integer.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> Integer<S, N, 0, OM> {
+        Integer::from_le_bytes(self.0)
+    }
+
+    /// Returns a reference to `self` viewed as an integer.
+    #[must_use]
+    #[inline]
+    pub const fn as_integer(&self) -> &Integer<S, N, 0, OM> {
+        // SAFETY: `Integer` and `IntegerBytes` are both `#[repr(transparent)]` over `[u8; N]`, and when `B` is `0` every bit pattern is a valid value of `Integer`
+        unsafe { &*(self as *const Self).cast() }
+    }
+
+    /// Returns a mutable reference to `self` viewed as an integer.
+    #[must_use]
+    #[inline]
+    pub const fn as_integer_mut(&mut self) -> &mut Integer<S, N, 0, OM> {
+        // SAFETY: `Integer` and `IntegerBytes` are both `#[repr(transparent)]` over `[u8; N]`, and when `B` is `0` every bit pattern is a valid value of both types
+        unsafe { &mut *(self as *mut Self).cast() }
+    }
+
+    /// Returns a reference to `slice` viewed as a slice of integers.
+    #[must_use]
+    #[inline]
+    pub const fn as_integers(slice: &[Self]) -> &[Integer<S, N, 0, OM>] {
+        // SAFETY: `Integer` and `IntegerBytes` are both `#[repr(transparent)]` over `[u8; N]`, so slices of them have the same layout, and when `B` is `0` every bit pattern is a valid value of `Integer`
+        unsafe { core::slice::from_raw_parts(slice.as_ptr().cast(), slice.len()) }
+    }
+
+    /// Returns a mutable reference to `slice` viewed as a slice of integers.
+    #[must_use]
+    #[inline]
+    pub const fn as_integers_mut(slice: &mut [Self]) -> &mut [Integer<S, N, 0, OM>] {
+        // SAFETY: `Integer` and `IntegerBytes` are both `#[repr(transparent)]` over `[u8; N]`, so slices of them have the same layout, and when `B` is `0` every bit pattern is a valid value of both types
+        unsafe { core::slice::from_raw_parts_mut(slice.as_mut_ptr().cast(), slice.len()) }
+    }
+}
+
+impl<const S: bool, const N: usize, const B: usize, const OM: u8> From<Integer<S, N, B, OM>> for IntegerBytes<S, N, B, OM> {
+    #[inline]
+    fn from(value: Integer<S, N, B, OM>) -> Self {
+        Self(value.to_bytes())
+    }
+}
+
+impl<const S: bool, const N: usize, const OM: u8> From<IntegerBytes<S, N, 0, OM>> for Integer<S, N, 0, OM> {
+    #[inline]
+    fn from(value: IntegerBytes<S, N, 0, OM>) -> Self {
+        value.get()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IntegerBytes;
+    use crate::cast::As;
+    use crate::types::{I256, U256};
+    use crate::{Int, Uint};
+    use zerocopy::{FromBytes, FromZeros, IntoBytes};
+
+    /// 20-bit signed integer
+    type I20 = Int<3, 20>;
+
+    /// 20-bit unsigned integer
+    type U20 = Uint<3, 20>;
+
+    #[test]
+    fn from_bytes() {
+        let mut buffer = [0u8; 64];
+        buffer[0] = 0x01;
+        buffer[32..].fill(0xFF);
+        let bytes = <[IntegerBytes<true, 32>]>::ref_from_bytes(&buffer).unwrap();
+        assert_eq!(IntegerBytes::as_integers(bytes), [1.as_::<I256>(), (-1).as_()]);
+        assert_eq!(IntegerBytes::try_as_integers(bytes), Some(&[1.as_::<I256>(), (-1).as_()][..]));
+        let (bytes, rest) = IntegerBytes::<false, 32>::ref_from_prefix(&buffer[32..]).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(*bytes.as_integer(), U256::MAX);
+        assert_eq!(IntegerBytes::<false, 32>::new_zeroed().get(), U256::MIN);
+
+        let mut bytes = IntegerBytes::<false, 32>::read_from_bytes(&buffer[..32]).unwrap();
+        *bytes.as_integer_mut() += U256::ONE;
+        assert_eq!(bytes.as_bytes()[0], 0x02);
+    }
+
+    #[test]
+    fn padding() {
+        assert_eq!(IntegerBytes::<false, 3, 20>([0xFF, 0xFF, 0x0F]).try_get(), Some(U20::MAX));
+        assert_eq!(IntegerBytes::<false, 3, 20>([0xFF, 0xFF, 0x1F]).try_get(), None);
+        assert_eq!(IntegerBytes::<true, 3, 20>([0x00, 0x00, 0xF8]).try_as_integer(), Some(&I20::MIN));
+        assert_eq!(IntegerBytes::<true, 3, 20>([0xFF, 0xFF, 0x07]).try_as_integer(), Some(&I20::MAX));
+        // the padding bits must be copies of the sign bit
+        assert_eq!(IntegerBytes::<true, 3, 20>([0x00, 0x00, 0x08]).try_as_integer(), None);
+        assert_eq!(IntegerBytes::<true, 3, 20>([0xFF, 0xFF, 0xF7]).try_get(), None);
+
+        let bytes = [IntegerBytes::from(U20::MAX), IntegerBytes([0x00, 0x00, 0x10])];
+        assert_eq!(IntegerBytes::try_as_integers(&bytes[..1]), Some(&[U20::MAX][..]));
+        assert_eq!(IntegerBytes::try_as_integers(&bytes), None);
+    }
+
+    quickcheck::quickcheck! {
+        fn quickcheck_round_trip(a: U256, b: I20) -> bool {
+            IntegerBytes::from(a).get() == a
+                && IntegerBytes::from(a).as_bytes() == a.to_bytes()
+                && IntegerBytes::from(b).try_get() == Some(b)
+        }
+    }
+}